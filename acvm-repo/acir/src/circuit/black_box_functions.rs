@@ -47,6 +47,18 @@ pub enum BlackBoxFunc {
     /// Compute a recursive aggregation object when verifying a proof inside another circuit.
     /// This outputted aggregation object will then be either checked in a top-level verifier or aggregated upon again.
     RecursiveAggregation,
+    /// Addition over the bigint field with the modulus of its operands.
+    BigIntAdd,
+    /// Subtraction over the bigint field with the modulus of its operands.
+    BigIntSub,
+    /// Multiplication over the bigint field with the modulus of its operands.
+    BigIntMul,
+    /// Division over the bigint field with the modulus of its operands.
+    BigIntDiv,
+    /// Creates a bigint from its little-endian bytes and the bytes of its modulus.
+    BigIntFromLeBytes,
+    /// Returns the little-endian bytes of a bigint.
+    BigIntToLeBytes,
 }
 
 impl std::fmt::Display for BlackBoxFunc {
@@ -72,6 +84,12 @@ impl BlackBoxFunc {
             BlackBoxFunc::Keccak256 => "keccak256",
             BlackBoxFunc::RecursiveAggregation => "recursive_aggregation",
             BlackBoxFunc::EcdsaSecp256r1 => "ecdsa_secp256r1",
+            BlackBoxFunc::BigIntAdd => "bigint_add",
+            BlackBoxFunc::BigIntSub => "bigint_sub",
+            BlackBoxFunc::BigIntMul => "bigint_mul",
+            BlackBoxFunc::BigIntDiv => "bigint_div",
+            BlackBoxFunc::BigIntFromLeBytes => "bigint_from_le_bytes",
+            BlackBoxFunc::BigIntToLeBytes => "bigint_to_le_bytes",
         }
     }
     pub fn lookup(op_name: &str) -> Option<BlackBoxFunc> {
@@ -90,6 +108,12 @@ impl BlackBoxFunc {
             "range" => Some(BlackBoxFunc::RANGE),
             "keccak256" => Some(BlackBoxFunc::Keccak256),
             "recursive_aggregation" => Some(BlackBoxFunc::RecursiveAggregation),
+            "bigint_add" => Some(BlackBoxFunc::BigIntAdd),
+            "bigint_sub" => Some(BlackBoxFunc::BigIntSub),
+            "bigint_mul" => Some(BlackBoxFunc::BigIntMul),
+            "bigint_div" => Some(BlackBoxFunc::BigIntDiv),
+            "bigint_from_le_bytes" => Some(BlackBoxFunc::BigIntFromLeBytes),
+            "bigint_to_le_bytes" => Some(BlackBoxFunc::BigIntToLeBytes),
             _ => None,
         }
     }
@@ -0,0 +1,54 @@
+use acir::{
+    circuit::opcodes::FunctionInput,
+    native_types::{Witness, WitnessMap},
+    BlackBoxFunc, FieldElement,
+};
+use acvm_blackbox_solver::BigIntSolver;
+
+use crate::pwg::{insert_value, witness_to_value};
+use crate::OpcodeResolutionError;
+
+/// Attempts to solve a `BigIntFromLeBytes` opcode by reading one byte from each input.
+pub(super) fn solve_bigint_from_le_bytes(
+    initial_witness: &WitnessMap,
+    bigint_solver: &mut BigIntSolver,
+    inputs: &[FunctionInput],
+    modulus: &[u8],
+    output: u32,
+) -> Result<(), OpcodeResolutionError> {
+    let mut bytes = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let value = witness_to_value(initial_witness, input.witness)?;
+        bytes.push(value.to_u128() as u8);
+    }
+    bigint_solver.bigint_from_bytes(&bytes, modulus, output)?;
+    Ok(())
+}
+
+/// Attempts to solve a `BigIntToLeBytes` opcode.
+/// If successful, `initial_witness` will contain the bytes of the bigint, padded with zeroes
+/// up to the number of outputs.
+pub(super) fn solve_bigint_to_le_bytes(
+    initial_witness: &mut WitnessMap,
+    bigint_solver: &BigIntSolver,
+    input: u32,
+    outputs: &[Witness],
+) -> Result<(), OpcodeResolutionError> {
+    let mut bytes = bigint_solver.bigint_to_bytes(input)?;
+    if bytes.len() > outputs.len() {
+        return Err(OpcodeResolutionError::BlackBoxFunctionFailed(
+            BlackBoxFunc::BigIntToLeBytes,
+            format!(
+                "bigint needs {} bytes but only {} outputs were given",
+                bytes.len(),
+                outputs.len()
+            ),
+        ));
+    }
+    bytes.resize(outputs.len(), 0);
+
+    for (output, byte) in outputs.iter().zip(bytes) {
+        insert_value(output, FieldElement::from(byte as u128), initial_witness)?;
+    }
+    Ok(())
+}
@@ -3,13 +3,14 @@ use acir::{
     native_types::{Witness, WitnessMap},
     FieldElement,
 };
-use acvm_blackbox_solver::{blake2s, keccak256, sha256};
+use acvm_blackbox_solver::{blake2s, keccak256, sha256, BigIntSolver};
 
 use self::pedersen::pedersen_hash;
 
 use super::{insert_value, OpcodeNotSolvable, OpcodeResolutionError};
 use crate::BlackBoxFunctionSolver;
 
+mod bigint;
 mod fixed_base_scalar_mul;
 mod hash;
 mod logic;
@@ -17,6 +18,7 @@ mod pedersen;
 mod range;
 mod signature;
 
+use bigint::{solve_bigint_from_le_bytes, solve_bigint_to_le_bytes};
 use fixed_base_scalar_mul::fixed_base_scalar_mul;
 // Hash functions should eventually be exposed for external consumers.
 use hash::{solve_generic_256_hash_opcode, solve_hash_to_field};
@@ -53,6 +55,7 @@ pub(crate) fn solve(
     backend: &impl BlackBoxFunctionSolver,
     initial_witness: &mut WitnessMap,
     bb_func: &BlackBoxFuncCall,
+    bigint_solver: &mut BigIntSolver,
 ) -> Result<(), OpcodeResolutionError> {
     let inputs = bb_func.get_inputs_vec();
     if !contains_all_inputs(initial_witness, &inputs) {
@@ -164,5 +167,18 @@ pub(crate) fn solve(
             }
             Ok(())
         }
+        BlackBoxFuncCall::BigIntAdd { lhs, rhs, output }
+        | BlackBoxFuncCall::BigIntSub { lhs, rhs, output }
+        | BlackBoxFuncCall::BigIntMul { lhs, rhs, output }
+        | BlackBoxFuncCall::BigIntDiv { lhs, rhs, output } => {
+            bigint_solver.bigint_op(*lhs, *rhs, *output, bb_func.get_black_box_func())?;
+            Ok(())
+        }
+        BlackBoxFuncCall::BigIntFromLeBytes { inputs, modulus, output } => {
+            solve_bigint_from_le_bytes(initial_witness, bigint_solver, inputs, modulus, *output)
+        }
+        BlackBoxFuncCall::BigIntToLeBytes { input, outputs } => {
+            solve_bigint_to_le_bytes(initial_witness, bigint_solver, *input, outputs)
+        }
     }
 }
@@ -8,7 +8,7 @@ use acir::{
     native_types::{Expression, Witness, WitnessMap},
     BlackBoxFunc, FieldElement,
 };
-use acvm_blackbox_solver::BlackBoxResolutionError;
+use acvm_blackbox_solver::{BigIntSolver, BlackBoxResolutionError};
 
 use self::{arithmetic::ExpressionSolver, directives::solve_directives, memory_op::MemoryOpSolver};
 use crate::BlackBoxFunctionSolver;
@@ -140,6 +140,9 @@ pub struct ACVM<'a, B: BlackBoxFunctionSolver> {
     witness_map: WitnessMap,
 
     brillig_solver: Option<BrilligSolver<'a, B>>,
+
+    /// Stores the values of the bigints created by the bigint black box functions.
+    bigint_solver: BigIntSolver,
 }
 
 impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
@@ -153,6 +156,7 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
             instruction_pointer: 0,
             witness_map: initial_witness,
             brillig_solver: None,
+            bigint_solver: BigIntSolver::default(),
         }
     }
 
@@ -254,9 +258,12 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
 
         let resolution = match opcode {
             Opcode::AssertZero(expr) => ExpressionSolver::solve(&mut self.witness_map, expr),
-            Opcode::BlackBoxFuncCall(bb_func) => {
-                blackbox::solve(self.backend, &mut self.witness_map, bb_func)
-            }
+            Opcode::BlackBoxFuncCall(bb_func) => blackbox::solve(
+                self.backend,
+                &mut self.witness_map,
+                bb_func,
+                &mut self.bigint_solver,
+            ),
             Opcode::Directive(directive) => solve_directives(&mut self.witness_map, directive),
             Opcode::MemoryInit { block_id, init } => {
                 let solver = self.block_solvers.entry(*block_id).or_default();
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use acir::BlackBoxFunc;
+use num_bigint::BigUint;
+
+use crate::BlackBoxResolutionError;
+
+/// Resolves the bigint opcodes of ACIR and Brillig.
+///
+/// Bigints are not stored in witnesses but are referred to by an id. The solver keeps the value
+/// of each bigint along with the modulus of the field which it is an element of.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct BigIntSolver {
+    bigint_id_to_value: HashMap<u32, BigUint>,
+    bigint_id_to_modulus: HashMap<u32, BigUint>,
+}
+
+impl BigIntSolver {
+    pub fn get_bigint(
+        &self,
+        id: u32,
+        func: BlackBoxFunc,
+    ) -> Result<BigUint, BlackBoxResolutionError> {
+        self.bigint_id_to_value
+            .get(&id)
+            .ok_or_else(|| {
+                BlackBoxResolutionError::Failed(func, format!("could not find bigint of id {id}"))
+            })
+            .cloned()
+    }
+
+    pub fn get_modulus(
+        &self,
+        id: u32,
+        func: BlackBoxFunc,
+    ) -> Result<BigUint, BlackBoxResolutionError> {
+        self.bigint_id_to_modulus
+            .get(&id)
+            .ok_or_else(|| {
+                BlackBoxResolutionError::Failed(
+                    func,
+                    format!("could not find the modulus of bigint of id {id}"),
+                )
+            })
+            .cloned()
+    }
+
+    /// Creates the bigint `output` from its little-endian bytes, reduced by the given modulus.
+    pub fn bigint_from_bytes(
+        &mut self,
+        inputs: &[u8],
+        modulus: &[u8],
+        output: u32,
+    ) -> Result<(), BlackBoxResolutionError> {
+        let modulus = BigUint::from_bytes_le(modulus);
+        if modulus <= BigUint::from(1_u32) {
+            return Err(BlackBoxResolutionError::Failed(
+                BlackBoxFunc::BigIntFromLeBytes,
+                format!("invalid bigint modulus {modulus}"),
+            ));
+        }
+        let value = BigUint::from_bytes_le(inputs) % &modulus;
+        self.bigint_id_to_value.insert(output, value);
+        self.bigint_id_to_modulus.insert(output, modulus);
+        Ok(())
+    }
+
+    /// Returns the little-endian bytes of the bigint `input`.
+    pub fn bigint_to_bytes(&self, input: u32) -> Result<Vec<u8>, BlackBoxResolutionError> {
+        let value = self.get_bigint(input, BlackBoxFunc::BigIntToLeBytes)?;
+        Ok(value.to_bytes_le())
+    }
+
+    /// Computes `output` by applying the operation `func` to `lhs` and `rhs`.
+    ///
+    /// Both operands must share the same modulus. Division multiplies by the inverse of `rhs`,
+    /// which is computed using Fermat's little theorem and so requires the modulus to be prime.
+    pub fn bigint_op(
+        &mut self,
+        lhs: u32,
+        rhs: u32,
+        output: u32,
+        func: BlackBoxFunc,
+    ) -> Result<(), BlackBoxResolutionError> {
+        let modulus = self.get_modulus(lhs, func)?;
+        if self.get_modulus(rhs, func)? != modulus {
+            return Err(BlackBoxResolutionError::Failed(
+                func,
+                "bigint operands have different moduli".to_string(),
+            ));
+        }
+        let lhs = self.get_bigint(lhs, func)?;
+        let rhs = self.get_bigint(rhs, func)?;
+
+        let result = match func {
+            BlackBoxFunc::BigIntAdd => lhs + rhs,
+            BlackBoxFunc::BigIntSub => lhs + &modulus - rhs,
+            BlackBoxFunc::BigIntMul => lhs * rhs,
+            BlackBoxFunc::BigIntDiv => {
+                if rhs == BigUint::from(0_u32) {
+                    return Err(BlackBoxResolutionError::Failed(
+                        func,
+                        "attempted to divide by zero".to_string(),
+                    ));
+                }
+                let exponent = &modulus - BigUint::from(2_u32);
+                lhs * rhs.modpow(&exponent, &modulus)
+            }
+            _ => unreachable!("ICE - bigint_op must be called for an operation"),
+        } % &modulus;
+
+        self.bigint_id_to_value.insert(output, result);
+        self.bigint_id_to_modulus.insert(output, modulus);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acir::BlackBoxFunc;
+
+    use super::BigIntSolver;
+
+    #[test]
+    fn arithmetic_is_reduced_by_the_modulus() {
+        let modulus = [7];
+        let mut solver = BigIntSolver::default();
+        solver.bigint_from_bytes(&[5], &modulus, 0).unwrap();
+        solver.bigint_from_bytes(&[3], &modulus, 1).unwrap();
+
+        solver.bigint_op(0, 1, 2, BlackBoxFunc::BigIntAdd).unwrap();
+        assert_eq!(solver.bigint_to_bytes(2).unwrap(), vec![1]);
+
+        solver.bigint_op(1, 0, 3, BlackBoxFunc::BigIntSub).unwrap();
+        assert_eq!(solver.bigint_to_bytes(3).unwrap(), vec![5]);
+
+        solver.bigint_op(0, 1, 4, BlackBoxFunc::BigIntMul).unwrap();
+        assert_eq!(solver.bigint_to_bytes(4).unwrap(), vec![1]);
+
+        // 5 / 3 = 5 * 5 = 4 (mod 7)
+        solver.bigint_op(0, 1, 5, BlackBoxFunc::BigIntDiv).unwrap();
+        assert_eq!(solver.bigint_to_bytes(5).unwrap(), vec![4]);
+    }
+
+    #[test]
+    fn rejects_operands_with_different_moduli() {
+        let mut solver = BigIntSolver::default();
+        solver.bigint_from_bytes(&[1], &[7], 0).unwrap();
+        solver.bigint_from_bytes(&[1], &[11], 1).unwrap();
+
+        assert!(solver.bigint_op(0, 1, 2, BlackBoxFunc::BigIntAdd).is_err());
+    }
+}
@@ -14,6 +14,10 @@ use sha2::Sha256;
 use sha3::Keccak256;
 use thiserror::Error;
 
+mod bigint;
+
+pub use bigint::BigIntSolver;
+
 #[derive(Clone, PartialEq, Eq, Debug, Error)]
 pub enum BlackBoxResolutionError {
     #[error("failed to solve blackbox function: {0}, reason: {1}")]
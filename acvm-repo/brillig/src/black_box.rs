@@ -47,4 +47,16 @@ pub enum BlackBoxOp {
     PedersenHash { inputs: HeapVector, domain_separator: RegisterIndex, output: RegisterIndex },
     /// Performs scalar multiplication over the embedded curve.
     FixedBaseScalarMul { low: RegisterIndex, high: RegisterIndex, result: HeapArray },
+    /// Adds two bigints which share the same modulus.
+    BigIntAdd { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Subtracts two bigints which share the same modulus.
+    BigIntSub { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Multiplies two bigints which share the same modulus.
+    BigIntMul { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Divides two bigints which share the same modulus.
+    BigIntDiv { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Creates a bigint from its little-endian bytes and the little-endian bytes of its modulus.
+    BigIntFromLeBytes { inputs: HeapVector, modulus: HeapVector, output: RegisterIndex },
+    /// Writes the little-endian bytes of a bigint.
+    BigIntToLeBytes { input: RegisterIndex, output: HeapVector },
 }
@@ -1,8 +1,8 @@
-use acir::brillig::{BlackBoxOp, HeapArray, HeapVector, Value};
+use acir::brillig::{BlackBoxOp, HeapArray, HeapVector, RegisterIndex, Value};
 use acir::{BlackBoxFunc, FieldElement};
 use acvm_blackbox_solver::{
     blake2s, ecdsa_secp256k1_verify, ecdsa_secp256r1_verify, hash_to_field_128_security, keccak256,
-    sha256, BlackBoxFunctionSolver, BlackBoxResolutionError,
+    sha256, BigIntSolver, BlackBoxFunctionSolver, BlackBoxResolutionError,
 };
 
 use crate::{Memory, Registers};
@@ -39,11 +39,53 @@ fn to_value_vec(input: &[u8]) -> Vec<Value> {
     input.iter().map(|x| Value::from(*x as usize)).collect()
 }
 
+/// Wraps a [BigIntSolver] to assign ids to the bigints created during execution,
+/// as unlike in ACIR these are not known when the bytecode is generated.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BrilligBigintSolver {
+    bigint_solver: BigIntSolver,
+    last_id: u32,
+}
+
+impl BrilligBigintSolver {
+    fn create_bigint_id(&mut self) -> u32 {
+        let id = self.last_id;
+        self.last_id += 1;
+        id
+    }
+
+    fn bigint_op(
+        &mut self,
+        lhs: u32,
+        rhs: u32,
+        func: BlackBoxFunc,
+    ) -> Result<u32, BlackBoxResolutionError> {
+        let id = self.create_bigint_id();
+        self.bigint_solver.bigint_op(lhs, rhs, id, func)?;
+        Ok(id)
+    }
+
+    fn bigint_from_bytes(
+        &mut self,
+        inputs: &[u8],
+        modulus: &[u8],
+    ) -> Result<u32, BlackBoxResolutionError> {
+        let id = self.create_bigint_id();
+        self.bigint_solver.bigint_from_bytes(inputs, modulus, id)?;
+        Ok(id)
+    }
+}
+
+fn read_bigint_id(registers: &Registers, register: RegisterIndex) -> u32 {
+    registers.get(register).to_u128() as u32
+}
+
 pub(crate) fn evaluate_black_box<Solver: BlackBoxFunctionSolver>(
     op: &BlackBoxOp,
     solver: &Solver,
     registers: &mut Registers,
     memory: &mut Memory,
+    bigint_solver: &mut BrilligBigintSolver,
 ) -> Result<(), BlackBoxResolutionError> {
     match op {
         BlackBoxOp::Sha256 { message, output } => {
@@ -175,6 +217,44 @@ pub(crate) fn evaluate_black_box<Solver: BlackBoxFunctionSolver>(
             registers.set(*output, hash.into());
             Ok(())
         }
+        BlackBoxOp::BigIntAdd { lhs, rhs, output }
+        | BlackBoxOp::BigIntSub { lhs, rhs, output }
+        | BlackBoxOp::BigIntMul { lhs, rhs, output }
+        | BlackBoxOp::BigIntDiv { lhs, rhs, output } => {
+            let func = match op {
+                BlackBoxOp::BigIntAdd { .. } => BlackBoxFunc::BigIntAdd,
+                BlackBoxOp::BigIntSub { .. } => BlackBoxFunc::BigIntSub,
+                BlackBoxOp::BigIntMul { .. } => BlackBoxFunc::BigIntMul,
+                BlackBoxOp::BigIntDiv { .. } => BlackBoxFunc::BigIntDiv,
+                _ => unreachable!(),
+            };
+            let lhs = read_bigint_id(registers, *lhs);
+            let rhs = read_bigint_id(registers, *rhs);
+            let id = bigint_solver.bigint_op(lhs, rhs, func)?;
+            registers.set(*output, Value::from(id as usize));
+            Ok(())
+        }
+        BlackBoxOp::BigIntFromLeBytes { inputs, modulus, output } => {
+            let inputs = to_u8_vec(read_heap_vector(memory, registers, inputs));
+            let modulus = to_u8_vec(read_heap_vector(memory, registers, modulus));
+            let id = bigint_solver.bigint_from_bytes(&inputs, &modulus)?;
+            registers.set(*output, Value::from(id as usize));
+            Ok(())
+        }
+        BlackBoxOp::BigIntToLeBytes { input, output } => {
+            let input = read_bigint_id(registers, *input);
+            let mut bytes = bigint_solver.bigint_solver.bigint_to_bytes(input)?;
+            let num_bytes = registers.get(output.size).to_usize();
+            if bytes.len() > num_bytes {
+                return Err(BlackBoxResolutionError::Failed(
+                    BlackBoxFunc::BigIntToLeBytes,
+                    format!("bigint needs {} bytes but the output has {num_bytes}", bytes.len()),
+                ));
+            }
+            bytes.resize(num_bytes, 0);
+            memory.write_slice(registers.get(output.pointer).to_usize(), &to_value_vec(&bytes));
+            Ok(())
+        }
     }
 }
 
@@ -183,7 +263,7 @@ mod test {
     use acir::brillig::BlackBoxOp;
 
     use crate::{
-        black_box::{evaluate_black_box, to_u8_vec, to_value_vec},
+        black_box::{evaluate_black_box, to_u8_vec, to_value_vec, BrilligBigintSolver},
         DummyBlackBoxSolver, HeapArray, HeapVector, Memory, Registers, Value,
     };
 
@@ -210,7 +290,14 @@ mod test {
             output: HeapArray { pointer: 2.into(), size: 32 },
         };
 
-        evaluate_black_box(&op, &DummyBlackBoxSolver, &mut registers, &mut memory).unwrap();
+        evaluate_black_box(
+            &op,
+            &DummyBlackBoxSolver,
+            &mut registers,
+            &mut memory,
+            &mut BrilligBigintSolver::default(),
+        )
+        .unwrap();
 
         let result = memory.read_slice(result_pointer, 32);
 
@@ -26,7 +26,7 @@ mod registers;
 
 use acvm_blackbox_solver::{BlackBoxFunctionSolver, BlackBoxResolutionError};
 use arithmetic::{evaluate_binary_bigint_op, evaluate_binary_field_op};
-use black_box::evaluate_black_box;
+use black_box::{evaluate_black_box, BrilligBigintSolver};
 
 pub use memory::Memory;
 use num_bigint::BigUint;
@@ -81,6 +81,8 @@ pub struct VM<'a, B: BlackBoxFunctionSolver> {
     call_stack: Vec<Value>,
     /// The solver for blackbox functions
     black_box_solver: &'a B,
+    /// The solver for the bigints created during execution
+    bigint_solver: BrilligBigintSolver,
 }
 
 impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
@@ -102,6 +104,7 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
             memory: memory.into(),
             call_stack: Vec::new(),
             black_box_solver,
+            bigint_solver: BrilligBigintSolver::default(),
         }
     }
 
@@ -328,6 +331,7 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
                     self.black_box_solver,
                     &mut self.registers,
                     &mut self.memory,
+                    &mut self.bigint_solver,
                 ) {
                     Ok(()) => self.increment_program_counter(),
                     Err(e) => self.fail(e.to_string()),
@@ -3,8 +3,8 @@ use iter_extended::vecmap;
 use noirc_frontend::macros_api::FieldElement;
 use noirc_frontend::macros_api::{
     BlockExpression, CallExpression, CastExpression, Distinctness, Expression, ExpressionKind,
-    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, FunctionVisibility,
-    HirContext, HirExpression, HirLiteral, HirStatement, Ident, ImportStatement, IndexExpression,
+    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, HirContext, HirExpression,
+    HirLiteral, HirStatement, Ident, ImportStatement, IndexExpression, ItemVisibility,
     LetStatement, Literal, MemberAccessExpression, MethodCallExpression, NoirFunction, NoirStruct,
     Param, Path, PathKind, Pattern, PrefixExpression, SecondaryAttribute, Signedness, Span,
     Statement, StatementKind, StructType, Type, TypeImpl, UnaryOp, UnresolvedType,
@@ -183,7 +183,7 @@ fn index_array_variable(array: Expression, index: &str) -> Expression {
 }
 
 fn import(path: Path) -> ImportStatement {
-    ImportStatement { path, alias: None }
+    ImportStatement { visibility: ItemVisibility::Private, path, alias: None }
 }
 
 //
@@ -509,7 +509,7 @@ fn generate_selector_impl(structure: &NoirStruct) -> TypeImpl {
         &FunctionReturnType::Ty(make_type(UnresolvedTypeData::FieldElement)),
     );
 
-    selector_fn_def.visibility = FunctionVisibility::Public;
+    selector_fn_def.visibility = ItemVisibility::Public;
 
     // Seems to be necessary on contract modules
     selector_fn_def.return_visibility = Visibility::Public;
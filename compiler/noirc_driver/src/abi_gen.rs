@@ -40,13 +40,17 @@ pub(super) fn compute_function_abi(
 }
 
 /// Attempts to retrieve the name of this parameter. Returns None
-/// if this parameter is a tuple or struct pattern.
+/// if this parameter is a tuple, struct or array pattern.
 fn get_param_name<'a>(pattern: &HirPattern, interner: &'a NodeInterner) -> Option<&'a str> {
     match pattern {
         HirPattern::Identifier(ident) => Some(interner.definition_name(ident.id)),
         HirPattern::Mutable(pattern, _) => get_param_name(pattern, interner),
+        HirPattern::Wildcard(_) => Some("_"),
         HirPattern::Tuple(_, _) => None,
         HirPattern::Struct(_, _, _) => None,
+        HirPattern::EnumVariant(_, _, _, _) => None,
+        HirPattern::Literal(_, _) => None,
+        HirPattern::Array(_, _, _, _) => None,
     }
 }
 
@@ -3,21 +3,23 @@
 #![warn(unreachable_pub)]
 #![warn(clippy::semicolon_if_nothing_returned)]
 
-use clap::Args;
+use clap::{builder::PossibleValuesParser, Args};
 use fm::{FileId, FileManager};
 use iter_extended::vecmap;
 use noirc_abi::{AbiParameter, AbiType, ContractEvent};
+use noirc_errors::reporter::MessageFormat;
 use noirc_errors::{CustomDiagnostic, FileDiagnostic};
-use noirc_evaluator::create_circuit;
-use noirc_evaluator::errors::RuntimeError;
+use noirc_evaluator::errors::{RuntimeError, SsaReport};
+use noirc_evaluator::{check_ssa, create_circuit, SsaEvaluatorOptions, SSA_PASSES};
 use noirc_frontend::graph::{CrateId, CrateName};
 use noirc_frontend::hir::def_map::{Contract, CrateDefMap};
 use noirc_frontend::hir::Context;
+use noirc_frontend::lints::LintLevel;
 use noirc_frontend::macros_api::MacroProcessor;
 use noirc_frontend::monomorphization::monomorphize;
 use noirc_frontend::node_interner::FuncId;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
 mod abi_gen;
@@ -52,6 +54,18 @@ pub struct CompileOptions {
     #[arg(long, hide = true)]
     pub show_brillig: bool,
 
+    /// Write the SSA of each function after each optimization pass into files within this directory
+    #[arg(long, hide = true)]
+    pub ssa_dump_dir: Option<PathBuf>,
+
+    /// Do not run the given SSA optimization pass. Can be given more than once.
+    #[arg(long, hide = true, value_parser = PossibleValuesParser::new(SSA_PASSES))]
+    pub skip_ssa_pass: Vec<String>,
+
+    /// Print the change in the number of instructions and the time taken by each SSA pass
+    #[arg(long, hide = true)]
+    pub ssa_pass_stats: bool,
+
     /// Display the ACIR for compiled circuit
     #[arg(long)]
     pub print_acir: bool,
@@ -64,6 +78,10 @@ pub struct CompileOptions {
     #[arg(long, conflicts_with = "deny_warnings")]
     pub silence_warnings: bool,
 
+    /// The format in which errors and warnings are reported: `human` or `json`
+    #[arg(long, default_value_t = MessageFormat::Human)]
+    pub message_format: MessageFormat,
+
     /// Output ACIR gzipped bytecode instead of the JSON artefact
     #[arg(long, hide = true)]
     pub only_acir: bool,
@@ -169,6 +187,7 @@ pub fn check_crate(
     disable_macros: bool,
 ) -> CompilationResult<()> {
     let macros: Vec<&dyn MacroProcessor> = if disable_macros {
+        context.disable_crate_macro_processors();
         vec![]
     } else {
         vec![&aztec_macros::AztecMacro as &dyn MacroProcessor]
@@ -223,7 +242,7 @@ pub fn compile_main(
     let compiled_program = compile_no_check(context, options, main, cached_program, force_compile)
         .map_err(FileDiagnostic::from)?;
     let compilation_warnings = vecmap(compiled_program.warnings.clone(), FileDiagnostic::from);
-    if options.deny_warnings && !compilation_warnings.is_empty() {
+    if has_errors(&compilation_warnings, options.deny_warnings) {
         return Err(compilation_warnings);
     }
     warnings.extend(compilation_warnings);
@@ -329,7 +348,13 @@ fn compile_contract_inner(
                 continue;
             }
         };
-        warnings.extend(function.warnings);
+        for warning in function.warnings {
+            if warning.is_error() {
+                errors.push(FileDiagnostic::from(warning));
+            } else {
+                warnings.push(warning);
+            }
+        }
         let modifiers = context.def_interner.function_modifiers(&function_id);
         let func_type = modifiers
             .contract_function_type
@@ -385,21 +410,33 @@ pub fn compile_no_check(
 ) -> Result<CompiledProgram, RuntimeError> {
     let program = monomorphize(main_function, &context.def_interner);
 
-    let hash = fxhash::hash64(&program);
+    // Skipping SSA passes changes the circuit generated for the same program, so the skipped
+    // passes are part of the hash to avoid reusing the artifact when they run again.
+    let hash = if options.skip_ssa_pass.is_empty() {
+        fxhash::hash64(&program)
+    } else {
+        fxhash::hash64(&(&program, &options.skip_ssa_pass))
+    };
     let hashes_match = cached_program.as_ref().map_or(false, |program| program.hash == hash);
 
     // If user has specified that they want to see intermediate steps printed then we should
     // force compilation even if the program hasn't changed.
-    let force_compile =
-        force_compile || options.print_acir || options.show_brillig || options.show_ssa;
+    let force_compile = force_compile
+        || options.print_acir
+        || options.show_brillig
+        || options.show_ssa
+        || options.ssa_dump_dir.is_some()
+        || options.ssa_pass_stats;
 
     if !force_compile && hashes_match {
         info!("Program matches existing artifact, returning early");
         return Ok(cached_program.expect("cache must exist for hashes to match"));
     }
     let visibility = program.return_visibility;
+    let ssa_options = ssa_evaluator_options(context, options, main_function);
     let (circuit, debug, input_witnesses, return_witnesses, warnings) =
-        create_circuit(program, options.show_ssa, options.show_brillig)?;
+        create_circuit(program, &ssa_options)?;
+    let warnings = apply_lint_levels(context, warnings);
 
     let abi =
         abi_gen::gen_abi(context, &main_function, input_witnesses, return_witnesses, visibility);
@@ -415,3 +452,60 @@ pub fn compile_no_check(
         warnings,
     })
 }
+
+/// Runs the SSA passes over `main_function` without generating ACIR, returning the warnings
+/// they find such as integer operations on constants which overflow.
+///
+/// This is cheaper than [compile_no_check], but errors which are only found while generating
+/// ACIR are not reported.
+pub fn check_ssa_no_compile(
+    context: &Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+) -> Result<Vec<SsaReport>, RuntimeError> {
+    let program = monomorphize(main_function, &context.def_interner);
+    let ssa_options = ssa_evaluator_options(context, options, main_function);
+    let warnings = check_ssa(program, &ssa_options)?;
+    Ok(apply_lint_levels(context, warnings))
+}
+
+fn ssa_evaluator_options(
+    context: &Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+) -> SsaEvaluatorOptions {
+    SsaEvaluatorOptions {
+        enable_ssa_logging: options.show_ssa,
+        enable_brillig_logging: options.show_brillig,
+        // Each program gets its own directory, as a contract or a test run compiles several
+        ssa_dump_dir: options
+            .ssa_dump_dir
+            .as_ref()
+            .map(|dump_dir| dump_dir.join(context.function_name(&main_function))),
+        skipped_passes: options.skip_ssa_pass.clone(),
+        print_pass_stats: options.ssa_pass_stats,
+    }
+}
+
+/// Drops the warnings whose lint is allowed where they occur, and marks those whose lint is
+/// denied so that they are reported as errors.
+fn apply_lint_levels(context: &Context, warnings: Vec<SsaReport>) -> Vec<SsaReport> {
+    let lint_levels = context.def_interner.lint_levels();
+    warnings
+        .into_iter()
+        .filter_map(|report| match report {
+            SsaReport::Warning(warning) => {
+                let level = match warning.location() {
+                    Some(location) => lint_levels.level(warning.lint(), location),
+                    None => LintLevel::Warn,
+                };
+                match level {
+                    LintLevel::Allow => None,
+                    LintLevel::Warn => Some(SsaReport::Warning(warning)),
+                    LintLevel::Deny => Some(SsaReport::DeniedWarning(warning)),
+                }
+            }
+            denied @ SsaReport::DeniedWarning(_) => Some(denied),
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+//! Longer explanations of the diagnostics reported by the compiler, looked up by their code.
+//!
+//! Each explanation lives in `error_codes/<CODE>.md` and describes what causes the diagnostic,
+//! usually with an example program which triggers it and how to fix it.
+
+macro_rules! register_error_codes {
+    ($($code:ident),* $(,)?) => {
+        /// Every known error code along with its explanation, ordered by code.
+        pub const ERROR_CODES: &[(&str, &str)] = &[
+            $((stringify!($code), include_str!(concat!("error_codes/", stringify!($code), ".md"))),)*
+        ];
+    };
+}
+
+register_error_codes!(
+    E0001, E0002, E0003, E0004, E0005, E0006, E0007, E0008, E0009, E0010, E0011, E0012, E0013,
+    E0014, E0015, E0016, E0017, E0018, E0019, E0020, E0021, E0022, E0023, E0024, E0025, E0026,
+    E0100, E0101, E0102, E0103, E0104, E0105, E0106, E0107, E0108, E0109, E0110, E0111, E0112,
+    E0113, E0114, E0115, E0116, E0117, E0118, E0120, E0121, E0122, E0200, E0201, E0202, E0203,
+    E0204, E0205, E0206, E0207, E0208, E0209, E0210, E0211, E0212, E0213, E0214, E0215, E0216,
+    E0217, E0218, E0219, E0220, E0221, E0222, E0223, E0224, E0225, E0226, E0227, E0228, E0229,
+    E0230, E0231, E0232, E0233, E0234, E0235, E0236, E0237, E0238, E0239, E0300, E0301, E0302,
+    E0303, E0304, E0305, E0306, E0307, E0308, E0309, E0310, E0311, E0312, E0313, E0314, E0315,
+    E0316, E0317, E0318, E0319, E0320, E0321, E0322, E0323, E0324, E0325, E0326, E0327, E0328,
+    E0329, E0330, E0331, E0332, E0333, E0334, E0335, E0336, E0400, E0401, E0402, E0403, E0404,
+    E0405, E0406, E0407, E0408, E0409, E0410, E0411, E0412, E0413, E0414, E0500, E0501, E0502,
+    E0503, E0504, E0505, E0506, E0507, E0508, E0509, E0510, E0511, E0512, E0513, E0514,
+);
+
+/// Returns the explanation of the given error code, such as `E0201`.
+pub fn explain(code: &str) -> Option<&'static str> {
+    ERROR_CODES
+        .iter()
+        .find(|(known_code, _)| *known_code == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explain, ERROR_CODES};
+
+    #[test]
+    fn error_codes_are_sorted_and_well_formed() {
+        for window in ERROR_CODES.windows(2) {
+            assert!(window[0].0 < window[1].0, "{} is out of order", window[1].0);
+        }
+
+        for (code, explanation) in ERROR_CODES {
+            assert!(code.len() == 5 && code.starts_with('E'), "{code} is malformed");
+            assert!(code[1..].chars().all(|c| c.is_ascii_digit()), "{code} is malformed");
+            assert!(!explanation.trim().is_empty(), "{code} has an empty explanation");
+        }
+    }
+
+    #[test]
+    fn explains_known_codes_only() {
+        assert!(explain("E0201").unwrap().contains("never used"));
+        assert!(explain("E9999").is_none());
+    }
+}
@@ -4,10 +4,11 @@
 #![warn(clippy::semicolon_if_nothing_returned)]
 
 pub mod debug_info;
+pub mod error_codes;
 mod position;
 pub mod reporter;
 pub use position::{Location, Position, Span, Spanned};
-pub use reporter::{CustomDiagnostic, DiagnosticKind};
+pub use reporter::{Applicability, CustomDiagnostic, CustomSuggestion, DiagnosticKind};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileDiagnostic {
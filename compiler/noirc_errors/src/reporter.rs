@@ -1,8 +1,11 @@
+use std::str::FromStr;
+
 use crate::{FileDiagnostic, Location, Span};
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::files::Files;
 use codespan_reporting::term;
 use codespan_reporting::term::termcolor::{ColorChoice, StandardStream};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CustomDiagnostic {
@@ -10,6 +13,11 @@ pub struct CustomDiagnostic {
     pub secondaries: Vec<CustomLabel>,
     notes: Vec<String>,
     pub kind: DiagnosticKind,
+    /// A stable code identifying the kind of this diagnostic, such as `E0201`,
+    /// whose explanation can be looked up with [crate::error_codes::explain].
+    pub code: Option<&'static str>,
+    /// Edits to the source code which would fix this diagnostic.
+    pub suggestions: Vec<CustomSuggestion>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -18,6 +26,38 @@ pub enum DiagnosticKind {
     Warning,
 }
 
+/// The format in which diagnostics are written to stderr.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageFormat {
+    /// Rendered diagnostics with source snippets, meant to be read by people.
+    #[default]
+    Human,
+    /// One JSON object per line for each diagnostic, meant to be read by other tools.
+    Json,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            _ => Err(format!("unknown message format `{format}`, expected `human` or `json`")),
+        }
+    }
+}
+
+impl std::fmt::Display for MessageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageFormat::Human => write!(f, "human"),
+            MessageFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
 /// A count of errors that have been already reported to stderr
 #[derive(Debug, Copy, Clone)]
 pub struct ReportedErrors {
@@ -31,6 +71,8 @@ impl CustomDiagnostic {
             secondaries: Vec::new(),
             notes: Vec::new(),
             kind: DiagnosticKind::Error,
+            code: None,
+            suggestions: Vec::new(),
         }
     }
 
@@ -44,6 +86,8 @@ impl CustomDiagnostic {
             secondaries: vec![CustomLabel::new(secondary_message, secondary_span)],
             notes: Vec::new(),
             kind: DiagnosticKind::Error,
+            code: None,
+            suggestions: Vec::new(),
         }
     }
 
@@ -57,6 +101,8 @@ impl CustomDiagnostic {
             secondaries: vec![CustomLabel::new(secondary_message, secondary_span)],
             notes: Vec::new(),
             kind: DiagnosticKind::Warning,
+            code: None,
+            suggestions: Vec::new(),
         }
     }
 
@@ -64,6 +110,11 @@ impl CustomDiagnostic {
         FileDiagnostic::new(file_id, self)
     }
 
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
     pub fn add_note(&mut self, message: String) {
         self.notes.push(message);
     }
@@ -72,6 +123,18 @@ impl CustomDiagnostic {
         self.secondaries.push(CustomLabel::new(message, span));
     }
 
+    /// Suggests replacing the source code at `span` with `replacement` to fix this diagnostic.
+    /// An empty `span` inserts the replacement, while an empty `replacement` removes the span.
+    pub fn add_suggestion(
+        &mut self,
+        message: String,
+        span: Span,
+        replacement: String,
+        applicability: Applicability,
+    ) {
+        self.suggestions.push(CustomSuggestion { message, span, replacement, applicability });
+    }
+
     pub fn is_error(&self) -> bool {
         matches!(self.kind, DiagnosticKind::Error)
     }
@@ -93,6 +156,10 @@ impl std::fmt::Display for CustomDiagnostic {
             write!(f, "\nnote: {note}")?;
         }
 
+        for suggestion in &self.suggestions {
+            write!(f, "\nhelp: {}", suggestion.message)?;
+        }
+
         Ok(())
     }
 }
@@ -109,6 +176,25 @@ impl CustomLabel {
     }
 }
 
+/// A suggested edit to the source code of the file a diagnostic was reported in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomSuggestion {
+    pub message: String,
+    pub span: Span,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+/// Whether a suggestion can be applied by tools such as `nargo fix` without being reviewed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Applicability {
+    /// The suggestion is known to fix the diagnostic without changing what the program means.
+    MachineApplicable,
+    /// The suggestion contains placeholder code which has to be filled in by the user.
+    HasPlaceholders,
+}
+
 /// Writes the given diagnostics to stderr and returns the count
 /// of diagnostics that were errors.
 pub fn report_all<'files>(
@@ -116,6 +202,7 @@ pub fn report_all<'files>(
     diagnostics: &[FileDiagnostic],
     deny_warnings: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> ReportedErrors {
     // Report warnings before any errors
     let (warnings, mut errors): (Vec<_>, _) =
@@ -124,8 +211,10 @@ pub fn report_all<'files>(
     let mut diagnostics = if silence_warnings { Vec::new() } else { warnings };
     diagnostics.append(&mut errors);
 
-    let error_count =
-        diagnostics.iter().map(|error| error.report(files, deny_warnings) as u32).sum();
+    let error_count = diagnostics
+        .iter()
+        .map(|error| error.report(files, deny_warnings, message_format) as u32)
+        .sum();
 
     ReportedErrors { error_count }
 }
@@ -135,11 +224,146 @@ impl FileDiagnostic {
         &self,
         files: &'files impl Files<'files, FileId = fm::FileId>,
         deny_warnings: bool,
+        message_format: MessageFormat,
     ) -> bool {
-        report(files, &self.diagnostic, Some(self.file_id), &self.call_stack, deny_warnings)
+        match message_format {
+            MessageFormat::Human => {
+                report(files, &self.diagnostic, Some(self.file_id), &self.call_stack, deny_warnings)
+            }
+            MessageFormat::Json => report_json(files, self, deny_warnings),
+        }
+    }
+}
+
+/// Report the given diagnostic to stderr as a single line of JSON, and return true if it was an error
+pub fn report_json<'files>(
+    files: &'files impl Files<'files, FileId = fm::FileId>,
+    file_diagnostic: &FileDiagnostic,
+    deny_warnings: bool,
+) -> bool {
+    let diagnostic = JsonDiagnostic::new(files, file_diagnostic, deny_warnings);
+    let json = serde_json::to_string(&diagnostic).expect("diagnostics should serialize to JSON");
+    eprintln!("{json}");
+
+    deny_warnings || file_diagnostic.diagnostic.is_error()
+}
+
+/// The JSON representation of a diagnostic. Positions are given both as byte offsets into the
+/// file, and as the 1-based line and column of the start of the span.
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic<'a> {
+    severity: &'static str,
+    code: Option<&'static str>,
+    message: &'a str,
+    file: String,
+    labels: Vec<JsonLabel<'a>>,
+    notes: &'a [String],
+    suggestions: Vec<JsonSuggestion<'a>>,
+    call_stack: Vec<JsonLocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonLabel<'a> {
+    message: &'a str,
+    #[serde(flatten)]
+    location: JsonLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSuggestion<'a> {
+    message: &'a str,
+    replacement: &'a str,
+    applicability: Applicability,
+    #[serde(flatten)]
+    location: JsonLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonLocation {
+    file: String,
+    start: u32,
+    end: u32,
+    line: u32,
+    column: u32,
+}
+
+impl<'a> JsonDiagnostic<'a> {
+    fn new<'files>(
+        files: &'files impl Files<'files, FileId = fm::FileId>,
+        file_diagnostic: &'a FileDiagnostic,
+        deny_warnings: bool,
+    ) -> Self {
+        let diagnostic = &file_diagnostic.diagnostic;
+        let severity = match (diagnostic.kind, deny_warnings) {
+            (DiagnosticKind::Warning, false) => "warning",
+            _ => "error",
+        };
+
+        let file_id = file_diagnostic.file_id;
+        let labels = diagnostic
+            .secondaries
+            .iter()
+            .map(|label| JsonLabel {
+                message: &label.message,
+                location: JsonLocation::new(files, Location::new(label.span, file_id)),
+            })
+            .collect();
+        let suggestions = diagnostic
+            .suggestions
+            .iter()
+            .map(|suggestion| JsonSuggestion {
+                message: &suggestion.message,
+                replacement: &suggestion.replacement,
+                applicability: suggestion.applicability,
+                location: JsonLocation::new(files, Location::new(suggestion.span, file_id)),
+            })
+            .collect();
+        let call_stack = file_diagnostic
+            .call_stack
+            .iter()
+            .map(|location| JsonLocation::new(files, *location))
+            .collect();
+
+        JsonDiagnostic {
+            severity,
+            code: diagnostic.code,
+            message: &diagnostic.message,
+            file: file_name(files, file_id),
+            labels,
+            notes: &diagnostic.notes,
+            suggestions,
+            call_stack,
+        }
     }
 }
 
+impl JsonLocation {
+    fn new<'files>(
+        files: &'files impl Files<'files, FileId = fm::FileId>,
+        location: Location,
+    ) -> Self {
+        let (line, column) = match files.source(location.file) {
+            Ok(source) => self::location(source.as_ref(), location.span.start()),
+            Err(_) => (0, 0),
+        };
+
+        JsonLocation {
+            file: file_name(files, location.file),
+            start: location.span.start(),
+            end: location.span.end(),
+            line,
+            column,
+        }
+    }
+}
+
+fn file_name<'files>(
+    files: &'files impl Files<'files, FileId = fm::FileId>,
+    file_id: fm::FileId,
+) -> String {
+    files.name(file_id).map(|name| name.to_string()).unwrap_or_default()
+}
+
 /// Report the given diagnostic, and return true if it was an error
 pub fn report<'files>(
     files: &'files impl Files<'files, FileId = fm::FileId>,
@@ -164,11 +388,15 @@ fn convert_diagnostic(
     stack_trace: String,
     deny_warnings: bool,
 ) -> Diagnostic<fm::FileId> {
-    let diagnostic = match (cd.kind, deny_warnings) {
+    let mut diagnostic = match (cd.kind, deny_warnings) {
         (DiagnosticKind::Warning, false) => Diagnostic::warning(),
         _ => Diagnostic::error(),
     };
 
+    if let Some(code) = cd.code {
+        diagnostic = diagnostic.with_code(code);
+    }
+
     let secondary_labels = if let Some(file_id) = file {
         cd.secondaries
             .iter()
@@ -183,6 +411,7 @@ fn convert_diagnostic(
     };
 
     let mut notes = cd.notes.clone();
+    notes.extend(cd.suggestions.iter().map(|suggestion| format!("help: {}", suggestion.message)));
     notes.push(stack_trace);
 
     diagnostic.with_message(&cd.message).with_labels(secondary_labels).with_notes(notes)
@@ -228,3 +457,91 @@ fn location(source: &str, span_start: u32) -> (u32, u32) {
 
     (line, column)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use fm::{FileMap, PathString};
+    use serde_json::json;
+
+    use super::{Applicability, CustomDiagnostic, JsonDiagnostic};
+    use crate::{Location, Span};
+
+    #[test]
+    fn json_diagnostics_have_stable_field_names_and_positions() {
+        let mut files = FileMap::default();
+        let source = "fn main() {\n    let x = 1;\n}\n";
+        let file_id =
+            files.add_file(PathString::from_path(PathBuf::from("main.nr")), source.to_string());
+
+        let mut diagnostic = CustomDiagnostic::simple_warning(
+            "unused variable x".to_string(),
+            "unused variable".to_string(),
+            Span::from(20..21),
+        )
+        .with_code("E0201");
+        diagnostic.add_secondary("in this function".to_string(), Span::from(3..7));
+        diagnostic.add_note("variables starting with `_` are not reported".to_string());
+        diagnostic.add_suggestion(
+            "prefix it with an underscore".to_string(),
+            Span::from(20..21),
+            "_x".to_string(),
+            Applicability::MachineApplicable,
+        );
+        let file_diagnostic = diagnostic
+            .in_file(file_id)
+            .with_call_stack(vec![Location::new(Span::from(3..7), file_id)]);
+
+        let json = serde_json::to_value(JsonDiagnostic::new(&files, &file_diagnostic, false))
+            .expect("diagnostics should serialize to JSON");
+        assert_eq!(
+            json,
+            json!({
+                "severity": "warning",
+                "code": "E0201",
+                "message": "unused variable x",
+                "file": "main.nr",
+                "labels": [
+                    {
+                        "message": "unused variable",
+                        "file": "main.nr",
+                        "start": 20,
+                        "end": 21,
+                        "line": 2,
+                        "column": 9,
+                    },
+                    {
+                        "message": "in this function",
+                        "file": "main.nr",
+                        "start": 3,
+                        "end": 7,
+                        "line": 1,
+                        "column": 4,
+                    },
+                ],
+                "notes": ["variables starting with `_` are not reported"],
+                "suggestions": [
+                    {
+                        "message": "prefix it with an underscore",
+                        "replacement": "_x",
+                        "applicability": "machine-applicable",
+                        "file": "main.nr",
+                        "start": 20,
+                        "end": 21,
+                        "line": 2,
+                        "column": 9,
+                    },
+                ],
+                "call_stack": [
+                    { "file": "main.nr", "start": 3, "end": 7, "line": 1, "column": 4 },
+                ],
+            })
+        );
+
+        // Warnings are reported as errors when they are denied
+        let json = serde_json::to_value(JsonDiagnostic::new(&files, &file_diagnostic, true))
+            .expect("diagnostics should serialize to JSON");
+        assert_eq!(json["severity"], "error");
+    }
+}
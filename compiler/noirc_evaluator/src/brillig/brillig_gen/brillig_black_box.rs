@@ -1,4 +1,7 @@
-use acvm::acir::{brillig::BlackBoxOp, BlackBoxFunc};
+use acvm::acir::{
+    brillig::{BlackBoxOp, RegisterIndex, Value},
+    BlackBoxFunc,
+};
 
 use crate::brillig::brillig_ir::{
     brillig_variable::{BrilligVariable, BrilligVector},
@@ -160,10 +163,83 @@ pub(crate) fn convert_black_box_call(
                 )
             }
         }
+        BlackBoxFunc::BigIntAdd
+        | BlackBoxFunc::BigIntSub
+        | BlackBoxFunc::BigIntMul
+        | BlackBoxFunc::BigIntDiv => {
+            if let (
+                [BrilligVariable::Simple(lhs), BrilligVariable::Simple(lhs_modulus), BrilligVariable::Simple(rhs), BrilligVariable::Simple(_)],
+                [BrilligVariable::Simple(output), BrilligVariable::Simple(output_modulus)],
+            ) = (function_arguments, function_results)
+            {
+                let (lhs, rhs, output) = (*lhs, *rhs, *output);
+                brillig_context.black_box_op_instruction(bigint_op(bb_func, lhs, rhs, output));
+                brillig_context.mov_instruction(*output_modulus, *lhs_modulus);
+            } else {
+                unreachable!(
+                    "ICE: {} expects two bigint arguments and one bigint result",
+                    bb_func.name()
+                )
+            }
+        }
+        BlackBoxFunc::BigIntFromLeBytes => {
+            if let (
+                [inputs, modulus],
+                [BrilligVariable::Simple(output), BrilligVariable::Simple(output_modulus)],
+            ) = (function_arguments, function_results)
+            {
+                let inputs_vector = convert_array_or_vector(brillig_context, inputs, bb_func);
+                let modulus_vector = convert_array_or_vector(brillig_context, modulus, bb_func);
+                brillig_context.black_box_op_instruction(BlackBoxOp::BigIntFromLeBytes {
+                    inputs: inputs_vector.to_heap_vector(),
+                    modulus: modulus_vector.to_heap_vector(),
+                    output: *output,
+                });
+                // The VM keeps track of the modulus of each bigint,
+                // so the modulus id is only used by ACIR.
+                brillig_context.const_instruction(*output_modulus, Value::from(0_usize));
+            } else {
+                unreachable!(
+                    "ICE: BigIntFromLeBytes expects two array arguments and one bigint result"
+                )
+            }
+        }
+        BlackBoxFunc::BigIntToLeBytes => {
+            if let (
+                [BrilligVariable::Simple(input), BrilligVariable::Simple(_modulus)],
+                [BrilligVariable::BrilligArray(result_array)],
+            ) = (function_arguments, function_results)
+            {
+                let output = brillig_context.array_to_vector(result_array);
+                brillig_context.black_box_op_instruction(BlackBoxOp::BigIntToLeBytes {
+                    input: *input,
+                    output: output.to_heap_vector(),
+                });
+            } else {
+                unreachable!(
+                    "ICE: BigIntToLeBytes expects one bigint argument and one array result"
+                )
+            }
+        }
         _ => unimplemented!("ICE: Black box function {:?} is not implemented", bb_func),
     }
 }
 
+fn bigint_op(
+    bb_func: &BlackBoxFunc,
+    lhs: RegisterIndex,
+    rhs: RegisterIndex,
+    output: RegisterIndex,
+) -> BlackBoxOp {
+    match bb_func {
+        BlackBoxFunc::BigIntAdd => BlackBoxOp::BigIntAdd { lhs, rhs, output },
+        BlackBoxFunc::BigIntSub => BlackBoxOp::BigIntSub { lhs, rhs, output },
+        BlackBoxFunc::BigIntMul => BlackBoxOp::BigIntMul { lhs, rhs, output },
+        BlackBoxFunc::BigIntDiv => BlackBoxOp::BigIntDiv { lhs, rhs, output },
+        _ => unreachable!("ICE: {} is not a bigint operation", bb_func.name()),
+    }
+}
+
 fn convert_array_or_vector(
     brillig_context: &mut BrilligContext,
     array_or_vector: &BrilligVariable,
@@ -1144,10 +1144,151 @@ impl<'block> BrilligBlock<'block> {
         let left = self.convert_ssa_register_value(binary.lhs, dfg);
         let right = self.convert_ssa_register_value(binary.rhs, dfg);
 
+        if let (BinaryOp::Lt, Type::Numeric(NumericType::Signed { bit_size })) =
+            (binary.operator, &binary_type)
+        {
+            self.convert_signed_less_than(left, right, result_register, *bit_size);
+            return;
+        }
+
         let brillig_binary_op =
             convert_ssa_binary_op_to_brillig_binary_op(binary.operator, &binary_type);
 
-        self.brillig_context.binary_instruction(left, right, result_register, brillig_binary_op);
+        self.brillig_context.binary_instruction(
+            left,
+            right,
+            result_register,
+            brillig_binary_op.clone(),
+        );
+
+        if let Type::Numeric(NumericType::Unsigned { .. }) = binary_type {
+            self.add_overflow_check(brillig_binary_op, left, right, result_register);
+        }
+    }
+
+    /// Brillig compares integers as unsigned values, which does not order negative numbers
+    /// below positive ones. Adding 2^{bit_size-1} to both operands maps the signed range
+    /// onto the unsigned range while preserving the order, so we compare the offset values instead.
+    fn convert_signed_less_than(
+        &mut self,
+        left: RegisterIndex,
+        right: RegisterIndex,
+        result: RegisterIndex,
+        bit_size: u32,
+    ) {
+        let offset = self.brillig_context.make_constant(
+            FieldElement::from(2_i128).pow(&FieldElement::from(bit_size as i128 - 1)).into(),
+        );
+        let left_offset = self.brillig_context.allocate_register();
+        let right_offset = self.brillig_context.allocate_register();
+        self.brillig_context.binary_instruction(
+            left,
+            offset,
+            left_offset,
+            BrilligBinaryOp::Integer { op: BinaryIntOp::Add, bit_size },
+        );
+        self.brillig_context.binary_instruction(
+            right,
+            offset,
+            right_offset,
+            BrilligBinaryOp::Integer { op: BinaryIntOp::Add, bit_size },
+        );
+        self.brillig_context.binary_instruction(
+            left_offset,
+            right_offset,
+            result,
+            BrilligBinaryOp::Integer { op: BinaryIntOp::LessThan, bit_size },
+        );
+        self.brillig_context.deallocate_register(right_offset);
+        self.brillig_context.deallocate_register(left_offset);
+        self.brillig_context.deallocate_register(offset);
+    }
+
+    /// Brillig computes integer operations modulo 2^bit_size, so unlike in ACIR an overflowing
+    /// unsigned operation would not be caught by a range check on its result.
+    /// Instead we check that the result is consistent with the operands.
+    fn add_overflow_check(
+        &mut self,
+        binary_operation: BrilligBinaryOp,
+        left: RegisterIndex,
+        right: RegisterIndex,
+        result: RegisterIndex,
+    ) {
+        let (op, bit_size) = match binary_operation {
+            BrilligBinaryOp::Integer { op, bit_size } => (op, bit_size),
+            _ => return,
+        };
+        let condition = self.brillig_context.allocate_register();
+        let message = match op {
+            BinaryIntOp::Add => {
+                // Check that lhs <= result
+                self.brillig_context.binary_instruction(
+                    left,
+                    result,
+                    condition,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::LessThanEquals, bit_size },
+                );
+                "attempt to add with overflow"
+            }
+            BinaryIntOp::Sub => {
+                // Check that rhs <= lhs
+                self.brillig_context.binary_instruction(
+                    right,
+                    left,
+                    condition,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::LessThanEquals, bit_size },
+                );
+                "attempt to subtract with overflow"
+            }
+            // Multiplication of booleans cannot overflow
+            BinaryIntOp::Mul if bit_size > 1 => {
+                // Check that lhs == 0 or result / lhs == rhs.
+                // We divide by lhs + (lhs == 0) so that we never divide by zero.
+                let zero = self.brillig_context.make_constant(FieldElement::zero().into());
+                let is_left_zero = self.brillig_context.allocate_register();
+                self.brillig_context.binary_instruction(
+                    left,
+                    zero,
+                    is_left_zero,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::Equals, bit_size },
+                );
+                let divisor = self.brillig_context.allocate_register();
+                self.brillig_context.binary_instruction(
+                    left,
+                    is_left_zero,
+                    divisor,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::Add, bit_size },
+                );
+                self.brillig_context.binary_instruction(
+                    result,
+                    divisor,
+                    divisor,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::UnsignedDiv, bit_size },
+                );
+                self.brillig_context.binary_instruction(
+                    divisor,
+                    right,
+                    condition,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::Equals, bit_size },
+                );
+                self.brillig_context.binary_instruction(
+                    condition,
+                    is_left_zero,
+                    condition,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::Or, bit_size: 1 },
+                );
+                self.brillig_context.deallocate_register(divisor);
+                self.brillig_context.deallocate_register(is_left_zero);
+                self.brillig_context.deallocate_register(zero);
+                "attempt to multiply with overflow"
+            }
+            _ => {
+                self.brillig_context.deallocate_register(condition);
+                return;
+            }
+        };
+        self.brillig_context.constrain_instruction(condition, Some(message.to_string()));
+        self.brillig_context.deallocate_register(condition);
     }
 
     /// Converts an SSA `ValueId` into a `RegisterOrMemory`. Initializes if necessary.
@@ -27,16 +27,11 @@ use acvm::{
 };
 use debug_show::DebugShow;
 
-/// Integer arithmetic in Brillig is limited to 127 bit
-/// integers.
+/// Field elements are compared in Brillig using integer
+/// arithmetic limited to 127 bit integers.
 ///
 /// We could lift this in the future and have Brillig
-/// do big integer arithmetic when it exceeds the field size
-/// or we could have users re-implement big integer arithmetic
-/// in Brillig.
-/// Since constrained functions do not have this property, it
-/// would mean that unconstrained functions will differ from
-/// constrained functions in terms of syntax compatibility.
+/// compare field elements over the full field size.
 pub(crate) const BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE: u32 = 127;
 /// The Brillig VM does not apply a limit to the memory address space,
 /// As a convention, we take use 64 bits. This means that we assume that
@@ -763,9 +758,9 @@ impl BrilligContext {
 
     /// Emits a modulo instruction against 2**target_bit_size
     ///
-    /// Integer arithmetic in Brillig is currently constrained to 127 bit integers.
-    /// We restrict the cast operation, so that integer types over 127 bits
-    /// cannot be created.
+    /// Integer types are limited to `FieldElement::max_num_bits() - 2` bits, as in ACIR,
+    /// so that the sum of two integers never overflows the field.
+    /// We restrict the cast operation, so that larger integer types cannot be created.
     pub(crate) fn cast_instruction(
         &mut self,
         destination: RegisterIndex,
@@ -774,7 +769,7 @@ impl BrilligContext {
     ) {
         self.debug_show.cast_instruction(destination, source, target_bit_size);
         assert!(
-            target_bit_size <= BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE,
+            target_bit_size <= FieldElement::max_num_bits() - 2,
             "tried to cast to a bit size greater than allowed {target_bit_size}"
         );
 
@@ -9,7 +9,8 @@
 //! An Error of the latter is an error in the implementation of the compiler
 use acvm::{acir::native_types::Expression, FieldElement};
 use iter_extended::vecmap;
-use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic};
+use noirc_errors::{CustomDiagnostic as Diagnostic, DiagnosticKind, FileDiagnostic, Location};
+use noirc_frontend::lints::Lint;
 use thiserror::Error;
 
 use crate::ssa::ir::{dfg::CallStack, types::NumericType};
@@ -42,6 +43,10 @@ pub enum RuntimeError {
     UnknownLoopBound { call_stack: CallStack },
     #[error("Argument is not constant")]
     AssertConstantFailed { call_stack: CallStack },
+    #[error("Could not determine the {name} at compile-time")]
+    UnknownBigInt { name: String, call_stack: CallStack },
+    #[error("BigInt operands must have the same modulus")]
+    BigIntModulusMismatch { call_stack: CallStack },
 }
 
 // We avoid showing the actual lhs and rhs since most of the time they are just 0
@@ -57,27 +62,27 @@ fn format_failed_constraint(message: &Option<String>) -> String {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SsaReport {
     Warning(InternalWarning),
+    /// A warning which is reported as an error since its lint is denied where it occurs.
+    DeniedWarning(InternalWarning),
+}
+
+impl SsaReport {
+    pub fn is_error(&self) -> bool {
+        matches!(self, SsaReport::DeniedWarning(_))
+    }
 }
 
 impl From<SsaReport> for FileDiagnostic {
     fn from(error: SsaReport) -> FileDiagnostic {
         match error {
-            SsaReport::Warning(warning) => {
-                let message = warning.to_string();
-                let (secondary_message, call_stack) = match warning {
-                    InternalWarning::ReturnConstant { call_stack } => {
-                        ("constant value".to_string(), call_stack)
-                    },
-                    InternalWarning::VerifyProof { call_stack } => {
-                        ("verify_proof(...) aggregates data for the verifier, the actual verification will be done when the full proof is verified using nargo verify. nargo prove may generate an invalid proof if bad data is used as input to verify_proof".to_string(), call_stack)
-                    },
-                };
-                let call_stack = vecmap(call_stack, |location| location);
-                let file_id = call_stack.last().map(|location| location.file).unwrap_or_default();
-                let location = call_stack.last().expect("Expected RuntimeError to have a location");
-                let diagnostic =
-                    Diagnostic::simple_warning(message, secondary_message, location.span);
-                diagnostic.in_file(file_id).with_call_stack(call_stack)
+            SsaReport::Warning(warning) => warning.into_file_diagnostic(),
+            SsaReport::DeniedWarning(warning) => {
+                let lint = warning.lint();
+                let mut file_diagnostic = warning.into_file_diagnostic();
+                let diagnostic = &mut file_diagnostic.diagnostic;
+                diagnostic.kind = DiagnosticKind::Error;
+                diagnostic.add_note(format!("`{lint}` is denied by a `#[deny(...)]` attribute"));
+                file_diagnostic
             }
         }
     }
@@ -89,6 +94,60 @@ pub enum InternalWarning {
     ReturnConstant { call_stack: CallStack },
     #[error("Calling std::verify_proof(...) does not verify a proof")]
     VerifyProof { call_stack: CallStack },
+    #[error("attempt to {operation} with overflow")]
+    IntegerOverflow { operation: String, value: String, typ: String, call_stack: CallStack },
+}
+
+impl InternalWarning {
+    /// The stable code of this warning, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            InternalWarning::ReturnConstant { .. } => "E0510",
+            InternalWarning::VerifyProof { .. } => "E0511",
+            InternalWarning::IntegerOverflow { .. } => "E0512",
+        }
+    }
+
+    /// The lint which controls this warning.
+    pub fn lint(&self) -> Lint {
+        match self {
+            InternalWarning::ReturnConstant { .. } => Lint::ReturnConstant,
+            InternalWarning::VerifyProof { .. } => Lint::VerifyProof,
+            InternalWarning::IntegerOverflow { .. } => Lint::ArithmeticOverflow,
+        }
+    }
+
+    /// The location this warning is reported at.
+    pub fn location(&self) -> Option<Location> {
+        let call_stack = match self {
+            InternalWarning::ReturnConstant { call_stack }
+            | InternalWarning::VerifyProof { call_stack }
+            | InternalWarning::IntegerOverflow { call_stack, .. } => call_stack,
+        };
+        call_stack.back().copied()
+    }
+
+    fn into_file_diagnostic(self) -> FileDiagnostic {
+        let message = self.to_string();
+        let code = self.code();
+        let (secondary_message, call_stack) = match self {
+            InternalWarning::ReturnConstant { call_stack } => {
+                ("constant value".to_string(), call_stack)
+            },
+            InternalWarning::VerifyProof { call_stack } => {
+                ("verify_proof(...) aggregates data for the verifier, the actual verification will be done when the full proof is verified using nargo verify. nargo prove may generate an invalid proof if bad data is used as input to verify_proof".to_string(), call_stack)
+            },
+            InternalWarning::IntegerOverflow { value, typ, call_stack, .. } => {
+                (format!("the result {value} does not fit within type {typ}, so this will fail at runtime"), call_stack)
+            },
+        };
+        let call_stack = vecmap(call_stack, |location| location);
+        let file_id = call_stack.last().map(|location| location.file).unwrap_or_default();
+        let location = call_stack.last().expect("Expected RuntimeError to have a location");
+        let diagnostic =
+            Diagnostic::simple_warning(message, secondary_message, location.span).with_code(code);
+        diagnostic.in_file(file_id).with_call_stack(call_stack)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Error)]
@@ -128,10 +187,31 @@ impl RuntimeError {
             | RuntimeError::UnInitialized { call_stack, .. }
             | RuntimeError::UnknownLoopBound { call_stack }
             | RuntimeError::AssertConstantFailed { call_stack }
+            | RuntimeError::UnknownBigInt { call_stack, .. }
+            | RuntimeError::BigIntModulusMismatch { call_stack }
             | RuntimeError::IntegerOutOfBounds { call_stack, .. }
             | RuntimeError::UnsupportedIntegerSize { call_stack, .. } => call_stack,
         }
     }
+
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    /// Internal errors all share a single code, as they are bugs in the compiler.
+    pub fn code(&self) -> &'static str {
+        match self {
+            RuntimeError::FailedConstraint { .. } => "E0500",
+            RuntimeError::InternalError(_) => "E0501",
+            RuntimeError::IndexOutOfBounds { .. } => "E0502",
+            RuntimeError::InvalidRangeConstraint { .. } => "E0503",
+            RuntimeError::IntegerOutOfBounds { .. } => "E0504",
+            RuntimeError::TypeConversion { .. } => "E0505",
+            RuntimeError::UnInitialized { .. } => "E0506",
+            RuntimeError::UnsupportedIntegerSize { .. } => "E0507",
+            RuntimeError::UnknownLoopBound { .. } => "E0508",
+            RuntimeError::AssertConstantFailed { .. } => "E0509",
+            RuntimeError::UnknownBigInt { .. } => "E0513",
+            RuntimeError::BigIntModulusMismatch { .. } => "E0514",
+        }
+    }
 }
 
 impl From<RuntimeError> for FileDiagnostic {
@@ -145,7 +225,8 @@ impl From<RuntimeError> for FileDiagnostic {
 
 impl RuntimeError {
     fn into_diagnostic(self) -> Diagnostic {
-        match self {
+        let code = self.code();
+        let diagnostic = match self {
             RuntimeError::InternalError(cause) => {
                 Diagnostic::simple_error(
                     "Internal Consistency Evaluators Errors: \n
@@ -161,6 +242,7 @@ impl RuntimeError {
 
                 Diagnostic::simple_error(message, String::new(), location.span)
             }
-        }
+        };
+        diagnostic.with_code(code)
     }
 }
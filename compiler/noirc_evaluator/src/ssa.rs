@@ -7,7 +7,11 @@
 //! This module heavily borrows from Cranelift
 #![allow(dead_code)]
 
-use std::collections::BTreeSet;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
 use crate::{
     brillig::Brillig,
@@ -31,46 +35,72 @@ mod acir_gen;
 pub(super) mod function_builder;
 pub mod ir;
 mod opt;
+mod parser;
 pub mod ssa_gen;
 
+/// The names of the SSA passes, which can be skipped with [`SsaEvaluatorOptions::skipped_passes`].
+pub const SSA_PASSES: [&str; 13] = [
+    "defunctionalize",
+    "inlining",
+    "mem2reg",
+    "assert_constant",
+    "unrolling",
+    "simplify_cfg",
+    "flatten_cfg",
+    "constant_folding",
+    "gvn",
+    "licm",
+    "strength_reduction",
+    "die",
+    "fill_internal_slices",
+];
+
+/// Options controlling how the SSA of a program is optimized and reported while it is compiled.
+#[derive(Debug, Clone, Default)]
+pub struct SsaEvaluatorOptions {
+    /// Print the SSA after each pass
+    pub enable_ssa_logging: bool,
+
+    /// Print the generated Brillig bytecode
+    pub enable_brillig_logging: bool,
+
+    /// Write the SSA of each function after each pass into this directory,
+    /// within a subdirectory per pass such as `03_mem2reg`
+    pub ssa_dump_dir: Option<PathBuf>,
+
+    /// The passes which are not run, named as in [`SSA_PASSES`].
+    /// Passes such as inlining and unrolling are required to generate ACIR, so skipping
+    /// them is only useful to find which pass is responsible for a bug.
+    pub skipped_passes: Vec<String>,
+
+    /// Print the change in the number of instructions and the time taken by each pass
+    pub print_pass_stats: bool,
+}
+
 /// Optimize the given program by converting it into SSA
 /// form and performing optimizations there. When finished,
 /// convert the final SSA into ACIR and return it.
 pub(crate) fn optimize_into_acir(
     program: Program,
-    print_ssa_passes: bool,
-    print_brillig_trace: bool,
+    options: &SsaEvaluatorOptions,
 ) -> Result<GeneratedAcir, RuntimeError> {
     let abi_distinctness = program.return_distinctness;
 
     let ssa_gen_span = span!(Level::TRACE, "ssa_generation");
     let ssa_gen_span_guard = ssa_gen_span.enter();
-    let ssa_builder = SsaBuilder::new(program, print_ssa_passes)?
-        .run_pass(Ssa::defunctionalize, "After Defunctionalization:")
-        .run_pass(Ssa::inline_functions, "After Inlining:")
-        // Run mem2reg with the CFG separated into blocks
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .try_run_pass(Ssa::evaluate_assert_constant, "After Assert Constant:")?
-        .try_run_pass(Ssa::unroll_loops, "After Unrolling:")?
-        .run_pass(Ssa::simplify_cfg, "After Simplifying:")
-        // Run mem2reg before flattening to handle any promotion
-        // of values that can be accessed after loop unrolling.
-        // If there are slice mergers uncovered by loop unrolling
-        // and this pass is missed, slice merging will fail inside of flattening.
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .run_pass(Ssa::flatten_cfg, "After Flattening:")
-        // Run mem2reg once more with the flattened CFG to catch any remaining loads/stores
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .run_pass(Ssa::fold_constants, "After Constant Folding:")
-        .run_pass(Ssa::dead_instruction_elimination, "After Dead Instruction Elimination:");
+    let ssa_builder = optimize_into_ssa(program, options)?;
 
-    let brillig = ssa_builder.to_brillig(print_brillig_trace);
+    let brillig = ssa_builder.to_brillig(options.enable_brillig_logging);
 
     // Split off any passes the are not necessary for Brillig generation but are necessary for ACIR generation.
     // We only need to fill out nested slices as we need to have a known length when dealing with memory operations
     // in ACIR gen while this is not necessary in the Brillig IR.
     let ssa = ssa_builder
-        .run_pass(Ssa::fill_internal_slices, "After Fill Internal Slice Dummy Data:")
+        .run_pass(
+            Ssa::fill_internal_slices,
+            "fill_internal_slices",
+            "After Fill Internal Slice Dummy Data:",
+        )
         .finish();
     drop(ssa_gen_span_guard);
 
@@ -79,6 +109,51 @@ pub(crate) fn optimize_into_acir(
     ssa.into_acir(brillig, abi_distinctness, &last_array_uses)
 }
 
+/// Converts the given program into SSA form and runs the optimizations shared by Brillig
+/// and ACIR generation.
+fn optimize_into_ssa(
+    program: Program,
+    options: &SsaEvaluatorOptions,
+) -> Result<SsaBuilder<'_>, RuntimeError> {
+    let ssa_builder = SsaBuilder::new(program, options)?
+        .run_pass(Ssa::defunctionalize, "defunctionalize", "After Defunctionalization:")
+        .run_pass(Ssa::inline_functions, "inlining", "After Inlining:")
+        // Run mem2reg with the CFG separated into blocks
+        .run_pass(Ssa::mem2reg, "mem2reg", "After Mem2Reg:")
+        .try_run_pass(Ssa::evaluate_assert_constant, "assert_constant", "After Assert Constant:")?
+        .try_run_pass(Ssa::unroll_loops, "unrolling", "After Unrolling:")?
+        .run_pass(Ssa::simplify_cfg, "simplify_cfg", "After Simplifying:")
+        // Run mem2reg before flattening to handle any promotion
+        // of values that can be accessed after loop unrolling.
+        // If there are slice mergers uncovered by loop unrolling
+        // and this pass is missed, slice merging will fail inside of flattening.
+        .run_pass(Ssa::mem2reg, "mem2reg", "After Mem2Reg:")
+        .run_pass(Ssa::flatten_cfg, "flatten_cfg", "After Flattening:")
+        // Run mem2reg once more with the flattened CFG to catch any remaining loads/stores
+        .run_pass(Ssa::mem2reg, "mem2reg", "After Mem2Reg:")
+        .run_pass(Ssa::fold_constants, "constant_folding", "After Constant Folding:")
+        .run_pass(Ssa::global_value_numbering, "gvn", "After Global Value Numbering:")
+        // Brillig functions keep their loops, so loop optimizations only apply to them.
+        .run_pass(Ssa::loop_invariant_code_motion, "licm", "After Loop Invariant Code Motion:")
+        .run_pass(Ssa::reduce_strength, "strength_reduction", "After Strength Reduction:")
+        .run_pass(Ssa::dead_instruction_elimination, "die", "After Dead Instruction Elimination:");
+    Ok(ssa_builder)
+}
+
+/// Runs the SSA passes over the [`Program`] without generating Brillig or ACIR, returning the
+/// warnings found by the passes, such as integer operations on constants which overflow.
+///
+/// Errors which are only found while generating ACIR, such as unsupported operations, are not
+/// reported, so a program which passes this check may still fail to compile.
+#[tracing::instrument(level = "trace", skip_all)]
+pub fn check_ssa(
+    program: Program,
+    options: &SsaEvaluatorOptions,
+) -> Result<Vec<SsaReport>, RuntimeError> {
+    let ssa = optimize_into_ssa(program, options)?.finish();
+    Ok(ssa.warnings)
+}
+
 /// Compiles the [`Program`] into [`ACIR`][acvm::acir::circuit::Circuit].
 ///
 /// The output ACIR is is backend-agnostic and so must go through a transformation pass before usage in proof generation.
@@ -86,12 +161,10 @@ pub(crate) fn optimize_into_acir(
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn create_circuit(
     program: Program,
-    enable_ssa_logging: bool,
-    enable_brillig_logging: bool,
+    options: &SsaEvaluatorOptions,
 ) -> Result<(Circuit, DebugInfo, Vec<Witness>, Vec<Witness>, Vec<SsaReport>), RuntimeError> {
     let func_sig = program.main_function_signature.clone();
-    let mut generated_acir =
-        optimize_into_acir(program, enable_ssa_logging, enable_brillig_logging)?;
+    let mut generated_acir = optimize_into_acir(program, options)?;
     let opcodes = generated_acir.take_opcodes();
     let GeneratedAcir {
         current_witness_index,
@@ -167,46 +240,184 @@ fn split_public_and_private_inputs(
         })
 }
 
-// This is just a convenience object to bundle the ssa with `print_ssa_passes` for debug printing.
-struct SsaBuilder {
+// This is just a convenience object to bundle the ssa with the options for debug printing and skipping passes.
+struct SsaBuilder<'options> {
     ssa: Ssa,
-    print_ssa_passes: bool,
+    options: &'options SsaEvaluatorOptions,
+
+    /// The number of passes run so far, which numbers the SSA dumps in the order of the passes
+    passes_run: usize,
 }
 
-impl SsaBuilder {
-    fn new(program: Program, print_ssa_passes: bool) -> Result<SsaBuilder, RuntimeError> {
+impl<'options> SsaBuilder<'options> {
+    fn new(
+        program: Program,
+        options: &'options SsaEvaluatorOptions,
+    ) -> Result<SsaBuilder<'options>, RuntimeError> {
         let ssa = ssa_gen::generate_ssa(program)?;
-        Ok(SsaBuilder { print_ssa_passes, ssa }.print("Initial SSA:"))
+        Ok(SsaBuilder { ssa, options, passes_run: 0 }.print("initial", "Initial SSA:"))
     }
 
     fn finish(self) -> Ssa {
         self.ssa
     }
 
-    /// Runs the given SSA pass and prints the SSA afterward if `print_ssa_passes` is true.
-    fn run_pass(mut self, pass: fn(Ssa) -> Ssa, msg: &str) -> Self {
+    /// Runs the given SSA pass unless it is skipped, and prints the SSA afterward if
+    /// `enable_ssa_logging` is true.
+    fn run_pass(mut self, pass: fn(Ssa) -> Ssa, name: &str, msg: &str) -> Self {
+        if self.is_skipped(name) {
+            return self.skip_pass(name);
+        }
+        let stats = PassStats::start(&self.ssa);
         self.ssa = pass(self.ssa);
-        self.print(msg)
+        self.finish_pass(stats, name, msg)
     }
 
     /// The same as `run_pass` but for passes that may fail
     fn try_run_pass(
         mut self,
         pass: fn(Ssa) -> Result<Ssa, RuntimeError>,
+        name: &str,
         msg: &str,
     ) -> Result<Self, RuntimeError> {
+        if self.is_skipped(name) {
+            return Ok(self.skip_pass(name));
+        }
+        let stats = PassStats::start(&self.ssa);
         self.ssa = pass(self.ssa)?;
-        Ok(self.print(msg))
+        Ok(self.finish_pass(stats, name, msg))
+    }
+
+    fn is_skipped(&self, name: &str) -> bool {
+        self.options.skipped_passes.iter().any(|skipped| skipped == name)
+    }
+
+    fn skip_pass(self, name: &str) -> Self {
+        if self.options.print_pass_stats {
+            println!("{name}: skipped");
+        }
+        self
+    }
+
+    fn finish_pass(mut self, stats: PassStats, name: &str, msg: &str) -> Self {
+        self.passes_run += 1;
+        if self.options.print_pass_stats {
+            println!("{}", stats.report(name, &self.ssa));
+        }
+        self.print(name, msg)
     }
 
     fn to_brillig(&self, print_brillig_trace: bool) -> Brillig {
         self.ssa.to_brillig(print_brillig_trace)
     }
 
-    fn print(self, msg: &str) -> Self {
-        if self.print_ssa_passes {
+    fn print(self, name: &str, msg: &str) -> Self {
+        if self.options.enable_ssa_logging {
             println!("{msg}\n{}", self.ssa);
         }
+        if let Some(dump_dir) = &self.options.ssa_dump_dir {
+            self.dump(dump_dir, name);
+        }
         self
     }
+
+    /// Writes the SSA of each function into its own file, within a directory for the current pass.
+    fn dump(&self, dump_dir: &Path, name: &str) {
+        let pass_dir = dump_dir.join(format!("{:02}_{name}", self.passes_run));
+        let result = std::fs::create_dir_all(&pass_dir).and_then(|()| {
+            self.ssa.functions.values().try_for_each(|function| {
+                let file_name = format!("{}_{}.ssa", function.name(), function.id());
+                std::fs::write(pass_dir.join(file_name), format!("{function}\n"))
+            })
+        });
+        if let Err(error) = result {
+            eprintln!("Could not write the SSA into {}: {error}", pass_dir.display());
+        }
+    }
+}
+
+/// The size of the SSA before a pass, to print how much the pass changed it.
+struct PassStats {
+    instructions: usize,
+    start: Instant,
+}
+
+impl PassStats {
+    fn start(ssa: &Ssa) -> PassStats {
+        PassStats { instructions: ssa.instruction_count(), start: Instant::now() }
+    }
+
+    /// Describes how much the pass `name` changed the SSA, which is now `ssa`.
+    fn report(self, name: &str, ssa: &Ssa) -> String {
+        let elapsed = self.start.elapsed();
+        let instructions = ssa.instruction_count();
+        let delta = instructions as i64 - self.instructions as i64;
+        format!(
+            "{name}: {} -> {instructions} instructions ({delta:+}) in {elapsed:.2?}",
+            self.instructions
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PassStats, SsaBuilder, SsaEvaluatorOptions};
+    use crate::ssa::ssa_gen::Ssa;
+
+    // `v2` is unused, so dead instruction elimination removes it
+    const SRC: &str = "
+        acir fn main f0 {
+          b0(v0: Field):
+            v2 = add v0, Field 1
+            return v0
+        }
+        ";
+
+    fn run_die(options: &SsaEvaluatorOptions) -> SsaBuilder<'_> {
+        let ssa = SRC.parse::<Ssa>().unwrap();
+        SsaBuilder { ssa, options, passes_run: 0 }.print("initial", "Initial SSA:").run_pass(
+            Ssa::dead_instruction_elimination,
+            "die",
+            "After Dead Instruction Elimination:",
+        )
+    }
+
+    #[test]
+    fn dumps_the_ssa_after_each_pass() {
+        let dump_dir = tempfile::tempdir().unwrap();
+        let options = SsaEvaluatorOptions {
+            ssa_dump_dir: Some(dump_dir.path().to_path_buf()),
+            ..SsaEvaluatorOptions::default()
+        };
+        run_die(&options);
+
+        let initial = std::fs::read_to_string(dump_dir.path().join("00_initial/main_f0.ssa"));
+        assert!(initial.unwrap().contains("add v0, Field 1"));
+        let after_die = std::fs::read_to_string(dump_dir.path().join("01_die/main_f0.ssa"));
+        assert!(!after_die.unwrap().contains("add v0, Field 1"));
+    }
+
+    #[test]
+    fn skipped_passes_are_not_run() {
+        let options = SsaEvaluatorOptions::default();
+        let builder = run_die(&options);
+        assert_eq!(builder.passes_run, 1);
+        assert_eq!(builder.ssa.instruction_count(), 0);
+
+        let options =
+            SsaEvaluatorOptions { skipped_passes: vec!["die".into()], ..Default::default() };
+        let builder = run_die(&options);
+        assert_eq!(builder.passes_run, 0);
+        assert_eq!(builder.ssa.instruction_count(), 1);
+    }
+
+    #[test]
+    fn reports_the_change_in_instructions() {
+        let ssa = SRC.parse::<Ssa>().unwrap();
+        let stats = PassStats::start(&ssa);
+        let ssa = ssa.dead_instruction_elimination();
+
+        let report = stats.report("die", &ssa);
+        assert!(report.starts_with("die: 1 -> 0 instructions (-1) in "), "{report}");
+    }
 }
@@ -1,3 +1,4 @@
+use super::big_int::BigIntContext;
 use super::generated_acir::GeneratedAcir;
 use crate::brillig::brillig_gen::brillig_directive;
 use crate::brillig::brillig_ir::artifact::GeneratedBrillig;
@@ -107,6 +108,9 @@ pub(crate) struct AcirContext {
     /// then the `acir_ir` will be populated to assert this
     /// addition.
     acir_ir: GeneratedAcir,
+
+    /// The ids assigned to the bigints and moduli used by the program.
+    big_int_ctx: BigIntContext,
 }
 
 impl AcirContext {
@@ -457,7 +461,10 @@ impl AcirContext {
             // max - ((max - a) AND (max -b))
             // Subtracting from max flips the bits, so this is effectively:
             // (NOT a) NAND (NOT b)
-            let max = self.add_constant((1_u128 << bit_size) - 1);
+            let max = self.add_constant(
+                FieldElement::from(2_i128).pow(&FieldElement::from(bit_size as i128))
+                    - FieldElement::one(),
+            );
             let a = self.sub_var(max, lhs)?;
             let b = self.sub_var(max, rhs)?;
             let a_and_b = self.and_var(a, b, typ)?;
@@ -521,7 +528,7 @@ impl AcirContext {
             }
             NumericType::Signed { bit_size } => {
                 let (quotient_var, _remainder_var) =
-                    self.signed_division_var(lhs, rhs, bit_size)?;
+                    self.signed_division_var(lhs, rhs, bit_size, predicate)?;
                 Ok(quotient_var)
             }
         }
@@ -599,7 +606,10 @@ impl AcirContext {
     pub(crate) fn not_var(&mut self, x: AcirVar, typ: AcirType) -> Result<AcirVar, RuntimeError> {
         let bit_size = typ.bit_size();
         // Subtracting from max flips the bits
-        let max = self.add_constant((1_u128 << bit_size) - 1);
+        let max = self.add_constant(
+            FieldElement::from(2_i128).pow(&FieldElement::from(bit_size as i128))
+                - FieldElement::one(),
+        );
         self.sub_var(max, x)
     }
 
@@ -681,13 +691,14 @@ impl AcirContext {
 
         // Avoids overflow: 'q*b+r < 2^max_q_bits*2^max_rhs_bits'
         let mut avoid_overflow = false;
+        let mut limb_decomposition = false;
         if max_q_bits + max_rhs_bits >= FieldElement::max_num_bits() - 1 {
             // q*b+r can overflow; we avoid this when b is constant
             if self.var_to_expression(rhs)?.is_const() {
                 avoid_overflow = true;
             } else {
-                // we do not support unbounded division
-                unreachable!("overflow in unbounded division");
+                // otherwise we compute q*b using limbs, which ensures that it is less than 2^bit_size.
+                limb_decomposition = true;
             }
         }
 
@@ -733,7 +744,14 @@ impl AcirContext {
         // When the predicate is 0, the equation always passes.
         // When the predicate is 1, the euclidean division needs to be
         // true.
-        let rhs_constraint = self.mul_var(rhs, quotient_var)?;
+        let rhs_constraint = if limb_decomposition {
+            // `q` is not constrained by the Brillig directive when the predicate is zero,
+            // so we nullify it to prevent the limb product from failing.
+            let quotient_var = self.mul_var(quotient_var, predicate)?;
+            self.wide_mul_var(rhs, quotient_var, bit_size, None)?
+        } else {
+            self.mul_var(rhs, quotient_var)?
+        };
         let rhs_constraint = self.add_var(rhs_constraint, remainder_var)?;
         let rhs_constraint = self.mul_var(rhs_constraint, predicate)?;
 
@@ -771,6 +789,51 @@ impl AcirContext {
         Ok((quotient_var, remainder_var))
     }
 
+    /// Returns an `AcirVar` constrained to be the product of `lhs` and `rhs`, two unsigned integers
+    /// of `bit_size` bits, and constrains the product to also fit within `bit_size` bits.
+    ///
+    /// Unlike `mul_var`, this does not require `2 * bit_size` to be less than the field's bit size:
+    /// each operand is split into a high and a low limb of `half = ceil(bit_size / 2)` bits,
+    /// `x = x_hi * 2^half + x_lo`, so that the product becomes
+    /// `(lhs_hi * rhs_hi) * 2^{2*half} + (lhs_hi * rhs_lo + lhs_lo * rhs_hi) * 2^half + lhs_lo * rhs_lo`.
+    /// As `2 * half >= bit_size`, the product of the high limbs must be zero and the cross terms
+    /// must fit in `bit_size - half` bits. Every intermediate value is then less than
+    /// `2^bit_size + 2^{bit_size + 1}`, which does not overflow the field for integers of up to
+    /// `FieldElement::max_num_bits() - 2` bits.
+    pub(crate) fn wide_mul_var(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+        assert_message: Option<String>,
+    ) -> Result<AcirVar, RuntimeError> {
+        let half = (bit_size + 1) / 2;
+        let limb_base =
+            self.add_constant(FieldElement::from(2_i128).pow(&FieldElement::from(half as i128)));
+        let one = self.add_constant(FieldElement::one());
+        let zero = self.add_constant(FieldElement::zero());
+
+        let (lhs_hi, lhs_lo) = self.euclidean_division_var(lhs, limb_base, bit_size, one)?;
+        let (rhs_hi, rhs_lo) = self.euclidean_division_var(rhs, limb_base, bit_size, one)?;
+
+        let hi_product = self.mul_var(lhs_hi, rhs_hi)?;
+        self.assert_eq_var(hi_product, zero, assert_message.clone())?;
+
+        let lhs_cross = self.mul_var(lhs_hi, rhs_lo)?;
+        let rhs_cross = self.mul_var(lhs_lo, rhs_hi)?;
+        let cross_terms = self.add_var(lhs_cross, rhs_cross)?;
+        self.range_constrain_var(
+            cross_terms,
+            &NumericType::Unsigned { bit_size: bit_size - half },
+            assert_message.clone(),
+        )?;
+
+        let lo_product = self.mul_var(lhs_lo, rhs_lo)?;
+        let product = self.mul_var(cross_terms, limb_base)?;
+        let product = self.add_var(product, lo_product)?;
+        self.range_constrain_var(product, &NumericType::Unsigned { bit_size }, assert_message)
+    }
+
     /// Generate constraints that are satisfied iff
     /// lhs < rhs , when offset is 1, or
     /// lhs <= rhs, when offset is 0
@@ -804,9 +867,9 @@ impl AcirContext {
 
         let mut lhs_offset = self.add_var(lhs, offset)?;
 
-        // Optimization when rhs is const and fits within a u128
+        // Optimization when rhs is const and `2^bit_size` fits within a u128
         let rhs_expr = self.var_to_expression(rhs)?;
-        if rhs_expr.is_const() && rhs_expr.q_c.fits_in_u128() {
+        if rhs_expr.is_const() && rhs_expr.q_c.num_bits() < 128 {
             // We try to move the offset to rhs
             let rhs_offset = if self.is_constant_one(&offset) && rhs_expr.q_c.to_u128() >= 1 {
                 lhs_offset = lhs;
@@ -860,11 +923,15 @@ impl AcirContext {
     /// and |remainder| < |rhs|
     /// and remainder has the same sign than lhs
     /// Note that this is not the euclidean division, where we have instead remainder < |rhs|
+    ///
+    /// This matches the `SignedDiv` operation of Brillig, in particular dividing the minimum
+    /// value by -1 wraps around to the minimum value.
     fn signed_division_var(
         &mut self,
         lhs: AcirVar,
         rhs: AcirVar,
         bit_size: u32,
+        predicate: AcirVar,
     ) -> Result<(AcirVar, AcirVar), RuntimeError> {
         // We derive the signed division from the unsigned euclidean division.
         // note that this is not euclidean division!
@@ -892,29 +959,57 @@ impl AcirContext {
         let unsigned_lhs = self.two_complement(lhs, lhs_leading, bit_size)?;
         let unsigned_rhs = self.two_complement(rhs, rhs_leading, bit_size)?;
 
-        // Performs the division using the unsigned values of lhs and rhs
+        // Performs the division using the unsigned values of lhs and rhs.
+        // The absolute value of the minimum integer is 2^{bit_size-1}, so they may take up to `bit_size` bits.
         let (q1, r1) =
-            self.euclidean_division_var(unsigned_lhs, unsigned_rhs, bit_size - 1, one)?;
+            self.euclidean_division_var(unsigned_lhs, unsigned_rhs, bit_size, predicate)?;
 
         // Unsigned to signed: derive q and r from q1,r1 and the signs of lhs and rhs
         // Quotient sign is lhs sign * rhs sign, whose resulting sign bit is the XOR of the sign bits
         let q_sign = self.xor_var(lhs_leading, rhs_leading, AcirType::unsigned(1))?;
 
+        // A zero quotient or remainder is never negative, otherwise its two-complement would be 2^{bit_size}
+        let q_sign = self.mul_if_non_zero_var(q_sign, q1)?;
+        let r_sign = self.mul_if_non_zero_var(lhs_leading, r1)?;
+
         let quotient = self.two_complement(q1, q_sign, bit_size)?;
-        let remainder = self.two_complement(r1, lhs_leading, bit_size)?;
+        let remainder = self.two_complement(r1, r_sign, bit_size)?;
 
         Ok((quotient, remainder))
     }
 
-    /// Returns a variable which is constrained to be `lhs mod rhs`
+    /// Returns `flag` if `value` is non-zero, and zero otherwise.
+    fn mul_if_non_zero_var(
+        &mut self,
+        flag: AcirVar,
+        value: AcirVar,
+    ) -> Result<AcirVar, RuntimeError> {
+        let zero = self.add_constant(FieldElement::zero());
+        let is_zero = self.eq_var(value, zero)?;
+        let is_non_zero = self.not_var(is_zero, AcirType::unsigned(1))?;
+        self.mul_var(flag, is_non_zero)
+    }
+
+    /// Returns a variable which is constrained to be `lhs mod rhs`.
+    /// For signed integers the remainder has the same sign as `lhs`.
     pub(crate) fn modulo_var(
         &mut self,
         lhs: AcirVar,
         rhs: AcirVar,
-        bit_size: u32,
+        typ: AcirType,
         predicate: AcirVar,
     ) -> Result<AcirVar, RuntimeError> {
-        let (_, remainder) = self.euclidean_division_var(lhs, rhs, bit_size, predicate)?;
+        let remainder = match typ {
+            AcirType::NumericType(NumericType::Signed { bit_size }) => {
+                let (_, remainder) = self.signed_division_var(lhs, rhs, bit_size, predicate)?;
+                remainder
+            }
+            _ => {
+                let (_, remainder) =
+                    self.euclidean_division_var(lhs, rhs, typ.bit_size(), predicate)?;
+                remainder
+            }
+        };
         Ok(remainder)
     }
 
@@ -1126,8 +1221,9 @@ impl AcirContext {
         mut inputs: Vec<AcirValue>,
         output_count: usize,
     ) -> Result<Vec<AcirVar>, RuntimeError> {
-        // Separate out any arguments that should be constants
-        let constants = match name {
+        // Separate out any arguments that should be constants, along with any outputs
+        // which are assigned at compile-time
+        let (constant_inputs, constant_outputs) = match name {
             BlackBoxFunc::PedersenCommitment | BlackBoxFunc::PedersenHash => {
                 // The last argument of pedersen is the domain separator, which must be a constant
                 let domain_var = match inputs.pop() {
@@ -1151,23 +1247,109 @@ impl AcirContext {
                     }
                 };
 
-                vec![domain_constant]
+                (vec![domain_constant], Vec::new())
             }
-            _ => vec![],
+            BlackBoxFunc::BigIntAdd
+            | BlackBoxFunc::BigIntSub
+            | BlackBoxFunc::BigIntMul
+            | BlackBoxFunc::BigIntDiv => {
+                // Both operands are a bigint id followed by the id of its modulus.
+                let operands =
+                    self.black_box_constant_inputs(std::mem::take(&mut inputs), "BigInt")?;
+                let (lhs, modulus, rhs) = match operands.as_slice() {
+                    [lhs, lhs_modulus, rhs, rhs_modulus] => {
+                        if lhs_modulus != rhs_modulus {
+                            return Err(RuntimeError::BigIntModulusMismatch {
+                                call_stack: self.get_call_stack(),
+                            });
+                        }
+                        (*lhs, *lhs_modulus, *rhs)
+                    }
+                    _ => {
+                        return Err(RuntimeError::InternalError(InternalError::MissingArg {
+                            name: name.to_string(),
+                            arg: "bigint operands".to_string(),
+                            call_stack: self.get_call_stack(),
+                        }))
+                    }
+                };
+                let output = self.big_int_ctx.new_big_int();
+                (vec![lhs, rhs], vec![output, modulus])
+            }
+            BlackBoxFunc::BigIntFromLeBytes => {
+                // The last argument is the little-endian bytes of the modulus, which must be constant.
+                let modulus = match inputs.pop() {
+                    Some(modulus) => {
+                        self.black_box_constant_inputs(vec![modulus], "BigInt modulus")?
+                    }
+                    None => {
+                        return Err(RuntimeError::InternalError(InternalError::MissingArg {
+                            name: name.to_string(),
+                            arg: "modulus".to_string(),
+                            call_stack: self.get_call_stack(),
+                        }))
+                    }
+                };
+                let modulus_bytes = vecmap(&modulus, |byte| byte.to_u128() as u8);
+                let modulus_id =
+                    self.big_int_ctx.get_or_insert_modulus(BigUint::from_bytes_le(&modulus_bytes));
+                let output = self.big_int_ctx.new_big_int();
+                (modulus, vec![output, modulus_id])
+            }
+            BlackBoxFunc::BigIntToLeBytes => {
+                let input =
+                    self.black_box_constant_inputs(std::mem::take(&mut inputs), "BigInt")?;
+                (vec![input[0]], Vec::new())
+            }
+            _ => (Vec::new(), Vec::new()),
         };
 
         // Convert `AcirVar` to `FunctionInput`
         let inputs = self.prepare_inputs_for_black_box_func_call(inputs)?;
 
+        // Bigints are returned as the ids assigned above rather than as witnesses.
+        let witness_output_count = output_count - constant_outputs.len();
+
         // Call Black box with `FunctionInput`
-        let outputs = self.acir_ir.call_black_box(name, &inputs, constants, output_count)?;
+        let outputs = self.acir_ir.call_black_box(
+            name,
+            &inputs,
+            constant_inputs,
+            constant_outputs.clone(),
+            witness_output_count,
+        )?;
 
         // Convert `Witness` values which are now constrained to be the output of the
         // black box function call into `AcirVar`s.
         //
         // We do not apply range information on the output of the black box function.
         // See issue #1439
-        Ok(vecmap(&outputs, |witness_index| self.add_data(AcirVarData::Witness(*witness_index))))
+        let mut results =
+            vecmap(&outputs, |witness_index| self.add_data(AcirVarData::Witness(*witness_index)));
+        results.extend(constant_outputs.into_iter().map(|constant| self.add_constant(constant)));
+        Ok(results)
+    }
+
+    /// Returns the values of the given inputs to a black box function, which must all be known
+    /// at compile-time. This is not the case when e.g. a bigint is chosen by a runtime condition.
+    fn black_box_constant_inputs(
+        &self,
+        inputs: Vec<AcirValue>,
+        name: &str,
+    ) -> Result<Vec<FieldElement>, RuntimeError> {
+        let mut constants = Vec::new();
+        for (var, _) in inputs.into_iter().flat_map(AcirValue::flatten) {
+            match self.vars[&var].as_constant() {
+                Some(constant) => constants.push(constant),
+                None => {
+                    return Err(RuntimeError::UnknownBigInt {
+                        name: name.to_string(),
+                        call_stack: self.get_call_stack(),
+                    })
+                }
+            }
+        }
+        Ok(constants)
     }
 
     /// Black box function calls expect their inputs to be in a specific data structure (FunctionInput).
@@ -0,0 +1,34 @@
+use acvm::FieldElement;
+use num_bigint::BigUint;
+
+/// Bigints are not stored in witnesses in ACIR but are referred to by an id, which is
+/// assigned at compile-time. A bigint is represented in SSA by its id along with the id
+/// of its modulus, both of which must be constants by the time ACIR is generated.
+#[derive(Default, Debug)]
+pub(crate) struct BigIntContext {
+    /// The moduli used by the program, indexed by their id.
+    modulus: Vec<BigUint>,
+    /// The number of bigints created so far.
+    big_int_count: u32,
+}
+
+impl BigIntContext {
+    /// Returns the id of a new bigint.
+    pub(crate) fn new_big_int(&mut self) -> FieldElement {
+        let id = self.big_int_count;
+        self.big_int_count += 1;
+        FieldElement::from(id as u128)
+    }
+
+    /// Returns the id of the given modulus, adding it to the context if it is not already present.
+    pub(crate) fn get_or_insert_modulus(&mut self, modulus: BigUint) -> FieldElement {
+        let id = match self.modulus.iter().position(|existing| existing == &modulus) {
+            Some(id) => id,
+            None => {
+                self.modulus.push(modulus);
+                self.modulus.len() - 1
+            }
+        };
+        FieldElement::from(id as u128)
+    }
+}
@@ -158,13 +158,15 @@ impl AcirValue {
 impl Ssa {
     #[tracing::instrument(level = "trace", skip_all)]
     pub(crate) fn into_acir(
-        self,
+        mut self,
         brillig: Brillig,
         abi_distinctness: Distinctness,
         last_array_uses: &HashMap<ValueId, InstructionId>,
     ) -> Result<GeneratedAcir, RuntimeError> {
+        let ssa_warnings = std::mem::take(&mut self.warnings);
         let context = Context::new();
         let mut generated_acir = context.convert_ssa(self, brillig, last_array_uses)?;
+        generated_acir.warnings.extend(ssa_warnings);
 
         match abi_distinctness {
             Distinctness::Distinct => {
@@ -1536,22 +1538,29 @@ impl Context {
         let rhs = self.convert_numeric_value(binary.rhs, dfg)?;
 
         let binary_type = self.type_of_binary_operation(binary, dfg);
-        match &binary_type {
-            Type::Numeric(NumericType::Unsigned { bit_size })
-            | Type::Numeric(NumericType::Signed { bit_size }) => {
-                // Conservative max bit size that is small enough such that two operands can be
-                // multiplied and still fit within the field modulus. This is necessary for the
-                // truncation technique: result % 2^bit_size to be valid.
-                let max_integer_bit_size = FieldElement::max_num_bits() / 2;
-                if *bit_size > max_integer_bit_size {
-                    return Err(RuntimeError::UnsupportedIntegerSize {
-                        num_bits: *bit_size,
-                        max_num_bits: max_integer_bit_size,
-                        call_stack: self.acir_context.get_call_stack(),
-                    });
-                }
+        let bit_size_limit = match &binary_type {
+            // Max bit size that is small enough such that the sum of two operands still fits
+            // within the field modulus. Products which could overflow the field are computed
+            // using limbs instead.
+            Type::Numeric(NumericType::Unsigned { bit_size }) => {
+                Some((*bit_size, FieldElement::max_num_bits() - 2))
+            }
+            // Conservative max bit size that is small enough such that two operands can be
+            // multiplied and still fit within the field modulus. This is necessary for the
+            // truncation technique: result % 2^bit_size to be valid.
+            Type::Numeric(NumericType::Signed { bit_size }) => {
+                Some((*bit_size, FieldElement::max_num_bits() / 2))
+            }
+            _ => None,
+        };
+        if let Some((bit_size, max_integer_bit_size)) = bit_size_limit {
+            if bit_size > max_integer_bit_size {
+                return Err(RuntimeError::UnsupportedIntegerSize {
+                    num_bits: bit_size,
+                    max_num_bits: max_integer_bit_size,
+                    call_stack: self.acir_context.get_call_stack(),
+                });
             }
-            _ => {}
         }
 
         let binary_type = AcirType::from(binary_type);
@@ -1560,7 +1569,20 @@ impl Context {
         match binary.operator {
             BinaryOp::Add => self.acir_context.add_var(lhs, rhs),
             BinaryOp::Sub => self.acir_context.sub_var(lhs, rhs),
-            BinaryOp::Mul => self.acir_context.mul_var(lhs, rhs),
+            BinaryOp::Mul => match binary_type {
+                // The product of two such integers may not fit within a field element
+                AcirType::NumericType(NumericType::Unsigned { bit_size })
+                    if 2 * bit_size >= FieldElement::max_num_bits() =>
+                {
+                    self.acir_context.wide_mul_var(
+                        lhs,
+                        rhs,
+                        bit_size,
+                        Some("attempt to multiply with overflow".to_string()),
+                    )
+                }
+                _ => self.acir_context.mul_var(lhs, rhs),
+            },
             BinaryOp::Div => self.acir_context.div_var(
                 lhs,
                 rhs,
@@ -1587,7 +1609,7 @@ impl Context {
             BinaryOp::Mod => self.acir_context.modulo_var(
                 lhs,
                 rhs,
-                bit_count,
+                binary_type,
                 self.current_side_effects_enabled_var,
             ),
         }
@@ -1690,7 +1712,9 @@ impl Context {
                 ) {
                     // Subtractions must first have the integer modulus added before truncation can be
                     // applied. This is done in order to prevent underflow.
-                    let integer_modulus = self.acir_context.add_constant(2_u128.pow(bit_size));
+                    let integer_modulus = self.acir_context.add_constant(
+                        FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128)),
+                    );
                     var = self.acir_context.add_var(var, integer_modulus)?;
                 }
             }
@@ -157,6 +157,21 @@ impl FunctionBuilder {
         )
     }
 
+    /// Inserts a new instruction at the end of the current block and returns its results,
+    /// without simplifying it first. This keeps SSA parsed from text exactly as it was written.
+    pub(crate) fn insert_instruction_without_simplification(
+        &mut self,
+        instruction: Instruction,
+        ctrl_typevars: Option<Vec<Type>>,
+    ) -> InsertInstructionResult {
+        self.current_function.dfg.insert_instruction_without_simplification(
+            instruction,
+            self.current_block,
+            ctrl_typevars,
+            self.call_stack.clone(),
+        )
+    }
+
     /// Switch to inserting instructions in the given block.
     /// Expects the given block to be within the same function. If you want to insert
     /// instructions into a new function, call new_function instead.
@@ -267,7 +282,9 @@ impl FunctionBuilder {
     }
 
     /// Insert ssa instructions which computes lhs << rhs by doing lhs*2^rhs
-    /// and truncate the result to bit_size
+    /// and truncate the result to bit_size.
+    /// The instructions never overflow the type of lhs, so that they pass the overflow checks
+    /// of integer operations in both ACIR and Brillig.
     pub(crate) fn insert_wrapping_shift_left(
         &mut self,
         lhs: ValueId,
@@ -276,45 +293,82 @@ impl FunctionBuilder {
     ) -> ValueId {
         let base = self.field_constant(FieldElement::from(2_u128));
         let typ = self.current_function.dfg.type_of_value(lhs);
-        let (max_bit, pow) =
-            if let Some(rhs_constant) = self.current_function.dfg.get_numeric_constant(rhs) {
-                // Happy case is that we know precisely by how many bits the the integer will
-                // increase: lhs_bit_size + rhs
-                let (rhs_bit_size_pow_2, overflows) =
-                    2_u128.overflowing_pow(rhs_constant.to_u128() as u32);
-                if overflows {
-                    assert!(bit_size < 128, "ICE - shift left with big integers are not supported");
-                    if bit_size < 128 {
-                        let zero = self.numeric_constant(FieldElement::zero(), typ);
-                        return InsertInstructionResult::SimplifiedTo(zero).first();
-                    }
-                }
-                let pow = self.numeric_constant(FieldElement::from(rhs_bit_size_pow_2), typ);
-                (bit_size + (rhs_constant.to_u128() as u32), pow)
-            } else {
-                // we use a predicate to nullify the result in case of overflow
-                let bit_size_var =
-                    self.numeric_constant(FieldElement::from(bit_size as u128), typ.clone());
-                let overflow = self.insert_binary(rhs, BinaryOp::Lt, bit_size_var);
-                let one = self.numeric_constant(FieldElement::one(), Type::unsigned(1));
-                let predicate = self.insert_binary(overflow, BinaryOp::Eq, one);
-                let predicate = self.insert_cast(predicate, typ.clone());
-                // we can safely cast to unsigned because overflow_checks prevent bit-shift with a negative value
-                let rhs_unsigned = self.insert_cast(rhs, Type::unsigned(bit_size));
-                let pow = self.pow(base, rhs_unsigned);
-                let pow = self.insert_cast(pow, typ);
-                (FieldElement::max_num_bits(), self.insert_binary(predicate, BinaryOp::Mul, pow))
-            };
-
-        if max_bit <= bit_size {
-            self.insert_binary(lhs, BinaryOp::Mul, pow)
-        } else {
+        if let Some(rhs_constant) = self.current_function.dfg.get_numeric_constant(rhs) {
+            // Happy case is that we know precisely how many bits of lhs are kept,
+            // so we remove the others before shifting: lhs << rhs = (lhs % 2^{bit_size-rhs}) * 2^rhs
+            let rhs_constant = rhs_constant.try_into_u128().unwrap_or(u128::MAX);
+            if rhs_constant == 0 {
+                return lhs;
+            }
+            if rhs_constant >= bit_size as u128 {
+                return self.numeric_constant(FieldElement::zero(), typ);
+            }
+            // lhs is cast to an unsigned integer so that its sign is not taken into account
+            let unsigned_type = Type::unsigned(bit_size);
+            let rhs_constant = rhs_constant as u32;
+            let two = FieldElement::from(2_u128);
+            let kept_bits = two.pow(&FieldElement::from((bit_size - rhs_constant) as u128));
+            let kept_bits = self.numeric_constant(kept_bits, unsigned_type.clone());
+            let pow = two.pow(&FieldElement::from(rhs_constant as u128));
+            let pow = self.numeric_constant(pow, unsigned_type.clone());
+            let lhs = self.insert_cast(lhs, unsigned_type);
+            let lhs = self.insert_binary(lhs, BinaryOp::Mod, kept_bits);
+            let result = self.insert_binary(lhs, BinaryOp::Mul, pow);
+            return self.insert_cast(result, typ);
+        }
+
+        // we use a predicate to nullify the result in case of overflow
+        let bit_size_var = self.numeric_constant(FieldElement::from(bit_size as u128), typ.clone());
+        let overflow = self.insert_binary(rhs, BinaryOp::Lt, bit_size_var);
+        let one = self.numeric_constant(FieldElement::one(), Type::unsigned(1));
+        let predicate = self.insert_binary(overflow, BinaryOp::Eq, one);
+
+        if 2 * bit_size < FieldElement::max_num_bits() {
+            // lhs*2^rhs fits in a field element, so we compute it there and truncate it back to bit_size
+            let predicate = self.insert_cast(predicate, Type::field());
+            // we can safely cast to unsigned because overflow_checks prevent bit-shift with a negative value
+            let rhs_unsigned = self.insert_cast(rhs, Type::unsigned(bit_size));
+            let pow = self.pow(base, rhs_unsigned);
+            let pow = self.insert_binary(predicate, BinaryOp::Mul, pow);
+            let lhs = self.insert_cast(lhs, Type::field());
             let result = self.insert_binary(lhs, BinaryOp::Mul, pow);
-            self.insert_truncate(result, bit_size, max_bit)
+            return self.insert_cast(result, typ);
         }
+
+        // Otherwise lhs*2^rhs could overflow the field, so we remove the bits of lhs which are shifted out
+        // before shifting it. 2^{bit_size-rhs} may not fit within bit_size bits so instead of reducing lhs
+        // modulo 2^{bit_size-rhs}, we divide it by 2^{bit_size-rhs-1} and then clear the lowest bit of the quotient.
+        // The shift is less than the bit size, which is less than 2^8, so it is computed as a u8
+        let shift_type = Type::unsigned(8);
+        let unsigned_type = Type::unsigned(bit_size);
+        let predicate = self.insert_cast(predicate, shift_type.clone());
+        let rhs = self.insert_cast(rhs, shift_type.clone());
+        let shift = self.insert_binary(predicate, BinaryOp::Mul, rhs);
+        let max_shift =
+            self.numeric_constant(FieldElement::from((bit_size - 1) as u128), shift_type);
+        let exponent = self.insert_binary(max_shift, BinaryOp::Sub, shift);
+        let divisor = self.pow(base, exponent);
+        let divisor = self.insert_cast(divisor, unsigned_type.clone());
+
+        let lhs = self.insert_cast(lhs, unsigned_type.clone());
+        let quotient = self.insert_binary(lhs, BinaryOp::Div, divisor);
+        let two = self.numeric_constant(FieldElement::from(2_u128), unsigned_type.clone());
+        let lowest_bit = self.insert_binary(quotient, BinaryOp::Mod, two);
+        let quotient = self.insert_binary(quotient, BinaryOp::Sub, lowest_bit);
+        let shifted_out = self.insert_binary(quotient, BinaryOp::Mul, divisor);
+        let lhs = self.insert_binary(lhs, BinaryOp::Sub, shifted_out);
+
+        let pow = self.pow(base, shift);
+        let pow = self.insert_cast(pow, unsigned_type);
+        let result = self.insert_binary(lhs, BinaryOp::Mul, pow);
+        self.insert_cast(result, typ)
     }
 
     /// Insert ssa instructions which computes lhs >> rhs by doing lhs/2^rhs
+    ///
+    /// For signed integers this is an arithmetic shift, which rounds towards negative infinity:
+    /// the bits of the two's complement representation of lhs are shifted and the sign bit is
+    /// copied into the vacated high bits.
     pub(crate) fn insert_shift_right(
         &mut self,
         lhs: ValueId,
@@ -325,7 +379,33 @@ impl FunctionBuilder {
         // we can safely cast to unsigned because overflow_checks prevent bit-shift with a negative value
         let rhs_unsigned = self.insert_cast(rhs, Type::unsigned(bit_size));
         let pow = self.pow(base, rhs_unsigned);
-        self.insert_binary(lhs, BinaryOp::Div, pow)
+
+        let typ = self.current_function.dfg.type_of_value(lhs);
+        if !matches!(typ, Type::Numeric(NumericType::Signed { .. })) {
+            return self.insert_binary(lhs, BinaryOp::Div, pow);
+        }
+
+        // Shift the two's complement representation of lhs as an unsigned integer
+        let unsigned_type = Type::unsigned(bit_size);
+        let lhs_unsigned = self.insert_cast(lhs, unsigned_type.clone());
+        let shifted = self.insert_binary(lhs_unsigned, BinaryOp::Div, pow);
+
+        // If lhs is negative, set the rhs highest bits: 2^bit_size - 2^{bit_size-rhs}
+        let half_width = self.numeric_constant(
+            FieldElement::from(2_i128).pow(&FieldElement::from((bit_size - 1) as i128)),
+            unsigned_type,
+        );
+        let sign = self.insert_binary(lhs_unsigned, BinaryOp::Div, half_width);
+        let max = self
+            .field_constant(FieldElement::from(2_i128).pow(&FieldElement::from(bit_size as i128)));
+        let vacated = self.insert_binary(max, BinaryOp::Div, pow);
+        let high_bits = self.insert_binary(max, BinaryOp::Sub, vacated);
+
+        let sign = self.insert_cast(sign, Type::field());
+        let high_bits = self.insert_binary(sign, BinaryOp::Mul, high_bits);
+        let shifted = self.insert_cast(shifted, Type::field());
+        let result = self.insert_binary(shifted, BinaryOp::Add, high_bits);
+        self.insert_cast(result, typ)
     }
 
     /// Computes lhs^rhs via square&multiply, using the bits decomposition of rhs
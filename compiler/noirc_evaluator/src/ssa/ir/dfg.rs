@@ -168,14 +168,31 @@ impl DataFlowGraph {
             SimplifyResult::Remove => InstructionRemoved,
             result @ (SimplifyResult::SimplifiedToInstruction(_) | SimplifyResult::None) => {
                 let instruction = result.instruction().unwrap_or(instruction);
-                let id = self.make_instruction(instruction, ctrl_typevars);
-                self.blocks[block].insert_instruction(id);
-                self.locations.insert(id, call_stack);
-                InsertInstructionResult::Results(id, self.instruction_results(id))
+                self.insert_instruction_without_simplification(
+                    instruction,
+                    block,
+                    ctrl_typevars,
+                    call_stack,
+                )
             }
         }
     }
 
+    /// Inserts a new instruction at the end of the given block and returns its results,
+    /// keeping the instruction as it is even if it could be simplified.
+    pub(crate) fn insert_instruction_without_simplification(
+        &mut self,
+        instruction: Instruction,
+        block: BasicBlockId,
+        ctrl_typevars: Option<Vec<Type>>,
+        call_stack: CallStack,
+    ) -> InsertInstructionResult {
+        let id = self.make_instruction(instruction, ctrl_typevars);
+        self.blocks[block].insert_instruction(id);
+        self.locations.insert(id, call_stack);
+        InsertInstructionResult::Results(id, self.instruction_results(id))
+    }
+
     /// Insert a value into the dfg's storage and return an id to reference it.
     /// Until the value is used in an instruction it is unreachable.
     pub(crate) fn make_value(&mut self, value: Value) -> ValueId {
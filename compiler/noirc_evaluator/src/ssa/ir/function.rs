@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 
 use iter_extended::vecmap;
+use noirc_frontend::token::InlineType;
 
 use super::basic_block::BasicBlockId;
 use super::dfg::DataFlowGraph;
@@ -35,6 +36,9 @@ pub(crate) struct Function {
 
     runtime: RuntimeType,
 
+    /// Set by an `#[inline(..)]` attribute on the function. Only used by the inlining pass.
+    inline_type: Option<InlineType>,
+
     /// The DataFlowGraph holds the majority of data pertaining to the function
     /// including its blocks, instructions, and values.
     pub(crate) dfg: DataFlowGraph,
@@ -47,7 +51,7 @@ impl Function {
     pub(crate) fn new(name: String, id: FunctionId) -> Self {
         let mut dfg = DataFlowGraph::default();
         let entry_block = dfg.make_block();
-        Self { name, id, entry_block, dfg, runtime: RuntimeType::Acir }
+        Self { name, id, entry_block, dfg, runtime: RuntimeType::Acir, inline_type: None }
     }
 
     /// The name of the function.
@@ -71,6 +75,17 @@ impl Function {
         self.runtime = runtime;
     }
 
+    /// Whether calls to the function should always or never be inlined, if this is not
+    /// left to the inlining pass.
+    pub(crate) fn inline_type(&self) -> Option<InlineType> {
+        self.inline_type
+    }
+
+    /// Set the inline type of the function.
+    pub(crate) fn set_inline_type(&mut self, inline_type: Option<InlineType>) {
+        self.inline_type = inline_type;
+    }
+
     /// Retrieves the entry block of a function.
     ///
     /// A function's entry block contains the instructions
@@ -46,6 +46,7 @@ pub(crate) enum Intrinsic {
     BlackBox(BlackBoxFunc),
     FromField,
     AsField,
+    WrappingMul,
 }
 
 impl std::fmt::Display for Intrinsic {
@@ -68,6 +69,7 @@ impl std::fmt::Display for Intrinsic {
             Intrinsic::BlackBox(function) => write!(f, "{function}"),
             Intrinsic::FromField => write!(f, "from_field"),
             Intrinsic::AsField => write!(f, "as_field"),
+            Intrinsic::WrappingMul => write!(f, "wrapping_mul"),
         }
     }
 }
@@ -92,7 +94,8 @@ impl Intrinsic {
             | Intrinsic::ToBits(_)
             | Intrinsic::ToRadix(_)
             | Intrinsic::FromField
-            | Intrinsic::AsField => false,
+            | Intrinsic::AsField
+            | Intrinsic::WrappingMul => false,
 
             // Some black box functions have side-effects
             Intrinsic::BlackBox(func) => matches!(func, BlackBoxFunc::RecursiveAggregation),
@@ -119,6 +122,7 @@ impl Intrinsic {
             "to_be_bits" => Some(Intrinsic::ToBits(Endian::Big)),
             "from_field" => Some(Intrinsic::FromField),
             "as_field" => Some(Intrinsic::AsField),
+            "wrapping_mul" => Some(Intrinsic::WrappingMul),
             other => BlackBoxFunc::lookup(other).map(Intrinsic::BlackBox),
         }
     }
@@ -531,9 +535,11 @@ impl Instruction {
             }
             Instruction::Truncate { value, bit_size, max_bit_size } => {
                 if let Some((numeric_constant, typ)) = dfg.get_numeric_constant_with_type(*value) {
-                    let integer_modulus = 2_u128.pow(*bit_size);
-                    let truncated = numeric_constant.to_u128() % integer_modulus;
-                    SimplifiedTo(dfg.make_constant(truncated.into(), typ))
+                    let integer_modulus = BigUint::from(2_u128).pow(*bit_size);
+                    let truncated =
+                        BigUint::from_bytes_be(&numeric_constant.to_be_bytes()) % integer_modulus;
+                    let truncated = FieldElement::from_be_bytes_reduce(&truncated.to_bytes_be());
+                    SimplifiedTo(dfg.make_constant(truncated, typ))
                 } else if let Value::Instruction { instruction, .. } = &dfg[dfg.resolve(*value)] {
                     if let Instruction::Truncate { bit_size: src_bit_size, .. } = &dfg[*instruction]
                     {
@@ -947,7 +953,7 @@ fn eval_constant_binary_op(
             }
             let result = function(lhs, rhs)?;
             // Check for overflow
-            if result >= 2u128.pow(*bit_size) {
+            if *bit_size < 128 && result >= 2u128.pow(*bit_size) {
                 return None;
             }
             result.into()
@@ -990,6 +996,10 @@ fn eval_constant_binary_op(
 }
 
 fn truncate(int: u128, bit_size: u32) -> u128 {
+    if bit_size >= 128 {
+        // Any u128 already fits within the bit size
+        return int;
+    }
     let max = 2u128.pow(bit_size);
     int % max
 }
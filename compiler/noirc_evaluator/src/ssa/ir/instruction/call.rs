@@ -10,7 +10,7 @@ use crate::ssa::{
         dfg::{CallStack, DataFlowGraph},
         instruction::Intrinsic,
         map::Id,
-        types::Type,
+        types::{NumericType, Type},
         value::{Value, ValueId},
     },
     opt::flatten_cfg::value_merger::ValueMerger,
@@ -245,9 +245,78 @@ pub(super) fn simplify_call(
             let instruction = Instruction::Cast(arguments[0], ctrl_typevars.unwrap().remove(0));
             SimplifyResult::SimplifiedToInstruction(instruction)
         }
+        Intrinsic::WrappingMul => simplify_wrapping_mul(arguments[0], arguments[1], dfg, block),
     }
 }
 
+/// Expands a wrapping multiplication into a product computed in the field, which is then
+/// truncated to the bit size of the operands by casting it back to their type.
+///
+/// When the product of two operands may not fit within a field element, each operand is split into
+/// limbs as in the checked multiplication of wide integers: `x = x_hi * 2^half + x_lo` where
+/// `half = ceil(bit_size / 2)`. The product of the high limbs is a multiple of `2^bit_size`, so it is
+/// dropped, and the cross terms are truncated to `bit_size - half` bits before being shifted by `half`.
+/// The remaining sum is less than `3 * 2^bit_size`, which fits in a field element for integers of up
+/// to `FieldElement::max_num_bits() - 2` bits.
+fn simplify_wrapping_mul(
+    lhs: ValueId,
+    rhs: ValueId,
+    dfg: &mut DataFlowGraph,
+    block: BasicBlockId,
+) -> SimplifyResult {
+    let typ = dfg.type_of_value(lhs);
+    let bit_size = match &typ {
+        Type::Numeric(NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size }) => {
+            *bit_size
+        }
+        _ => {
+            let instruction = Instruction::Binary(Binary { lhs, operator: BinaryOp::Mul, rhs });
+            return SimplifyResult::SimplifiedToInstruction(instruction);
+        }
+    };
+
+    let call_stack = dfg.get_value_call_stack(lhs);
+    let insert = |dfg: &mut DataFlowGraph, instruction| {
+        dfg.insert_instruction_and_results(instruction, block, None, call_stack.clone()).first()
+    };
+    let binary = |dfg: &mut DataFlowGraph, lhs, operator, rhs| {
+        insert(dfg, Instruction::Binary(Binary { lhs, operator, rhs }))
+    };
+
+    let lhs = insert(dfg, Instruction::Cast(lhs, Type::field()));
+    let rhs = insert(dfg, Instruction::Cast(rhs, Type::field()));
+
+    if 2 * bit_size < FieldElement::max_num_bits() {
+        let product = binary(dfg, lhs, BinaryOp::Mul, rhs);
+        return SimplifyResult::SimplifiedToInstruction(Instruction::Cast(product, typ));
+    }
+
+    let half = (bit_size + 1) / 2;
+    let limb_base = FieldElement::from(2_u128).pow(&FieldElement::from(half as u128));
+    let limb_base = dfg.make_constant(limb_base, Type::field());
+
+    let split = |dfg: &mut DataFlowGraph, value| {
+        let low = insert(dfg, Instruction::Cast(value, Type::unsigned(half)));
+        let low = insert(dfg, Instruction::Cast(low, Type::field()));
+        let high = binary(dfg, value, BinaryOp::Sub, low);
+        let high = binary(dfg, high, BinaryOp::Div, limb_base);
+        (high, low)
+    };
+    let (lhs_high, lhs_low) = split(dfg, lhs);
+    let (rhs_high, rhs_low) = split(dfg, rhs);
+
+    let lhs_cross = binary(dfg, lhs_high, BinaryOp::Mul, rhs_low);
+    let rhs_cross = binary(dfg, lhs_low, BinaryOp::Mul, rhs_high);
+    let cross_terms = binary(dfg, lhs_cross, BinaryOp::Add, rhs_cross);
+    let cross_terms = insert(dfg, Instruction::Cast(cross_terms, Type::unsigned(bit_size - half)));
+    let cross_terms = insert(dfg, Instruction::Cast(cross_terms, Type::field()));
+
+    let low_product = binary(dfg, lhs_low, BinaryOp::Mul, rhs_low);
+    let product = binary(dfg, cross_terms, BinaryOp::Mul, limb_base);
+    let product = binary(dfg, product, BinaryOp::Add, low_product);
+    SimplifyResult::SimplifiedToInstruction(Instruction::Cast(product, typ))
+}
+
 /// Slices have a tuple structure (slice length, slice contents) to enable logic
 /// that uses dynamic slice lengths (such as with merging slices in the flattening pass).
 /// This method codegens an update to the slice length.
@@ -421,7 +490,13 @@ fn simplify_black_box_func(
             SimplifyResult::None
         }
 
-        BlackBoxFunc::RecursiveAggregation => SimplifyResult::None,
+        BlackBoxFunc::RecursiveAggregation
+        | BlackBoxFunc::BigIntAdd
+        | BlackBoxFunc::BigIntSub
+        | BlackBoxFunc::BigIntMul
+        | BlackBoxFunc::BigIntDiv
+        | BlackBoxFunc::BigIntFromLeBytes
+        | BlackBoxFunc::BigIntToLeBytes => SimplifyResult::None,
 
         BlackBoxFunc::AND => {
             unreachable!("ICE: `BlackBoxFunc::AND` calls should be transformed into a `BinaryOp`")
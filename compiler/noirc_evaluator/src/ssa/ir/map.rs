@@ -19,9 +19,10 @@ pub(crate) struct Id<T> {
 
 impl<T> Id<T> {
     /// Constructs a new Id for the given index.
-    /// This constructor is deliberately private to prevent
-    /// constructing invalid IDs.
-    fn new(index: usize) -> Self {
+    /// Ids should otherwise be obtained from DenseMap::insert and SparseMap::insert,
+    /// this constructor is only for recreating the ids of parsed SSA, whose validity
+    /// is up to the caller.
+    pub(crate) fn new(index: usize) -> Self {
         Self { index, _marker: std::marker::PhantomData }
     }
 
@@ -1,4 +1,5 @@
 //! This file is for pretty-printing the SSA IR in a human-readable form for debugging.
+//! The printed form can be read back in by the [SSA parser][crate::ssa::parser].
 use std::{
     collections::HashSet,
     fmt::{Formatter, Result},
@@ -68,13 +69,12 @@ fn value(function: &Function, id: ValueId) -> String {
         }
         Value::Function(id) => id.to_string(),
         Value::Intrinsic(intrinsic) => intrinsic.to_string(),
-        Value::Array { array, .. } => {
+        Value::Array { array, typ } => {
             let elements = vecmap(array, |element| value(function, *element));
-            format!("[{}]", elements.join(", "))
-        }
-        Value::Param { .. } | Value::Instruction { .. } | Value::ForeignFunction(_) => {
-            id.to_string()
+            format!("[{}] of {typ}", elements.join(", "))
         }
+        Value::ForeignFunction(name) => name.clone(),
+        Value::Param { .. } | Value::Instruction { .. } => id.to_string(),
     }
 }
 
@@ -93,6 +93,16 @@ fn value_list(function: &Function, values: &[ValueId]) -> String {
     vecmap(values, |id| value(function, *id)).join(", ")
 }
 
+/// Display the types of an instruction's results, for the instructions whose
+/// result types are not known from their operands. E.g. ` -> Field, u1`
+fn result_types(function: &Function, results: &[ValueId]) -> String {
+    if results.is_empty() {
+        return String::new();
+    }
+    let types = vecmap(results, |id| function.dfg.type_of_value(*id).to_string());
+    format!(" -> {}", types.join(", "))
+}
+
 /// Display a terminator instruction
 pub(crate) fn display_terminator(
     function: &Function,
@@ -150,10 +160,14 @@ pub(crate) fn display_instruction(
             None => writeln!(f, "constrain {} == {}", show(*lhs), show(*rhs)),
         },
         Instruction::Call { func, arguments } => {
-            writeln!(f, "call {}({})", show(*func), value_list(function, arguments))
+            let arguments = value_list(function, arguments);
+            let result_types = result_types(function, results);
+            writeln!(f, "call {}({arguments}){result_types}", show(*func))
+        }
+        Instruction::Allocate => writeln!(f, "allocate{}", result_types(function, results)),
+        Instruction::Load { address } => {
+            writeln!(f, "load {}{}", show(*address), result_types(function, results))
         }
-        Instruction::Allocate => writeln!(f, "allocate"),
-        Instruction::Load { address } => writeln!(f, "load {}", show(*address)),
         Instruction::Store { address, value } => {
             writeln!(f, "store {} at {}", show(*value), show(*address))
         }
@@ -161,7 +175,8 @@ pub(crate) fn display_instruction(
             writeln!(f, "enable_side_effects {}", show(*condition))
         }
         Instruction::ArrayGet { array, index } => {
-            writeln!(f, "array_get {}, index {}", show(*array), show(*index))
+            let result_types = result_types(function, results);
+            writeln!(f, "array_get {}, index {}{result_types}", show(*array), show(*index))
         }
         Instruction::ArraySet { array, index, value } => {
             writeln!(
@@ -175,8 +190,14 @@ pub(crate) fn display_instruction(
         Instruction::IncrementRc { value } => {
             writeln!(f, "inc_rc {}", show(*value))
         }
-        Instruction::RangeCheck { value, max_bit_size, .. } => {
-            writeln!(f, "range_check {} to {} bits", show(*value), *max_bit_size,)
+        Instruction::RangeCheck { value, max_bit_size, assert_message } => {
+            let value = show(*value);
+            match assert_message {
+                Some(message) => {
+                    writeln!(f, "range_check {value} to {max_bit_size} bits '{message}'")
+                }
+                None => writeln!(f, "range_check {value} to {max_bit_size} bits"),
+            }
         }
     }
 }
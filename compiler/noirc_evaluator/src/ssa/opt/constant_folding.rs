@@ -18,21 +18,28 @@
 //! [`DataFlowGraph::set_value_from_id`] are used on a value which enables instructions dependent on the value to
 //! now be simplified.
 //!
-//! This is the only pass which removes duplicated pure [`Instruction`]s however and so is needed when
-//! different blocks are merged, i.e. after the [`flatten_cfg`][super::flatten_cfg] pass.
+//! Together with [`gvn`][super::gvn], which also removes duplicates across blocks, this is the only
+//! pass which removes duplicated pure [`Instruction`]s however and so is needed when different blocks
+//! are merged, i.e. after the [`flatten_cfg`][super::flatten_cfg] pass.
 use std::collections::HashSet;
 
+use acvm::FieldElement;
 use iter_extended::vecmap;
+use num_bigint::{BigInt, BigUint};
 
-use crate::ssa::{
-    ir::{
-        basic_block::BasicBlockId,
-        dfg::{DataFlowGraph, InsertInstructionResult},
-        function::Function,
-        instruction::{Instruction, InstructionId},
-        value::ValueId,
+use crate::{
+    errors::{InternalWarning, SsaReport},
+    ssa::{
+        ir::{
+            basic_block::BasicBlockId,
+            dfg::{DataFlowGraph, InsertInstructionResult},
+            function::Function,
+            instruction::{BinaryOp, Instruction, InstructionId},
+            types::{NumericType, Type},
+            value::ValueId,
+        },
+        ssa_gen::Ssa,
     },
-    ssa_gen::Ssa,
 };
 use fxhash::FxHashMap as HashMap;
 
@@ -43,7 +50,8 @@ impl Ssa {
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) fn fold_constants(mut self) -> Ssa {
         for function in self.functions.values_mut() {
-            constant_fold(function);
+            let warnings = constant_fold(function);
+            self.warnings.extend(warnings);
         }
         self
     }
@@ -51,7 +59,9 @@ impl Ssa {
 
 /// The structure of this pass is simple:
 /// Go through each block and re-insert all instructions.
-fn constant_fold(function: &mut Function) {
+///
+/// Returns a warning for each integer operation on constants which overflows its type.
+fn constant_fold(function: &mut Function) -> Vec<SsaReport> {
     let mut context = Context::default();
     context.block_queue.push(function.entry_block());
 
@@ -63,6 +73,7 @@ fn constant_fold(function: &mut Function) {
         context.visited_blocks.insert(block);
         context.fold_constants_in_block(function, block);
     }
+    context.warnings
 }
 
 #[derive(Default)]
@@ -70,6 +81,7 @@ struct Context {
     /// Maps pre-folded ValueIds to the new ValueIds obtained by re-inserting the instruction.
     visited_blocks: HashSet<BasicBlockId>,
     block_queue: Vec<BasicBlockId>,
+    warnings: Vec<SsaReport>,
 }
 
 impl Context {
@@ -79,7 +91,24 @@ impl Context {
         // Cache of instructions without any side-effects along with their outputs.
         let mut cached_instruction_results: HashMap<Instruction, Vec<ValueId>> = HashMap::default();
 
+        // Instructions are not executed while side effects are known to be disabled,
+        // so they cannot fail and we do not report them.
+        let mut side_effects_disabled = false;
+
         for instruction_id in instructions {
+            if let Instruction::EnableSideEffects { condition } = &function.dfg[instruction_id] {
+                side_effects_disabled =
+                    function.dfg.get_numeric_constant(*condition).map_or(false, |c| c.is_zero());
+            }
+            if !side_effects_disabled {
+                if let Some(warning) = check_for_overflow(&function.dfg, instruction_id) {
+                    // Unrolled loops may repeat an overflowing operation, which is reported once.
+                    if !self.warnings.iter().any(|reported| same_location(reported, &warning)) {
+                        self.warnings.push(warning);
+                    }
+                }
+            }
+
             Self::fold_constants_into_instruction(
                 &mut function.dfg,
                 block,
@@ -176,18 +205,91 @@ impl Context {
     }
 }
 
+/// Returns a warning if the instruction is an addition, subtraction or multiplication of integer
+/// constants whose result overflows their type. Such operations are not folded, and they fail at runtime.
+fn check_for_overflow(dfg: &DataFlowGraph, instruction_id: InstructionId) -> Option<SsaReport> {
+    let binary = match &dfg[instruction_id] {
+        Instruction::Binary(binary) => binary,
+        _ => return None,
+    };
+    let operation = match binary.operator {
+        BinaryOp::Add => "add",
+        BinaryOp::Sub => "subtract",
+        BinaryOp::Mul => "multiply",
+        _ => return None,
+    };
+    let typ = match dfg.type_of_value(binary.lhs) {
+        Type::Numeric(typ @ (NumericType::Signed { .. } | NumericType::Unsigned { .. })) => typ,
+        _ => return None,
+    };
+    let lhs = integer_value(dfg.get_numeric_constant(binary.lhs)?, typ);
+    let rhs = integer_value(dfg.get_numeric_constant(binary.rhs)?, typ);
+
+    let value = match binary.operator {
+        BinaryOp::Add => lhs + rhs,
+        BinaryOp::Sub => lhs - rhs,
+        _ => lhs * rhs,
+    };
+    let (min, max) = match typ {
+        NumericType::Signed { bit_size } => {
+            let half_width = BigInt::from(1) << (bit_size - 1);
+            (-half_width.clone(), half_width - 1)
+        }
+        NumericType::Unsigned { bit_size } => (BigInt::from(0), (BigInt::from(1) << bit_size) - 1),
+        NumericType::NativeField => unreachable!("ICE: Field operations cannot overflow"),
+    };
+    if min <= value && value <= max {
+        return None;
+    }
+
+    Some(SsaReport::Warning(InternalWarning::IntegerOverflow {
+        operation: operation.to_string(),
+        value: value.to_string(),
+        typ: typ.to_string(),
+        call_stack: dfg.get_call_stack(instruction_id),
+    }))
+}
+
+/// Returns the integer represented by a constant of the given type,
+/// decoding the two's complement representation of signed integers.
+fn integer_value(constant: FieldElement, typ: NumericType) -> BigInt {
+    let value = BigInt::from(BigUint::from_bytes_be(&constant.to_be_bytes()));
+    match typ {
+        NumericType::Signed { bit_size } if value >= BigInt::from(1) << (bit_size - 1) => {
+            value - (BigInt::from(1) << bit_size)
+        }
+        _ => value,
+    }
+}
+
+/// Returns true if both reports are overflow warnings for the same location.
+fn same_location(lhs: &SsaReport, rhs: &SsaReport) -> bool {
+    match (lhs, rhs) {
+        (
+            SsaReport::Warning(InternalWarning::IntegerOverflow { call_stack: lhs, .. }),
+            SsaReport::Warning(InternalWarning::IntegerOverflow { call_stack: rhs, .. }),
+        ) => lhs == rhs,
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::rc::Rc;
 
-    use crate::ssa::{
-        function_builder::FunctionBuilder,
-        ir::{
-            function::RuntimeType,
-            instruction::{BinaryOp, Instruction, TerminatorInstruction},
-            map::Id,
-            types::Type,
-            value::{Value, ValueId},
+    use crate::{
+        errors::{InternalWarning, SsaReport},
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{
+                function::RuntimeType,
+                instruction::{BinaryOp, Instruction, TerminatorInstruction},
+                map::Id,
+                types::Type,
+                value::{Value, ValueId},
+            },
+            parser::assert_ssa_equals,
+            ssa_gen::Ssa,
         },
     };
 
@@ -247,6 +349,67 @@ mod test {
         }
     }
 
+    #[test]
+    fn deduplicates_pure_instructions() {
+        let src = "
+            acir fn main f0 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                v3 = add v0, Field 1
+                v4 = mul v2, v3
+                return v4
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+
+        // Each instruction is re-inserted, which gives the results new ids
+        let expected = "
+            acir fn main f0 {
+              b0(v0: Field):
+                v5 = add v0, Field 1
+                v6 = mul v5, v5
+                return v6
+            }
+            ";
+        assert_ssa_equals(&ssa.fold_constants(), expected);
+    }
+
+    #[test]
+    fn reports_overflowing_constant_operations() {
+        // fn main f0 {
+        //   b0(v0: u8):
+        //     v1 = add v0, u8 100
+        //     v2 = add v0, u8 50
+        //     return v1, v2
+        // }
+        //
+        // After constructing this IR, we set the value of v0 to 200.
+        // Only the first addition overflows.
+        let main_id = Id::test_new(0);
+
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::unsigned(8));
+
+        let fifty = builder.numeric_constant(50u128, Type::unsigned(8));
+        let hundred = builder.numeric_constant(100u128, Type::unsigned(8));
+        let two_hundred = builder.numeric_constant(200u128, Type::unsigned(8));
+
+        let v1 = builder.insert_binary(v0, BinaryOp::Add, hundred);
+        let v2 = builder.insert_binary(v0, BinaryOp::Add, fifty);
+        builder.terminate_with_return(vec![v1, v2]);
+
+        let mut ssa = builder.finish();
+        ssa.main_mut().dfg.set_value_from_id(v0, two_hundred);
+
+        let ssa = ssa.fold_constants();
+        assert_eq!(ssa.warnings.len(), 1);
+        assert!(matches!(
+            &ssa.warnings[0],
+            SsaReport::Warning(InternalWarning::IntegerOverflow { value, typ, .. })
+                if value == "300" && typ == "u8"
+        ));
+    }
+
     #[test]
     fn arrays_elements_are_updated() {
         // fn main f0 {
@@ -0,0 +1,292 @@
+//! The global value numbering (GVN) pass removes instructions which recompute a value that an
+//! identical instruction has already computed on every path leading to them.
+//!
+//! The pass works as follows:
+//! - Walk the blocks of each function depth-first along its [dominator tree][DominatorTree],
+//!   keeping a table of the [pure][Instruction::is_pure()] instructions seen in the dominating blocks.
+//! - When an instruction with the same (resolved) inputs is already in the table, replace its
+//!   results with the results of the earlier instruction and remove it from its block.
+//! - When leaving a block, forget the instructions it added to the table, as they do not
+//!   dominate the sibling blocks visited next.
+//!
+//! Whether some instructions fail or which value they output depends on the condition set by the
+//! latest [`EnableSideEffects`][Instruction::EnableSideEffects] instruction, e.g. an out of bounds
+//! `array_get` or a division by zero while side effects are disabled. These instructions are only
+//! deduplicated when they are executed under the same condition. Pure intrinsic calls, such as
+//! most black box functions, do not depend on this condition and are deduplicated regardless of it.
+//!
+//! Unlike [`constant_folding`][super::constant_folding], which only deduplicates instructions
+//! within a single block, this pass also works on unconstrained functions with many blocks.
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        dfg::DataFlowGraph,
+        dom::DominatorTree,
+        function::{Function, RuntimeType},
+        instruction::{BinaryOp, Instruction},
+        post_order::PostOrder,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+use fxhash::FxHashMap as HashMap;
+
+impl Ssa {
+    /// Removes any pure instruction which is dominated by an identical instruction.
+    ///
+    /// See [`gvn`][self] module for more information.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn global_value_numbering(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            global_value_numbering(function);
+        }
+        self
+    }
+}
+
+fn global_value_numbering(function: &mut Function) {
+    let dom_tree = DominatorTree::with_function(function);
+    let post_order = PostOrder::with_function(function);
+
+    // Blocks are pushed in reverse post order so the children of each block are in that order too.
+    let mut children: HashMap<BasicBlockId, Vec<BasicBlockId>> = HashMap::default();
+    for block in post_order.as_slice().iter().rev() {
+        if let Some(dominator) = dom_tree.immediate_dominator(*block) {
+            children.entry(dominator).or_default().push(*block);
+        }
+    }
+
+    // Side effects are only ever disabled in flattened ACIR functions. Otherwise the condition
+    // is the same everywhere and we do not need to track it across blocks.
+    let tracks_side_effects = post_order.as_slice().iter().any(|block| {
+        function.dfg[*block]
+            .instructions()
+            .iter()
+            .any(|id| matches!(function.dfg[*id], Instruction::EnableSideEffects { .. }))
+    });
+
+    let mut context = Context { tracks_side_effects, ..Context::default() };
+
+    // The dominator tree may be deep in unconstrained code, so it is walked without recursion.
+    let mut stack = vec![Visit::Enter(function.entry_block())];
+    while let Some(visit) = stack.pop() {
+        match visit {
+            Visit::Enter(block) => {
+                stack.push(Visit::Exit(context.numbered.len()));
+                context.number_block(function, block);
+                if let Some(children) = children.get(&block) {
+                    stack.extend(children.iter().rev().map(|child| Visit::Enter(*child)));
+                }
+            }
+            Visit::Exit(numbered) => {
+                for key in context.numbered.drain(numbered..) {
+                    context.value_numbers.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+enum Visit {
+    Enter(BasicBlockId),
+    /// Leaves a block, forgetting all instructions numbered after the given number of instructions.
+    Exit(usize),
+}
+
+/// The condition under which instructions are executed, set by the latest
+/// [`EnableSideEffects`][Instruction::EnableSideEffects] instruction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Predicate {
+    Enabled,
+    Condition(ValueId),
+    /// The condition which is active when entering a block with several predecessors
+    /// is not known, so it is only equal to itself.
+    AtStartOf(BasicBlockId),
+}
+
+/// An instruction with resolved inputs along with the predicate it depends on, if any.
+type Key = (Instruction, Option<Predicate>);
+
+#[derive(Default)]
+struct Context {
+    tracks_side_effects: bool,
+    /// The results of each instruction in the blocks dominating the current block.
+    value_numbers: HashMap<Key, Vec<ValueId>>,
+    /// The keys of `value_numbers` in the order they were inserted.
+    numbered: Vec<Key>,
+}
+
+impl Context {
+    fn number_block(&mut self, function: &mut Function, block: BasicBlockId) {
+        let mut predicate = if self.tracks_side_effects && block != function.entry_block() {
+            Predicate::AtStartOf(block)
+        } else {
+            Predicate::Enabled
+        };
+
+        let runtime = function.runtime();
+        let instructions = function.dfg[block].take_instructions();
+        let mut remaining_instructions = Vec::with_capacity(instructions.len());
+
+        for instruction_id in instructions {
+            let dfg = &mut function.dfg;
+            let instruction = dfg[instruction_id].map_values(|value| dfg.resolve(value));
+
+            if let Instruction::EnableSideEffects { condition } = &instruction {
+                predicate = match dfg.get_numeric_constant(*condition) {
+                    Some(constant) if constant.is_one() => Predicate::Enabled,
+                    _ => Predicate::Condition(*condition),
+                };
+            }
+
+            let results = dfg.instruction_results(instruction_id).to_vec();
+            if let Some(key) = Self::key(dfg, runtime, &instruction, &results, predicate) {
+                if let Some(earlier_results) = self.value_numbers.get(&key) {
+                    for (result, earlier_result) in results.into_iter().zip(earlier_results) {
+                        dfg.set_value_from_id(result, *earlier_result);
+                    }
+                    continue;
+                }
+                self.value_numbers.insert(key.clone(), results);
+                self.numbered.push(key);
+            }
+
+            dfg[instruction_id] = instruction;
+            remaining_instructions.push(instruction_id);
+        }
+
+        *function.dfg[block].instructions_mut() = remaining_instructions;
+    }
+
+    /// Returns the key to number the given instruction by, or `None` if it cannot be replaced
+    /// by an identical instruction.
+    fn key(
+        dfg: &DataFlowGraph,
+        runtime: RuntimeType,
+        instruction: &Instruction,
+        results: &[ValueId],
+        predicate: Predicate,
+    ) -> Option<Key> {
+        // Divisions are only impure because their result depends on the predicate in ACIR
+        let is_division =
+            matches!(instruction, Instruction::Binary(binary) if binary.operator == BinaryOp::Div);
+        if !instruction.is_pure(dfg) && !is_division {
+            return None;
+        }
+
+        // Brillig mutates arrays in place when their reference count allows it, so two
+        // arrays created by separate instructions must not be merged into one.
+        if runtime == RuntimeType::Brillig
+            && results.iter().any(|result| dfg.type_of_value(*result).contains_an_array())
+        {
+            return None;
+        }
+
+        let depends_on_predicate = match instruction {
+            Instruction::Binary(binary) => {
+                matches!(binary.operator, BinaryOp::Div | BinaryOp::Mod)
+            }
+            Instruction::ArrayGet { .. } | Instruction::ArraySet { .. } => true,
+            _ => false,
+        };
+        Some((instruction.clone(), depends_on_predicate.then_some(predicate)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ssa::{parser::assert_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn only_deduplicates_instructions_in_dominating_blocks() {
+        // v5 repeats v2 from the entry block. v8 repeats v4, but b1 does not dominate b3.
+        let src = "
+            brillig fn main f0 {
+              b0(v0: Field, v1: u1):
+                v2 = mul v0, v0
+                jmpif v1 then: b1, else: b2
+              b1():
+                v4 = add v0, Field 1
+                v5 = mul v0, v0
+                v6 = add v4, v5
+                jmp b3(v6)
+              b3(v7: Field):
+                v8 = add v0, Field 1
+                v9 = add v7, v8
+                return v9
+              b2():
+                v10 = add v0, Field 1
+                jmp b3(v10)
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+
+        let expected = "
+            brillig fn main f0 {
+              b0(v0: Field, v1: u1):
+                v2 = mul v0, v0
+                jmpif v1 then: b1, else: b2
+              b1():
+                v4 = add v0, Field 1
+                v6 = add v4, v2
+                jmp b3(v6)
+              b3(v7: Field):
+                v8 = add v0, Field 1
+                v9 = add v7, v8
+                return v9
+              b2():
+                v10 = add v0, Field 1
+                jmp b3(v10)
+            }
+            ";
+        assert_ssa_equals(&ssa.global_value_numbering(), expected);
+    }
+
+    #[test]
+    fn deduplicates_array_gets_under_the_same_predicate() {
+        // The calls do not depend on the predicate, so v8 is replaced by v5 even though
+        // side effects were disabled for v5. Only v9 repeats v7 under the same predicate.
+        let src = "
+            acir fn main f0 {
+              b0(v0: [u8; 2], v1: u32, v2: u1):
+                enable_side_effects v2
+                v3 = array_get v0, index v1 -> u8
+                v5 = call sha256(v0) -> [u8; 32]
+                enable_side_effects u1 1
+                v7 = array_get v0, index v1 -> u8
+                v8 = call sha256(v0) -> [u8; 32]
+                v9 = array_get v0, index v1 -> u8
+                return v3, v5, v7, v8, v9
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+
+        let expected = "
+            acir fn main f0 {
+              b0(v0: [u8; 2], v1: u32, v2: u1):
+                enable_side_effects v2
+                v3 = array_get v0, index v1 -> u8
+                v5 = call sha256(v0) -> [u8; 32]
+                enable_side_effects u1 1
+                v7 = array_get v0, index v1 -> u8
+                return v3, v5, v7, v5, v7
+            }
+            ";
+        assert_ssa_equals(&ssa.global_value_numbering(), expected);
+    }
+
+    #[test]
+    fn does_not_merge_brillig_arrays() {
+        // Merging v4 into v3 would let a later in-place mutation of one array change the other
+        let src = "
+            brillig fn main f0 {
+              b0(v0: [Field; 2], v1: u32, v2: Field):
+                v3 = array_set v0, index v1, value v2
+                v4 = array_set v0, index v1, value v2
+                return v3, v4
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+        assert_ssa_equals(&ssa.global_value_numbering(), src);
+    }
+}
@@ -2,9 +2,17 @@
 //! The purpose of this pass is to inline the instructions of each function call
 //! within the function caller. If all function calls are known, there will only
 //! be a single function remaining when the pass finishes.
-use std::collections::{BTreeSet, HashSet};
+//!
+//! ACIR has no way to call another ACIR function, so every call between constrained
+//! functions is inlined. Unconstrained code can call other Brillig functions, so calls
+//! in unconstrained code are only inlined when the callee is small, as decided by
+//! [`inline_into_brillig`], or when it is marked `#[inline(always)]`. When a constrained
+//! function is called but not inlined from unconstrained code, a Brillig version of it
+//! is created and called instead.
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use iter_extended::{btree_map, vecmap};
+use noirc_frontend::token::InlineType;
 
 use crate::ssa::{
     function_builder::FunctionBuilder,
@@ -12,7 +20,7 @@ use crate::ssa::{
         basic_block::BasicBlockId,
         dfg::{CallStack, InsertInstructionResult},
         function::{Function, FunctionId, RuntimeType},
-        instruction::{Instruction, InstructionId, TerminatorInstruction},
+        instruction::{Instruction, InstructionId, Intrinsic, TerminatorInstruction},
         value::{Value, ValueId},
     },
     ssa_gen::Ssa,
@@ -23,6 +31,11 @@ use fxhash::FxHashMap as HashMap;
 /// frames at any point in time.
 const RECURSION_LIMIT: u32 = 1000;
 
+/// Calls from unconstrained code to functions with up to this many instructions are inlined.
+/// Calling a larger function is cheaper than duplicating its code, as Brillig calls only need
+/// to save and restore the registers of the caller.
+const INLINE_COST_THRESHOLD: usize = 20;
+
 impl Ssa {
     /// Inline all functions within the IR.
     ///
@@ -38,11 +51,33 @@ impl Ssa {
     /// as well save the work for later instead of performing it twice.
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) fn inline_functions(mut self) -> Ssa {
-        self.functions = btree_map(get_entry_point_functions(&self), |entry_point| {
-            let new_function = InlineContext::new(&self, entry_point).inline_all(&self);
-            (entry_point, new_function)
+        let recursive_functions = get_recursive_functions(&self);
+        let brillig_versions = get_brillig_versions(&self, &recursive_functions);
+
+        let mut functions = btree_map(get_entry_point_functions(&self), |entry_point| {
+            let source = &self.functions[&entry_point];
+            let context = InlineContext::new(
+                source,
+                entry_point,
+                source.runtime(),
+                &recursive_functions,
+                &brillig_versions,
+            );
+            (entry_point, context.inline_all(&self))
         });
 
+        for (acir_function, brillig_function) in &brillig_versions {
+            let context = InlineContext::new(
+                &self.functions[acir_function],
+                *brillig_function,
+                RuntimeType::Brillig,
+                &recursive_functions,
+                &brillig_versions,
+            );
+            functions.insert(*brillig_function, context.inline_all(&self));
+        }
+
+        self.functions = functions;
         self
     }
 }
@@ -60,6 +95,13 @@ struct InlineContext {
 
     // The FunctionId of the entry point function we're inlining into in the old, unmodified Ssa.
     entry_point: FunctionId,
+
+    /// Functions which may call themselves. These are never inlined into unconstrained code.
+    recursive_functions: BTreeSet<FunctionId>,
+
+    /// The Brillig version of each constrained function which is called from
+    /// unconstrained code without being inlined.
+    brillig_versions: BTreeMap<FunctionId, FunctionId>,
 }
 
 /// The per-function inlining context contains information that is only valid for one function.
@@ -107,16 +149,170 @@ fn get_entry_point_functions(ssa: &Ssa) -> BTreeSet<FunctionId> {
     entry_points
 }
 
+/// Returns the functions which are called by each call instruction of the given function
+/// with a known target.
+fn get_called_functions(function: &Function) -> BTreeSet<FunctionId> {
+    let mut called_functions = BTreeSet::new();
+    for block in function.reachable_blocks() {
+        for instruction in function.dfg[block].instructions() {
+            if let Instruction::Call { func, .. } = &function.dfg[*instruction] {
+                if let Value::Function(callee) = function.dfg[*func] {
+                    called_functions.insert(callee);
+                }
+            }
+        }
+    }
+    called_functions
+}
+
+/// Returns each function which may call itself, either directly or through other functions.
+fn get_recursive_functions(ssa: &Ssa) -> BTreeSet<FunctionId> {
+    let callees = btree_map(&ssa.functions, |(id, function)| (*id, get_called_functions(function)));
+
+    let calls_itself = |function: FunctionId| {
+        let mut seen = BTreeSet::new();
+        let mut stack: Vec<_> = callees[&function].iter().copied().collect();
+        while let Some(callee) = stack.pop() {
+            if callee == function {
+                return true;
+            }
+            if seen.insert(callee) {
+                stack.extend(callees.get(&callee).into_iter().flatten().copied());
+            }
+        }
+        false
+    };
+
+    callees.keys().copied().filter(|function| calls_itself(*function)).collect()
+}
+
+/// Returns a fresh id for the Brillig version of each constrained function which is
+/// called from unconstrained code, where the call is not inlined.
+fn get_brillig_versions(
+    ssa: &Ssa,
+    recursive_functions: &BTreeSet<FunctionId>,
+) -> BTreeMap<FunctionId, FunctionId> {
+    let mut brillig_versions = BTreeMap::new();
+
+    // Each function whose code ends up in unconstrained code, whether it is inlined or not
+    let mut queue: Vec<_> = ssa
+        .functions
+        .values()
+        .filter(|function| function.runtime() == RuntimeType::Brillig)
+        .map(|function| function.id())
+        .collect();
+    let mut visited = BTreeSet::new();
+
+    while let Some(function) = queue.pop() {
+        if !visited.insert(function) {
+            continue;
+        }
+        for callee in get_called_functions(&ssa.functions[&function]) {
+            let callee_function = &ssa.functions[&callee];
+            if callee_function.runtime() == RuntimeType::Acir
+                && !inline_into_brillig(callee_function, recursive_functions)
+            {
+                brillig_versions.entry(callee).or_insert_with(|| ssa.next_id.next());
+            }
+            queue.push(callee);
+        }
+    }
+    brillig_versions
+}
+
+/// Returns true if calls to the given function from unconstrained code should be inlined.
+fn inline_into_brillig(function: &Function, recursive_functions: &BTreeSet<FunctionId>) -> bool {
+    // Calls to `assert_constant` only pass if the arguments of the call are known,
+    // as in the `to_le_bits` method of `Field`.
+    if calls_assert_constant(function) {
+        return true;
+    }
+    // Inlining a recursive function would only terminate if its recursion
+    // depends on constants, which is rare in unconstrained code.
+    if recursive_functions.contains(&function.id()) {
+        return false;
+    }
+    match function.inline_type() {
+        Some(InlineType::Always) => true,
+        Some(InlineType::Never) => false,
+        None => {
+            let blocks = function.reachable_blocks();
+            let cost: usize =
+                blocks.iter().map(|block| function.dfg[*block].instructions().len()).sum();
+            cost <= INLINE_COST_THRESHOLD
+        }
+    }
+}
+
+fn calls_assert_constant(function: &Function) -> bool {
+    function.reachable_blocks().iter().any(|block| {
+        function.dfg[*block].instructions().iter().any(|instruction| {
+            matches!(
+                &function.dfg[*instruction],
+                Instruction::Call { func, .. }
+                    if function.dfg[*func] == Value::Intrinsic(Intrinsic::AssertConstant)
+            )
+        })
+    })
+}
+
 impl InlineContext {
     /// Create a new context object for the function inlining pass.
     /// This starts off with an empty mapping of instructions for main's parameters.
     /// The function being inlined into will always be the main function, although it is
     /// actually a copy that is created in case the original main is still needed from a function
     /// that could not be inlined calling it.
-    fn new(ssa: &Ssa, entry_point: FunctionId) -> InlineContext {
-        let source = &ssa.functions[&entry_point];
-        let builder = FunctionBuilder::new(source.name().to_owned(), entry_point, source.runtime());
-        Self { builder, recursion_level: 0, entry_point, call_stack: CallStack::new() }
+    ///
+    /// The new function is given the id and runtime passed in, which differ from those of the
+    /// source function when creating the Brillig version of a constrained function.
+    fn new(
+        source: &Function,
+        id: FunctionId,
+        runtime: RuntimeType,
+        recursive_functions: &BTreeSet<FunctionId>,
+        brillig_versions: &BTreeMap<FunctionId, FunctionId>,
+    ) -> InlineContext {
+        let mut builder = FunctionBuilder::new(source.name().to_owned(), id, runtime);
+        builder.current_function.set_inline_type(source.inline_type());
+        Self {
+            builder,
+            recursion_level: 0,
+            entry_point: source.id(),
+            call_stack: CallStack::new(),
+            recursive_functions: recursive_functions.clone(),
+            brillig_versions: brillig_versions.clone(),
+        }
+    }
+
+    /// Returns true if a call to the given function should be inlined into the function being built.
+    fn should_inline_call(&self, ssa: &Ssa, callee: FunctionId) -> bool {
+        // Calls to Brillig versions of constrained functions are never inlined.
+        // These functions are created by this pass, so they are not part of `ssa`.
+        let callee = match ssa.functions.get(&callee) {
+            Some(callee) => callee,
+            None => return false,
+        };
+
+        match (self.builder.current_function.runtime(), callee.runtime()) {
+            (RuntimeType::Acir, RuntimeType::Acir) => true,
+            (RuntimeType::Acir, RuntimeType::Brillig) => false,
+            // Constrained functions which should not be inlined were already
+            // replaced by their Brillig version when importing the function.
+            (RuntimeType::Brillig, RuntimeType::Acir) => true,
+            (RuntimeType::Brillig, RuntimeType::Brillig) => {
+                inline_into_brillig(callee, &self.recursive_functions)
+            }
+        }
+    }
+
+    /// Imports the given function into the function being built. Unconstrained code refers
+    /// to the Brillig version of a constrained function instead, if there is one.
+    fn import_function(&mut self, function: FunctionId) -> ValueId {
+        let function = match self.builder.current_function.runtime() {
+            RuntimeType::Brillig => *self.brillig_versions.get(&function).unwrap_or(&function),
+            RuntimeType::Acir => function,
+        };
+        self.builder.import_function(function)
     }
 
     /// Start inlining the entry point function and all functions reachable from it.
@@ -218,7 +414,7 @@ impl<'function> PerFunctionContext<'function> {
             Value::NumericConstant { constant, typ } => {
                 self.context.builder.numeric_constant(*constant, typ.clone())
             }
-            Value::Function(function) => self.context.builder.import_function(*function),
+            Value::Function(function) => self.context.import_function(*function),
             Value::Intrinsic(intrinsic) => self.context.builder.import_intrinsic_id(*intrinsic),
             Value::ForeignFunction(function) => {
                 self.context.builder.import_foreign_function(function)
@@ -349,11 +545,10 @@ impl<'function> PerFunctionContext<'function> {
         for id in block.instructions() {
             match &self.source_function.dfg[*id] {
                 Instruction::Call { func, arguments } => match self.get_function(*func) {
-                    Some(function) => match ssa.functions[&function].runtime() {
-                        RuntimeType::Acir => self.inline_function(ssa, *id, function, arguments),
-                        RuntimeType::Brillig => self.push_instruction(*id),
-                    },
-                    None => self.push_instruction(*id),
+                    Some(function) if self.context.should_inline_call(ssa, function) => {
+                        self.inline_function(ssa, *id, function, arguments);
+                    }
+                    _ => self.push_instruction(*id),
                 },
                 _ => self.push_instruction(*id),
             }
@@ -509,6 +704,7 @@ impl<'function> PerFunctionContext<'function> {
 #[cfg(test)]
 mod test {
     use acvm::FieldElement;
+    use noirc_frontend::token::InlineType;
 
     use crate::ssa::{
         function_builder::FunctionBuilder,
@@ -519,6 +715,8 @@ mod test {
             map::Id,
             types::Type,
         },
+        parser::assert_ssa_equals,
+        ssa_gen::Ssa,
     };
 
     #[test]
@@ -787,4 +985,96 @@ mod test {
         let main = ssa.main();
         assert_eq!(main.reachable_blocks().len(), 4);
     }
+
+    #[test]
+    fn inlines_small_functions_into_brillig_code() {
+        let src = "
+            brillig fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            brillig fn square f1 {
+              b0(v0: Field):
+                v1 = mul v0, v0
+                return v1
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+
+        let expected = "
+            brillig fn main f0 {
+              b0(v0: Field):
+                v2 = mul v0, v0
+                return v2
+            }
+            brillig fn square f1 {
+              b0(v0: Field):
+                v1 = mul v0, v0
+                return v1
+            }
+            ";
+        assert_ssa_equals(&ssa.inline_functions(), expected);
+    }
+
+    #[test]
+    fn calls_brillig_versions_of_constrained_functions_marked_inline_never() {
+        let src = "
+            brillig fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            acir fn square f1 {
+              b0(v0: Field):
+                v1 = mul v0, v0
+                return v1
+            }
+            ";
+        let mut ssa: Ssa = src.parse().unwrap();
+        ssa.functions.get_mut(&Id::test_new(1)).unwrap().set_inline_type(Some(InlineType::Never));
+
+        // The constrained function is only kept as the Brillig function f2
+        let expected = "
+            brillig fn main f0 {
+              b0(v0: Field):
+                v2 = call f2(v0) -> Field
+                return v2
+            }
+            brillig fn square f2 {
+              b0(v0: Field):
+                v1 = mul v0, v0
+                return v1
+            }
+            ";
+        assert_ssa_equals(&ssa.inline_functions(), expected);
+    }
+
+    #[test]
+    fn inlines_every_call_in_constrained_code() {
+        // ACIR cannot call other ACIR functions, so `#[inline(never)]` has no effect here
+        let src = "
+            acir fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            acir fn square f1 {
+              b0(v0: Field):
+                v1 = mul v0, v0
+                return v1
+            }
+            ";
+        let mut ssa: Ssa = src.parse().unwrap();
+        ssa.functions.get_mut(&Id::test_new(1)).unwrap().set_inline_type(Some(InlineType::Never));
+
+        let expected = "
+            acir fn main f0 {
+              b0(v0: Field):
+                v2 = mul v0, v0
+                return v2
+            }
+            ";
+        assert_ssa_equals(&ssa.inline_functions(), expected);
+    }
 }
@@ -0,0 +1,267 @@
+//! Loop-invariant code motion (LICM) moves instructions out of the loops of Brillig functions
+//! when their results are the same on every iteration, so that they are only executed once.
+//!
+//! Brillig functions keep their loops, as loops are only unrolled in ACIR functions.
+//! The loops are found as in the [`unrolling`][super::unrolling] pass. For each loop, starting
+//! with the inner loops, an instruction is moved to the end of the block entering the loop when:
+//! - it is [pure][Instruction::is_pure()], so it does not interact with memory,
+//! - all of its inputs are defined outside of the loop, or are results of instructions
+//!   which have already been moved out of it,
+//! - its results are not arrays. Brillig mutates arrays in place when their reference count
+//!   allows it, so each iteration must create its own copy,
+//! - and it cannot fail, unless it is in the loop header. A loop may not run any iteration,
+//!   but its header always runs when the loop is entered.
+use std::collections::HashSet;
+
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        dfg::DataFlowGraph,
+        function::{Function, RuntimeType},
+        instruction::{BinaryOp, Instruction, InstructionId, TerminatorInstruction},
+        post_order::PostOrder,
+        types::{NumericType, Type},
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+use super::unrolling::find_all_loops;
+
+impl Ssa {
+    /// Moves the loop-invariant instructions of Brillig functions out of their loops.
+    ///
+    /// See [`licm`][self] module for more information.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn loop_invariant_code_motion(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            // ACIR functions have no loops left after unrolling.
+            if function.runtime() == RuntimeType::Brillig {
+                hoist_loop_invariants(function);
+            }
+        }
+        self
+    }
+}
+
+/// A loop with a single entry, along with all the back edges to its header.
+pub(super) struct LoopWithPreHeader {
+    pub(super) header: BasicBlockId,
+
+    /// The only block outside of the loop which jumps to the header.
+    /// It does not jump anywhere else, so instructions added to it run whenever the loop is entered.
+    pub(super) pre_header: BasicBlockId,
+
+    /// The blocks which jump back to the header. Loops using `continue` have several of them.
+    pub(super) back_edge_starts: Vec<BasicBlockId>,
+
+    /// All the blocks contained within the loop, including `header` and `back_edge_starts`.
+    pub(super) blocks: HashSet<BasicBlockId>,
+}
+
+/// Returns the loops of the function which have a pre-header, inner loops first.
+pub(super) fn find_loops_with_pre_header(function: &Function) -> Vec<LoopWithPreHeader> {
+    let all_loops = find_all_loops(function);
+
+    // `find_all_loops` returns a loop for each back edge, which are merged by their header here.
+    let mut loops: Vec<LoopWithPreHeader> = Vec::new();
+    for loop_ in all_loops.yet_to_unroll {
+        match loops.iter_mut().find(|existing| existing.header == loop_.header) {
+            Some(existing) => {
+                existing.back_edge_starts.push(loop_.back_edge_start);
+                existing.blocks.extend(loop_.blocks);
+            }
+            None => loops.push(LoopWithPreHeader {
+                header: loop_.header,
+                pre_header: loop_.header,
+                back_edge_starts: vec![loop_.back_edge_start],
+                blocks: loop_.blocks,
+            }),
+        }
+    }
+
+    loops.retain_mut(|loop_| {
+        let mut entries = all_loops
+            .cfg
+            .predecessors(loop_.header)
+            .filter(|predecessor| !loop_.blocks.contains(predecessor));
+
+        match (entries.next(), entries.next()) {
+            (Some(pre_header), None) => {
+                loop_.pre_header = pre_header;
+                matches!(
+                    function.dfg[pre_header].terminator(),
+                    Some(TerminatorInstruction::Jmp { .. })
+                )
+            }
+            _ => false,
+        }
+    });
+
+    loops.sort_by_key(|loop_| loop_.blocks.len());
+    loops
+}
+
+fn hoist_loop_invariants(function: &mut Function) {
+    let loops = find_loops_with_pre_header(function);
+    if loops.is_empty() {
+        return;
+    }
+
+    // Blocks are visited in reverse post order, so the instructions defining the
+    // inputs of an instruction are visited before it.
+    let mut blocks = PostOrder::with_function(function).into_vec();
+    blocks.reverse();
+
+    for loop_ in &loops {
+        hoist_loop_invariants_of_loop(function, loop_, &blocks);
+    }
+}
+
+fn hoist_loop_invariants_of_loop(
+    function: &mut Function,
+    loop_: &LoopWithPreHeader,
+    reverse_post_order: &[BasicBlockId],
+) {
+    let dfg = &mut function.dfg;
+
+    let mut defined_in_loop: HashSet<ValueId> = HashSet::new();
+    for block in &loop_.blocks {
+        defined_in_loop.extend(dfg.block_parameters(*block));
+        for instruction in dfg[*block].instructions() {
+            defined_in_loop.extend(dfg.instruction_results(*instruction));
+        }
+    }
+
+    for block in reverse_post_order.iter().filter(|block| loop_.blocks.contains(*block)) {
+        let is_header = *block == loop_.header;
+        let instructions = dfg[*block].take_instructions();
+        let mut remaining_instructions = Vec::with_capacity(instructions.len());
+
+        for instruction_id in instructions {
+            if can_hoist(dfg, instruction_id, is_header, &defined_in_loop) {
+                for result in dfg.instruction_results(instruction_id) {
+                    defined_in_loop.remove(result);
+                }
+                dfg[loop_.pre_header].insert_instruction(instruction_id);
+            } else {
+                remaining_instructions.push(instruction_id);
+            }
+        }
+
+        *dfg[*block].instructions_mut() = remaining_instructions;
+    }
+}
+
+fn can_hoist(
+    dfg: &DataFlowGraph,
+    instruction_id: InstructionId,
+    is_header: bool,
+    defined_in_loop: &HashSet<ValueId>,
+) -> bool {
+    let instruction = &dfg[instruction_id];
+    if !instruction.is_pure(dfg) || (!is_header && can_fail(dfg, instruction)) {
+        return false;
+    }
+
+    let results = dfg.instruction_results(instruction_id);
+    if results.iter().any(|result| dfg.type_of_value(*result).contains_an_array()) {
+        return false;
+    }
+
+    let mut is_invariant = true;
+    instruction.for_each_value(|value| {
+        is_invariant &= !defined_in_loop.contains(&dfg.resolve(value));
+    });
+    is_invariant
+}
+
+/// Returns true if the instruction may halt the execution of a Brillig function.
+fn can_fail(dfg: &DataFlowGraph, instruction: &Instruction) -> bool {
+    match instruction {
+        Instruction::Binary(binary) => match binary.operator {
+            BinaryOp::Div | BinaryOp::Mod => true,
+            // Brillig checks unsigned integer operations for overflows
+            BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul => {
+                matches!(dfg.type_of_value(binary.lhs), Type::Numeric(NumericType::Unsigned { .. }))
+            }
+            _ => false,
+        },
+        Instruction::Cast(..) | Instruction::Not(_) => false,
+        // Array accesses and calls are conservatively expected to fail
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ssa::{parser::assert_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn hoists_loop_invariants() {
+        // v4 and v8 are invariant. v7 is not moved as it may overflow, which must not happen
+        // if the loop does not run, but v4 is moved as it is in the loop header.
+        let src = "
+            brillig fn main f0 {
+              b0(v0: u32, v1: u32):
+                jmp b1(u32 0)
+              b1(v3: u32):
+                v4 = mul v0, v1
+                v6 = lt v3, u32 4
+                jmpif v6 then: b2, else: b3
+              b2():
+                v7 = add v0, v1
+                v8 = xor v0, v1
+                v9 = add v3, v8
+                v11 = add v3, u32 1
+                jmp b1(v11)
+              b3():
+                return v4
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+
+        let expected = "
+            brillig fn main f0 {
+              b0(v0: u32, v1: u32):
+                v4 = mul v0, v1
+                v8 = xor v0, v1
+                jmp b1(u32 0)
+              b1(v3: u32):
+                v6 = lt v3, u32 4
+                jmpif v6 then: b2, else: b3
+              b2():
+                v7 = add v0, v1
+                v9 = add v3, v8
+                v11 = add v3, u32 1
+                jmp b1(v11)
+              b3():
+                return v4
+            }
+            ";
+        assert_ssa_equals(&ssa.loop_invariant_code_motion(), expected);
+    }
+
+    #[test]
+    fn does_not_hoist_memory_accesses() {
+        let src = "
+            brillig fn main f0 {
+              b0(v0: &mut Field, v1: Field):
+                jmp b1(u32 0)
+              b1(v3: u32):
+                v5 = lt v3, u32 4
+                jmpif v5 then: b2, else: b3
+              b2():
+                v6 = load v0 -> Field
+                v7 = add v6, v1
+                store v7 at v0
+                v9 = add v3, u32 1
+                jmp b1(v9)
+              b3():
+                return
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+        assert_ssa_equals(&ssa.loop_invariant_code_motion(), src);
+    }
+}
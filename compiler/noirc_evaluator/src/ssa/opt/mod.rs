@@ -10,7 +10,10 @@ mod defunctionalize;
 mod die;
 mod fill_internal_slices;
 pub(crate) mod flatten_cfg;
+mod gvn;
 mod inlining;
+mod licm;
 mod mem2reg;
 mod simplify_cfg;
+mod strength_reduction;
 mod unrolling;
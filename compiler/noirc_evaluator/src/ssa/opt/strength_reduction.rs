@@ -0,0 +1,361 @@
+//! Strength reduction replaces multiplications of the induction variable of a Brillig loop
+//! by a constant with a new parameter of the loop header, which is increased by a multiple
+//! of that constant on each iteration instead.
+//!
+//! For example, in the loop `for i in 0..10 { .. i * 3 .. }` the multiplication is replaced
+//! by a value which starts at `0` and is increased by `3` whenever the loop jumps back to its
+//! header. Brillig checks unsigned multiplications for overflows with a division, so the
+//! addition is much cheaper.
+//!
+//! An induction variable is a parameter of the header of a [loop with a pre-header][LoopWithPreHeader]
+//! which is increased by the same constant on every back edge of the loop. The new parameter
+//! is computed for the next iteration before the loop condition is checked, so it reaches
+//! one step further than the multiplication. Unsigned multiplications are thus only replaced
+//! when the bounds of the loop are constant and the new parameter is known to fit in its type.
+use acvm::FieldElement;
+
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        dfg::{CallStack, DataFlowGraph},
+        function::{Function, RuntimeType},
+        instruction::{Binary, BinaryOp, Instruction, InstructionId, TerminatorInstruction},
+        types::{NumericType, Type},
+        value::{Value, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+use fxhash::FxHashMap as HashMap;
+
+use super::licm::{find_loops_with_pre_header, LoopWithPreHeader};
+
+impl Ssa {
+    /// Replaces multiplications of the induction variables of loops in Brillig functions
+    /// with additions.
+    ///
+    /// See [`strength_reduction`][self] module for more information.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn reduce_strength(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            // ACIR functions have no loops left after unrolling.
+            if function.runtime() == RuntimeType::Brillig {
+                for loop_ in find_loops_with_pre_header(function) {
+                    reduce_strength_in_loop(function, &loop_);
+                }
+            }
+        }
+        self
+    }
+}
+
+/// A parameter of a loop header which is increased by `step` on every iteration of the loop.
+struct InductionVariable {
+    value: ValueId,
+    typ: Type,
+    /// The value the parameter has on the first iteration
+    start: ValueId,
+    step: FieldElement,
+}
+
+/// A multiplication of an induction variable by a constant.
+struct Multiplication {
+    block: BasicBlockId,
+    instruction: InstructionId,
+    factor: FieldElement,
+}
+
+fn reduce_strength_in_loop(function: &mut Function, loop_: &LoopWithPreHeader) {
+    let parameters = function.dfg.block_parameters(loop_.header).to_vec();
+    for (index, parameter) in parameters.into_iter().enumerate() {
+        if let Some(induction_variable) =
+            find_induction_variable(&function.dfg, loop_, index, parameter)
+        {
+            reduce_multiplications(&mut function.dfg, loop_, &induction_variable);
+        }
+    }
+}
+
+fn find_induction_variable(
+    dfg: &DataFlowGraph,
+    loop_: &LoopWithPreHeader,
+    index: usize,
+    parameter: ValueId,
+) -> Option<InductionVariable> {
+    let start = jmp_arguments(dfg, loop_.pre_header)?[index];
+
+    let mut step = None;
+    for back_edge_start in &loop_.back_edge_starts {
+        let next_value = dfg.resolve(jmp_arguments(dfg, *back_edge_start)?[index]);
+        let next_step = match defining_binary(dfg, next_value)? {
+            Binary { lhs, operator: BinaryOp::Add, rhs } if dfg.resolve(*lhs) == parameter => {
+                dfg.get_numeric_constant(*rhs)?
+            }
+            _ => return None,
+        };
+        if step.map_or(false, |step| step != next_step) {
+            return None;
+        }
+        step = Some(next_step);
+    }
+
+    let typ = dfg.type_of_value(parameter);
+    Some(InductionVariable { value: parameter, typ, start, step: step? })
+}
+
+fn reduce_multiplications(
+    dfg: &mut DataFlowGraph,
+    loop_: &LoopWithPreHeader,
+    induction_variable: &InductionVariable,
+) {
+    let mut blocks: Vec<_> = loop_.blocks.iter().copied().collect();
+    blocks.sort();
+
+    // Multiplications are collected first as replacing them adds instructions to the loop.
+    let mut multiplications = Vec::new();
+    for block in blocks {
+        for instruction in dfg[block].instructions() {
+            if let Some(factor) = multiplication_factor(dfg, *instruction, induction_variable) {
+                if cannot_overflow(dfg, loop_, induction_variable, factor) {
+                    multiplications.push(Multiplication {
+                        block,
+                        instruction: *instruction,
+                        factor,
+                    });
+                }
+            }
+        }
+    }
+
+    // Multiplications by the same factor share one new parameter
+    let mut parameters: HashMap<FieldElement, ValueId> = HashMap::default();
+    for multiplication in multiplications {
+        let parameter = match parameters.get(&multiplication.factor) {
+            Some(parameter) => *parameter,
+            None => {
+                let call_stack = dfg.get_call_stack(multiplication.instruction);
+                let parameter = add_multiple_parameter(
+                    dfg,
+                    loop_,
+                    induction_variable,
+                    multiplication.factor,
+                    call_stack,
+                );
+                parameters.insert(multiplication.factor, parameter);
+                parameter
+            }
+        };
+
+        let result = dfg.instruction_results(multiplication.instruction)[0];
+        dfg.set_value_from_id(result, parameter);
+        dfg[multiplication.block]
+            .instructions_mut()
+            .retain(|instruction| *instruction != multiplication.instruction);
+    }
+}
+
+/// Returns the constant the induction variable is multiplied by, if the instruction is such a multiplication.
+fn multiplication_factor(
+    dfg: &DataFlowGraph,
+    instruction: InstructionId,
+    induction_variable: &InductionVariable,
+) -> Option<FieldElement> {
+    match &dfg[instruction] {
+        Instruction::Binary(Binary { lhs, operator: BinaryOp::Mul, rhs }) => {
+            let (lhs, rhs) = (dfg.resolve(*lhs), dfg.resolve(*rhs));
+            if lhs == induction_variable.value {
+                dfg.get_numeric_constant(rhs)
+            } else if rhs == induction_variable.value {
+                dfg.get_numeric_constant(lhs)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Adds a parameter to the loop header which is always the induction variable multiplied by `factor`.
+fn add_multiple_parameter(
+    dfg: &mut DataFlowGraph,
+    loop_: &LoopWithPreHeader,
+    induction_variable: &InductionVariable,
+    factor: FieldElement,
+    call_stack: CallStack,
+) -> ValueId {
+    let typ = induction_variable.typ.clone();
+    let parameter = dfg.add_block_parameter(loop_.header, typ.clone());
+
+    let factor_value = dfg.make_constant(factor, typ.clone());
+    let start =
+        Binary { lhs: induction_variable.start, operator: BinaryOp::Mul, rhs: factor_value };
+    let start = insert_binary(dfg, loop_.pre_header, start, call_stack.clone());
+    push_jmp_argument(dfg, loop_.pre_header, start);
+
+    let step = dfg.make_constant(induction_variable.step * factor, typ);
+    for back_edge_start in &loop_.back_edge_starts {
+        let next = Binary { lhs: parameter, operator: BinaryOp::Add, rhs: step };
+        let next = insert_binary(dfg, *back_edge_start, next, call_stack.clone());
+        push_jmp_argument(dfg, *back_edge_start, next);
+    }
+
+    parameter
+}
+
+/// Returns true if the values of the induction variable multiplied by `factor`
+/// are known to fit in its type, on every iteration and when the loop ends.
+fn cannot_overflow(
+    dfg: &DataFlowGraph,
+    loop_: &LoopWithPreHeader,
+    induction_variable: &InductionVariable,
+    factor: FieldElement,
+) -> bool {
+    let bit_size = match induction_variable.typ {
+        Type::Numeric(NumericType::NativeField) => return true,
+        Type::Numeric(NumericType::Unsigned { bit_size }) => bit_size,
+        _ => return false,
+    };
+
+    let start = dfg.get_numeric_constant(induction_variable.start);
+    let end = loop_end(dfg, loop_, induction_variable.value);
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return false,
+    };
+    let constants = [start, end, induction_variable.step, factor];
+    if constants.iter().any(|constant| constant.num_bits() > 64) {
+        return false;
+    }
+    let [start, end, step, factor] = constants.map(|constant| constant.to_u128());
+
+    // The induction variable is below `end` while the loop runs, and is increased by `step` once more before it ends.
+    let last = start.max((end + step).saturating_sub(1));
+    match last.checked_mul(factor) {
+        Some(value) => bit_size >= 128 || value < 1 << bit_size,
+        None => false,
+    }
+}
+
+/// Returns the end of the loop if its header checks that the induction variable is less than a constant.
+fn loop_end(
+    dfg: &DataFlowGraph,
+    loop_: &LoopWithPreHeader,
+    induction_variable: ValueId,
+) -> Option<FieldElement> {
+    match dfg[loop_.header].terminator()? {
+        TerminatorInstruction::JmpIf { condition, then_destination, else_destination }
+            if loop_.blocks.contains(then_destination)
+                && !loop_.blocks.contains(else_destination) =>
+        {
+            match defining_binary(dfg, dfg.resolve(*condition))? {
+                Binary { lhs, operator: BinaryOp::Lt, rhs }
+                    if dfg.resolve(*lhs) == induction_variable =>
+                {
+                    dfg.get_numeric_constant(*rhs)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn defining_binary(dfg: &DataFlowGraph, value: ValueId) -> Option<&Binary> {
+    match &dfg[value] {
+        Value::Instruction { instruction, .. } => match &dfg[*instruction] {
+            Instruction::Binary(binary) => Some(binary),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn jmp_arguments(dfg: &DataFlowGraph, block: BasicBlockId) -> Option<&[ValueId]> {
+    match dfg[block].terminator()? {
+        TerminatorInstruction::Jmp { arguments, .. } => Some(arguments),
+        _ => None,
+    }
+}
+
+fn push_jmp_argument(dfg: &mut DataFlowGraph, block: BasicBlockId, argument: ValueId) {
+    match dfg[block].unwrap_terminator_mut() {
+        TerminatorInstruction::Jmp { arguments, .. } => arguments.push(argument),
+        other => unreachable!("Expected a jmp to the loop header, found {other:?}"),
+    }
+}
+
+fn insert_binary(
+    dfg: &mut DataFlowGraph,
+    block: BasicBlockId,
+    binary: Binary,
+    call_stack: CallStack,
+) -> ValueId {
+    dfg.insert_instruction_and_results(Instruction::Binary(binary), block, None, call_stack).first()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ssa::{parser::assert_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn replaces_multiplications_of_induction_variables() {
+        let src = "
+            brillig fn main f0 {
+              b0(v0: u32):
+                jmp b1(u32 0)
+              b1(v2: u32):
+                v4 = lt v2, u32 10
+                jmpif v4 then: b2, else: b3
+              b2():
+                v6 = mul v2, u32 3
+                v7 = add v0, v6
+                v9 = add v2, u32 1
+                jmp b1(v9)
+              b3():
+                return
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+
+        // v10 is always v2 * 3, and starts at 0 * 3
+        let expected = "
+            brillig fn main f0 {
+              b0(v0: u32):
+                jmp b1(u32 0, u32 0)
+              b1(v2: u32, v10: u32):
+                v4 = lt v2, u32 10
+                jmpif v4 then: b2, else: b3
+              b2():
+                v7 = add v0, v10
+                v9 = add v2, u32 1
+                v11 = add v10, u32 3
+                jmp b1(v9, v11)
+              b3():
+                return
+            }
+            ";
+        assert_ssa_equals(&ssa.reduce_strength(), expected);
+    }
+
+    #[test]
+    fn does_not_replace_multiplications_which_may_overflow() {
+        // The last value of v6 is 8 * 30 = 240, but the new parameter would reach 9 * 30 = 270
+        let src = "
+            brillig fn main f0 {
+              b0(v0: u8):
+                jmp b1(u8 0)
+              b1(v2: u8):
+                v4 = lt v2, u8 9
+                jmpif v4 then: b2, else: b3
+              b2():
+                v6 = mul v2, u8 30
+                v7 = add v0, v6
+                v9 = add v2, u8 1
+                jmp b1(v9)
+              b3():
+                return
+            }
+            ";
+        let ssa: Ssa = src.parse().unwrap();
+        assert_ssa_equals(&ssa.reduce_strength(), src);
+    }
+}
@@ -0,0 +1,768 @@
+//! Parses the textual form of the SSA, as printed by [`Ssa`]'s Display impl and `--show-ssa`,
+//! back into an [`Ssa`]. This lets SSA dumps be used as reproducible inputs, and lets tests of
+//! the optimization passes be written as snapshots of the SSA before and after a pass:
+//!
+//! ```text
+//! acir fn main f0 {
+//!   b0(v0: u1, v1: Field):
+//!     v3 = add v1, Field 1
+//!     jmpif v0 then: b1, else: b2
+//!   b1():
+//!     jmp b2()
+//!   b2():
+//!     return v3
+//! }
+//! ```
+//!
+//! The program is rebuilt with a [`FunctionBuilder`], but instructions are inserted without being
+//! simplified so that the SSA before a pass may contain instructions which the pass is expected
+//! to fold, such as `add Field 1, Field 2`. Functions keep their printed ids, while
+//! values and blocks are given new ids in the order they are first seen: these match the printed
+//! ones as long as the printed ids were also allocated in that order.
+mod lexer;
+
+use std::{collections::HashSet, rc::Rc, str::FromStr};
+
+use acvm::FieldElement;
+use fxhash::FxHashMap as HashMap;
+use thiserror::Error;
+
+use self::lexer::Token;
+
+use super::{
+    function_builder::FunctionBuilder,
+    ir::{
+        basic_block::BasicBlockId,
+        function::{FunctionId, RuntimeType},
+        instruction::{BinaryOp, Instruction, Intrinsic},
+        map::Id,
+        types::Type,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+#[derive(Debug, PartialEq, Eq, Error)]
+#[error("line {line}: {message}")]
+pub(crate) struct SsaParseError {
+    pub(crate) line: usize,
+    pub(crate) message: String,
+}
+
+impl FromStr for Ssa {
+    type Err = SsaParseError;
+
+    fn from_str(src: &str) -> Result<Ssa, SsaParseError> {
+        let tokens = lexer::lex(src)?;
+        Parser { tokens, position: 0, values: HashMap::default(), blocks: HashMap::default() }
+            .parse_ssa()
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+
+    /// The values of the function being parsed, by their printed names
+    values: HashMap<String, ValueId>,
+
+    /// The blocks of the function being parsed, by their printed names.
+    /// Blocks may be jumped to before they are printed, so they are created on first use.
+    blocks: HashMap<String, BasicBlockId>,
+}
+
+impl Parser {
+    fn parse_ssa(mut self) -> Result<Ssa, SsaParseError> {
+        let mut builder: Option<FunctionBuilder> = None;
+
+        loop {
+            let runtime = self.parse_runtime()?;
+            self.eat_keyword("fn")?;
+            let name = self.eat_ident("a function name")?;
+            let id = self.parse_function_id()?;
+
+            let mut function_builder = match builder.take() {
+                Some(mut function_builder) => {
+                    match runtime {
+                        RuntimeType::Acir => function_builder.new_function(name, id),
+                        RuntimeType::Brillig => function_builder.new_brillig_function(name, id),
+                    }
+                    function_builder
+                }
+                None => FunctionBuilder::new(name, id, runtime),
+            };
+            self.parse_function_body(&mut function_builder)?;
+            builder = Some(function_builder);
+
+            if self.peek().is_none() {
+                break;
+            }
+        }
+
+        Ok(builder.expect("at least one function is parsed").finish())
+    }
+
+    fn parse_runtime(&mut self) -> Result<RuntimeType, SsaParseError> {
+        match self.peek() {
+            Some(Token::Ident(name)) if name == "acir" => {
+                self.position += 1;
+                Ok(RuntimeType::Acir)
+            }
+            Some(Token::Ident(name)) if name == "brillig" => {
+                self.position += 1;
+                Ok(RuntimeType::Brillig)
+            }
+            _ => Err(self.expected("`acir` or `brillig`")),
+        }
+    }
+
+    fn parse_function_id(&mut self) -> Result<FunctionId, SsaParseError> {
+        let line = self.line();
+        let name = self.eat_ident("a function id")?;
+        function_id(&name)
+            .ok_or_else(|| self.error(line, format!("expected a function id, found `{name}`")))
+    }
+
+    fn parse_function_body(&mut self, builder: &mut FunctionBuilder) -> Result<(), SsaParseError> {
+        self.values.clear();
+        self.blocks.clear();
+        let mut defined_blocks = HashSet::new();
+
+        self.eat(Token::LeftBrace)?;
+        while !self.eat_if(Token::RightBrace) {
+            let line = self.line();
+            let label = self.eat_ident("a block")?;
+            if !is_block_name(&label) {
+                return Err(self.error(line, format!("expected a block, found `{label}`")));
+            }
+            if !defined_blocks.insert(label.clone()) {
+                return Err(self.error(line, format!("block `{label}` is defined twice")));
+            }
+
+            // The first block of a function is its entry block
+            let block = if defined_blocks.len() == 1 {
+                let entry = builder.current_block();
+                self.blocks.insert(label, entry);
+                entry
+            } else {
+                self.block(builder, label)
+            };
+            builder.switch_to_block(block);
+
+            self.parse_block_parameters(builder, block)?;
+            self.parse_block_body(builder)?;
+        }
+
+        if let Some(label) = self.blocks.keys().find(|label| !defined_blocks.contains(*label)) {
+            let line = self.line();
+            return Err(self.error(line, format!("block `{label}` is never defined")));
+        }
+        Ok(())
+    }
+
+    /// Parses the parameters of a block, e.g. `(v0: Field, v1: u1):`
+    fn parse_block_parameters(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        block: BasicBlockId,
+    ) -> Result<(), SsaParseError> {
+        self.eat(Token::LeftParen)?;
+        if !self.eat_if(Token::RightParen) {
+            loop {
+                let line = self.line();
+                let name = self.eat_ident("a parameter")?;
+                self.eat(Token::Colon)?;
+                let typ = self.parse_type()?;
+                let parameter = builder.add_block_parameter(block, typ);
+                self.define_value(name, parameter, line)?;
+
+                if !self.eat_if(Token::Comma) {
+                    self.eat(Token::RightParen)?;
+                    break;
+                }
+            }
+        }
+        self.eat(Token::Colon)
+    }
+
+    /// Parses each instruction of a block up to and including its terminator
+    fn parse_block_body(&mut self, builder: &mut FunctionBuilder) -> Result<(), SsaParseError> {
+        loop {
+            let line = self.line();
+            let keyword = self.eat_ident("an instruction")?;
+            match keyword.as_str() {
+                "jmp" => {
+                    let destination = self.parse_block(builder)?;
+                    let arguments = self.parse_arguments(builder)?;
+                    builder.terminate_with_jmp(destination, arguments);
+                    return Ok(());
+                }
+                "jmpif" => {
+                    let condition = self.parse_value(builder)?;
+                    self.eat_keyword("then")?;
+                    self.eat(Token::Colon)?;
+                    let then_destination = self.parse_block(builder)?;
+                    self.eat(Token::Comma)?;
+                    self.eat_keyword("else")?;
+                    self.eat(Token::Colon)?;
+                    let else_destination = self.parse_block(builder)?;
+                    builder.terminate_with_jmpif(condition, then_destination, else_destination);
+                    return Ok(());
+                }
+                "return" => {
+                    let mut return_values = Vec::new();
+                    if self.at_value() {
+                        return_values.push(self.parse_value(builder)?);
+                        while self.eat_if(Token::Comma) {
+                            return_values.push(self.parse_value(builder)?);
+                        }
+                    }
+                    builder.terminate_with_return(return_values);
+                    return Ok(());
+                }
+                _ if is_value_name(&keyword) => {
+                    let mut results = vec![keyword];
+                    while self.eat_if(Token::Comma) {
+                        results.push(self.eat_ident("a value")?);
+                    }
+                    self.eat(Token::Assign)?;
+                    let keyword = self.eat_ident("an instruction")?;
+                    self.parse_instruction(builder, &keyword, results, line)?;
+                }
+                _ => self.parse_instruction(builder, &keyword, Vec::new(), line)?,
+            }
+        }
+    }
+
+    /// Parses the rest of an instruction after its keyword and inserts it into the current block,
+    /// defining the given names for its results.
+    fn parse_instruction(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        keyword: &str,
+        results: Vec<String>,
+        line: usize,
+    ) -> Result<(), SsaParseError> {
+        let instruction = match keyword {
+            "allocate" => Instruction::Allocate,
+            "load" => Instruction::Load { address: self.parse_value(builder)? },
+            "store" => {
+                let value = self.parse_value(builder)?;
+                self.eat_keyword("at")?;
+                let address = self.parse_value(builder)?;
+                Instruction::Store { address, value }
+            }
+            "cast" => {
+                let value = self.parse_value(builder)?;
+                self.eat_keyword("as")?;
+                Instruction::Cast(value, self.parse_type()?)
+            }
+            "not" => Instruction::Not(self.parse_value(builder)?),
+            "truncate" => {
+                let value = self.parse_value(builder)?;
+                self.eat_keyword("to")?;
+                let bit_size = self.parse_u32()?;
+                self.eat_keyword("bits")?;
+                self.eat(Token::Comma)?;
+                self.eat_keyword("max_bit_size")?;
+                self.eat(Token::Colon)?;
+                let max_bit_size = self.parse_u32()?;
+                Instruction::Truncate { value, bit_size, max_bit_size }
+            }
+            "constrain" => {
+                let lhs = self.parse_value(builder)?;
+                self.eat(Token::Equal)?;
+                let rhs = self.parse_value(builder)?;
+                Instruction::Constrain(lhs, rhs, self.parse_message())
+            }
+            "range_check" => {
+                let value = self.parse_value(builder)?;
+                self.eat_keyword("to")?;
+                let max_bit_size = self.parse_u32()?;
+                self.eat_keyword("bits")?;
+                Instruction::RangeCheck {
+                    value,
+                    max_bit_size,
+                    assert_message: self.parse_message(),
+                }
+            }
+            "call" => {
+                let func = self.parse_value(builder)?;
+                let arguments = self.parse_arguments(builder)?;
+                Instruction::Call { func, arguments }
+            }
+            "enable_side_effects" => {
+                Instruction::EnableSideEffects { condition: self.parse_value(builder)? }
+            }
+            "array_get" => {
+                let array = self.parse_value(builder)?;
+                self.eat(Token::Comma)?;
+                self.eat_keyword("index")?;
+                let index = self.parse_value(builder)?;
+                Instruction::ArrayGet { array, index }
+            }
+            "array_set" => {
+                let array = self.parse_value(builder)?;
+                self.eat(Token::Comma)?;
+                self.eat_keyword("index")?;
+                let index = self.parse_value(builder)?;
+                self.eat(Token::Comma)?;
+                self.eat_keyword("value")?;
+                let value = self.parse_value(builder)?;
+                Instruction::ArraySet { array, index, value }
+            }
+            "inc_rc" => Instruction::IncrementRc { value: self.parse_value(builder)? },
+            other => match binary_operator(other) {
+                Some(operator) => {
+                    let lhs = self.parse_value(builder)?;
+                    self.eat(Token::Comma)?;
+                    let rhs = self.parse_value(builder)?;
+                    Instruction::binary(operator, lhs, rhs)
+                }
+                None => return Err(self.error(line, format!("unknown instruction `{other}`"))),
+            },
+        };
+
+        // The result types are printed for the instructions which can't infer them,
+        // except for calls without any results.
+        let ctrl_typevars = if self.eat_if(Token::Arrow) {
+            let mut types = vec![self.parse_type()?];
+            while self.eat_if(Token::Comma) {
+                types.push(self.parse_type()?);
+            }
+            Some(types)
+        } else if matches!(instruction, Instruction::Call { .. }) {
+            Some(Vec::new())
+        } else {
+            None
+        };
+        if ctrl_typevars.is_none() && instruction.requires_ctrl_typevars() {
+            let message = format!("expected the result types of `{keyword}`, e.g. `-> Field`");
+            return Err(self.error(line, message));
+        }
+
+        let values = builder
+            .insert_instruction_without_simplification(instruction, ctrl_typevars)
+            .results()
+            .into_owned();
+        if values.len() != results.len() {
+            let message = format!(
+                "`{keyword}` has {} results but {} were named",
+                values.len(),
+                results.len()
+            );
+            return Err(self.error(line, message));
+        }
+        for (name, value) in results.into_iter().zip(values) {
+            self.define_value(name, value, line)?;
+        }
+        Ok(())
+    }
+
+    /// Parses a parenthesized list of values, e.g. the arguments of a call or jmp
+    fn parse_arguments(
+        &mut self,
+        builder: &mut FunctionBuilder,
+    ) -> Result<Vec<ValueId>, SsaParseError> {
+        self.eat(Token::LeftParen)?;
+        let mut arguments = Vec::new();
+        if !self.eat_if(Token::RightParen) {
+            arguments.push(self.parse_value(builder)?);
+            while self.eat_if(Token::Comma) {
+                arguments.push(self.parse_value(builder)?);
+            }
+            self.eat(Token::RightParen)?;
+        }
+        Ok(arguments)
+    }
+
+    /// Parses a value, which is either the name of a value defined earlier, a constant
+    /// such as `u8 3` or `[Field 1, Field 2] of [Field; 2]`, or a function.
+    fn parse_value(&mut self, builder: &mut FunctionBuilder) -> Result<ValueId, SsaParseError> {
+        if self.eat_if(Token::LeftBracket) {
+            let mut elements = im::Vector::new();
+            if !self.eat_if(Token::RightBracket) {
+                elements.push_back(self.parse_value(builder)?);
+                while self.eat_if(Token::Comma) {
+                    elements.push_back(self.parse_value(builder)?);
+                }
+                self.eat(Token::RightBracket)?;
+            }
+            self.eat_keyword("of")?;
+            let line = self.line();
+            let typ = self.parse_type()?;
+            if !matches!(typ, Type::Array(..) | Type::Slice(_)) {
+                let message = format!("expected an array or slice type, found `{typ}`");
+                return Err(self.error(line, message));
+            }
+            return Ok(builder.array_constant(elements, typ));
+        }
+
+        let line = self.line();
+        let name = self.eat_ident("a value")?;
+        if let Some(typ) = numeric_type(&name) {
+            let constant = self.eat_int()?;
+            return Ok(builder.numeric_constant(constant, typ));
+        }
+        if is_value_name(&name) {
+            return match self.values.get(&name) {
+                Some(value) => Ok(*value),
+                None => Err(self.error(line, format!("value `{name}` is not defined"))),
+            };
+        }
+        if let Some(function) = function_id(&name) {
+            return Ok(builder.import_function(function));
+        }
+        // Any other name refers to an intrinsic or, failing that, an oracle
+        Ok(match Intrinsic::lookup(&name) {
+            Some(intrinsic) => builder.import_intrinsic_id(intrinsic),
+            None => builder.import_foreign_function(&name),
+        })
+    }
+
+    /// Whether the next token starts a value. Used to tell where the values of a `return` end.
+    fn at_value(&self) -> bool {
+        match self.peek() {
+            Some(Token::LeftBracket) => true,
+            Some(Token::Ident(name)) => !is_block_name(name),
+            _ => false,
+        }
+    }
+
+    fn parse_type(&mut self) -> Result<Type, SsaParseError> {
+        if self.eat_if(Token::Ampersand) {
+            self.eat_keyword("mut")?;
+            return Ok(Type::Reference(Rc::new(self.parse_type()?)));
+        }
+
+        if self.eat_if(Token::LeftBracket) {
+            let mut element_types = vec![self.parse_type()?];
+            while self.eat_if(Token::Comma) {
+                element_types.push(self.parse_type()?);
+            }
+            if self.eat_if(Token::Semicolon) {
+                let length = self.parse_u32()? as usize;
+                self.eat(Token::RightBracket)?;
+                return Ok(Type::Array(Rc::new(element_types), length));
+            }
+            self.eat(Token::RightBracket)?;
+            return Ok(Type::Slice(Rc::new(element_types)));
+        }
+
+        let line = self.line();
+        let name = self.eat_ident("a type")?;
+        if name == "function" {
+            return Ok(Type::Function);
+        }
+        numeric_type(&name).ok_or_else(|| self.error(line, format!("unknown type `{name}`")))
+    }
+
+    /// Returns the block with the given name, creating it if this is its first use.
+    fn block(&mut self, builder: &mut FunctionBuilder, name: String) -> BasicBlockId {
+        *self.blocks.entry(name).or_insert_with(|| builder.insert_block())
+    }
+
+    fn parse_block(
+        &mut self,
+        builder: &mut FunctionBuilder,
+    ) -> Result<BasicBlockId, SsaParseError> {
+        let line = self.line();
+        let name = self.eat_ident("a block")?;
+        if !is_block_name(&name) {
+            return Err(self.error(line, format!("expected a block, found `{name}`")));
+        }
+        Ok(self.block(builder, name))
+    }
+
+    fn define_value(
+        &mut self,
+        name: String,
+        value: ValueId,
+        line: usize,
+    ) -> Result<(), SsaParseError> {
+        if !is_value_name(&name) {
+            return Err(self.error(line, format!("expected a value, found `{name}`")));
+        }
+        if self.values.contains_key(&name) {
+            return Err(self.error(line, format!("value `{name}` is defined twice")));
+        }
+        self.values.insert(name, value);
+        Ok(())
+    }
+
+    /// Parses the optional message of a constrain or range_check instruction
+    fn parse_message(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(Token::Str(message)) => {
+                let message = message.clone();
+                self.position += 1;
+                Some(message)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_u32(&mut self) -> Result<u32, SsaParseError> {
+        let line = self.line();
+        let value = self.eat_int()?;
+        value
+            .try_to_u64()
+            .and_then(|value| u32::try_from(value).ok())
+            .ok_or_else(|| self.error(line, format!("expected a size, found `{value}`")))
+    }
+
+    fn eat_int(&mut self) -> Result<FieldElement, SsaParseError> {
+        match self.peek() {
+            Some(Token::Int(value)) => {
+                let value = *value;
+                self.position += 1;
+                Ok(value)
+            }
+            _ => Err(self.expected("a number")),
+        }
+    }
+
+    fn eat_ident(&mut self, expected: &str) -> Result<String, SsaParseError> {
+        match self.peek() {
+            Some(Token::Ident(name)) => {
+                let name = name.clone();
+                self.position += 1;
+                Ok(name)
+            }
+            _ => Err(self.expected(expected)),
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> Result<(), SsaParseError> {
+        match self.peek() {
+            Some(Token::Ident(name)) if name == keyword => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(self.expected(&format!("`{keyword}`"))),
+        }
+    }
+
+    fn eat(&mut self, token: Token) -> Result<(), SsaParseError> {
+        if self.eat_if(token.clone()) {
+            Ok(())
+        } else {
+            Err(self.expected(&token.to_string()))
+        }
+    }
+
+    fn eat_if(&mut self, token: Token) -> bool {
+        if self.peek() == Some(&token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    /// The line of the next token, or of the last one at the end of the input
+    fn line(&self) -> usize {
+        let index = self.position.min(self.tokens.len().saturating_sub(1));
+        self.tokens.get(index).map_or(1, |(_, line)| *line)
+    }
+
+    fn expected(&self, expected: &str) -> SsaParseError {
+        let message = match self.peek() {
+            Some(token) => format!("expected {expected}, found {token}"),
+            None => format!("expected {expected}, found the end of the input"),
+        };
+        self.error(self.line(), message)
+    }
+
+    fn error(&self, line: usize, message: String) -> SsaParseError {
+        SsaParseError { line, message }
+    }
+}
+
+/// Returns the index of a name such as `v3`, given its prefix
+fn index_of(name: &str, prefix: char) -> Option<usize> {
+    let index = name.strip_prefix(prefix)?;
+    if index.is_empty() || !index.chars().all(|char| char.is_ascii_digit()) {
+        return None;
+    }
+    index.parse().ok()
+}
+
+fn is_value_name(name: &str) -> bool {
+    index_of(name, 'v').is_some()
+}
+
+fn is_block_name(name: &str) -> bool {
+    index_of(name, 'b').is_some()
+}
+
+fn function_id(name: &str) -> Option<FunctionId> {
+    index_of(name, 'f').map(Id::new)
+}
+
+fn numeric_type(name: &str) -> Option<Type> {
+    if name == "Field" {
+        return Some(Type::field());
+    }
+    if let Some(bit_size) = index_of(name, 'u') {
+        return Some(Type::unsigned(bit_size as u32));
+    }
+    index_of(name, 'i').map(|bit_size| Type::signed(bit_size as u32))
+}
+
+fn binary_operator(name: &str) -> Option<BinaryOp> {
+    match name {
+        "add" => Some(BinaryOp::Add),
+        "sub" => Some(BinaryOp::Sub),
+        "mul" => Some(BinaryOp::Mul),
+        "div" => Some(BinaryOp::Div),
+        "eq" => Some(BinaryOp::Eq),
+        "mod" => Some(BinaryOp::Mod),
+        "lt" => Some(BinaryOp::Lt),
+        "and" => Some(BinaryOp::And),
+        "or" => Some(BinaryOp::Or),
+        "xor" => Some(BinaryOp::Xor),
+        _ => None,
+    }
+}
+
+/// Asserts that the given SSA prints as the expected text, ignoring the indentation of each line.
+#[cfg(test)]
+pub(crate) fn assert_ssa_equals(ssa: &Ssa, expected: &str) {
+    fn trim_lines(src: &str) -> String {
+        src.trim().lines().map(str::trim).collect::<Vec<_>>().join("\n")
+    }
+    assert_eq!(trim_lines(&ssa.to_string()), trim_lines(expected));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::ssa_gen::Ssa;
+
+    use super::{assert_ssa_equals, SsaParseError};
+
+    fn assert_ssa_roundtrip(src: &str) {
+        let ssa: Ssa = src.parse().unwrap_or_else(|error| panic!("{error}"));
+        assert_ssa_equals(&ssa, src);
+    }
+
+    #[test]
+    fn roundtrips_control_flow() {
+        // Blocks are printed depth first, so b3 is printed before b2
+        let src = "
+            acir fn main f0 {
+              b0(v0: u1, v1: Field):
+                jmpif v0 then: b1, else: b2
+              b1():
+                v3 = add v1, Field 1
+                jmp b3(v3)
+              b3(v4: Field):
+                return v4
+              b2():
+                jmp b3(v1)
+            }
+            ";
+        assert_ssa_roundtrip(src);
+    }
+
+    #[test]
+    fn roundtrips_each_instruction() {
+        let src = "
+            acir fn main f0 {
+              b0(v0: Field, v1: u32):
+                v2 = allocate -> &mut Field
+                store v0 at v2
+                v3 = load v2 -> Field
+                v6 = array_set [Field 1, v3] of [Field; 2], index v1, value v0
+                inc_rc v6
+                v7 = array_get v6, index v1 -> Field
+                v8 = cast v7 as u32
+                v9 = truncate v8 to 8 bits, max_bit_size: 32
+                v10 = not v9
+                v12 = lt v10, u32 10
+                enable_side_effects v12
+                constrain v12 == u1 1 'v10 isn't less than 10'
+                range_check v9 to 8 bits
+                v15 = call f1(v7) -> Field
+                v19, v20 = call to_le_radix(v15, u32 2⁸, u32 2⁵) -> u32, [u8]
+                call print_field(v15)
+                return v19, v20
+            }
+            acir fn foo f1 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        assert_ssa_roundtrip(src);
+    }
+
+    #[test]
+    fn roundtrips_runtimes_and_large_constants() {
+        let src = "
+            acir fn main f0 {
+              b0(v0: Field):
+                v3 = call f1(v0, Field -1) -> Field
+                return v3
+            }
+            brillig fn foo f1 {
+              b0(v0: Field, v1: Field):
+                v3 = mul v0, Field 2⁶⁴
+                v5 = add v3, Field 2³²×5
+                v6 = sub v5, v1
+                return v6
+            }
+            ";
+        assert_ssa_roundtrip(src);
+    }
+
+    #[test]
+    fn roundtrips_unsimplified_instructions() {
+        let src = "
+            acir fn main f0 {
+              b0(v0: Field, v1: u1):
+                v4 = add Field 1, Field 2
+                v5 = mul v0, Field 1
+                v6 = not v1
+                v7 = not v6
+                v8 = eq v0, v0
+                constrain v8 == u1 1
+                v10 = cast v4 as Field
+                return v4, v5, v7, v10
+            }
+            ";
+        assert_ssa_roundtrip(src);
+    }
+
+    #[test]
+    fn reports_undefined_values() {
+        let src = "
+            acir fn main f0 {
+              b0():
+                return v1
+            }
+            ";
+        let error = src.parse::<Ssa>().err().expect("expected a parse error");
+        assert_eq!(
+            error,
+            SsaParseError { line: 4, message: "value `v1` is not defined".to_string() }
+        );
+    }
+
+    #[test]
+    fn reports_missing_result_types() {
+        let src = "
+            acir fn main f0 {
+              b0(v0: &mut Field):
+                v1 = load v0
+                return v1
+            }
+            ";
+        let error = src.parse::<Ssa>().err().expect("expected a parse error");
+        assert_eq!(error.line, 4);
+        assert!(error.message.starts_with("expected the result types of `load`"));
+    }
+}
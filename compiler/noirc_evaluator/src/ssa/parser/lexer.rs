@@ -0,0 +1,185 @@
+//! Splits the text of the SSA into the [Token]s read by the [parser][super].
+use std::{iter::Peekable, str::Chars};
+
+use acvm::FieldElement;
+
+use super::SsaParseError;
+
+/// The superscript digits which the exponents of large constants are printed with, e.g. `2⁶⁴`.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Token {
+    /// Names of values, blocks, functions, types and instructions, as well as keywords.
+    Ident(String),
+    Int(FieldElement),
+    /// The message of a constrain or range_check instruction, e.g. `'attempt to add with overflow'`
+    Str(String),
+    LeftParen,
+    RightParen,
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Comma,
+    Colon,
+    Semicolon,
+    Assign,
+    Equal,
+    Arrow,
+    Ampersand,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "`{name}`"),
+            Token::Int(value) => write!(f, "`{value}`"),
+            Token::Str(message) => write!(f, "'{message}'"),
+            Token::LeftParen => write!(f, "`(`"),
+            Token::RightParen => write!(f, "`)`"),
+            Token::LeftBrace => write!(f, "`{{`"),
+            Token::RightBrace => write!(f, "`}}`"),
+            Token::LeftBracket => write!(f, "`[`"),
+            Token::RightBracket => write!(f, "`]`"),
+            Token::Comma => write!(f, "`,`"),
+            Token::Colon => write!(f, "`:`"),
+            Token::Semicolon => write!(f, "`;`"),
+            Token::Assign => write!(f, "`=`"),
+            Token::Equal => write!(f, "`==`"),
+            Token::Arrow => write!(f, "`->`"),
+            Token::Ampersand => write!(f, "`&`"),
+        }
+    }
+}
+
+/// Returns each token of the given source along with the line it is on, starting from 1.
+pub(super) fn lex(src: &str) -> Result<Vec<(Token, usize)>, SsaParseError> {
+    let mut lexer = Lexer { chars: src.chars().peekable(), line: 1 };
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token()? {
+        tokens.push((token, lexer.line));
+    }
+    Ok(tokens)
+}
+
+struct Lexer<'src> {
+    chars: Peekable<Chars<'src>>,
+    line: usize,
+}
+
+impl<'src> Lexer<'src> {
+    fn next_token(&mut self) -> Result<Option<Token>, SsaParseError> {
+        self.skip_whitespace_and_comments();
+
+        let token = match self.chars.next() {
+            Some(char) => char,
+            None => return Ok(None),
+        };
+        let token = match token {
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '{' => Token::LeftBrace,
+            '}' => Token::RightBrace,
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            ';' => Token::Semicolon,
+            '&' => Token::Ampersand,
+            '=' if self.eat('=') => Token::Equal,
+            '=' => Token::Assign,
+            '-' if self.eat('>') => Token::Arrow,
+            '-' => match self.chars.next() {
+                Some(digit) if digit.is_ascii_digit() => Token::Int(-self.lex_number(digit)?),
+                _ => return Err(self.error("expected a number after `-`".to_string())),
+            },
+            '\'' => self.lex_message()?,
+            digit if digit.is_ascii_digit() => Token::Int(self.lex_number(digit)?),
+            start if start.is_ascii_alphabetic() || start == '_' => {
+                let rest = self.eat_while(|char| char.is_ascii_alphanumeric() || char == '_');
+                Token::Ident(format!("{start}{rest}"))
+            }
+            other => return Err(self.error(format!("unexpected character `{other}`"))),
+        };
+        Ok(Some(token))
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            match self.chars.peek().copied() {
+                Some('\n') => {
+                    self.line += 1;
+                    self.chars.next();
+                }
+                Some(char) if char.is_whitespace() => {
+                    self.chars.next();
+                }
+                Some('/') if self.chars.clone().nth(1) == Some('/') => {
+                    // Comments are never printed but can be useful in hand-written SSA
+                    self.eat_while(|char| char != '\n');
+                }
+                _ => return,
+            }
+        }
+    }
+
+    /// Lexes a number starting with the given digit. Besides plain integers this accepts the
+    /// forms FieldElement is displayed with for large values, such as `2⁶⁴` and `2³²×5`.
+    fn lex_number(&mut self, first_digit: char) -> Result<FieldElement, SsaParseError> {
+        let digits = format!("{first_digit}{}", self.eat_while(|char| char.is_ascii_digit()));
+        let mut value = self.parse_decimal(&digits)?;
+
+        let exponent = self.eat_while(|char| SUPERSCRIPT_DIGITS.contains(&char));
+        if !exponent.is_empty() {
+            let exponent: String = exponent
+                .chars()
+                .filter_map(|char| SUPERSCRIPT_DIGITS.iter().position(|digit| *digit == char))
+                .map(|digit| char::from(b'0' + digit as u8))
+                .collect();
+            value = value.pow(&self.parse_decimal(&exponent)?);
+
+            if self.eat('×') {
+                let factor = self.eat_while(|char| char.is_ascii_digit());
+                value = value * self.parse_decimal(&factor)?;
+            }
+        }
+        Ok(value)
+    }
+
+    fn parse_decimal(&self, digits: &str) -> Result<FieldElement, SsaParseError> {
+        if digits.is_empty() {
+            return Err(self.error("expected a number".to_string()));
+        }
+        FieldElement::try_from_str(digits)
+            .ok_or_else(|| self.error(format!("`{digits}` is not a valid field element")))
+    }
+
+    /// Messages are the last thing on their line but are printed without escaping any
+    /// quotes within them, so a message extends to the last quote on its line.
+    fn lex_message(&mut self) -> Result<Token, SsaParseError> {
+        let rest_of_line = self.eat_while(|char| char != '\n');
+        match rest_of_line.rfind('\'') {
+            Some(end) if rest_of_line[end + 1..].trim().is_empty() => {
+                Ok(Token::Str(rest_of_line[..end].to_string()))
+            }
+            _ => Err(self.error("unterminated message".to_string())),
+        }
+    }
+
+    fn eat(&mut self, char: char) -> bool {
+        self.chars.next_if_eq(&char).is_some()
+    }
+
+    fn eat_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut eaten = String::new();
+        while let Some(char) = self.chars.next_if(|char| predicate(*char)) {
+            eaten.push(char);
+        }
+        eaten
+    }
+
+    fn error(&self, message: String) -> SsaParseError {
+        SsaParseError { line: self.line, message }
+    }
+}
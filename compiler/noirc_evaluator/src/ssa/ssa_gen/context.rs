@@ -10,6 +10,7 @@ use noirc_frontend::{BinaryOpKind, Signedness};
 
 use crate::errors::RuntimeError;
 use crate::ssa::function_builder::FunctionBuilder;
+use crate::ssa::ir::basic_block::BasicBlockId;
 use crate::ssa::ir::dfg::DataFlowGraph;
 use crate::ssa::ir::function::FunctionId as IrFunctionId;
 use crate::ssa::ir::function::{Function, RuntimeType};
@@ -38,6 +39,24 @@ pub(super) struct FunctionContext<'a> {
 
     pub(super) builder: FunctionBuilder,
     shared_context: &'a SharedContext,
+
+    /// The loops enclosing the expression currently being compiled, innermost last.
+    /// These are the targets of any `break` or `continue` expressions.
+    loops: Vec<Loop>,
+}
+
+/// The blocks of a loop which `break` and `continue` may jump to.
+#[derive(Copy, Clone)]
+pub(super) struct Loop {
+    /// The block checking the loop condition, which `continue` jumps back to.
+    pub(super) loop_entry: BasicBlockId,
+
+    /// The index of a `for` loop. This must be incremented before jumping back to
+    /// `loop_entry`. `while` loops have no index.
+    pub(super) loop_index: Option<ValueId>,
+
+    /// The block following the loop, which `break` jumps to.
+    pub(super) loop_end: BasicBlockId,
 }
 
 /// Shared context for all functions during ssa codegen. This is the only
@@ -96,7 +115,8 @@ impl<'a> FunctionContext<'a> {
             .1;
 
         let builder = FunctionBuilder::new(function_name, function_id, runtime);
-        let mut this = Self { definitions: HashMap::default(), builder, shared_context };
+        let mut this =
+            Self { definitions: HashMap::default(), builder, shared_context, loops: Vec::new() };
         this.add_parameters_to_scope(parameters);
         this
     }
@@ -108,11 +128,13 @@ impl<'a> FunctionContext<'a> {
     /// avoid calling new_function until the previous function is completely finished with ssa-gen.
     pub(super) fn new_function(&mut self, id: IrFunctionId, func: &ast::Function) {
         self.definitions.clear();
+        assert!(self.loops.is_empty(), "Loop stack was not emptied after the previous function");
         if func.unconstrained {
             self.builder.new_brillig_function(func.name.clone(), id);
         } else {
             self.builder.new_function(func.name.clone(), id);
         }
+        self.builder.current_function.set_inline_type(func.inline_type);
         self.add_parameters_to_scope(&func.parameters);
     }
 
@@ -674,6 +696,25 @@ impl<'a> FunctionContext<'a> {
         address
     }
 
+    pub(super) fn enter_loop(
+        &mut self,
+        loop_entry: BasicBlockId,
+        loop_index: Option<ValueId>,
+        loop_end: BasicBlockId,
+    ) {
+        self.loops.push(Loop { loop_entry, loop_index, loop_end });
+    }
+
+    pub(super) fn exit_loop(&mut self) {
+        self.loops.pop();
+    }
+
+    /// Return the innermost loop enclosing the current expression.
+    /// Panics if there is none, this should have already been checked during name resolution.
+    pub(super) fn current_loop(&self) -> Loop {
+        *self.loops.last().expect("Expected `break` or `continue` to be within a loop")
+    }
+
     /// Define a local variable to be some Values that can later be retrieved
     /// by calling self.lookup(id)
     pub(super) fn define(&mut self, id: LocalId, value: Values) {
@@ -133,6 +133,7 @@ impl<'a> FunctionContext<'a> {
             Expression::Index(index) => self.codegen_index(index),
             Expression::Cast(cast) => self.codegen_cast(cast),
             Expression::For(for_expr) => self.codegen_for(for_expr),
+            Expression::While(while_expr) => self.codegen_while(while_expr),
             Expression::If(if_expr) => self.codegen_if(if_expr),
             Expression::Tuple(tuple) => self.codegen_tuple(tuple),
             Expression::ExtractTupleField(tuple, index) => {
@@ -145,6 +146,8 @@ impl<'a> FunctionContext<'a> {
             }
             Expression::Assign(assign) => self.codegen_assign(assign),
             Expression::Semi(semi) => self.codegen_semi(semi),
+            Expression::Break => Ok(self.codegen_break()),
+            Expression::Continue => Ok(self.codegen_continue()),
         }
     }
 
@@ -488,7 +491,9 @@ impl<'a> FunctionContext<'a> {
         // Compile the loop body
         self.builder.switch_to_block(loop_body);
         self.define(for_expr.index_variable, loop_index.into());
+        self.enter_loop(loop_entry, Some(loop_index), loop_end);
         self.codegen_expression(&for_expr.block)?;
+        self.exit_loop();
         let new_loop_index = self.make_offset(loop_index, 1);
         self.builder.terminate_with_jmp(loop_entry, vec![new_loop_index]);
 
@@ -497,6 +502,74 @@ impl<'a> FunctionContext<'a> {
         Ok(Self::unit_value())
     }
 
+    /// Codegens a while loop, creating three new blocks in the process.
+    /// Unlike for loops, these are never unrolled so they are only valid in brillig functions.
+    ///
+    /// For example, the loop `while cond { body }` is codegen'd as:
+    ///
+    ///   jmp while_entry()
+    /// while_entry:
+    ///   v0 = ... codegen cond ...
+    ///   jmpif v0, then: while_body, else: while_end
+    /// while_body():
+    ///   v1 = ... codegen body ...
+    ///   jmp while_entry()
+    /// while_end:
+    ///   ... This is the current insert point after codegen_while finishes ...
+    fn codegen_while(&mut self, while_expr: &ast::While) -> Result<Values, RuntimeError> {
+        let while_entry = self.builder.insert_block();
+        let while_body = self.builder.insert_block();
+        let while_end = self.builder.insert_block();
+
+        self.builder.terminate_with_jmp(while_entry, vec![]);
+
+        // Compile the loop entry block
+        self.builder.switch_to_block(while_entry);
+        let condition = self.codegen_non_tuple_expression(&while_expr.condition)?;
+        self.builder.terminate_with_jmpif(condition, while_body, while_end);
+
+        // Compile the loop body
+        self.builder.switch_to_block(while_body);
+        self.enter_loop(while_entry, None, while_end);
+        self.codegen_expression(&while_expr.body)?;
+        self.exit_loop();
+        self.builder.terminate_with_jmp(while_entry, vec![]);
+
+        // Finish by switching to the end of the loop
+        self.builder.switch_to_block(while_end);
+        Ok(Self::unit_value())
+    }
+
+    /// Codegens a `break`, jumping to the end of the innermost loop.
+    fn codegen_break(&mut self) -> Values {
+        let loop_end = self.current_loop().loop_end;
+        self.builder.terminate_with_jmp(loop_end, vec![]);
+        self.switch_to_unreachable_block();
+        Self::unit_value()
+    }
+
+    /// Codegens a `continue`, jumping back to the entry of the innermost loop.
+    /// For loops must increment their index before doing so.
+    fn codegen_continue(&mut self) -> Values {
+        let current_loop = self.current_loop();
+        let arguments = match current_loop.loop_index {
+            Some(loop_index) => vec![self.make_offset(loop_index, 1)],
+            None => vec![],
+        };
+        self.builder.terminate_with_jmp(current_loop.loop_entry, arguments);
+        self.switch_to_unreachable_block();
+        Self::unit_value()
+    }
+
+    /// Any code following a `break` or `continue` in the same block can never execute.
+    /// It is still compiled into a fresh block with no predecessors so that the enclosing
+    /// expression can terminate it as usual without overwriting the jump we just inserted.
+    /// Since nothing jumps to this block it is dropped once the function is inlined.
+    fn switch_to_unreachable_block(&mut self) {
+        let unreachable_block = self.builder.insert_block();
+        self.builder.switch_to_block(unreachable_block);
+    }
+
     /// Codegens an if expression, handling the case of what to do if there is no 'else'.
     ///
     /// For example, the expression `if cond { a } else { b }` is codegen'd as:
@@ -2,9 +2,12 @@ use std::{collections::BTreeMap, fmt::Display};
 
 use iter_extended::btree_map;
 
-use crate::ssa::ir::{
-    function::{Function, FunctionId},
-    map::AtomicCounter,
+use crate::{
+    errors::SsaReport,
+    ssa::ir::{
+        function::{Function, FunctionId},
+        map::AtomicCounter,
+    },
 };
 
 /// Contains the entire SSA representation of the program.
@@ -12,6 +15,8 @@ pub(crate) struct Ssa {
     pub(crate) functions: BTreeMap<FunctionId, Function>,
     pub(crate) main_id: FunctionId,
     pub(crate) next_id: AtomicCounter<Function>,
+    /// Warnings found by the optimization passes, such as operations on constants which are known to fail.
+    pub(crate) warnings: Vec<SsaReport>,
 }
 
 impl Ssa {
@@ -26,7 +31,12 @@ impl Ssa {
             (f.id(), f)
         });
 
-        Self { functions, main_id, next_id: AtomicCounter::starting_after(max_id) }
+        Self {
+            functions,
+            main_id,
+            next_id: AtomicCounter::starting_after(max_id),
+            warnings: Vec::new(),
+        }
     }
 
     /// Returns the entry-point function of the program
@@ -39,6 +49,20 @@ impl Ssa {
         self.functions.get_mut(&self.main_id).expect("ICE: Ssa should have a main function")
     }
 
+    /// Returns the number of instructions in the reachable blocks of every function
+    pub(crate) fn instruction_count(&self) -> usize {
+        self.functions
+            .values()
+            .map(|function| {
+                let blocks = function.reachable_blocks();
+                blocks
+                    .into_iter()
+                    .map(|block| function.dfg[block].instructions().len())
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
     /// Adds a new function to the program
     pub(crate) fn add_fn(
         &mut self,
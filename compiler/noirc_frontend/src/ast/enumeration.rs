@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use crate::{token::SecondaryAttribute, Ident, ItemVisibility, UnresolvedGenerics, UnresolvedType};
+use iter_extended::vecmap;
+use noirc_errors::Span;
+
+/// Ast node for an enum
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoirEnum {
+    pub name: Ident,
+    pub attributes: Vec<SecondaryAttribute>,
+    pub visibility: ItemVisibility,
+    pub generics: UnresolvedGenerics,
+    /// Each variant's name along with the types of the values it carries.
+    /// Unit variants such as `None` have no parameters.
+    pub variants: Vec<(Ident, Vec<UnresolvedType>)>,
+    pub span: Span,
+}
+
+impl NoirEnum {
+    pub fn new(
+        name: Ident,
+        attributes: Vec<SecondaryAttribute>,
+        visibility: ItemVisibility,
+        generics: Vec<Ident>,
+        variants: Vec<(Ident, Vec<UnresolvedType>)>,
+        span: Span,
+    ) -> NoirEnum {
+        NoirEnum { name, attributes, visibility, generics, variants, span }
+    }
+}
+
+impl Display for NoirEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let generics = vecmap(&self.generics, |generic| generic.to_string());
+        let generics = if generics.is_empty() { "".into() } else { generics.join(", ") };
+
+        writeln!(f, "{}enum {}{} {{", self.visibility, self.name, generics)?;
+
+        for (name, params) in self.variants.iter() {
+            if params.is_empty() {
+                writeln!(f, "    {name},")?;
+            } else {
+                let params = vecmap(params, ToString::to_string);
+                writeln!(f, "    {name}({}),", params.join(", "))?;
+            }
+        }
+
+        write!(f, "}}")
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! Noir's Ast is produced by the parser and taken as input to name resolution,
 //! where it is converted into the Hir (defined in the hir_def module).
+mod enumeration;
 mod expression;
 mod function;
 mod statement;
@@ -11,6 +12,7 @@ mod structure;
 mod traits;
 mod type_alias;
 
+pub use enumeration::*;
 pub use expression::*;
 pub use function::*;
 
@@ -78,7 +80,8 @@ pub struct UnresolvedType {
 
 /// The precursor to TypeExpression, this is the type that the parser allows
 /// to be used in the length position of an array type. Only constants, variables,
-/// and numeric binary operators are allowed here.
+/// numeric binary operators and, in the type of a `let` statement, comptime blocks
+/// are allowed here.
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum UnresolvedTypeExpression {
     Variable(Path),
@@ -89,6 +92,7 @@ pub enum UnresolvedTypeExpression {
         Box<UnresolvedTypeExpression>,
         Span,
     ),
+    Comptime(Box<BlockExpression>, Span),
 }
 
 impl Recoverable for UnresolvedType {
@@ -174,6 +178,7 @@ impl std::fmt::Display for UnresolvedTypeExpression {
             UnresolvedTypeExpression::BinaryOperation(lhs, op, rhs, _) => {
                 write!(f, "({lhs} {op} {rhs})")
             }
+            UnresolvedTypeExpression::Comptime(block, _) => write!(f, "comptime {block}"),
         }
     }
 }
@@ -229,6 +234,7 @@ impl UnresolvedTypeExpression {
             UnresolvedTypeExpression::Variable(path) => path.span(),
             UnresolvedTypeExpression::Constant(_, span) => *span,
             UnresolvedTypeExpression::BinaryOperation(_, _, _, span) => *span,
+            UnresolvedTypeExpression::Comptime(_, span) => *span,
         }
     }
 
@@ -242,6 +248,9 @@ impl UnresolvedTypeExpression {
                 }
             }
             ExpressionKind::Variable(path) => Ok(UnresolvedTypeExpression::Variable(path)),
+            ExpressionKind::Comptime(block) => {
+                Ok(UnresolvedTypeExpression::Comptime(Box::new(block), expr.span))
+            }
             ExpressionKind::Prefix(prefix) if prefix.operator == UnaryOp::Minus => {
                 let lhs = Box::new(UnresolvedTypeExpression::Constant(0, expr.span));
                 let rhs = Box::new(UnresolvedTypeExpression::from_expr_helper(prefix.rhs)?);
@@ -278,13 +287,24 @@ impl UnresolvedTypeExpression {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-/// Represents whether the function can be called outside its module/crate
-pub enum FunctionVisibility {
+/// Represents whether an item can be referenced outside its module/crate.
+/// Private items are only visible within the module they are declared in and its children.
+pub enum ItemVisibility {
     Public,
     Private,
     PublicCrate,
 }
 
+impl std::fmt::Display for ItemVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemVisibility::Public => write!(f, "pub "),
+            ItemVisibility::Private => Ok(()),
+            ItemVisibility::PublicCrate => write!(f, "pub(crate) "),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 /// Represents whether the parameter is public or known only to the prover.
 pub enum Visibility {
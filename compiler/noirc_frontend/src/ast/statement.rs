@@ -3,10 +3,10 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 use crate::lexer::token::SpannedToken;
 use crate::parser::{ParserError, ParserErrorReason};
-use crate::token::Token;
+use crate::token::{SecondaryAttribute, Token};
 use crate::{
-    BlockExpression, Expression, ExpressionKind, IndexExpression, MemberAccessExpression,
-    MethodCallExpression, UnresolvedType,
+    BlockExpression, Expression, ExpressionKind, IndexExpression, ItemVisibility, Literal,
+    MemberAccessExpression, MethodCallExpression, UnresolvedType,
 };
 use acvm::FieldElement;
 use iter_extended::vecmap;
@@ -35,8 +35,14 @@ pub enum StatementKind {
     Expression(Expression),
     Assign(AssignStatement),
     For(ForLoopStatement),
+    While(WhileStatement),
+    Break,
+    Continue,
     // This is an expression with a trailing semi-colon
     Semi(Expression),
+    /// A statement along with the attributes placed on it,
+    /// as in `#[allow(unused_variables)] let x = 1;`
+    Attributed(Vec<SecondaryAttribute>, Box<Statement>),
     // This statement is the result of a recovered parse error.
     // To avoid issuing multiple errors in later steps, it should
     // be skipped in any future analysis if possible.
@@ -66,13 +72,32 @@ impl Statement {
                 }
                 self.kind
             }
-            // A semicolon on a for loop is optional and does nothing
-            StatementKind::For(_) => self.kind,
+            // A semicolon on a for or while loop is optional and does nothing
+            StatementKind::For(_) | StatementKind::While(_) => self.kind,
+
+            // The semicolon belongs to the statement the attributes are placed on
+            StatementKind::Attributed(attributes, statement) => {
+                let statement =
+                    statement.add_semicolon(semi, span, last_statement_in_block, emit_error);
+                StatementKind::Attributed(attributes, Box::new(statement))
+            }
+
+            // Like expressions, `break` and `continue` may omit their semicolon
+            // only when they are the last statement in a block
+            StatementKind::Break | StatementKind::Continue => {
+                if semi.is_none() && !last_statement_in_block {
+                    emit_error(missing_semicolon);
+                }
+                self.kind
+            }
 
             StatementKind::Expression(expr) => {
                 match (&expr.kind, semi, last_statement_in_block) {
                     // Semicolons are optional for these expressions
-                    (ExpressionKind::Block(_), semi, _) | (ExpressionKind::If(_), semi, _) => {
+                    (ExpressionKind::Block(_), semi, _)
+                    | (ExpressionKind::If(_), semi, _)
+                    | (ExpressionKind::Match(_), semi, _)
+                    | (ExpressionKind::Comptime(_), semi, _) => {
                         if semi.is_some() {
                             StatementKind::Semi(expr)
                         } else {
@@ -242,6 +267,8 @@ pub trait Recoverable {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ImportStatement {
+    /// Imports are private unless they are declared `pub use`, which re-exports them.
+    pub visibility: ItemVisibility,
     pub path: Path,
     pub alias: Option<Ident>,
 }
@@ -289,7 +316,7 @@ pub enum UseTreeKind {
 }
 
 impl UseTree {
-    pub fn desugar(self, root: Option<Path>) -> Vec<ImportStatement> {
+    pub fn desugar(self, root: Option<Path>, visibility: ItemVisibility) -> Vec<ImportStatement> {
         let prefix = if let Some(mut root) = root {
             root.segments.extend(self.prefix.segments);
             root
@@ -299,11 +326,12 @@ impl UseTree {
 
         match self.kind {
             UseTreeKind::Path(name, alias) => {
-                vec![ImportStatement { path: prefix.join(name), alias }]
-            }
-            UseTreeKind::List(trees) => {
-                trees.into_iter().flat_map(|tree| tree.desugar(Some(prefix.clone()))).collect()
+                vec![ImportStatement { visibility, path: prefix.join(name), alias }]
             }
+            UseTreeKind::List(trees) => trees
+                .into_iter()
+                .flat_map(|tree| tree.desugar(Some(prefix.clone()), visibility))
+                .collect(),
         }
     }
 }
@@ -431,15 +459,28 @@ pub enum Pattern {
     Mutable(Box<Pattern>, Span),
     Tuple(Vec<Pattern>, Span),
     Struct(Path, Vec<(Ident, Pattern)>, Span),
+    /// An enum variant pattern such as `Option::Some(x)` or `Option::None`
+    EnumVariant(Path, Vec<Pattern>, Span),
+    /// `_`, which matches any value without binding it
+    Wildcard(Span),
+    /// An integer or boolean literal such as `3`, `-1` or `true`
+    Literal(Literal, Span),
+    /// An array pattern such as `[first, .., last]`. The usize is the position of
+    /// the rest pattern `..` among the elements, if there is one.
+    Array(Vec<Pattern>, Option<usize>, Span),
 }
 
 impl Pattern {
     pub fn span(&self) -> Span {
         match self {
             Pattern::Identifier(ident) => ident.span(),
-            Pattern::Mutable(_, span) | Pattern::Tuple(_, span) | Pattern::Struct(_, _, span) => {
-                *span
-            }
+            Pattern::Mutable(_, span)
+            | Pattern::Tuple(_, span)
+            | Pattern::Struct(_, _, span)
+            | Pattern::EnumVariant(_, _, span)
+            | Pattern::Wildcard(span)
+            | Pattern::Literal(_, span)
+            | Pattern::Array(_, _, span) => *span,
         }
     }
     pub fn name_ident(&self) -> &Ident {
@@ -614,6 +655,13 @@ pub struct ForLoopStatement {
     pub span: Span,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WhileStatement {
+    pub condition: Expression,
+    pub block: Expression,
+    pub span: Span,
+}
+
 impl Display for StatementKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -622,7 +670,16 @@ impl Display for StatementKind {
             StatementKind::Expression(expression) => expression.fmt(f),
             StatementKind::Assign(assign) => assign.fmt(f),
             StatementKind::For(for_loop) => for_loop.fmt(f),
+            StatementKind::While(while_loop) => while_loop.fmt(f),
+            StatementKind::Break => write!(f, "break"),
+            StatementKind::Continue => write!(f, "continue"),
             StatementKind::Semi(semi) => write!(f, "{semi};"),
+            StatementKind::Attributed(attributes, statement) => {
+                for attribute in attributes {
+                    write!(f, "{attribute} ")?;
+                }
+                statement.kind.fmt(f)
+            }
             StatementKind::Error => write!(f, "Error"),
         }
     }
@@ -676,7 +733,7 @@ impl Display for PathKind {
 
 impl Display for ImportStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "use {}", self.path)?;
+        write!(f, "{}use {}", self.visibility, self.path)?;
         if let Some(alias) = &self.alias {
             write!(f, " as {alias}")?;
         }
@@ -697,6 +754,23 @@ impl Display for Pattern {
                 let fields = vecmap(fields, |(name, pattern)| format!("{name}: {pattern}"));
                 write!(f, "{} {{ {} }}", typename, fields.join(", "))
             }
+            Pattern::EnumVariant(variant, arguments, _) => {
+                if arguments.is_empty() {
+                    write!(f, "{variant}")
+                } else {
+                    let arguments = vecmap(arguments, ToString::to_string);
+                    write!(f, "{}({})", variant, arguments.join(", "))
+                }
+            }
+            Pattern::Wildcard(_) => write!(f, "_"),
+            Pattern::Literal(literal, _) => literal.fmt(f),
+            Pattern::Array(elements, rest, _) => {
+                let mut elements = vecmap(elements, ToString::to_string);
+                if let Some(rest) = rest {
+                    elements.insert(*rest, "..".to_string());
+                }
+                write!(f, "[{}]", elements.join(", "))
+            }
         }
     }
 }
@@ -711,3 +785,9 @@ impl Display for ForLoopStatement {
         write!(f, "for {} in {range} {}", self.identifier, self.block)
     }
 }
+
+impl Display for WhileStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "while {} {}", self.condition, self.block)
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::{token::SecondaryAttribute, Ident, UnresolvedGenerics, UnresolvedType};
+use crate::{token::SecondaryAttribute, Ident, ItemVisibility, UnresolvedGenerics, UnresolvedType};
 use iter_extended::vecmap;
 use noirc_errors::Span;
 
@@ -9,6 +9,7 @@ use noirc_errors::Span;
 pub struct NoirStruct {
     pub name: Ident,
     pub attributes: Vec<SecondaryAttribute>,
+    pub visibility: ItemVisibility,
     pub generics: UnresolvedGenerics,
     pub fields: Vec<(Ident, UnresolvedType)>,
     pub span: Span,
@@ -18,11 +19,12 @@ impl NoirStruct {
     pub fn new(
         name: Ident,
         attributes: Vec<SecondaryAttribute>,
+        visibility: ItemVisibility,
         generics: Vec<Ident>,
         fields: Vec<(Ident, UnresolvedType)>,
         span: Span,
     ) -> NoirStruct {
-        NoirStruct { name, attributes, generics, fields, span }
+        NoirStruct { name, attributes, visibility, generics, fields, span }
     }
 }
 
@@ -31,7 +33,7 @@ impl Display for NoirStruct {
         let generics = vecmap(&self.generics, |generic| generic.to_string());
         let generics = if generics.is_empty() { "".into() } else { generics.join(", ") };
 
-        writeln!(f, "struct {}{} {{", self.name, generics)?;
+        writeln!(f, "{}struct {}{} {{", self.visibility, self.name, generics)?;
 
         for (name, typ) in self.fields.iter() {
             writeln!(f, "    {name}: {typ},")?;
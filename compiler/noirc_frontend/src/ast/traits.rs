@@ -4,8 +4,8 @@ use iter_extended::vecmap;
 use noirc_errors::Span;
 
 use crate::{
-    node_interner::TraitId, BlockExpression, Expression, FunctionReturnType, Ident, NoirFunction,
-    Path, UnresolvedGenerics, UnresolvedType,
+    node_interner::TraitId, BlockExpression, Expression, FunctionReturnType, Ident, ItemVisibility,
+    NoirFunction, Path, UnresolvedGenerics, UnresolvedType,
 };
 
 /// AST node for trait definitions:
@@ -13,6 +13,7 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct NoirTrait {
     pub name: Ident,
+    pub visibility: ItemVisibility,
     pub generics: Vec<Ident>,
     pub where_clause: Vec<UnresolvedTraitConstraint>,
     pub span: Span,
@@ -117,7 +118,7 @@ impl Display for NoirTrait {
         let generics = vecmap(&self.generics, |generic| generic.to_string());
         let generics = if generics.is_empty() { "".into() } else { generics.join(", ") };
 
-        writeln!(f, "trait {}{} {{", self.name, generics)?;
+        writeln!(f, "{}trait {}{} {{", self.visibility, self.name, generics)?;
 
         for item in self.items.iter() {
             let item = item.to_string();
@@ -1,4 +1,4 @@
-use crate::{Ident, UnresolvedGenerics, UnresolvedType};
+use crate::{Ident, ItemVisibility, UnresolvedGenerics, UnresolvedType};
 use iter_extended::vecmap;
 use noirc_errors::Span;
 use std::fmt::Display;
@@ -7,6 +7,7 @@ use std::fmt::Display;
 #[derive(Clone, Debug)]
 pub struct NoirTypeAlias {
     pub name: Ident,
+    pub visibility: ItemVisibility,
     pub generics: UnresolvedGenerics,
     pub typ: UnresolvedType,
     pub span: Span,
@@ -15,17 +16,19 @@ pub struct NoirTypeAlias {
 impl NoirTypeAlias {
     pub fn new(
         name: Ident,
+        visibility: ItemVisibility,
         generics: UnresolvedGenerics,
         typ: UnresolvedType,
         span: Span,
     ) -> NoirTypeAlias {
-        NoirTypeAlias { name, generics, typ, span }
+        NoirTypeAlias { name, visibility, generics, typ, span }
     }
 }
 
 impl Display for NoirTypeAlias {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let generics = vecmap(&self.generics, |generic| generic.to_string());
-        write!(f, "type {}<{}> = {}", self.name, generics.join(", "), self.typ)
+        let (visibility, name) = (self.visibility, &self.name);
+        write!(f, "{visibility}type {name}<{}> = {}", generics.join(", "), self.typ)
     }
 }
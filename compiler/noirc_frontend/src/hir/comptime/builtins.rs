@@ -0,0 +1,154 @@
+use acvm::FieldElement;
+use iter_extended::vecmap;
+use noirc_errors::Location;
+
+use crate::Type;
+
+use super::errors::InterpreterError;
+use super::interpreter::IResult;
+use super::value::{sign_extend, to_le_radix, truncate, Value};
+
+/// Evaluates a call to the `#[builtin(name)]` function with the given name.
+/// Returns None if the builtin cannot be evaluated at compile-time.
+pub(super) fn call_builtin(
+    name: &str,
+    arguments: Vec<Value>,
+    return_type: &Type,
+    location: Location,
+) -> Option<IResult<Value>> {
+    let mut arguments = arguments.into_iter();
+    let mut argument = || arguments.next().expect("Type checking should check argument counts");
+
+    let result = match name {
+        "array_len" => {
+            let length = array(argument()).len();
+            let length = Value::integer(length.into(), false, return_type)
+                .expect("array_len should return an integer");
+            Ok(length)
+        }
+        "assert_constant" => Ok(Value::Unit),
+        "as_field" => Ok(Value::Field(argument().to_field().expect("Expected a numeric value"))),
+        "from_field" => Ok(argument().cast(return_type).expect("Expected a numeric return type")),
+        "wrapping_mul" => Ok(wrapping_mul(argument(), argument())),
+        "modulus_num_bits" => Ok(Value::Field((FieldElement::max_num_bits() as u128).into())),
+        "modulus_le_bits" => Ok(bytes_to_array(FieldElement::modulus().to_radix_le(2), 1)),
+        "modulus_be_bits" => Ok(bytes_to_array(FieldElement::modulus().to_radix_be(2), 1)),
+        "modulus_le_bytes" => Ok(bytes_to_array(FieldElement::modulus().to_bytes_le(), 8)),
+        "modulus_be_bytes" => Ok(bytes_to_array(FieldElement::modulus().to_bytes_be(), 8)),
+        "str_as_bytes" => match argument() {
+            Value::String(string) => Ok(bytes_to_array(string.as_bytes().to_vec(), 8)),
+            value => unreachable!("Expected a string, found {value:?}"),
+        },
+        "to_le_bits" | "to_be_bits" => {
+            let (field, limbs) = (argument(), argument());
+            decompose(field, 2, limbs, name == "to_be_bits", 1, location)
+        }
+        "to_le_radix" | "to_be_radix" => {
+            let (field, radix, limbs) = (argument(), argument(), argument());
+            let radix = radix.to_u128().expect("Expected an integer radix") as u32;
+            decompose(field, radix, limbs, name == "to_be_radix", 8, location)
+        }
+        "slice_push_back" => {
+            let (mut slice, element) = (array(argument()), argument());
+            slice.push(element);
+            Ok(Value::Array(slice))
+        }
+        "slice_push_front" => {
+            let (mut slice, element) = (array(argument()), argument());
+            slice.insert(0, element);
+            Ok(Value::Array(slice))
+        }
+        "slice_pop_back" => {
+            let mut slice = array(argument());
+            match slice.pop() {
+                Some(element) => Ok(Value::Tuple(vec![Value::Array(slice), element])),
+                None => Err(InterpreterError::IndexOutOfBounds { index: 0, length: 0, location }),
+            }
+        }
+        "slice_pop_front" => {
+            let mut slice = array(argument());
+            if slice.is_empty() {
+                Err(InterpreterError::IndexOutOfBounds { index: 0, length: 0, location })
+            } else {
+                let element = slice.remove(0);
+                Ok(Value::Tuple(vec![element, Value::Array(slice)]))
+            }
+        }
+        "slice_insert" => {
+            let (mut slice, index, element) = (array(argument()), argument(), argument());
+            let index = index.to_u128().expect("Expected an integer index");
+            if index <= slice.len() as u128 {
+                slice.insert(index as usize, element);
+                Ok(Value::Array(slice))
+            } else {
+                Err(InterpreterError::IndexOutOfBounds { index, length: slice.len(), location })
+            }
+        }
+        "slice_remove" => {
+            let (mut slice, index) = (array(argument()), argument());
+            let index = index.to_u128().expect("Expected an integer index");
+            if index < slice.len() as u128 {
+                let element = slice.remove(index as usize);
+                Ok(Value::Tuple(vec![Value::Array(slice), element]))
+            } else {
+                Err(InterpreterError::IndexOutOfBounds { index, length: slice.len(), location })
+            }
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Integers of up to 128 bits are multiplied modulo 2^128, which they all divide.
+fn wrapping_mul(lhs: Value, rhs: Value) -> Value {
+    match (lhs, rhs) {
+        (Value::Unsigned(lhs, bit_size), Value::Unsigned(rhs, _)) => {
+            Value::Unsigned(truncate(lhs.wrapping_mul(rhs), bit_size), bit_size)
+        }
+        (Value::Signed(lhs, bit_size), Value::Signed(rhs, _)) => {
+            Value::Signed(sign_extend(lhs.wrapping_mul(rhs) as u128, bit_size), bit_size)
+        }
+        (lhs, rhs) => {
+            let lhs = lhs.to_field().expect("Expected a numeric value");
+            let rhs = rhs.to_field().expect("Expected a numeric value");
+            Value::Field(lhs * rhs)
+        }
+    }
+}
+
+fn array(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(elements) => elements,
+        value => unreachable!("Expected an array or slice, found {value:?}"),
+    }
+}
+
+fn bytes_to_array(bytes: Vec<u8>, bit_size: u32) -> Value {
+    Value::Array(vecmap(bytes, |byte| Value::Unsigned(byte as u128, bit_size)))
+}
+
+fn decompose(
+    field: Value,
+    radix: u32,
+    limbs: Value,
+    big_endian: bool,
+    bit_size: u32,
+    location: Location,
+) -> IResult<Value> {
+    let field = field.to_field().expect("Expected a Field to decompose");
+    let limbs = limbs.to_u128().expect("Expected an integer limb count") as u32;
+
+    if !(2..=256).contains(&radix) {
+        return Err(InterpreterError::FieldDecompositionFailed { limbs, location });
+    }
+
+    match to_le_radix(field, radix, limbs) {
+        Some(mut digits) => {
+            if big_endian {
+                digits.reverse();
+            }
+            Ok(bytes_to_array(digits, bit_size))
+        }
+        None => Err(InterpreterError::FieldDecompositionFailed { limbs, location }),
+    }
+}
@@ -0,0 +1,196 @@
+use noirc_errors::{CustomDiagnostic as Diagnostic, Location};
+use thiserror::Error;
+
+use crate::{BinaryOpKind, Type};
+
+/// Errors which may occur while evaluating `comptime` code.
+#[derive(Error, Debug, Clone)]
+pub enum InterpreterError {
+    #[error("Variable is not known at compile-time")]
+    NonComptimeVariable { name: String, location: Location },
+    #[error("Generic is not known at compile-time")]
+    NonComptimeGeneric { name: String, location: Location },
+    #[error("Function cannot be called at compile-time")]
+    NonComptimeFunction { name: String, location: Location },
+    #[error("Expression is not supported at compile-time")]
+    UnsupportedExpression { expression: &'static str, location: Location },
+    #[error("Invalid operands to binary operator")]
+    InvalidBinaryOperands { operator: BinaryOpKind, location: Location },
+    #[error("Integer overflow")]
+    IntegerOverflow { operator: BinaryOpKind, location: Location },
+    #[error("Division by zero")]
+    DivisionByZero { location: Location },
+    #[error("Index out of bounds")]
+    IndexOutOfBounds { index: u128, length: usize, location: Location },
+    #[error("Failed to decompose field")]
+    FieldDecompositionFailed { limbs: u32, location: Location },
+    #[error("Failed assertion")]
+    FailedAssertion { message: Option<String>, location: Location },
+    #[error("Value cannot be used at runtime")]
+    CannotSplice { typ: Type, location: Location },
+    #[error("Too many evaluation steps")]
+    EvaluationLimitExceeded { location: Location },
+    #[error("Call depth exceeded")]
+    CallDepthExceeded { location: Location },
+    #[error("Invalid array length")]
+    InvalidArrayLength { typ: Type, location: Location },
+    #[error("Function cannot be called before type checking")]
+    UncheckedFunction { name: String, location: Location },
+
+    /// `break` and `continue` are implemented as errors which are caught by the innermost
+    /// enclosing loop. The resolver ensures they never escape a loop.
+    #[error("break")]
+    Break,
+    #[error("continue")]
+    Continue,
+}
+
+impl InterpreterError {
+    pub fn location(&self) -> Location {
+        match self {
+            InterpreterError::NonComptimeVariable { location, .. }
+            | InterpreterError::NonComptimeGeneric { location, .. }
+            | InterpreterError::NonComptimeFunction { location, .. }
+            | InterpreterError::UnsupportedExpression { location, .. }
+            | InterpreterError::InvalidBinaryOperands { location, .. }
+            | InterpreterError::IntegerOverflow { location, .. }
+            | InterpreterError::DivisionByZero { location }
+            | InterpreterError::IndexOutOfBounds { location, .. }
+            | InterpreterError::FieldDecompositionFailed { location, .. }
+            | InterpreterError::FailedAssertion { location, .. }
+            | InterpreterError::CannotSplice { location, .. }
+            | InterpreterError::EvaluationLimitExceeded { location }
+            | InterpreterError::CallDepthExceeded { location }
+            | InterpreterError::InvalidArrayLength { location, .. }
+            | InterpreterError::UncheckedFunction { location, .. } => *location,
+            InterpreterError::Break | InterpreterError::Continue => {
+                unreachable!("break and continue should be caught by their enclosing loop")
+            }
+        }
+    }
+
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            InterpreterError::NonComptimeVariable { .. } => "E0400",
+            InterpreterError::NonComptimeGeneric { .. } => "E0401",
+            InterpreterError::NonComptimeFunction { .. } => "E0402",
+            InterpreterError::UnsupportedExpression { .. } => "E0403",
+            InterpreterError::InvalidBinaryOperands { .. } => "E0404",
+            InterpreterError::IntegerOverflow { .. } => "E0405",
+            InterpreterError::DivisionByZero { .. } => "E0406",
+            InterpreterError::IndexOutOfBounds { .. } => "E0407",
+            InterpreterError::FieldDecompositionFailed { .. } => "E0408",
+            InterpreterError::FailedAssertion { .. } => "E0409",
+            InterpreterError::CannotSplice { .. } => "E0410",
+            InterpreterError::EvaluationLimitExceeded { .. } => "E0411",
+            InterpreterError::CallDepthExceeded { .. } => "E0412",
+            InterpreterError::InvalidArrayLength { .. } => "E0413",
+            InterpreterError::UncheckedFunction { .. } => "E0414",
+            InterpreterError::Break | InterpreterError::Continue => {
+                unreachable!("break and continue should be caught by their enclosing loop")
+            }
+        }
+    }
+}
+
+impl From<InterpreterError> for Diagnostic {
+    fn from(error: InterpreterError) -> Diagnostic {
+        let span = error.location().span;
+        let code = error.code();
+        let diagnostic = match error {
+            InterpreterError::NonComptimeVariable { name, .. } => Diagnostic::simple_error(
+                format!("`{name}` is not known at compile-time"),
+                "Only variables declared within comptime code can be used here".into(),
+                span,
+            ),
+            InterpreterError::NonComptimeGeneric { name, .. } => Diagnostic::simple_error(
+                format!("The value of generic `{name}` is not known at compile-time"),
+                String::new(),
+                span,
+            ),
+            InterpreterError::NonComptimeFunction { name, .. } => Diagnostic::simple_error(
+                format!("`{name}` cannot be called at compile-time"),
+                "Foreign, oracle and most builtin functions are only available at runtime".into(),
+                span,
+            ),
+            InterpreterError::UnsupportedExpression { expression, .. } => {
+                Diagnostic::simple_error(
+                    format!("{expression} are not supported in comptime code"),
+                    String::new(),
+                    span,
+                )
+            }
+            InterpreterError::InvalidBinaryOperands { operator, .. } => Diagnostic::simple_error(
+                format!("Invalid operands to `{operator}` in comptime code"),
+                String::new(),
+                span,
+            ),
+            InterpreterError::IntegerOverflow { operator, .. } => Diagnostic::simple_error(
+                format!("Attempt to {} with overflow", operator_verb(operator)),
+                "Overflow during compile-time evaluation".into(),
+                span,
+            ),
+            InterpreterError::DivisionByZero { .. } => Diagnostic::simple_error(
+                "Attempt to divide by zero".into(),
+                "Division by zero during compile-time evaluation".into(),
+                span,
+            ),
+            InterpreterError::IndexOutOfBounds { index, length, .. } => Diagnostic::simple_error(
+                format!("Index out of bounds: the length is {length} but the index is {index}"),
+                String::new(),
+                span,
+            ),
+            InterpreterError::FieldDecompositionFailed { limbs, .. } => Diagnostic::simple_error(
+                format!("Field failed to decompose into {limbs} limbs"),
+                String::new(),
+                span,
+            ),
+            InterpreterError::FailedAssertion { message, .. } => {
+                let primary = match message {
+                    Some(message) => format!("Assertion failed at compile-time: '{message}'"),
+                    None => "Assertion failed at compile-time".into(),
+                };
+                Diagnostic::simple_error(primary, String::new(), span)
+            }
+            InterpreterError::CannotSplice { typ, .. } => Diagnostic::simple_error(
+                format!("A value of type `{typ}` cannot be used outside of comptime code"),
+                "Only numbers, booleans, strings, arrays, tuples, structs and enums can be produced by comptime code".into(),
+                span,
+            ),
+            InterpreterError::EvaluationLimitExceeded { .. } => Diagnostic::simple_error(
+                "Comptime evaluation exceeded the maximum number of steps".into(),
+                String::new(),
+                span,
+            ),
+            InterpreterError::CallDepthExceeded { .. } => Diagnostic::simple_error(
+                "Comptime evaluation exceeded the maximum call depth".into(),
+                String::new(),
+                span,
+            ),
+            InterpreterError::InvalidArrayLength { typ, .. } => Diagnostic::simple_error(
+                "Array length must be a non-negative integer which fits in a u64".into(),
+                format!("This evaluates to a `{typ}` which is not a valid length"),
+                span,
+            ),
+            InterpreterError::UncheckedFunction { name, .. } => Diagnostic::simple_error(
+                format!("`{name}` cannot be called in an array length"),
+                "Array lengths are evaluated before type checking, so only comptime functions can be called".into(),
+                span,
+            ),
+            InterpreterError::Break | InterpreterError::Continue => {
+                unreachable!("break and continue should be caught by their enclosing loop")
+            }
+        };
+        diagnostic.with_code(code)
+    }
+}
+
+fn operator_verb(operator: BinaryOpKind) -> &'static str {
+    match operator {
+        BinaryOpKind::Add => "add",
+        BinaryOpKind::Subtract => "subtract",
+        BinaryOpKind::Multiply => "multiply",
+        _ => "evaluate",
+    }
+}
@@ -0,0 +1,875 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use iter_extended::{try_vecmap, vecmap};
+use noirc_errors::Location;
+
+use crate::graph::CrateId;
+use crate::hir_def::expr::{
+    HirArrayLiteral, HirBlockExpression, HirCallExpression, HirCastExpression,
+    HirConstructorExpression, HirExpression, HirIdent, HirIfExpression, HirIndexExpression,
+    HirInfixExpression, HirLambda, HirLiteral, HirMatchExpression, HirMemberAccess,
+    HirPrefixExpression,
+};
+use crate::hir_def::stmt::{
+    array_pattern_indices, HirConstrainStatement, HirForStatement, HirLValue, HirPattern,
+    HirStatement, HirWhileStatement,
+};
+use crate::node_interner::{
+    DefinitionId, DefinitionKind, ExprId, FuncId, NodeInterner, StmtId, TraitImplKind,
+    TraitMethodId,
+};
+use crate::token::FunctionAttribute;
+use crate::{BinaryOpKind, FunctionKind, Shared, Type, TypeBinding, TypeBindings, UnaryOp};
+
+use super::builtins::call_builtin;
+use super::errors::InterpreterError;
+use super::value::{perform_bindings, sign_extend, truncate, undo_bindings, Value};
+
+/// The maximum number of expressions a single comptime evaluation may evaluate
+/// before it is assumed to never terminate.
+const MAX_EVALUATION_STEPS: usize = 50_000_000;
+
+/// The maximum depth of nested function calls within a single comptime evaluation.
+const MAX_CALL_DEPTH: usize = 256;
+
+pub(super) type IResult<T> = Result<T, InterpreterError>;
+
+/// Evaluates type checked HIR directly, without monomorphizing it first.
+pub struct Interpreter<'interner> {
+    interner: &'interner NodeInterner,
+
+    /// Each scope maps the variables declared within it to their current value.
+    /// Calling a function starts a fresh set of scopes since callees cannot refer
+    /// to their caller's variables.
+    scopes: Vec<HashMap<DefinitionId, Shared<Value>>>,
+
+    steps: usize,
+    call_depth: usize,
+
+    /// The crate being compiled when evaluating code before it is type checked, such as
+    /// array lengths. Only the comptime functions of this crate have been type checked.
+    unchecked_crate: Option<CrateId>,
+}
+
+impl<'interner> Interpreter<'interner> {
+    pub fn new(interner: &'interner NodeInterner) -> Self {
+        Self {
+            interner,
+            scopes: vec![HashMap::new()],
+            steps: 0,
+            call_depth: 0,
+            unchecked_crate: None,
+        }
+    }
+
+    /// Creates an interpreter for code evaluated before the crate `crate_id` is type checked,
+    /// which may only call the comptime functions of that crate.
+    pub fn before_type_checking(interner: &'interner NodeInterner, crate_id: CrateId) -> Self {
+        Self { unchecked_crate: Some(crate_id), ..Self::new(interner) }
+    }
+
+    pub fn evaluate(&mut self, id: ExprId) -> IResult<Value> {
+        self.steps += 1;
+        if self.steps > MAX_EVALUATION_STEPS {
+            let location = self.interner.expr_location(&id);
+            return Err(InterpreterError::EvaluationLimitExceeded { location });
+        }
+
+        match self.interner.expression(&id) {
+            HirExpression::Ident(ident) => self.evaluate_ident(ident, id),
+            HirExpression::Literal(literal) => self.evaluate_literal(literal, id),
+            HirExpression::Block(block) | HirExpression::Comptime(block) => {
+                self.evaluate_block(block)
+            }
+            HirExpression::Prefix(prefix) => self.evaluate_prefix(prefix, id),
+            HirExpression::Infix(infix) => self.evaluate_infix(infix),
+            HirExpression::Index(index) => self.evaluate_index(index, id),
+            HirExpression::Constructor(constructor) => self.evaluate_constructor(constructor),
+            HirExpression::EnumConstructor(constructor) => {
+                let arguments = try_vecmap(constructor.arguments, |arg| self.evaluate(arg))?;
+                Ok(Value::Enum(constructor.variant_index, arguments))
+            }
+            HirExpression::MemberAccess(access) => self.evaluate_member_access(access, id),
+            HirExpression::Call(call) => self.evaluate_call(call, id),
+            HirExpression::Cast(cast) => self.evaluate_cast(cast, id),
+            HirExpression::If(if_expr) => self.evaluate_if(if_expr),
+            HirExpression::Match(match_expr) => self.evaluate_match(match_expr),
+            HirExpression::Tuple(fields) => {
+                Ok(Value::Tuple(try_vecmap(fields, |field| self.evaluate(field))?))
+            }
+            HirExpression::Lambda(lambda) => self.evaluate_lambda(lambda),
+            HirExpression::TraitMethodReference(method) => self.evaluate_trait_method(method, id),
+            HirExpression::MethodCall(_) => {
+                unreachable!(
+                    "Method calls should be replaced with function calls during type checking"
+                )
+            }
+            HirExpression::Error => {
+                unreachable!("Comptime code is only evaluated when type checking succeeds")
+            }
+        }
+    }
+
+    pub fn evaluate_block(&mut self, block: HirBlockExpression) -> IResult<Value> {
+        self.scopes.push(HashMap::new());
+        let result = self.evaluate_statements(block.statements());
+        self.scopes.pop();
+        result
+    }
+
+    fn evaluate_statements(&mut self, statements: &[StmtId]) -> IResult<Value> {
+        let mut result = Value::Unit;
+        for statement in statements {
+            result = self.evaluate_statement(*statement)?;
+        }
+        Ok(result)
+    }
+
+    fn evaluate_statement(&mut self, statement: StmtId) -> IResult<Value> {
+        match self.interner.statement(&statement) {
+            HirStatement::Let(let_statement) => {
+                let value = self.evaluate(let_statement.expression)?;
+                self.define_pattern(&let_statement.pattern, value);
+                Ok(Value::Unit)
+            }
+            HirStatement::Constrain(constrain) => self.evaluate_constrain(constrain),
+            HirStatement::Assign(assign) => {
+                let value = self.evaluate(assign.expression)?;
+                self.store_lvalue(assign.lvalue, value)?;
+                Ok(Value::Unit)
+            }
+            HirStatement::For(for_loop) => self.evaluate_for(for_loop),
+            HirStatement::While(while_loop) => self.evaluate_while(while_loop),
+            HirStatement::Break => Err(InterpreterError::Break),
+            HirStatement::Continue => Err(InterpreterError::Continue),
+            HirStatement::Expression(expression) => self.evaluate(expression),
+            HirStatement::Semi(expression) => {
+                self.evaluate(expression)?;
+                Ok(Value::Unit)
+            }
+            HirStatement::Error => {
+                unreachable!("Comptime code is only evaluated when type checking succeeds")
+            }
+        }
+    }
+
+    fn evaluate_ident(&mut self, ident: HirIdent, id: ExprId) -> IResult<Value> {
+        let definition = self.interner.definition(ident.id);
+        match &definition.kind {
+            DefinitionKind::Function(function) => {
+                let bindings = follow_bindings(self.interner.get_instantiation_bindings(id));
+                Ok(Value::Function(*function, bindings))
+            }
+            DefinitionKind::Local(_) => Ok(self.lookup(&ident)?.borrow().clone()),
+            DefinitionKind::Global(expression) => self.evaluate(*expression),
+            DefinitionKind::GenericType(type_variable) => {
+                let value = match &*type_variable.borrow() {
+                    TypeBinding::Bound(binding) => binding.evaluate_to_u64(),
+                    TypeBinding::Unbound(_) => None,
+                };
+                let value = value.ok_or_else(|| InterpreterError::NonComptimeGeneric {
+                    name: definition.name.clone(),
+                    location: ident.location,
+                })?;
+                self.integer((value as u128).into(), false, id)
+            }
+        }
+    }
+
+    fn evaluate_literal(&mut self, literal: HirLiteral, id: ExprId) -> IResult<Value> {
+        match literal {
+            HirLiteral::Unit => Ok(Value::Unit),
+            HirLiteral::Bool(value) => Ok(Value::Bool(value)),
+            HirLiteral::Integer(value, is_negative) => self.integer(value, is_negative, id),
+            HirLiteral::Str(string) => Ok(Value::String(Rc::new(string))),
+            HirLiteral::FmtStr(..) => Err(InterpreterError::UnsupportedExpression {
+                expression: "Format strings",
+                location: self.interner.expr_location(&id),
+            }),
+            HirLiteral::Array(HirArrayLiteral::Standard(elements)) => {
+                Ok(Value::Array(try_vecmap(elements, |element| self.evaluate(element))?))
+            }
+            HirLiteral::Array(HirArrayLiteral::Repeated { repeated_element, length }) => {
+                let element = self.evaluate(repeated_element)?;
+                let length = length.evaluate_to_u64().ok_or_else(|| {
+                    InterpreterError::NonComptimeGeneric {
+                        name: length.to_string(),
+                        location: self.interner.expr_location(&id),
+                    }
+                })?;
+                Ok(Value::Array(vec![element; length as usize]))
+            }
+        }
+    }
+
+    /// Creates an integer value with the type of the given expression
+    fn integer(&self, value: acvm::FieldElement, is_negative: bool, id: ExprId) -> IResult<Value> {
+        let typ = self.interner.id_type(id);
+        Value::integer(value, is_negative, &typ).ok_or_else(|| {
+            InterpreterError::NonComptimeGeneric {
+                name: typ.to_string(),
+                location: self.interner.expr_location(&id),
+            }
+        })
+    }
+
+    fn evaluate_prefix(&mut self, prefix: HirPrefixExpression, id: ExprId) -> IResult<Value> {
+        if prefix.operator == UnaryOp::MutableReference {
+            return self.evaluate_reference(prefix.rhs);
+        }
+
+        let location = self.interner.expr_location(&id);
+        let overflow =
+            InterpreterError::IntegerOverflow { operator: BinaryOpKind::Subtract, location };
+
+        match (prefix.operator, self.evaluate(prefix.rhs)?) {
+            (UnaryOp::Minus, Value::Field(value)) => Ok(Value::Field(-value)),
+            (UnaryOp::Minus, Value::Unsigned(0, bit_size)) => Ok(Value::Unsigned(0, bit_size)),
+            (UnaryOp::Minus, Value::Unsigned(..)) => Err(overflow),
+            (UnaryOp::Minus, Value::Signed(value, bit_size)) => match value.checked_neg() {
+                Some(negated) if sign_extend(negated as u128, bit_size) == negated => {
+                    Ok(Value::Signed(negated, bit_size))
+                }
+                _ => Err(overflow),
+            },
+            (UnaryOp::Not, Value::Bool(value)) => Ok(Value::Bool(!value)),
+            (UnaryOp::Not, Value::Unsigned(value, bit_size)) => {
+                Ok(Value::Unsigned(truncate(!value, bit_size), bit_size))
+            }
+            (UnaryOp::Not, Value::Signed(value, bit_size)) => Ok(Value::Signed(!value, bit_size)),
+            (UnaryOp::Dereference { .. }, Value::Pointer(element)) => Ok(element.borrow().clone()),
+            (operator, value) => {
+                unreachable!("Type checking should prevent applying {operator:?} to {value:?}")
+            }
+        }
+    }
+
+    /// `&mut variable` refers to the variable itself so that mutations through the
+    /// reference are visible to later uses of the variable.
+    fn evaluate_reference(&mut self, rhs: ExprId) -> IResult<Value> {
+        if let HirExpression::Ident(ident) = self.interner.expression(&rhs) {
+            if let DefinitionKind::Local(_) = self.interner.definition(ident.id).kind {
+                return Ok(Value::Pointer(self.lookup(&ident)?));
+            }
+        }
+        Ok(Value::Pointer(Shared::new(self.evaluate(rhs)?)))
+    }
+
+    fn evaluate_infix(&mut self, infix: HirInfixExpression) -> IResult<Value> {
+        let lhs = self.evaluate(infix.lhs)?;
+        let rhs = self.evaluate(infix.rhs)?;
+        evaluate_binary(infix.operator.kind, lhs, rhs, infix.operator.location)
+    }
+
+    fn evaluate_index(&mut self, index: HirIndexExpression, id: ExprId) -> IResult<Value> {
+        let collection = self.evaluate(index.collection)?;
+        let index = self.evaluate(index.index)?;
+        let location = self.interner.expr_location(&id);
+
+        match dereference(collection) {
+            Value::Array(mut elements) => {
+                let index = check_index(&index, elements.len(), location)?;
+                Ok(elements.swap_remove(index))
+            }
+            value => unreachable!("Type checking should prevent indexing {value:?}"),
+        }
+    }
+
+    fn evaluate_constructor(&mut self, constructor: HirConstructorExpression) -> IResult<Value> {
+        let struct_type = constructor.r#type.borrow();
+        let generics = &constructor.struct_generics;
+
+        // Fields are evaluated in source order but stored in declaration order
+        let mut fields = vec![Value::Unit; constructor.fields.len()];
+        for (name, field) in constructor.fields.iter() {
+            let (_, index) = struct_type
+                .get_field(&name.0.contents, generics)
+                .expect("Type checking should ensure each field exists");
+            fields[index] = self.evaluate(*field)?;
+        }
+        Ok(Value::Struct(fields))
+    }
+
+    fn evaluate_member_access(&mut self, access: HirMemberAccess, id: ExprId) -> IResult<Value> {
+        let index = self.interner.get_field_index(id);
+        match dereference(self.evaluate(access.lhs)?) {
+            Value::Tuple(mut fields) | Value::Struct(mut fields) => Ok(fields.swap_remove(index)),
+            value => unreachable!("Type checking should prevent accessing a field of {value:?}"),
+        }
+    }
+
+    fn evaluate_call(&mut self, call: HirCallExpression, id: ExprId) -> IResult<Value> {
+        let function = self.evaluate(call.func)?;
+        let arguments = try_vecmap(call.arguments, |argument| self.evaluate(argument))?;
+
+        match function {
+            Value::Function(function, bindings) => {
+                self.call_function(function, arguments, bindings, id, call.location)
+            }
+            Value::Closure(lambda, environment) => {
+                self.call_closure(lambda, environment, arguments, call.location)
+            }
+            value => unreachable!("Type checking should prevent calling {value:?}"),
+        }
+    }
+
+    pub fn call_function(
+        &mut self,
+        function: FuncId,
+        arguments: Vec<Value>,
+        bindings: TypeBindings,
+        call_id: ExprId,
+        location: Location,
+    ) -> IResult<Value> {
+        let meta = self.interner.function_meta(&function);
+        if meta.kind != FunctionKind::Normal {
+            return self.call_builtin(function, arguments, call_id, location);
+        }
+        if self.is_unchecked(function) {
+            let name = self.interner.function_name(&function).to_owned();
+            return Err(InterpreterError::UncheckedFunction { name, location });
+        }
+
+        let previous_bindings = perform_bindings(&bindings);
+        let body = *self.interner.function(&function).as_expr();
+
+        let result = self.call_with_new_scope(location, |this| {
+            for ((pattern, _, _), argument) in meta.parameters.0.iter().zip(arguments) {
+                this.define_pattern(pattern, argument);
+            }
+            this.evaluate(body)
+        });
+
+        undo_bindings(previous_bindings);
+        result
+    }
+
+    fn is_unchecked(&self, function: FuncId) -> bool {
+        self.unchecked_crate.map_or(false, |crate_id| {
+            self.interner.function_module(function).krate == crate_id
+                && !self.interner.function_modifiers(&function).is_comptime
+        })
+    }
+
+    fn call_closure(
+        &mut self,
+        lambda: HirLambda,
+        environment: Vec<(DefinitionId, Value)>,
+        arguments: Vec<Value>,
+        location: Location,
+    ) -> IResult<Value> {
+        self.call_with_new_scope(location, |this| {
+            for (id, value) in environment {
+                this.define(id, value);
+            }
+            for ((pattern, _), argument) in lambda.parameters.iter().zip(arguments) {
+                this.define_pattern(pattern, argument);
+            }
+            this.evaluate(lambda.body)
+        })
+    }
+
+    fn call_with_new_scope(
+        &mut self,
+        location: Location,
+        f: impl FnOnce(&mut Self) -> IResult<Value>,
+    ) -> IResult<Value> {
+        if self.call_depth >= MAX_CALL_DEPTH {
+            return Err(InterpreterError::CallDepthExceeded { location });
+        }
+
+        let caller_scopes = std::mem::replace(&mut self.scopes, vec![HashMap::new()]);
+        self.call_depth += 1;
+        let result = f(self);
+        self.call_depth -= 1;
+        self.scopes = caller_scopes;
+        result
+    }
+
+    fn call_builtin(
+        &mut self,
+        function: FuncId,
+        arguments: Vec<Value>,
+        call_id: ExprId,
+        location: Location,
+    ) -> IResult<Value> {
+        let attribute = self.interner.function_attributes(&function).function.clone();
+        if let Some(FunctionAttribute::Builtin(name)) = attribute {
+            let return_type = self.interner.id_type(call_id);
+            if let Some(result) = call_builtin(&name, arguments, &return_type, location) {
+                return result;
+            }
+        }
+
+        let name = self.interner.function_name(&function).to_owned();
+        Err(InterpreterError::NonComptimeFunction { name, location })
+    }
+
+    fn evaluate_cast(&mut self, cast: HirCastExpression, id: ExprId) -> IResult<Value> {
+        let value = self.evaluate(cast.lhs)?;
+        value.cast(&cast.r#type).ok_or_else(|| InterpreterError::NonComptimeGeneric {
+            name: cast.r#type.to_string(),
+            location: self.interner.expr_location(&id),
+        })
+    }
+
+    fn evaluate_if(&mut self, if_expr: HirIfExpression) -> IResult<Value> {
+        match self.evaluate(if_expr.condition)? {
+            Value::Bool(true) => self.evaluate(if_expr.consequence),
+            Value::Bool(false) => match if_expr.alternative {
+                Some(alternative) => self.evaluate(alternative),
+                None => Ok(Value::Unit),
+            },
+            value => {
+                unreachable!("Type checking should ensure if conditions are bools, not {value:?}")
+            }
+        }
+    }
+
+    fn evaluate_match(&mut self, match_expr: HirMatchExpression) -> IResult<Value> {
+        let value = self.evaluate(match_expr.expression)?;
+
+        for (pattern, branch) in match_expr.rules {
+            if self.pattern_matches(&pattern, &value)? {
+                self.scopes.push(HashMap::new());
+                self.define_pattern(&pattern, value);
+                let result = self.evaluate(branch);
+                self.scopes.pop();
+                return result;
+            }
+        }
+        unreachable!("Match expressions are checked for exhaustiveness during type checking")
+    }
+
+    fn evaluate_lambda(&mut self, lambda: HirLambda) -> IResult<Value> {
+        let environment = try_vecmap(&lambda.captures, |capture| {
+            let value = self.lookup(&capture.ident)?;
+            let value = value.borrow().clone();
+            Ok::<_, InterpreterError>((capture.ident.id, value))
+        })?;
+        Ok(Value::Closure(lambda, environment))
+    }
+
+    fn evaluate_trait_method(&mut self, method: TraitMethodId, id: ExprId) -> IResult<Value> {
+        let bindings = follow_bindings(self.interner.get_instantiation_bindings(id));
+        let impl_kind = self
+            .interner
+            .get_selected_impl_for_ident(id)
+            .expect("ICE: missing trait impl - should be caught during type checking");
+
+        let impl_id = match impl_kind {
+            TraitImplKind::Normal(impl_id) => impl_id,
+            // Assumed impls refer to the generics of the function currently being
+            // evaluated, which are bound for the duration of the call.
+            TraitImplKind::Assumed { object_type } => {
+                match self.interner.try_lookup_trait_implementation(&object_type, method.trait_id) {
+                    Ok((TraitImplKind::Normal(impl_id), _)) => impl_id,
+                    _ => {
+                        return Err(InterpreterError::NonComptimeGeneric {
+                            name: object_type.to_string(),
+                            location: self.interner.expr_location(&id),
+                        })
+                    }
+                }
+            }
+        };
+
+        let function =
+            self.interner.get_trait_implementation(impl_id).borrow().methods[method.method_index];
+        Ok(Value::Function(function, bindings))
+    }
+
+    fn evaluate_constrain(&mut self, constrain: HirConstrainStatement) -> IResult<Value> {
+        let HirConstrainStatement(expression, _, message) = constrain;
+        match self.evaluate(expression)? {
+            Value::Bool(true) => Ok(Value::Unit),
+            Value::Bool(false) => {
+                let location = self.interner.expr_location(&expression);
+                Err(InterpreterError::FailedAssertion { message, location })
+            }
+            value => {
+                unreachable!("Type checking should ensure asserts are on bools, not {value:?}")
+            }
+        }
+    }
+
+    fn evaluate_for(&mut self, for_loop: HirForStatement) -> IResult<Value> {
+        let start = self.evaluate(for_loop.start_range)?;
+        let end = self.evaluate(for_loop.end_range)?;
+
+        let (start_index, end_index) = match (start.to_u128(), end.to_u128()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => unreachable!("Type checking should ensure loop bounds are integers"),
+        };
+
+        for index in start_index..end_index {
+            let index = match start {
+                Value::Unsigned(_, bit_size) => Value::Unsigned(index, bit_size),
+                _ => Value::Field(index.into()),
+            };
+
+            self.scopes.push(HashMap::new());
+            self.define(for_loop.identifier.id, index);
+            let result = self.evaluate(for_loop.block);
+            self.scopes.pop();
+
+            match result {
+                Ok(_) | Err(InterpreterError::Continue) => (),
+                Err(InterpreterError::Break) => break,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(Value::Unit)
+    }
+
+    fn evaluate_while(&mut self, while_loop: HirWhileStatement) -> IResult<Value> {
+        loop {
+            match self.evaluate(while_loop.condition)? {
+                Value::Bool(true) => (),
+                Value::Bool(false) => break,
+                value => {
+                    unreachable!(
+                        "Type checking should ensure loop conditions are bools, not {value:?}"
+                    )
+                }
+            }
+
+            match self.evaluate(while_loop.block) {
+                Ok(_) | Err(InterpreterError::Continue) => (),
+                Err(InterpreterError::Break) => break,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(Value::Unit)
+    }
+
+    fn store_lvalue(&mut self, lvalue: HirLValue, value: Value) -> IResult<()> {
+        match lvalue {
+            HirLValue::Ident(ident, _) => {
+                *self.lookup(&ident)?.borrow_mut() = value;
+                Ok(())
+            }
+            HirLValue::MemberAccess { object, field_index, .. } => {
+                let index = field_index.expect("Field index should be set during type checking");
+                let object_value = match self.evaluate_lvalue(&object)? {
+                    Value::Tuple(mut fields) => {
+                        fields[index] = value;
+                        Value::Tuple(fields)
+                    }
+                    Value::Struct(mut fields) => {
+                        fields[index] = value;
+                        Value::Struct(fields)
+                    }
+                    value => unreachable!("Cannot assign to a field of {value:?}"),
+                };
+                self.store_lvalue(*object, object_value)
+            }
+            HirLValue::Index { array, index, .. } => {
+                let location = self.interner.expr_location(&index);
+                let index = self.evaluate(index)?;
+                let array_value = match self.evaluate_lvalue(&array)? {
+                    Value::Array(mut elements) => {
+                        let index = check_index(&index, elements.len(), location)?;
+                        elements[index] = value;
+                        Value::Array(elements)
+                    }
+                    value => unreachable!("Cannot assign to an element of {value:?}"),
+                };
+                self.store_lvalue(*array, array_value)
+            }
+            HirLValue::Dereference { lvalue, .. } => match self.evaluate_lvalue(&lvalue)? {
+                Value::Pointer(element) => {
+                    *element.borrow_mut() = value;
+                    Ok(())
+                }
+                value => unreachable!("Cannot dereference {value:?}"),
+            },
+        }
+    }
+
+    fn evaluate_lvalue(&mut self, lvalue: &HirLValue) -> IResult<Value> {
+        match lvalue {
+            HirLValue::Ident(ident, _) => Ok(self.lookup(ident)?.borrow().clone()),
+            HirLValue::MemberAccess { object, field_index, .. } => {
+                let index = field_index.expect("Field index should be set during type checking");
+                match self.evaluate_lvalue(object)? {
+                    Value::Tuple(mut fields) | Value::Struct(mut fields) => {
+                        Ok(fields.swap_remove(index))
+                    }
+                    value => unreachable!("Cannot access a field of {value:?}"),
+                }
+            }
+            HirLValue::Index { array, index, .. } => {
+                let location = self.interner.expr_location(index);
+                let index = self.evaluate(*index)?;
+                match self.evaluate_lvalue(array)? {
+                    Value::Array(mut elements) => {
+                        let index = check_index(&index, elements.len(), location)?;
+                        Ok(elements.swap_remove(index))
+                    }
+                    value => unreachable!("Cannot index {value:?}"),
+                }
+            }
+            HirLValue::Dereference { lvalue, .. } => match self.evaluate_lvalue(lvalue)? {
+                Value::Pointer(element) => Ok(element.borrow().clone()),
+                value => unreachable!("Cannot dereference {value:?}"),
+            },
+        }
+    }
+
+    fn lookup(&self, ident: &HirIdent) -> IResult<Shared<Value>> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(value) = scope.get(&ident.id) {
+                return Ok(value.clone());
+            }
+        }
+
+        let name = self.interner.definition_name(ident.id).to_owned();
+        Err(InterpreterError::NonComptimeVariable { name, location: ident.location })
+    }
+
+    fn define(&mut self, id: DefinitionId, value: Value) {
+        let scope = self.scopes.last_mut().expect("There should always be at least one scope");
+        scope.insert(id, Shared::new(value));
+    }
+
+    /// Binds each variable in the pattern to its part of the given value.
+    /// The pattern is expected to match the value.
+    fn define_pattern(&mut self, pattern: &HirPattern, value: Value) {
+        match (pattern, value) {
+            (HirPattern::Identifier(ident), value) => self.define(ident.id, value),
+            (HirPattern::Mutable(pattern, _), value) => self.define_pattern(pattern, value),
+            (HirPattern::Tuple(patterns, _), Value::Tuple(fields))
+            | (HirPattern::EnumVariant(_, _, patterns, _), Value::Enum(_, fields)) => {
+                for (pattern, field) in patterns.iter().zip(fields) {
+                    self.define_pattern(pattern, field);
+                }
+            }
+            (HirPattern::Struct(typ, patterns, _), Value::Struct(mut fields)) => {
+                for (index, pattern) in struct_pattern_indices(typ, patterns) {
+                    let field = std::mem::replace(&mut fields[index], Value::Unit);
+                    self.define_pattern(pattern, field);
+                }
+            }
+            (HirPattern::Array(_, patterns, rest, _), Value::Array(mut elements)) => {
+                let length = elements.len() as u64;
+                let indices = array_pattern_indices(length, patterns.len(), *rest);
+                for (pattern, index) in patterns.iter().zip(indices) {
+                    let element = std::mem::replace(&mut elements[index as usize], Value::Unit);
+                    self.define_pattern(pattern, element);
+                }
+            }
+            (HirPattern::Wildcard(_) | HirPattern::Literal(..), _) => (),
+            (pattern, value) => unreachable!("Pattern {pattern:?} does not match {value:?}"),
+        }
+    }
+
+    fn pattern_matches(&mut self, pattern: &HirPattern, value: &Value) -> IResult<bool> {
+        match (pattern, value) {
+            (HirPattern::Identifier(_) | HirPattern::Wildcard(_), _) => Ok(true),
+            (HirPattern::Mutable(pattern, _), value) => self.pattern_matches(pattern, value),
+            (HirPattern::Literal(literal, _), value) => {
+                let literal = self.evaluate(*literal)?;
+                Ok(literal.equals(value).unwrap_or(false))
+            }
+            (HirPattern::Tuple(patterns, _), Value::Tuple(fields)) => {
+                self.all_patterns_match(patterns.iter().zip(fields))
+            }
+            (HirPattern::Struct(typ, patterns, _), Value::Struct(fields)) => {
+                let indices = struct_pattern_indices(typ, patterns);
+                self.all_patterns_match(
+                    indices.into_iter().map(|(i, pattern)| (pattern, &fields[i])),
+                )
+            }
+            (HirPattern::EnumVariant(_, variant, patterns, _), Value::Enum(index, arguments)) => {
+                if variant != index {
+                    return Ok(false);
+                }
+                self.all_patterns_match(patterns.iter().zip(arguments))
+            }
+            (HirPattern::Array(_, patterns, rest, _), Value::Array(elements)) => {
+                let indices = array_pattern_indices(elements.len() as u64, patterns.len(), *rest);
+                let elements = indices.into_iter().map(|index| &elements[index as usize]);
+                self.all_patterns_match(patterns.iter().zip(elements))
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn all_patterns_match<'a>(
+        &mut self,
+        patterns: impl IntoIterator<Item = (&'a HirPattern, &'a Value)>,
+    ) -> IResult<bool> {
+        for (pattern, value) in patterns {
+            if !self.pattern_matches(pattern, value)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Pairs each field pattern of a struct pattern with the index of the field it refers to.
+fn struct_pattern_indices<'p>(
+    typ: &Type,
+    patterns: &'p [(crate::Ident, HirPattern)],
+) -> Vec<(usize, &'p HirPattern)> {
+    match typ.follow_bindings() {
+        Type::Struct(struct_type, generics) => {
+            let struct_type = struct_type.borrow();
+            vecmap(patterns, |(name, pattern)| {
+                let (_, index) = struct_type
+                    .get_field(&name.0.contents, &generics)
+                    .expect("Type checking should ensure each field exists");
+                (index, pattern)
+            })
+        }
+        other => unreachable!("Expected a struct type for a struct pattern, found {other}"),
+    }
+}
+
+fn dereference(value: Value) -> Value {
+    match value {
+        Value::Pointer(element) => dereference(element.borrow().clone()),
+        value => value,
+    }
+}
+
+fn check_index(index: &Value, length: usize, location: Location) -> IResult<usize> {
+    let index = index.to_u128().expect("Type checking should ensure indices are integers");
+    if index < length as u128 {
+        Ok(index as usize)
+    } else {
+        Err(InterpreterError::IndexOutOfBounds { index, length, location })
+    }
+}
+
+/// Follow any type variable links within the given bindings so that they are unaffected
+/// by later changes to the bindings of the function they were taken from.
+fn follow_bindings(bindings: &TypeBindings) -> TypeBindings {
+    bindings
+        .iter()
+        .map(|(id, (var, binding))| (*id, (var.clone(), binding.follow_bindings())))
+        .collect()
+}
+
+fn evaluate_binary(
+    operator: BinaryOpKind,
+    lhs: Value,
+    rhs: Value,
+    location: Location,
+) -> IResult<Value> {
+    use BinaryOpKind::*;
+    let invalid = || InterpreterError::InvalidBinaryOperands { operator, location };
+    let overflow = || InterpreterError::IntegerOverflow { operator, location };
+
+    match operator {
+        Equal | NotEqual => {
+            let equal = lhs.equals(&rhs).ok_or_else(invalid)?;
+            return Ok(Value::Bool(equal == (operator == Equal)));
+        }
+        Less | LessEqual | Greater | GreaterEqual => {
+            let ordering = match (&lhs, &rhs) {
+                (Value::Field(lhs), Value::Field(rhs)) => lhs.cmp(rhs),
+                (Value::Unsigned(lhs, _), Value::Unsigned(rhs, _)) => lhs.cmp(rhs),
+                (Value::Signed(lhs, _), Value::Signed(rhs, _)) => lhs.cmp(rhs),
+                (Value::Bool(lhs), Value::Bool(rhs)) => lhs.cmp(rhs),
+                _ => return Err(invalid()),
+            };
+            return Ok(Value::Bool(match operator {
+                Less => ordering.is_lt(),
+                LessEqual => ordering.is_le(),
+                Greater => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            }));
+        }
+        // Bits shifted past the end of an integer are discarded rather than overflowing
+        ShiftLeft | ShiftRight => {
+            let shift = rhs.to_u128().ok_or_else(invalid)?;
+            return match lhs {
+                Value::Unsigned(lhs, bit_size) => {
+                    let result = match (operator, shift < bit_size as u128) {
+                        (ShiftLeft, true) => truncate(lhs << shift, bit_size),
+                        (_, true) => lhs >> shift,
+                        (_, false) => 0,
+                    };
+                    Ok(Value::Unsigned(result, bit_size))
+                }
+                Value::Signed(lhs, bit_size) => {
+                    let result = match (operator, shift < bit_size as u128) {
+                        (ShiftLeft, true) => sign_extend((lhs << shift) as u128, bit_size),
+                        (_, true) => lhs >> shift,
+                        (ShiftLeft, false) => 0,
+                        (_, false) => lhs >> 127,
+                    };
+                    Ok(Value::Signed(result, bit_size))
+                }
+                _ => Err(invalid()),
+            };
+        }
+        _ => (),
+    }
+
+    match (lhs, rhs) {
+        (Value::Field(lhs), Value::Field(rhs)) => match operator {
+            Add => Ok(Value::Field(lhs + rhs)),
+            Subtract => Ok(Value::Field(lhs - rhs)),
+            Multiply => Ok(Value::Field(lhs * rhs)),
+            Divide if rhs.is_zero() => Err(InterpreterError::DivisionByZero { location }),
+            Divide => Ok(Value::Field(lhs / rhs)),
+            _ => Err(invalid()),
+        },
+        (Value::Bool(lhs), Value::Bool(rhs)) => match operator {
+            And => Ok(Value::Bool(lhs & rhs)),
+            Or => Ok(Value::Bool(lhs | rhs)),
+            Xor => Ok(Value::Bool(lhs ^ rhs)),
+            _ => Err(invalid()),
+        },
+        (Value::Unsigned(lhs, bit_size), Value::Unsigned(rhs, _)) => {
+            let result = match operator {
+                Add => lhs.checked_add(rhs),
+                Subtract => lhs.checked_sub(rhs),
+                Multiply => lhs.checked_mul(rhs),
+                Divide | Modulo if rhs == 0 => {
+                    return Err(InterpreterError::DivisionByZero { location })
+                }
+                Divide => Some(lhs / rhs),
+                Modulo => Some(lhs % rhs),
+                And => Some(lhs & rhs),
+                Or => Some(lhs | rhs),
+                Xor => Some(lhs ^ rhs),
+                _ => return Err(invalid()),
+            };
+            match result {
+                Some(result) if truncate(result, bit_size) == result => {
+                    Ok(Value::Unsigned(result, bit_size))
+                }
+                _ => Err(overflow()),
+            }
+        }
+        (Value::Signed(lhs, bit_size), Value::Signed(rhs, _)) => {
+            let result = match operator {
+                Add => lhs.checked_add(rhs),
+                Subtract => lhs.checked_sub(rhs),
+                Multiply => lhs.checked_mul(rhs),
+                Divide | Modulo if rhs == 0 => {
+                    return Err(InterpreterError::DivisionByZero { location })
+                }
+                Divide => lhs.checked_div(rhs),
+                Modulo => lhs.checked_rem(rhs),
+                And => Some(lhs & rhs),
+                Or => Some(lhs | rhs),
+                Xor => Some(lhs ^ rhs),
+                _ => return Err(invalid()),
+            };
+            match result {
+                Some(result) if sign_extend(result as u128, bit_size) == result => {
+                    Ok(Value::Signed(result, bit_size))
+                }
+                _ => Err(overflow()),
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,20 @@
+//! Compile-time evaluation of `comptime` blocks and calls to `comptime` functions.
+//!
+//! Once a crate has been type checked, each `comptime { ... }` block and each call
+//! to a `comptime fn` is evaluated by an [Interpreter] over the type checked HIR.
+//! The resulting [Value] is then converted back into a HIR literal which replaces
+//! the original expression, so later passes never see comptime code.
+//!
+//! Array lengths given by comptime code are the exception: they are needed to type check
+//! the crate, so they are evaluated beforehand, once only the comptime functions have been
+//! type checked (see [evaluate_array_length]).
+mod builtins;
+mod errors;
+mod interpreter;
+mod scan;
+mod value;
+
+pub use errors::InterpreterError;
+pub use interpreter::Interpreter;
+pub use scan::{evaluate_array_length, scan_function, scan_global};
+pub use value::Value;
@@ -0,0 +1,188 @@
+use crate::graph::CrateId;
+use crate::hir_def::expr::{HirArrayLiteral, HirExpression, HirLiteral};
+use crate::hir_def::stmt::{HirLValue, HirStatement};
+use crate::node_interner::{DefinitionKind, ExprId, FuncId, NodeInterner, StmtId};
+
+use super::errors::InterpreterError;
+use super::interpreter::Interpreter;
+
+/// Evaluates each `comptime` block and each call to a `comptime fn` within the given
+/// function, replacing them with the values they evaluate to.
+pub fn scan_function(interner: &mut NodeInterner, function: FuncId) -> Vec<InterpreterError> {
+    // The body of a comptime function is only ever evaluated by the interpreter,
+    // which evaluates any comptime code nested within it along the way.
+    if interner.function_modifiers(&function).is_comptime {
+        return Vec::new();
+    }
+
+    let body = *interner.function(&function).as_expr();
+    let mut scanner = Scanner { interner, errors: Vec::new() };
+    scanner.scan_expression(body);
+    scanner.errors
+}
+
+/// Evaluates any comptime code within the initializer of the given global.
+pub fn scan_global(interner: &mut NodeInterner, global: StmtId) -> Vec<InterpreterError> {
+    let expression = interner.let_statement(&global).expression;
+    let mut scanner = Scanner { interner, errors: Vec::new() };
+    scanner.scan_expression(expression);
+    scanner.errors
+}
+
+/// Evaluates comptime code giving the length of an array, which must be a non-negative
+/// integer. Unlike other comptime code, this is evaluated before the rest of the crate
+/// `crate_id` is type checked, so it may only call the comptime functions of that crate.
+pub fn evaluate_array_length(
+    interner: &NodeInterner,
+    crate_id: CrateId,
+    expression: ExprId,
+) -> Result<u64, InterpreterError> {
+    let value = Interpreter::before_type_checking(interner, crate_id).evaluate(expression)?;
+    let length = value.to_u128().and_then(|length| u64::try_from(length).ok());
+    length.ok_or_else(|| InterpreterError::InvalidArrayLength {
+        typ: interner.id_type(expression),
+        location: interner.expr_location(&expression),
+    })
+}
+
+struct Scanner<'interner> {
+    interner: &'interner mut NodeInterner,
+    errors: Vec<InterpreterError>,
+}
+
+impl<'interner> Scanner<'interner> {
+    fn scan_expression(&mut self, id: ExprId) {
+        match self.interner.expression(&id) {
+            HirExpression::Comptime(_) => self.evaluate_and_splice(id),
+            HirExpression::Call(call) if self.is_comptime_function(call.func) => {
+                self.evaluate_and_splice(id);
+            }
+            HirExpression::Ident(_)
+            | HirExpression::TraitMethodReference(_)
+            | HirExpression::Error => (),
+            HirExpression::Literal(literal) => match literal {
+                HirLiteral::Array(HirArrayLiteral::Standard(elements)) => {
+                    self.scan_expressions(&elements);
+                }
+                HirLiteral::Array(HirArrayLiteral::Repeated { repeated_element, .. }) => {
+                    self.scan_expression(repeated_element);
+                }
+                HirLiteral::FmtStr(_, captures) => self.scan_expressions(&captures),
+                HirLiteral::Bool(_) | HirLiteral::Integer(..) | HirLiteral::Str(_) => (),
+                HirLiteral::Unit => (),
+            },
+            HirExpression::Block(block) => {
+                for statement in block.statements() {
+                    self.scan_statement(*statement);
+                }
+            }
+            HirExpression::Prefix(prefix) => self.scan_expression(prefix.rhs),
+            HirExpression::Infix(infix) => {
+                self.scan_expression(infix.lhs);
+                self.scan_expression(infix.rhs);
+            }
+            HirExpression::Index(index) => {
+                self.scan_expression(index.collection);
+                self.scan_expression(index.index);
+            }
+            HirExpression::Constructor(constructor) => {
+                for (_, field) in constructor.fields {
+                    self.scan_expression(field);
+                }
+            }
+            HirExpression::EnumConstructor(constructor) => {
+                self.scan_expressions(&constructor.arguments);
+            }
+            HirExpression::MemberAccess(access) => self.scan_expression(access.lhs),
+            HirExpression::Call(call) => {
+                self.scan_expression(call.func);
+                self.scan_expressions(&call.arguments);
+            }
+            HirExpression::MethodCall(call) => {
+                self.scan_expression(call.object);
+                self.scan_expressions(&call.arguments);
+            }
+            HirExpression::Cast(cast) => self.scan_expression(cast.lhs),
+            HirExpression::If(if_expr) => {
+                self.scan_expression(if_expr.condition);
+                self.scan_expression(if_expr.consequence);
+                if let Some(alternative) = if_expr.alternative {
+                    self.scan_expression(alternative);
+                }
+            }
+            HirExpression::Match(match_expr) => {
+                self.scan_expression(match_expr.expression);
+                for (_, branch) in match_expr.rules {
+                    self.scan_expression(branch);
+                }
+            }
+            HirExpression::Tuple(fields) => self.scan_expressions(&fields),
+            HirExpression::Lambda(lambda) => self.scan_expression(lambda.body),
+        }
+    }
+
+    fn scan_expressions(&mut self, expressions: &[ExprId]) {
+        for expression in expressions {
+            self.scan_expression(*expression);
+        }
+    }
+
+    fn scan_statement(&mut self, id: StmtId) {
+        match self.interner.statement(&id) {
+            HirStatement::Let(let_statement) => self.scan_expression(let_statement.expression),
+            HirStatement::Constrain(constrain) => self.scan_expression(constrain.0),
+            HirStatement::Assign(assign) => {
+                self.scan_lvalue(&assign.lvalue);
+                self.scan_expression(assign.expression);
+            }
+            HirStatement::For(for_loop) => {
+                self.scan_expression(for_loop.start_range);
+                self.scan_expression(for_loop.end_range);
+                self.scan_expression(for_loop.block);
+            }
+            HirStatement::While(while_loop) => {
+                self.scan_expression(while_loop.condition);
+                self.scan_expression(while_loop.block);
+            }
+            HirStatement::Expression(expression) | HirStatement::Semi(expression) => {
+                self.scan_expression(expression);
+            }
+            HirStatement::Break | HirStatement::Continue | HirStatement::Error => (),
+        }
+    }
+
+    fn scan_lvalue(&mut self, lvalue: &HirLValue) {
+        match lvalue {
+            HirLValue::Ident(..) => (),
+            HirLValue::MemberAccess { object, .. } => self.scan_lvalue(object),
+            HirLValue::Index { array, index, .. } => {
+                self.scan_lvalue(array);
+                self.scan_expression(*index);
+            }
+            HirLValue::Dereference { lvalue, .. } => self.scan_lvalue(lvalue),
+        }
+    }
+
+    fn is_comptime_function(&self, function: ExprId) -> bool {
+        match self.interner.expression(&function) {
+            HirExpression::Ident(ident) => match self.interner.definition(ident.id).kind {
+                DefinitionKind::Function(function) => {
+                    self.interner.function_modifiers(&function).is_comptime
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn evaluate_and_splice(&mut self, id: ExprId) {
+        let result = Interpreter::new(self.interner).evaluate(id);
+        let typ = self.interner.id_type(id);
+        let location = self.interner.expr_location(&id);
+
+        match result.and_then(|value| value.into_hir_expression(self.interner, &typ, location)) {
+            Ok(expression) => self.interner.replace_expr(&id, expression),
+            Err(error) => self.errors.push(error),
+        }
+    }
+}
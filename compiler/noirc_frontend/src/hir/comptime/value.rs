@@ -0,0 +1,297 @@
+use std::rc::Rc;
+
+use acvm::FieldElement;
+use iter_extended::{try_vecmap, vecmap};
+use noirc_errors::Location;
+
+use crate::hir_def::expr::{
+    HirArrayLiteral, HirConstructorExpression, HirEnumConstructorExpression, HirExpression,
+    HirLambda, HirLiteral,
+};
+use crate::node_interner::{DefinitionId, ExprId, FuncId, NodeInterner};
+use crate::{Ident, Shared, Signedness, Type, TypeBinding, TypeBindings, TypeVariable};
+
+use super::errors::InterpreterError;
+
+/// A value produced while evaluating comptime code.
+///
+/// Integers keep track of their bit size so that overflow can be detected the same way
+/// it would be at runtime. Structs are stored as a list of their fields in declaration
+/// order, in the same way member accesses refer to fields by index after type checking.
+#[derive(Debug, Clone)]
+pub enum Value {
+    Unit,
+    Bool(bool),
+    Field(FieldElement),
+    Unsigned(u128, u32),
+    Signed(i128, u32),
+    String(Rc<String>),
+    /// Both arrays and slices
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+    Struct(Vec<Value>),
+    /// The index of the variant along with its arguments
+    Enum(usize, Vec<Value>),
+    Function(FuncId, TypeBindings),
+    Closure(HirLambda, Vec<(DefinitionId, Value)>),
+    Pointer(Shared<Value>),
+}
+
+impl Value {
+    /// Creates an integer value of the given type, or None if the type is not numeric.
+    /// Values which do not fit in the given integer type are truncated.
+    pub(super) fn integer(value: FieldElement, is_negative: bool, typ: &Type) -> Option<Value> {
+        match typ.follow_bindings() {
+            Type::Integer(Signedness::Unsigned, bit_size) => {
+                let value = truncate(value.to_u128(), bit_size);
+                let value =
+                    if is_negative { truncate(value.wrapping_neg(), bit_size) } else { value };
+                Some(Value::Unsigned(value, bit_size))
+            }
+            Type::Integer(Signedness::Signed, bit_size) => {
+                let value = value.to_u128() as i128;
+                let value = if is_negative { value.wrapping_neg() } else { value };
+                Some(Value::Signed(sign_extend(value as u128, bit_size), bit_size))
+            }
+            // Integer literals whose type was never constrained default to a Field
+            Type::FieldElement | Type::TypeVariable(..) => {
+                Some(Value::Field(if is_negative { -value } else { value }))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns this value as an unsigned integer, used for indices and loop bounds.
+    pub(super) fn to_u128(&self) -> Option<u128> {
+        match self {
+            Value::Field(value) => value.try_into_u128(),
+            Value::Unsigned(value, _) => Some(*value),
+            Value::Signed(value, _) => u128::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    pub(super) fn to_field(&self) -> Option<FieldElement> {
+        match self {
+            Value::Field(value) => Some(*value),
+            Value::Unsigned(value, _) => Some((*value).into()),
+            Value::Signed(value, _) => Some((*value).into()),
+            Value::Bool(value) => Some((*value).into()),
+            _ => None,
+        }
+    }
+
+    /// Casts a numeric value to the given type. Casting to a smaller integer type truncates
+    /// the value, while casting a signed integer to a Field uses its two's complement form.
+    pub(super) fn cast(self, typ: &Type) -> Option<Value> {
+        let typ = typ.follow_bindings();
+        let (value, is_negative) = match self {
+            Value::Field(value) => (value, false),
+            Value::Unsigned(value, _) => (value.into(), false),
+            Value::Signed(value, bit_size) if typ == Type::FieldElement => {
+                (truncate(value as u128, bit_size).into(), false)
+            }
+            Value::Signed(value, _) => (value.unsigned_abs().into(), value < 0),
+            Value::Bool(value) => (value.into(), false),
+            _ => return None,
+        };
+
+        match typ {
+            Type::Bool => Some(Value::Bool(!value.is_zero())),
+            typ => Value::integer(value, is_negative, &typ),
+        }
+    }
+
+    /// Structural equality, following the semantics of `==` on primitive types.
+    /// Returns None for values which cannot be compared.
+    pub(super) fn equals(&self, other: &Value) -> Option<bool> {
+        let all_equal = |lhs: &[Value], rhs: &[Value]| {
+            if lhs.len() != rhs.len() {
+                return Some(false);
+            }
+            for (lhs, rhs) in lhs.iter().zip(rhs) {
+                if !lhs.equals(rhs)? {
+                    return Some(false);
+                }
+            }
+            Some(true)
+        };
+
+        match (self, other) {
+            (Value::Unit, Value::Unit) => Some(true),
+            (Value::Bool(lhs), Value::Bool(rhs)) => Some(lhs == rhs),
+            (Value::Field(lhs), Value::Field(rhs)) => Some(lhs == rhs),
+            (Value::Unsigned(lhs, _), Value::Unsigned(rhs, _)) => Some(lhs == rhs),
+            (Value::Signed(lhs, _), Value::Signed(rhs, _)) => Some(lhs == rhs),
+            (Value::String(lhs), Value::String(rhs)) => Some(lhs == rhs),
+            (Value::Array(lhs), Value::Array(rhs))
+            | (Value::Tuple(lhs), Value::Tuple(rhs))
+            | (Value::Struct(lhs), Value::Struct(rhs)) => all_equal(lhs, rhs),
+            (Value::Enum(lhs_index, lhs), Value::Enum(rhs_index, rhs)) => {
+                if lhs_index == rhs_index {
+                    all_equal(lhs, rhs)
+                } else {
+                    Some(false)
+                }
+            }
+            (Value::Pointer(lhs), Value::Pointer(rhs)) => lhs.borrow().equals(&rhs.borrow()),
+            _ => None,
+        }
+    }
+
+    /// Converts this value back into a HIR expression of the given type so that it can
+    /// replace the comptime code which produced it. The expression is not interned, so
+    /// the caller may choose to either push it or replace an existing expression with it.
+    pub(crate) fn into_hir_expression(
+        self,
+        interner: &mut NodeInterner,
+        typ: &Type,
+        location: Location,
+    ) -> Result<HirExpression, InterpreterError> {
+        let typ = typ.follow_bindings();
+        let cannot_splice = || InterpreterError::CannotSplice { typ: typ.clone(), location };
+
+        let expression = match self {
+            Value::Unit => HirExpression::Literal(HirLiteral::Unit),
+            Value::Bool(value) => HirExpression::Literal(HirLiteral::Bool(value)),
+            Value::Field(value) => HirExpression::Literal(HirLiteral::Integer(value, false)),
+            Value::Unsigned(value, _) => {
+                HirExpression::Literal(HirLiteral::Integer(value.into(), false))
+            }
+            Value::Signed(value, _) => {
+                let magnitude = FieldElement::from(value.unsigned_abs());
+                HirExpression::Literal(HirLiteral::Integer(magnitude, value < 0))
+            }
+            Value::String(value) => HirExpression::Literal(HirLiteral::Str(value.to_string())),
+            Value::Array(elements) => {
+                let element_type = match &typ {
+                    Type::Array(_, element_type) => element_type.as_ref().clone(),
+                    _ => return Err(cannot_splice()),
+                };
+                let elements = try_vecmap(elements, |element| {
+                    element.into_expression_id(interner, &element_type, location)
+                })?;
+                HirExpression::Literal(HirLiteral::Array(HirArrayLiteral::Standard(elements)))
+            }
+            Value::Tuple(fields) => {
+                let field_types = match &typ {
+                    Type::Tuple(field_types) => field_types.clone(),
+                    _ => return Err(cannot_splice()),
+                };
+                let fields = try_vecmap(fields.into_iter().zip(field_types), |(field, typ)| {
+                    field.into_expression_id(interner, &typ, location)
+                })?;
+                HirExpression::Tuple(fields)
+            }
+            Value::Struct(fields) => {
+                let (definition, generics) = match &typ {
+                    Type::Struct(definition, generics) => (definition.clone(), generics.clone()),
+                    _ => return Err(cannot_splice()),
+                };
+                let field_types = definition.borrow().get_fields(&generics);
+                let fields =
+                    try_vecmap(fields.into_iter().zip(field_types), |(field, (name, typ))| {
+                        let field = field.into_expression_id(interner, &typ, location);
+                        field.map(|field| (Ident::new(name, location.span), field))
+                    })?;
+                HirExpression::Constructor(HirConstructorExpression {
+                    r#type: definition,
+                    struct_generics: generics,
+                    fields,
+                })
+            }
+            Value::Enum(variant_index, arguments) => {
+                let (definition, generics) = match &typ {
+                    Type::Struct(definition, generics) => (definition.clone(), generics.clone()),
+                    _ => return Err(cannot_splice()),
+                };
+                let (_, parameter_types) =
+                    definition.borrow().get_variant(variant_index, &generics);
+                let arguments =
+                    try_vecmap(arguments.into_iter().zip(parameter_types), |(argument, typ)| {
+                        argument.into_expression_id(interner, &typ, location)
+                    })?;
+                HirExpression::EnumConstructor(HirEnumConstructorExpression {
+                    r#type: definition,
+                    struct_generics: generics,
+                    variant_index,
+                    arguments,
+                })
+            }
+            Value::Function(..) | Value::Closure(..) | Value::Pointer(_) => {
+                return Err(cannot_splice())
+            }
+        };
+        Ok(expression)
+    }
+
+    fn into_expression_id(
+        self,
+        interner: &mut NodeInterner,
+        typ: &Type,
+        location: Location,
+    ) -> Result<ExprId, InterpreterError> {
+        let expression = self.into_hir_expression(interner, typ, location)?;
+        let id = interner.push_expr(expression);
+        interner.push_expr_location(id, location.span, location.file);
+        interner.push_expr_type(&id, typ.clone());
+        Ok(id)
+    }
+}
+
+/// Truncates the given value to its lowest `bit_size` bits.
+pub(super) fn truncate(value: u128, bit_size: u32) -> u128 {
+    if bit_size >= 128 {
+        value
+    } else {
+        value & ((1 << bit_size) - 1)
+    }
+}
+
+/// Interprets the lowest `bit_size` bits of the given value as a two's complement integer.
+pub(super) fn sign_extend(value: u128, bit_size: u32) -> i128 {
+    if bit_size >= 128 {
+        value as i128
+    } else {
+        let shift = 128 - bit_size;
+        ((value << shift) as i128) >> shift
+    }
+}
+
+/// Produces the elements of a field's decomposition into `limbs` digits of the given radix,
+/// least significant digit first. Returns None if the field does not fit in that many digits.
+pub(super) fn to_le_radix(value: FieldElement, radix: u32, limbs: u32) -> Option<Vec<u8>> {
+    let mut bytes = value.to_be_bytes();
+    let digits = vecmap(0..limbs, |_| {
+        // Long division of the big-endian byte string by the radix
+        let mut remainder = 0u32;
+        for byte in bytes.iter_mut() {
+            let current = (remainder << 8) | *byte as u32;
+            *byte = (current / radix) as u8;
+            remainder = current % radix;
+        }
+        remainder as u8
+    });
+    bytes.iter().all(|byte| *byte == 0).then_some(digits)
+}
+
+/// Binds each type variable to its type for the duration of a call, returning
+/// the previous binding of each so they can be restored afterward. Unlike
+/// monomorphization, interpretation may recursively re-enter the same function
+/// so existing bindings cannot simply be unbound.
+pub(super) fn perform_bindings(bindings: &TypeBindings) -> Vec<(TypeVariable, TypeBinding)> {
+    vecmap(bindings.values(), |(var, binding)| {
+        let previous = var.borrow().clone();
+        var.force_bind(binding.clone());
+        (var.clone(), previous)
+    })
+}
+
+pub(super) fn undo_bindings(previous: Vec<(TypeVariable, TypeBinding)>) {
+    for (var, binding) in previous.into_iter().rev() {
+        match binding {
+            TypeBinding::Bound(typ) => var.force_bind(typ),
+            TypeBinding::Unbound(id) => var.unbind(id),
+        }
+    }
+}
@@ -1,31 +1,33 @@
 use super::dc_mod::collect_defs;
 use super::errors::{DefCollectorErrorKind, DuplicateType};
 use crate::graph::CrateId;
-use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleId};
+use crate::hir::comptime::{self, InterpreterError};
+use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleDefId, ModuleId};
 use crate::hir::resolution::errors::ResolverError;
 
 use crate::hir::resolution::import::{resolve_imports, ImportDirective};
 use crate::hir::resolution::resolver::Resolver;
 use crate::hir::resolution::{
-    collect_impls, collect_trait_impls, path_resolver, resolve_free_functions, resolve_globals,
-    resolve_impls, resolve_structs, resolve_trait_by_path, resolve_trait_impls, resolve_traits,
-    resolve_type_aliases,
+    collect_impls, collect_trait_impls, path_resolver, resolve_enums, resolve_free_functions,
+    resolve_globals, resolve_impls, resolve_structs, resolve_trait_by_path, resolve_trait_impls,
+    resolve_traits, resolve_type_aliases,
 };
 use crate::hir::type_check::{type_check_func, TypeCheckError, TypeChecker};
 use crate::hir::Context;
+use crate::lints::{Lint, LintLevel};
 
 use crate::macros_api::MacroProcessor;
 use crate::node_interner::{FuncId, NodeInterner, StmtId, StructId, TraitId, TypeAliasId};
 
 use crate::parser::{ParserError, SortedModule};
 use crate::{
-    ExpressionKind, Ident, LetStatement, Literal, NoirFunction, NoirStruct, NoirTrait,
-    NoirTypeAlias, Path, PathKind, Type, UnresolvedGenerics, UnresolvedTraitConstraint,
-    UnresolvedType,
+    Expression, ExpressionKind, Ident, ItemVisibility, LetStatement, Literal, NoirEnum,
+    NoirFunction, NoirStruct, NoirTrait, NoirTypeAlias, Path, PathKind, Type, TypeVariableKind,
+    UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType,
 };
 use fm::FileId;
 use iter_extended::vecmap;
-use noirc_errors::{CustomDiagnostic, Span};
+use noirc_errors::{CustomDiagnostic, DiagnosticKind, Location, Span};
 use std::collections::{BTreeMap, HashMap};
 
 use std::vec;
@@ -45,6 +47,7 @@ impl UnresolvedFunctions {
 
     pub fn resolve_trait_bounds_trait_ids(
         &mut self,
+        interner: &mut NodeInterner,
         def_maps: &BTreeMap<CrateId, CrateDefMap>,
         crate_id: CrateId,
     ) -> Vec<DefCollectorErrorKind> {
@@ -54,8 +57,14 @@ impl UnresolvedFunctions {
             let module = ModuleId { krate: crate_id, local_id: *local_id };
 
             for bound in &mut func.def.where_clause {
-                match resolve_trait_by_path(def_maps, module, bound.trait_bound.trait_path.clone())
-                {
+                // Bounds from the where clause of a trait impl were already resolved from the
+                // module of the impl, which may differ from the module of a default method.
+                if bound.trait_bound.trait_id.is_some() {
+                    continue;
+                }
+
+                let trait_path = bound.trait_bound.trait_path.clone();
+                match resolve_trait_by_path(interner, def_maps, module, trait_path) {
                     Ok(trait_id) => {
                         bound.trait_bound.trait_id = Some(trait_id);
                     }
@@ -76,6 +85,14 @@ pub struct UnresolvedStruct {
     pub struct_def: NoirStruct,
 }
 
+pub struct UnresolvedEnum {
+    pub file_id: FileId,
+    pub module_id: LocalModuleId,
+    pub enum_def: NoirEnum,
+    /// The constructor function of each variant, in the order the variants were declared
+    pub variant_constructors: Vec<(FuncId, NoirFunction)>,
+}
+
 #[derive(Clone)]
 pub struct UnresolvedTrait {
     pub file_id: FileId,
@@ -93,6 +110,8 @@ pub struct UnresolvedTraitImpl {
     pub trait_path: Path,
     pub object_type: UnresolvedType,
     pub methods: UnresolvedFunctions,
+    pub associated_types: Vec<(Ident, UnresolvedType)>,
+    pub associated_constants: Vec<(Ident, UnresolvedType, Expression)>,
     pub generics: UnresolvedGenerics,
     pub where_clause: Vec<UnresolvedTraitConstraint>,
 }
@@ -118,6 +137,7 @@ pub struct DefCollector {
     pub(crate) collected_imports: Vec<ImportDirective>,
     pub(crate) collected_functions: Vec<UnresolvedFunctions>,
     pub(crate) collected_types: BTreeMap<StructId, UnresolvedStruct>,
+    pub(crate) collected_enums: BTreeMap<StructId, UnresolvedEnum>,
     pub(crate) collected_type_aliases: BTreeMap<TypeAliasId, UnresolvedTypeAlias>,
     pub(crate) collected_traits: BTreeMap<TraitId, UnresolvedTrait>,
     pub(crate) collected_globals: Vec<UnresolvedGlobal>,
@@ -141,6 +161,24 @@ pub enum CompilationError {
     DefinitionError(DefCollectorErrorKind),
     ResolverError(ResolverError),
     TypeError(TypeCheckError),
+    InterpreterError(InterpreterError),
+    /// A warning which is reported as an error since its lint is denied where it occurs.
+    DeniedLint(Box<CompilationError>, Lint),
+}
+
+impl CompilationError {
+    /// The lint which controls this warning, along with the span it is reported at,
+    /// or `None` if this is an error or a warning which can't be allowed.
+    pub fn lint(&self) -> Option<(Lint, Span)> {
+        match self {
+            CompilationError::ResolverError(error) => error.lint(),
+            CompilationError::TypeError(error) => error.lint(),
+            CompilationError::ParseError(_)
+            | CompilationError::DefinitionError(_)
+            | CompilationError::InterpreterError(_)
+            | CompilationError::DeniedLint(..) => None,
+        }
+    }
 }
 
 impl From<CompilationError> for CustomDiagnostic {
@@ -150,6 +188,13 @@ impl From<CompilationError> for CustomDiagnostic {
             CompilationError::DefinitionError(error) => error.into(),
             CompilationError::ResolverError(error) => error.into(),
             CompilationError::TypeError(error) => error.into(),
+            CompilationError::InterpreterError(error) => error.into(),
+            CompilationError::DeniedLint(error, lint) => {
+                let mut diagnostic = CustomDiagnostic::from(*error);
+                diagnostic.kind = DiagnosticKind::Error;
+                diagnostic.add_note(format!("`{lint}` is denied by a `#[deny(...)]` attribute"));
+                diagnostic
+            }
         }
     }
 }
@@ -177,6 +222,12 @@ impl From<TypeCheckError> for CompilationError {
     }
 }
 
+impl From<InterpreterError> for CompilationError {
+    fn from(value: InterpreterError) -> Self {
+        CompilationError::InterpreterError(value)
+    }
+}
+
 impl DefCollector {
     fn new(def_map: CrateDefMap) -> DefCollector {
         DefCollector {
@@ -184,6 +235,7 @@ impl DefCollector {
             collected_imports: vec![],
             collected_functions: vec![],
             collected_types: BTreeMap::new(),
+            collected_enums: BTreeMap::new(),
             collected_type_aliases: BTreeMap::new(),
             collected_traits: BTreeMap::new(),
             collected_impls: HashMap::new(),
@@ -274,11 +326,20 @@ impl DefCollector {
 
         // Populate module namespaces according to the imports used
         let current_def_map = context.def_maps.get_mut(&crate_id).unwrap();
-        for resolved_import in resolved {
-            let name = resolved_import.name;
+        for resolved_import in &resolved {
+            let name = &resolved_import.name;
+            // Re-exported imports may be used by other modules or crates
+            let is_reexport = resolved_import.visibility != ItemVisibility::Private;
+            if !resolved_import.is_prelude && !is_reexport {
+                let module_id =
+                    ModuleId { krate: crate_id, local_id: resolved_import.module_scope };
+                context.def_interner.usage_tracker_mut().add_unused_import(module_id, name.clone());
+            }
+
             for ns in resolved_import.resolved_namespace.iter_defs() {
                 let result = current_def_map.modules[resolved_import.module_scope.0].import(
                     name.clone(),
+                    resolved_import.visibility,
                     ns,
                     resolved_import.is_prelude,
                 );
@@ -294,6 +355,18 @@ impl DefCollector {
             }
         }
 
+        // An import may go through imports of other modules, such as `use crate::foo::bar` where
+        // `foo` imports `bar`, so the imports along each path are used once every import is known.
+        for resolved_import in &resolved {
+            let module_id = ModuleId { krate: crate_id, local_id: resolved_import.module_scope };
+            context.def_interner.usage_tracker_mut().mark_import_path_as_used(
+                &context.def_maps,
+                module_id,
+                &resolved_import.path,
+                &resolved_import.name,
+            );
+        }
+
         // We must first resolve and intern the globals before we can resolve any stmts inside each function.
         // Each function uses its own resolver with a newly created ScopeForest, and must be resolved again to be within a function's scope
         //
@@ -313,6 +386,8 @@ impl DefCollector {
         errors.extend(resolve_traits(context, def_collector.collected_traits, crate_id));
         // Must resolve structs before we resolve globals.
         errors.extend(resolve_structs(context, def_collector.collected_types, crate_id));
+        let file_enum_variant_ids =
+            resolve_enums(context, def_collector.collected_enums, crate_id, &mut errors);
 
         // We must wait to resolve non-integer globals until after we resolve structs since structs
         // globals will need to reference the struct type they're initialized to to ensure they are valid.
@@ -361,17 +436,170 @@ impl DefCollector {
 
         errors.extend(resolved_globals.errors);
 
-        for macro_processor in macro_processors {
+        let crate_macro_processors = context.crate_macro_processors(&crate_id);
+        for macro_processor in macro_processors.into_iter().chain(crate_macro_processors) {
             macro_processor.process_typed_ast(&crate_id, context);
         }
+        let comptime_globals = resolved_globals.globals.clone();
         errors.extend(type_check_globals(&mut context.def_interner, resolved_globals.globals));
 
+        let comptime_functions = vecmap(
+            file_func_ids.iter().chain(&file_method_ids).chain(&file_trait_impls_ids),
+            |id| *id,
+        );
+
+        // Comptime functions are type checked first so that the array lengths given by comptime
+        // code, which may call them, are known when the rest of the crate is type checked.
+        let interner = &mut context.def_interner;
+        let (comptime_func_ids, file_func_ids) = split_comptime_functions(interner, file_func_ids);
+        let (comptime_method_ids, file_method_ids) =
+            split_comptime_functions(interner, file_method_ids);
+        let (comptime_trait_impls_ids, file_trait_impls_ids) =
+            split_comptime_functions(interner, file_trait_impls_ids);
+        errors.extend(type_check_functions(interner, comptime_func_ids));
+        errors.extend(type_check_functions(interner, comptime_method_ids));
+        errors.extend(type_check_functions(interner, comptime_trait_impls_ids));
+
+        let has_errors =
+            errors.iter().any(|(error, _)| CustomDiagnostic::from(error.clone()).is_error());
+        errors.extend(evaluate_comptime_array_lengths(interner, crate_id, has_errors));
+
         // Type check all of the functions in the crate
+        errors.extend(type_check_functions(&mut context.def_interner, file_enum_variant_ids));
         errors.extend(type_check_functions(&mut context.def_interner, file_func_ids));
         errors.extend(type_check_functions(&mut context.def_interner, file_method_ids));
         errors.extend(type_check_functions(&mut context.def_interner, file_trait_impls_ids));
-        errors
+
+        // Comptime code is only evaluated once the crate type checks successfully
+        // since the interpreter relies on the types of each expression.
+        let has_errors =
+            errors.iter().any(|(error, _)| CustomDiagnostic::from(error.clone()).is_error());
+        if !has_errors {
+            errors.extend(evaluate_comptime(
+                &mut context.def_interner,
+                comptime_globals,
+                comptime_functions,
+            ));
+
+            // Whether an item is used is only known once every function has been type checked,
+            // and is unreliable if any of them failed to resolve.
+            errors.extend(check_unused_items(context, crate_id));
+        }
+        apply_lint_levels(&context.def_interner, errors)
+    }
+}
+
+/// Removes the warnings which are allowed where they are reported,
+/// and turns those which are denied into errors.
+fn apply_lint_levels(
+    interner: &NodeInterner,
+    errors: Vec<(CompilationError, FileId)>,
+) -> Vec<(CompilationError, FileId)> {
+    let lint_levels = interner.lint_levels();
+    errors
+        .into_iter()
+        .filter_map(|(error, file)| {
+            let (lint, span) = match error.lint() {
+                Some(lint) => lint,
+                None => return Some((error, file)),
+            };
+            match lint_levels.level(lint, Location::new(span, file)) {
+                LintLevel::Allow => None,
+                LintLevel::Warn => Some((error, file)),
+                LintLevel::Deny => {
+                    Some((CompilationError::DeniedLint(Box::new(error), lint), file))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Warns about the imports of a crate which are never referred to, along with its functions,
+/// globals and struct fields which are never used and are not visible outside of the crate.
+fn check_unused_items(context: &Context, crate_id: CrateId) -> Vec<(CompilationError, FileId)> {
+    if crate_id.is_stdlib() {
+        return Vec::new();
+    }
+
+    let mut warnings: Vec<(ResolverError, FileId, Span)> = Vec::new();
+
+    let def_map = &context.def_maps[&crate_id];
+    let interner = &context.def_interner;
+    let usage_tracker = interner.usage_tracker();
+    let main_function = def_map.main_function();
+
+    for (index, module) in def_map.modules().iter() {
+        let module_id = ModuleId { krate: crate_id, local_id: LocalModuleId(index) };
+
+        for ident in usage_tracker.unused_imports(&module_id) {
+            let warning = ResolverError::UnusedImport { ident: ident.clone() };
+            warnings.push((warning, def_map.file_id(module_id.local_id), ident.span()));
+        }
+
+        for (ident, scope) in module.definitions().values() {
+            for (trait_id, (item, visibility, _)) in scope {
+                // Trait methods are used through their trait rather than by name
+                if trait_id.is_some() || *visibility == ItemVisibility::Public {
+                    continue;
+                }
+
+                let (definition_id, item_type) = match item {
+                    ModuleDefId::FunctionId(func_id) => {
+                        let modifiers = interner.function_modifiers(func_id);
+                        let is_entry_point = Some(*func_id) == main_function
+                            || modifiers.attributes.is_test_function()
+                            || (modifiers.contract_function_type.is_some()
+                                && modifiers.attributes.is_contract_entry_point());
+                        if is_entry_point {
+                            continue;
+                        }
+                        (interner.function_definition_id(*func_id), "function")
+                    }
+                    ModuleDefId::GlobalId(stmt_id) => {
+                        (interner.let_statement(stmt_id).ident().id, "global")
+                    }
+                    _ => continue,
+                };
+
+                if !usage_tracker.is_definition_used(&definition_id) {
+                    let file_id = interner.definition(definition_id).location.file;
+                    let warning = ResolverError::UnusedItem { ident: ident.clone(), item_type };
+                    warnings.push((warning, file_id, ident.span()));
+                }
+            }
+        }
+
+        for (_, scope) in module.definitions().types() {
+            for (item, visibility, _) in scope.values() {
+                let struct_id = match item {
+                    ModuleDefId::TypeId(struct_id) if *visibility != ItemVisibility::Public => {
+                        *struct_id
+                    }
+                    _ => continue,
+                };
+
+                let struct_type = interner.get_struct(struct_id);
+                let struct_type = struct_type.borrow();
+                if struct_type.is_enum() {
+                    continue;
+                }
+
+                for field in struct_type.field_names() {
+                    if !usage_tracker.is_field_read(struct_id, &field.0.contents) {
+                        let span = field.span();
+                        let struct_name = struct_type.name.clone();
+                        let warning = ResolverError::UnreadField { field, struct_name };
+                        warnings.push((warning, struct_type.location.file, span));
+                    }
+                }
+            }
+        }
     }
+
+    // Modules and their items are stored in no particular order, so sort the warnings by where
+    // they occur in the source program.
+    warnings.sort_by_key(|(_, file_id, span)| (*file_id, span.start()));
+    vecmap(warnings, |(warning, file_id, _)| (warning.into(), file_id))
 }
 
 fn inject_prelude(
@@ -407,6 +635,7 @@ fn inject_prelude(
                         module_id: crate_root,
                         path: Path { segments, kind: PathKind::Dep, span: Span::default() },
                         alias: None,
+                        visibility: ItemVisibility::Private,
                         is_prelude: true,
                     },
                 );
@@ -459,12 +688,91 @@ fn type_check_functions(
         .collect()
 }
 
+/// Separates the comptime functions from the others, as they are type checked first.
+fn split_comptime_functions(
+    interner: &NodeInterner,
+    file_func_ids: Vec<(FileId, FuncId)>,
+) -> (Vec<(FileId, FuncId)>, Vec<(FileId, FuncId)>) {
+    file_func_ids.into_iter().partition(|(_, id)| interner.function_modifiers(id).is_comptime)
+}
+
+/// Evaluates each array length given by comptime code and binds it to the resulting value.
+/// Comptime blocks within types are type checked first, and nothing is evaluated if the crate
+/// already has errors since the interpreter relies on the types of the code it evaluates.
+fn evaluate_comptime_array_lengths(
+    interner: &mut NodeInterner,
+    crate_id: CrateId,
+    mut has_errors: bool,
+) -> Vec<(CompilationError, fm::FileId)> {
+    let mut errors: Vec<(CompilationError, FileId)> = Vec::new();
+    let array_lengths = interner.take_comptime_array_lengths();
+
+    for array_length in array_lengths.iter().filter(|array_length| !array_length.is_global) {
+        let file = interner.expr_location(&array_length.expression).file;
+        let type_errors =
+            TypeChecker::check_comptime_array_length(&array_length.expression, interner);
+        errors.extend(type_errors.into_iter().map(|error| (error.into(), file)));
+    }
+    has_errors |= errors.iter().any(|(error, _)| CustomDiagnostic::from(error.clone()).is_error());
+
+    for array_length in array_lengths {
+        let expression = array_length.expression;
+        let length = if has_errors {
+            Type::Error
+        } else {
+            match comptime::evaluate_array_length(interner, crate_id, expression) {
+                Ok(length) => Type::Constant(length),
+                Err(error) => {
+                    let file = error.location().file;
+                    errors.push((error.into(), file));
+                    Type::Error
+                }
+            }
+        };
+
+        // The length may already have been inferred while type checking the comptime functions
+        // and globals using it, in which case it must match the evaluated length.
+        let inferred = Type::TypeVariable(array_length.length, TypeVariableKind::Normal);
+        let mut type_errors = Vec::new();
+        length.unify(&inferred, &mut type_errors, || TypeCheckError::TypeMismatch {
+            expected_typ: inferred.to_string(),
+            expr_typ: length.to_string(),
+            expr_span: interner.expr_span(&expression),
+        });
+        let file = interner.expr_location(&expression).file;
+        errors.extend(type_errors.into_iter().map(|error| (error.into(), file)));
+    }
+    errors
+}
+
+/// Evaluates the comptime code within each global and function, replacing it with its result.
+fn evaluate_comptime(
+    interner: &mut NodeInterner,
+    global_ids: Vec<(FileId, StmtId)>,
+    file_func_ids: Vec<(FileId, FuncId)>,
+) -> Vec<(CompilationError, fm::FileId)> {
+    let mut errors = Vec::new();
+    for (_, global) in global_ids {
+        errors.extend(comptime::scan_global(interner, global));
+    }
+    for (_, function) in file_func_ids {
+        errors.extend(comptime::scan_function(interner, function));
+    }
+
+    // The error may originate from a comptime function defined in a different file
+    vecmap(errors, |error| {
+        let file = error.location().file;
+        (error.into(), file)
+    })
+}
+
 // TODO(vitkov): Move this out of here and into type_check
 pub(crate) fn check_methods_signatures(
     resolver: &mut Resolver,
     impl_methods: &Vec<(FileId, FuncId)>,
     trait_id: TraitId,
     trait_impl_generic_count: usize,
+    associated_types: &[Type],
     errors: &mut Vec<(CompilationError, FileId)>,
 ) {
     let self_type = resolver.get_self_type().expect("trait impl must have a Self type").clone();
@@ -473,6 +781,13 @@ pub(crate) fn check_methods_signatures(
     let the_trait = resolver.interner.get_trait_mut(trait_id);
     the_trait.self_type_typevar.bind(self_type);
 
+    // Likewise bind each associated type and constant to the one given by this impl
+    let associated_type_variables = the_trait.associated_type_variables();
+    let associated_generic_count = associated_type_variables.len();
+    for ((_, _, typevar), typ) in associated_type_variables.iter().zip(associated_types) {
+        typevar.bind(typ.clone());
+    }
+
     // Temporarily take the trait's methods so we can use both them and a mutable reference
     // to the interner within the loop.
     let trait_methods = std::mem::take(&mut the_trait.methods);
@@ -496,7 +811,9 @@ pub(crate) fn check_methods_signatures(
 
             // We subtract 1 here to account for the implicit generic `Self` type that is on all
             // traits (and thus trait methods) but is not required (or allowed) for users to specify.
-            let trait_method_generic_count = trait_method.generics().len() - 1;
+            // The trait's associated types and constants are similarly implicit generics.
+            let trait_method_generic_count =
+                trait_method.generics().len() - 1 - associated_generic_count;
 
             if impl_method_generic_count != trait_method_generic_count {
                 let error = DefCollectorErrorKind::MismatchTraitImplementationNumGenerics {
@@ -561,4 +878,7 @@ pub(crate) fn check_methods_signatures(
     let the_trait = resolver.interner.get_trait_mut(trait_id);
     the_trait.set_methods(trait_methods);
     the_trait.self_type_typevar.unbind(the_trait.self_type_typevar_id);
+    for (_, typevar_id, typevar) in the_trait.associated_type_variables() {
+        typevar.unbind(typevar_id);
+    }
 }
@@ -2,15 +2,18 @@ use std::{collections::HashMap, path::Path, vec};
 
 use acvm::acir::acir_field::FieldOptions;
 use fm::{FileId, FileManager, FILE_EXTENSION};
-use noirc_errors::Location;
+use iter_extended::vecmap;
+use noirc_errors::{Location, Span};
 
 use crate::{
     graph::CrateId,
-    hir::def_collector::dc_crate::{UnresolvedStruct, UnresolvedTrait},
+    hir::def_collector::dc_crate::{UnresolvedEnum, UnresolvedStruct, UnresolvedTrait},
     node_interner::{FunctionModifiers, TraitId, TypeAliasId},
-    parser::{SortedModule, SortedSubModule},
-    FunctionDefinition, Ident, LetStatement, NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl,
-    NoirTypeAlias, TraitImplItem, TraitItem, TypeImpl,
+    parser::{ModuleDeclaration, SortedModule, SortedSubModule},
+    token::SecondaryAttribute,
+    BlockExpression, FunctionDefinition, FunctionReturnType, Ident, ItemVisibility, LetStatement,
+    NoirEnum, NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl, NoirTypeAlias, TraitImplItem,
+    TraitItem, TypeImpl, UnresolvedType, UnresolvedTypeData,
 };
 
 use super::{
@@ -18,9 +21,11 @@ use super::{
         CompilationError, DefCollector, UnresolvedFunctions, UnresolvedGlobal, UnresolvedTraitImpl,
         UnresolvedTypeAlias,
     },
+    derive,
     errors::{DefCollectorErrorKind, DuplicateType},
 };
 use crate::hir::def_map::{parse_file, LocalModuleId, ModuleData, ModuleId};
+use crate::hir::resolution::errors::ResolverError;
 use crate::hir::resolution::import::ImportDirective;
 use crate::hir::Context;
 
@@ -58,6 +63,7 @@ pub fn collect_defs(
             module_id: collector.module_id,
             path: import.path,
             alias: import.alias,
+            visibility: import.visibility,
             is_prelude: false,
         });
     }
@@ -66,13 +72,30 @@ pub fn collect_defs(
 
     errors.extend(collector.collect_traits(context, ast.traits, crate_id));
 
+    let mut trait_impls = ast.trait_impls;
+    for struct_definition in &ast.types {
+        let (derived_impls, derive_errors) =
+            derive::derive_trait_impls(struct_definition, crate_id);
+        trait_impls.extend(derived_impls);
+        errors.extend(derive_errors.into_iter().map(|error| (error.into(), file_id)));
+    }
+    for enum_definition in &ast.enums {
+        if !derive::derived_traits(&enum_definition.attributes).is_empty() {
+            let error =
+                DefCollectorErrorKind::DeriveOnNonStruct { span: enum_definition.name.span() };
+            errors.push((error.into(), file_id));
+        }
+    }
+
     errors.extend(collector.collect_structs(context, ast.types, crate_id));
 
+    errors.extend(collector.collect_enums(context, ast.enums, crate_id));
+
     errors.extend(collector.collect_type_aliases(context, ast.type_aliases));
 
     errors.extend(collector.collect_functions(context, ast.functions, crate_id));
 
-    errors.extend(collector.collect_trait_impls(context, ast.trait_impls, crate_id));
+    errors.extend(collector.collect_trait_impls(context, trait_impls, crate_id));
 
     collector.collect_impls(context, ast.impls, crate_id);
 
@@ -83,10 +106,10 @@ impl<'a> ModCollector<'a> {
     fn collect_globals(
         &mut self,
         context: &mut Context,
-        globals: Vec<LetStatement>,
+        globals: Vec<(LetStatement, ItemVisibility)>,
     ) -> Vec<(CompilationError, fm::FileId)> {
         let mut errors = vec![];
-        for global in globals {
+        for (global, visibility) in globals {
             let name = global.pattern.name_ident().clone();
 
             // First create dummy function in the DefInterner
@@ -94,8 +117,8 @@ impl<'a> ModCollector<'a> {
             let stmt_id = context.def_interner.push_empty_global();
 
             // Add the statement to the scope so its path can be looked up later
-            let result =
-                self.def_collector.def_map.modules[self.module_id.0].declare_global(name, stmt_id);
+            let result = self.def_collector.def_map.modules[self.module_id.0]
+                .declare_global(name, visibility, stmt_id);
 
             if let Err((first_def, second_def)) = result {
                 let err = DefCollectorErrorKind::Duplicate {
@@ -148,15 +171,19 @@ impl<'a> ModCollector<'a> {
         for trait_impl in impls {
             let trait_name = trait_impl.trait_name.clone();
 
-            let mut unresolved_functions =
+            let unresolved_functions =
                 self.collect_trait_impl_function_overrides(context, &trait_impl, krate);
 
-            let module = ModuleId { krate, local_id: self.module_id };
-
-            for (_, func_id, noir_function) in &mut unresolved_functions.functions {
-                noir_function.def.where_clause.append(&mut trait_impl.where_clause.clone());
-                let location = Location::new(noir_function.def.span, self.file_id);
-                context.def_interner.push_function(*func_id, &noir_function.def, module, location);
+            let mut associated_types = Vec::new();
+            let mut associated_constants = Vec::new();
+            for item in trait_impl.items {
+                match item {
+                    TraitImplItem::Type { name, alias } => associated_types.push((name, alias)),
+                    TraitImplItem::Constant(name, typ, value) => {
+                        associated_constants.push((name, typ, value));
+                    }
+                    TraitImplItem::Function(_) => (),
+                }
             }
 
             let unresolved_trait_impl = UnresolvedTraitImpl {
@@ -164,6 +191,8 @@ impl<'a> ModCollector<'a> {
                 module_id: self.module_id,
                 trait_path: trait_name,
                 methods: unresolved_functions,
+                associated_types,
+                associated_constants,
                 object_type: trait_impl.object_type,
                 generics: trait_impl.impl_generics,
                 where_clause: trait_impl.where_clause,
@@ -219,6 +248,7 @@ impl<'a> ModCollector<'a> {
             }
 
             let name = function.name_ident().clone();
+            let visibility = function.def.visibility;
             let func_id = context.def_interner.push_empty_fn();
 
             // First create dummy function in the DefInterner
@@ -236,7 +266,7 @@ impl<'a> ModCollector<'a> {
 
             // Add function to scope/ns of the module
             let result = self.def_collector.def_map.modules[self.module_id.0]
-                .declare_function(name, func_id);
+                .declare_function(name, visibility, func_id);
 
             if let Err((first_def, second_def)) = result {
                 let error = DefCollectorErrorKind::Duplicate {
@@ -263,6 +293,12 @@ impl<'a> ModCollector<'a> {
         let mut definition_errors = vec![];
         for struct_definition in types {
             let name = struct_definition.name.clone();
+            definition_errors.extend(self.set_lint_levels(
+                context,
+                &struct_definition.attributes,
+                struct_definition.span,
+                name.span(),
+            ));
 
             let unresolved = UnresolvedStruct {
                 file_id: self.file_id,
@@ -282,8 +318,9 @@ impl<'a> ModCollector<'a> {
             };
 
             // Add the struct to scope so its path can be looked up later
-            let result =
-                self.def_collector.def_map.modules[self.module_id.0].declare_struct(name, id);
+            let visibility = unresolved.struct_def.visibility;
+            let result = self.def_collector.def_map.modules[self.module_id.0]
+                .declare_struct(name, visibility, id);
 
             if let Err((first_def, second_def)) = result {
                 let error = DefCollectorErrorKind::Duplicate {
@@ -300,6 +337,87 @@ impl<'a> ModCollector<'a> {
         definition_errors
     }
 
+    /// Collect any enum definitions declared within the ast.
+    /// Each variant of an enum is given a constructor function within the enum's namespace
+    /// so that `MyEnum::Variant(args)` may be called like any other function.
+    fn collect_enums(
+        &mut self,
+        context: &mut Context,
+        enums: Vec<NoirEnum>,
+        krate: CrateId,
+    ) -> Vec<(CompilationError, FileId)> {
+        let mut definition_errors = vec![];
+        for enum_definition in enums {
+            let name = enum_definition.name.clone();
+
+            let mut unresolved = UnresolvedEnum {
+                file_id: self.file_id,
+                module_id: self.module_id,
+                enum_def: enum_definition,
+                variant_constructors: Vec::new(),
+            };
+
+            // Create the corresponding module for the enum namespace
+            let (id, enum_module) = match self.push_child_module(&name, self.file_id, false, false)
+            {
+                Ok(local_id) => {
+                    let id =
+                        context.def_interner.new_enum(&unresolved, krate, local_id, self.file_id);
+                    (id, local_id)
+                }
+                Err(error) => {
+                    definition_errors.push((error.into(), self.file_id));
+                    continue;
+                }
+            };
+
+            // Add the enum to scope so its path can be looked up later
+            let visibility = unresolved.enum_def.visibility;
+            let result = self.def_collector.def_map.modules[self.module_id.0]
+                .declare_struct(name, visibility, id);
+
+            if let Err((first_def, second_def)) = result {
+                let error = DefCollectorErrorKind::Duplicate {
+                    typ: DuplicateType::TypeDefinition,
+                    first_def,
+                    second_def,
+                };
+                definition_errors.push((error.into(), self.file_id));
+            }
+
+            let module = ModuleId { krate, local_id: self.module_id };
+            for (index, (variant_name, params)) in unresolved.enum_def.variants.iter().enumerate() {
+                let constructor =
+                    enum_variant_constructor(&unresolved.enum_def, variant_name, params);
+                let func_id = context.def_interner.push_empty_fn();
+                let location = Location::new(variant_name.span(), self.file_id);
+                context.def_interner.push_function(func_id, &constructor.def, module, location);
+                context.def_interner.push_enum_variant(func_id, id, index);
+
+                // Variants are as visible as the enum itself, which is checked on the way to them
+                let result = self.def_collector.def_map.modules[enum_module.0].declare_function(
+                    variant_name.clone(),
+                    ItemVisibility::Public,
+                    func_id,
+                );
+
+                if let Err((first_def, second_def)) = result {
+                    let error = DefCollectorErrorKind::Duplicate {
+                        typ: DuplicateType::EnumVariant,
+                        first_def,
+                        second_def,
+                    };
+                    definition_errors.push((error.into(), self.file_id));
+                }
+
+                unresolved.variant_constructors.push((func_id, constructor));
+            }
+
+            self.def_collector.collected_enums.insert(id, unresolved);
+        }
+        definition_errors
+    }
+
     /// Collect any type aliases definitions declared within the ast.
     /// Returns a vector of errors if any type aliases were already defined.
     fn collect_type_aliases(
@@ -310,6 +428,7 @@ impl<'a> ModCollector<'a> {
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         for type_alias in type_aliases {
             let name = type_alias.name.clone();
+            let visibility = type_alias.visibility;
 
             // And store the TypeId -> TypeAlias mapping somewhere it is reachable
             let unresolved = UnresolvedTypeAlias {
@@ -321,8 +440,11 @@ impl<'a> ModCollector<'a> {
             let type_alias_id = context.def_interner.push_type_alias(&unresolved);
 
             // Add the type alias to scope so its path can be looked up later
-            let result = self.def_collector.def_map.modules[self.module_id.0]
-                .declare_type_alias(name, type_alias_id);
+            let result = self.def_collector.def_map.modules[self.module_id.0].declare_type_alias(
+                name,
+                visibility,
+                type_alias_id,
+            );
 
             if let Err((first_def, second_def)) = result {
                 let err = DefCollectorErrorKind::Duplicate {
@@ -349,6 +471,7 @@ impl<'a> ModCollector<'a> {
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         for trait_definition in traits {
             let name = trait_definition.name.clone();
+            let visibility = trait_definition.visibility;
 
             // Create the corresponding module for the trait namespace
             let trait_id = match self.push_child_module(&name, self.file_id, false, false) {
@@ -360,8 +483,8 @@ impl<'a> ModCollector<'a> {
             };
 
             // Add the trait to scope so its path can be looked up later
-            let result =
-                self.def_collector.def_map.modules[self.module_id.0].declare_trait(name, trait_id);
+            let result = self.def_collector.def_map.modules[self.module_id.0]
+                .declare_trait(name, visibility, trait_id);
 
             if let Err((first_def, second_def)) = result {
                 let error = DefCollectorErrorKind::Duplicate {
@@ -395,10 +518,11 @@ impl<'a> ModCollector<'a> {
 
                         let modifiers = FunctionModifiers {
                             name: name.to_string(),
-                            visibility: crate::FunctionVisibility::Public,
+                            visibility: ItemVisibility::Public,
                             // TODO(Maddiaa): Investigate trait implementations with attributes see: https://github.com/noir-lang/noir/issues/2629
                             attributes: crate::token::Attributes::empty(),
                             is_unconstrained: false,
+                            is_comptime: false,
                             contract_function_type: None,
                             is_internal: None,
                         };
@@ -409,7 +533,7 @@ impl<'a> ModCollector<'a> {
                             .push_function_definition(func_id, modifiers, trait_id.0, location);
 
                         match self.def_collector.def_map.modules[trait_id.0.local_id.0]
-                            .declare_function(name.clone(), func_id)
+                            .declare_function(name.clone(), ItemVisibility::Public, func_id)
                         {
                             Ok(()) => {
                                 if let Some(body) = body {
@@ -444,7 +568,7 @@ impl<'a> ModCollector<'a> {
 
                         if let Err((first_def, second_def)) = self.def_collector.def_map.modules
                             [trait_id.0.local_id.0]
-                            .declare_global(name.clone(), stmt_id)
+                            .declare_global(name.clone(), ItemVisibility::Public, stmt_id)
                         {
                             let error = DefCollectorErrorKind::Duplicate {
                                 typ: DuplicateType::TraitAssociatedConst,
@@ -458,7 +582,11 @@ impl<'a> ModCollector<'a> {
                         // TODO(nickysn or alexvitkov): implement context.def_interner.push_empty_type_alias and get an id, instead of using TypeAliasId::dummy_id()
                         if let Err((first_def, second_def)) = self.def_collector.def_map.modules
                             [trait_id.0.local_id.0]
-                            .declare_type_alias(name.clone(), TypeAliasId::dummy_id())
+                            .declare_type_alias(
+                                name.clone(),
+                                ItemVisibility::Public,
+                                TypeAliasId::dummy_id(),
+                            )
                         {
                             let error = DefCollectorErrorKind::Duplicate {
                                 typ: DuplicateType::TraitAssociatedType,
@@ -494,6 +622,13 @@ impl<'a> ModCollector<'a> {
     ) -> Vec<(CompilationError, FileId)> {
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         for submodule in submodules {
+            errors.extend(self.set_lint_levels(
+                context,
+                &submodule.attributes,
+                submodule.span,
+                submodule.name.span(),
+            ));
+
             match self.push_child_module(&submodule.name, file_id, true, submodule.is_contract) {
                 Ok(child) => {
                     errors.extend(collect_defs(
@@ -519,9 +654,10 @@ impl<'a> ModCollector<'a> {
     fn parse_module_declaration(
         &mut self,
         context: &mut Context,
-        mod_name: &Ident,
+        mod_decl: &ModuleDeclaration,
         crate_id: CrateId,
     ) -> Vec<(CompilationError, FileId)> {
+        let mod_name = &mod_decl.ident;
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         let child_file_id =
             match find_module(&context.file_manager, self.file_id, &mod_name.0.contents) {
@@ -554,9 +690,18 @@ impl<'a> ModCollector<'a> {
 
         context.visited_files.insert(child_file_id, location);
 
+        // The lint levels in effect where the module is declared apply to all of its file
+        errors.extend(self.set_lint_levels(
+            context,
+            &mod_decl.attributes,
+            location.span,
+            location.span,
+        ));
+        context.def_interner.lint_levels_mut().inherit(child_file_id, location);
+
         // Parse the AST for the module we just found and then recursively look for it's defs
         let (ast, parsing_errors) = parse_file(&context.file_manager, child_file_id);
-        let ast = ast.into_sorted();
+        let mut ast = ast.into_sorted();
 
         errors.extend(
             parsing_errors.iter().map(|e| (e.clone().into(), child_file_id)).collect::<Vec<_>>(),
@@ -565,6 +710,30 @@ impl<'a> ModCollector<'a> {
         // Add module into def collector and get a ModuleId
         match self.push_child_module(mod_name, child_file_id, true, false) {
             Ok(child_mod_id) => {
+                // The macros of this crate's dependencies also apply to the files of its modules
+                let module_path = self.def_collector.def_map.get_module_path_with_separator(
+                    child_mod_id.0,
+                    Some(self.module_id),
+                    "::",
+                );
+                for macro_processor in context.crate_macro_processors(&crate_id) {
+                    let result = macro_processor.process_untyped_module_file(
+                        ast,
+                        &crate_id,
+                        child_file_id,
+                        &module_path,
+                        context,
+                    );
+                    ast = match result {
+                        Ok(ast) => ast,
+                        Err((error, file_id)) => {
+                            let def_error = DefCollectorErrorKind::MacroError(error);
+                            errors.push((def_error.into(), file_id));
+                            return errors;
+                        }
+                    };
+                }
+
                 errors.extend(collect_defs(
                     self.def_collector,
                     ast,
@@ -581,6 +750,24 @@ impl<'a> ModCollector<'a> {
         errors
     }
 
+    /// Sets the lint levels given by any lint attributes in `attributes` for the code within
+    /// `span`, warning about any unknown lints at `warning_span`.
+    fn set_lint_levels(
+        &self,
+        context: &mut Context,
+        attributes: &[SecondaryAttribute],
+        span: Span,
+        warning_span: Span,
+    ) -> Vec<(CompilationError, FileId)> {
+        let location = Location::new(span, self.file_id);
+        let unknown_lints =
+            context.def_interner.lint_levels_mut().set_from_attributes(attributes, location);
+        vecmap(unknown_lints, |name| {
+            let error = ResolverError::UnknownLint { name, span: warning_span };
+            (error.into(), self.file_id)
+        })
+    }
+
     /// Add a child module to the current def_map.
     /// On error this returns None and pushes to `errors`
     fn push_child_module(
@@ -741,3 +928,41 @@ mod tests {
         find_module(&fm, sub_dir_file_id, "foo").unwrap();
     }
 }
+
+/// Creates the constructor function for an enum variant, e.g. for `Some(T)` in
+/// `enum Option<T> { ... }` this is `pub fn Some<T>($0: T) -> Option<T>`.
+/// The body is left empty, it is filled in during name resolution.
+fn enum_variant_constructor(
+    enum_def: &NoirEnum,
+    variant_name: &Ident,
+    params: &[UnresolvedType],
+) -> NoirFunction {
+    let params = vecmap(params.iter().enumerate(), |(i, typ)| {
+        let span = typ.span.unwrap_or_else(|| variant_name.span());
+        let name = Ident::new(format!("${i}"), span);
+        (name, typ.clone())
+    });
+
+    let generic_args = vecmap(&enum_def.generics, |generic| UnresolvedType {
+        typ: UnresolvedTypeData::Named(crate::Path::from_ident(generic.clone()), Vec::new()),
+        span: Some(generic.span()),
+    });
+    let return_type = UnresolvedType {
+        typ: UnresolvedTypeData::Named(
+            crate::Path::from_ident(enum_def.name.clone()),
+            generic_args,
+        ),
+        span: Some(enum_def.name.span()),
+    };
+
+    let mut def = FunctionDefinition::normal(
+        variant_name,
+        &enum_def.generics,
+        &params,
+        &BlockExpression(Vec::new()),
+        &[],
+        &FunctionReturnType::Ty(return_type),
+    );
+    def.visibility = ItemVisibility::Public;
+    NoirFunction::normal(def)
+}
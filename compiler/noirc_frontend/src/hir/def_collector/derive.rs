@@ -0,0 +1,245 @@
+//! Support for `#[derive(...)]` on struct definitions.
+//!
+//! Deriving a trait generates the AST of an impl of that trait for the struct, which is then
+//! collected along with the trait impls written in the source. The generated code refers to
+//! each field's own impl of the trait, so any generic type used by a field is required to
+//! implement the trait as well.
+
+use iter_extended::vecmap;
+use noirc_errors::{Span, Spanned};
+
+use crate::graph::CrateId;
+use crate::token::SecondaryAttribute;
+use crate::{
+    ArrayLiteral, BinaryOpKind, BlockExpression, ConstructorExpression, Expression, ExpressionKind,
+    FunctionDefinition, FunctionReturnType, Ident, InfixExpression, Literal, NoirFunction,
+    NoirStruct, NoirTraitImpl, Path, PathKind, Statement, StatementKind, TraitBound, TraitImplItem,
+    UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData,
+};
+
+use super::errors::DefCollectorErrorKind;
+
+/// Returns the names of each trait listed in the `#[derive(...)]` attributes of an item.
+pub(super) fn derived_traits(attributes: &[SecondaryAttribute]) -> Vec<&String> {
+    attributes
+        .iter()
+        .flat_map(|attribute| match attribute {
+            SecondaryAttribute::Derive(traits) => traits.iter().collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// Generates an impl for each trait the given struct derives.
+pub(super) fn derive_trait_impls(
+    struct_def: &NoirStruct,
+    krate: CrateId,
+) -> (Vec<NoirTraitImpl>, Vec<DefCollectorErrorKind>) {
+    let mut impls = Vec::new();
+    let mut errors = Vec::new();
+
+    for trait_name in derived_traits(&struct_def.attributes) {
+        let (module, method) = match trait_name.as_str() {
+            "Eq" => ("ops", derive_eq(struct_def)),
+            "Default" => ("default", derive_default(struct_def, krate)),
+            "Serialize" => ("serialize", derive_serialize(struct_def)),
+            _ => {
+                let span = struct_def.name.span();
+                errors.push(DefCollectorErrorKind::UnsupportedDerive {
+                    trait_name: trait_name.clone(),
+                    span,
+                });
+                continue;
+            }
+        };
+
+        let span = struct_def.name.span();
+        let trait_path = stdlib_path(krate, &[module, trait_name.as_str()], span);
+        impls.push(trait_impl(struct_def, trait_path, method));
+    }
+
+    (impls, errors)
+}
+
+/// `fn eq(self, other: Self) -> bool { self.a.eq(other.a) & self.b.eq(other.b) ... }`
+fn derive_eq(struct_def: &NoirStruct) -> NoirFunction {
+    let span = struct_def.name.span();
+    let field_eq = |field: &Ident| {
+        let lhs = member_access(variable("self", field.span()), field);
+        let rhs = member_access(variable("other", field.span()), field);
+        method_call(lhs, "eq", vec![rhs])
+    };
+
+    let mut fields = struct_def.fields.iter().map(|(field, _)| field_eq(field));
+    let body = match fields.next() {
+        Some(first) => fields.fold(first, |lhs, rhs| {
+            let span = rhs.span;
+            let operator = Spanned::from(span, BinaryOpKind::And);
+            let infix = InfixExpression { lhs, operator, rhs };
+            Expression::new(ExpressionKind::Infix(Box::new(infix)), span)
+        }),
+        None => Expression::new(ExpressionKind::Literal(Literal::Bool(true)), span),
+    };
+
+    let other = (Ident::new("other".into(), span), self_type(span));
+    let bool_type = UnresolvedTypeData::Bool.with_span(span);
+    method(struct_def, "eq", vec![other], body, bool_type)
+}
+
+/// `fn default() -> Self { Name { a: std::default::Default::default(), ... } }`
+fn derive_default(struct_def: &NoirStruct, krate: CrateId) -> NoirFunction {
+    let span = struct_def.name.span();
+    let fields = struct_def.fields.iter().map(|(field, _)| {
+        let function = stdlib_path(krate, &["default", "Default", "default"], field.span());
+        let default_function = Expression::new(ExpressionKind::Variable(function), field.span());
+        (field.clone(), Expression::call(default_function, Vec::new(), field.span()))
+    });
+
+    let constructor = ConstructorExpression {
+        type_name: Path::from_ident(struct_def.name.clone()),
+        fields: fields.collect(),
+    };
+    let body = Expression::new(ExpressionKind::Constructor(Box::new(constructor)), span);
+    NoirFunction::normal(FunctionDefinition::normal(
+        &Ident::new("default".into(), span),
+        &Vec::new(),
+        &[],
+        &block(body),
+        &[],
+        &FunctionReturnType::Ty(self_type(span)),
+    ))
+}
+
+/// `fn serialize(self) -> [Field] { self.a.serialize().append(self.b.serialize()) ... }`
+fn derive_serialize(struct_def: &NoirStruct) -> NoirFunction {
+    let span = struct_def.name.span();
+    let serialize_field = |field: &Ident| {
+        method_call(member_access(variable("self", field.span()), field), "serialize", Vec::new())
+    };
+
+    let mut fields = struct_def.fields.iter().map(|(field, _)| serialize_field(field));
+    let body = match fields.next() {
+        Some(first) => {
+            fields.fold(first, |result, field| method_call(result, "append", vec![field]))
+        }
+        None => {
+            let empty = Literal::Array(ArrayLiteral::Standard(Vec::new()));
+            Expression::new(ExpressionKind::Literal(empty), span)
+        }
+    };
+
+    let field_type = Box::new(UnresolvedTypeData::FieldElement.with_span(span));
+    let slice_type = UnresolvedTypeData::Array(None, field_type).with_span(span);
+    method(struct_def, "serialize", Vec::new(), body, slice_type)
+}
+
+/// Creates the impl of the given trait for the struct. Each generic which is used
+/// as the type of a field is required to implement the trait as well.
+fn trait_impl(struct_def: &NoirStruct, trait_path: Path, method: NoirFunction) -> NoirTraitImpl {
+    let span = struct_def.name.span();
+    let generics = vecmap(&struct_def.generics, |generic| named_type(generic.clone()));
+    let object_type =
+        UnresolvedTypeData::Named(Path::from_ident(struct_def.name.clone()), generics)
+            .with_span(span);
+
+    let where_clause = struct_def
+        .generics
+        .iter()
+        .filter(|generic| struct_def.fields.iter().any(|(_, typ)| is_used_as_type(generic, typ)))
+        .map(|generic| UnresolvedTraitConstraint {
+            typ: named_type(generic.clone()),
+            trait_bound: TraitBound {
+                trait_path: trait_path.clone(),
+                trait_id: None,
+                trait_generics: Vec::new(),
+            },
+        })
+        .collect();
+
+    NoirTraitImpl {
+        impl_generics: struct_def.generics.clone(),
+        trait_name: trait_path,
+        trait_generics: Vec::new(),
+        object_type,
+        where_clause,
+        items: vec![TraitImplItem::Function(method)],
+    }
+}
+
+fn named_type(name: Ident) -> UnresolvedType {
+    let span = name.span();
+    UnresolvedTypeData::Named(Path::from_ident(name), Vec::new()).with_span(span)
+}
+
+/// True if the given generic is used as a type, rather than only as a numeric
+/// array length, anywhere within the given field type.
+fn is_used_as_type(generic: &Ident, typ: &UnresolvedType) -> bool {
+    match &typ.typ {
+        UnresolvedTypeData::Named(path, generics) => {
+            (path.segments.len() == 1 && path.segments[0] == *generic)
+                || generics.iter().any(|typ| is_used_as_type(generic, typ))
+        }
+        UnresolvedTypeData::Array(_, element) | UnresolvedTypeData::FormatString(_, element) => {
+            is_used_as_type(generic, element)
+        }
+        UnresolvedTypeData::Parenthesized(typ) | UnresolvedTypeData::MutableReference(typ) => {
+            is_used_as_type(generic, typ)
+        }
+        UnresolvedTypeData::Tuple(fields) => fields.iter().any(|typ| is_used_as_type(generic, typ)),
+        _ => false,
+    }
+}
+
+/// Creates a path to an item within the standard library which is valid from within the given crate.
+fn stdlib_path(krate: CrateId, segments: &[&str], span: Span) -> Path {
+    let (kind, prefix) =
+        if krate.is_stdlib() { (PathKind::Crate, None) } else { (PathKind::Dep, Some("std")) };
+    let segments = prefix.into_iter().chain(segments.iter().copied());
+    let segments = segments.map(|segment| Ident::new(segment.to_string(), span)).collect();
+    Path { segments, kind, span }
+}
+
+/// Creates a method taking `self` followed by the given parameters.
+fn method(
+    struct_def: &NoirStruct,
+    name: &str,
+    parameters: Vec<(Ident, UnresolvedType)>,
+    body: Expression,
+    return_type: UnresolvedType,
+) -> NoirFunction {
+    let span = struct_def.name.span();
+    let self_parameter = (Ident::new("self".into(), span), self_type(span));
+    let parameters: Vec<_> = std::iter::once(self_parameter).chain(parameters).collect();
+
+    NoirFunction::normal(FunctionDefinition::normal(
+        &Ident::new(name.into(), span),
+        &Vec::new(),
+        &parameters,
+        &block(body),
+        &[],
+        &FunctionReturnType::Ty(return_type),
+    ))
+}
+
+fn block(expression: Expression) -> BlockExpression {
+    let span = expression.span;
+    BlockExpression(vec![Statement { kind: StatementKind::Expression(expression), span }])
+}
+
+fn self_type(span: Span) -> UnresolvedType {
+    named_type(Ident::new("Self".into(), span))
+}
+
+fn variable(name: &str, span: Span) -> Expression {
+    Expression::new(ExpressionKind::Variable(Path::from_single(name.into(), span)), span)
+}
+
+fn member_access(lhs: Expression, field: &Ident) -> Expression {
+    Expression::member_access_or_method_call(lhs, (field.clone(), None), field.span())
+}
+
+fn method_call(object: Expression, name: &str, arguments: Vec<Expression>) -> Expression {
+    let span = object.span;
+    let name = Ident::new(name.into(), span);
+    Expression::member_access_or_method_call(object, (name, Some(arguments)), span)
+}
@@ -21,6 +21,7 @@ pub enum DuplicateType {
     TraitAssociatedType,
     TraitAssociatedConst,
     TraitAssociatedFunction,
+    EnumVariant,
 }
 
 #[derive(Error, Debug, Clone)]
@@ -65,6 +66,10 @@ pub enum DefCollectorErrorKind {
     TraitNotFound { trait_path: Path },
     #[error("Missing Trait method implementation")]
     TraitMissingMethod { trait_name: Ident, method_name: Ident, trait_impl_span: Span },
+    #[error("Associated item is not defined in trait")]
+    AssociatedItemNotInTrait { trait_name: Ident, item_name: Ident },
+    #[error("Missing Trait associated item")]
+    TraitMissingAssociatedItem { trait_name: Ident, item_name: Ident, trait_impl_span: Span },
     #[error("Module is already part of the crate")]
     ModuleAlreadyPartOfCrate { mod_name: Ident, span: Span },
     #[error("Module was originally declared here")]
@@ -73,6 +78,10 @@ pub enum DefCollectorErrorKind {
         "Either the type or the trait must be from the same crate as the trait implementation"
     )]
     TraitImplOrphaned { span: Span },
+    #[error("Cannot derive `{trait_name}`")]
+    UnsupportedDerive { trait_name: String, span: Span },
+    #[error("`derive` is only supported on structs")]
+    DeriveOnNonStruct { span: Span },
     #[error("macro error : {0:?}")]
     MacroError(MacroError),
 }
@@ -89,6 +98,34 @@ impl DefCollectorErrorKind {
     pub fn into_file_diagnostic(self, file: fm::FileId) -> FileDiagnostic {
         Diagnostic::from(self).in_file(file)
     }
+
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            DefCollectorErrorKind::Duplicate { .. } => "E0100",
+            DefCollectorErrorKind::UnresolvedModuleDecl { .. } => "E0101",
+            DefCollectorErrorKind::PathResolutionError(error) => error.code(),
+            DefCollectorErrorKind::NonStructTypeInImpl { .. } => "E0102",
+            DefCollectorErrorKind::MutableReferenceInTraitImpl { .. } => "E0103",
+            DefCollectorErrorKind::OverlappingImpl { .. }
+            | DefCollectorErrorKind::OverlappingImplNote { .. } => "E0104",
+            DefCollectorErrorKind::ForeignImpl { .. } => "E0105",
+            DefCollectorErrorKind::MismatchTraitImplementationNumParameters { .. } => "E0106",
+            DefCollectorErrorKind::MismatchTraitImplementationNumGenerics { .. } => "E0107",
+            DefCollectorErrorKind::MethodNotInTrait { .. } => "E0108",
+            DefCollectorErrorKind::NotATrait { .. } => "E0109",
+            DefCollectorErrorKind::TraitNotFound { .. } => "E0110",
+            DefCollectorErrorKind::TraitMissingMethod { .. } => "E0111",
+            DefCollectorErrorKind::AssociatedItemNotInTrait { .. } => "E0112",
+            DefCollectorErrorKind::TraitMissingAssociatedItem { .. } => "E0113",
+            DefCollectorErrorKind::ModuleAlreadyPartOfCrate { .. }
+            | DefCollectorErrorKind::ModuleOriginallyDefined { .. } => "E0114",
+            DefCollectorErrorKind::TraitImplOrphaned { .. } => "E0115",
+            DefCollectorErrorKind::UnsupportedDerive { .. } => "E0116",
+            DefCollectorErrorKind::DeriveOnNonStruct { .. } => "E0117",
+            DefCollectorErrorKind::MacroError(_) => "E0118",
+        }
+    }
 }
 
 impl fmt::Display for DuplicateType {
@@ -104,13 +141,15 @@ impl fmt::Display for DuplicateType {
             DuplicateType::TraitAssociatedType => write!(f, "trait associated type"),
             DuplicateType::TraitAssociatedConst => write!(f, "trait associated constant"),
             DuplicateType::TraitAssociatedFunction => write!(f, "trait associated function"),
+            DuplicateType::EnumVariant => write!(f, "enum variant"),
         }
     }
 }
 
 impl From<DefCollectorErrorKind> for Diagnostic {
     fn from(error: DefCollectorErrorKind) -> Diagnostic {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             DefCollectorErrorKind::Duplicate { typ, first_def, second_def } => {
                 let primary_message = format!(
                     "Duplicate definitions of {} with name {} found",
@@ -207,6 +246,29 @@ impl From<DefCollectorErrorKind> for Diagnostic {
                 let primary_message = format!("Method with name `{impl_method_name}` is not part of trait `{trait_name}`, therefore it can't be implemented");
                 Diagnostic::simple_error(primary_message, "".to_owned(), impl_method_span)
             }
+            DefCollectorErrorKind::AssociatedItemNotInTrait { trait_name, item_name } => {
+                let trait_name = trait_name.0.contents;
+                let item_span = item_name.span();
+                let item_name = item_name.0.contents;
+                let primary_message = format!("Associated item `{item_name}` is not part of trait `{trait_name}`, therefore it can't be implemented");
+                Diagnostic::simple_error(primary_message, "".to_owned(), item_span)
+            }
+            DefCollectorErrorKind::TraitMissingAssociatedItem {
+                trait_name,
+                item_name,
+                trait_impl_span,
+            } => {
+                let trait_name = trait_name.0.contents;
+                let item_name = item_name.0.contents;
+                let primary_message = format!(
+                    "Associated item `{item_name}` from trait `{trait_name}` is not implemented"
+                );
+                Diagnostic::simple_error(
+                    primary_message,
+                    format!("Please implement {item_name} here"),
+                    trait_impl_span,
+                )
+            }
             DefCollectorErrorKind::TraitMissingMethod {
                 trait_name,
                 method_name,
@@ -246,9 +308,22 @@ impl From<DefCollectorErrorKind> for Diagnostic {
                 "Either the type or the trait must be from the same crate as the trait implementation".into(),
                 span,
             ),
+            DefCollectorErrorKind::UnsupportedDerive { trait_name, span } => {
+                Diagnostic::simple_error(
+                    format!("Cannot derive `{trait_name}`"),
+                    "Only `Eq`, `Default` and `Serialize` can be derived".into(),
+                    span,
+                )
+            }
+            DefCollectorErrorKind::DeriveOnNonStruct { span } => Diagnostic::simple_error(
+                "`derive` is only supported on structs".into(),
+                String::new(),
+                span,
+            ),
             DefCollectorErrorKind::MacroError(macro_error) => {
                 Diagnostic::simple_error(macro_error.primary_message, macro_error.secondary_message.unwrap_or_default(), macro_error.span.unwrap_or_default())
             },
-        }
+        };
+        diagnostic.with_code(code)
     }
 }
@@ -89,7 +89,8 @@ impl CrateDefMap {
         let (ast, parsing_errors) = parse_file(&context.file_manager, root_file_id);
         let mut ast = ast.into_sorted();
 
-        for macro_processor in &macro_processors {
+        let crate_macro_processors = context.crate_macro_processors(&crate_id);
+        for macro_processor in macro_processors.iter().chain(&crate_macro_processors) {
             ast = match macro_processor.process_untyped_ast(ast, &crate_id, context) {
                 Ok(ast) => ast,
                 Err((error, file_id)) => {
@@ -1,3 +1,4 @@
+pub mod comptime;
 pub mod def_collector;
 pub mod def_map;
 pub mod resolution;
@@ -6,6 +7,7 @@ pub mod type_check;
 
 use crate::graph::{CrateGraph, CrateId};
 use crate::hir_def::function::FuncMeta;
+use crate::macros_api::MacroProcessor;
 use crate::node_interner::{FuncId, NodeInterner, StructId};
 use def_map::{Contract, CrateDefMap};
 use fm::FileManager;
@@ -30,6 +32,10 @@ pub struct Context<'file_manager> {
     /// A map of each file that already has been visited from a prior `mod foo;` declaration.
     /// This is used to issue an error if a second `mod foo;` is declared to the same file.
     pub visited_files: BTreeMap<fm::FileId, Location>,
+
+    /// Macro processors which only run on a specific crate, such as those declared
+    /// by one of its dependencies. These run after any processors passed to `collect_defs`.
+    pub(crate) crate_macro_processors: BTreeMap<CrateId, Vec<&'static dyn MacroProcessor>>,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -47,6 +53,7 @@ impl Context<'_> {
             visited_files: BTreeMap::new(),
             crate_graph: CrateGraph::default(),
             file_manager: Cow::Owned(file_manager),
+            crate_macro_processors: BTreeMap::new(),
         }
     }
 
@@ -57,6 +64,7 @@ impl Context<'_> {
             visited_files: BTreeMap::new(),
             crate_graph: CrateGraph::default(),
             file_manager: Cow::Borrowed(file_manager),
+            crate_macro_processors: BTreeMap::new(),
         }
     }
 
@@ -74,6 +82,29 @@ impl Context<'_> {
         self.crate_graph.iter_keys()
     }
 
+    /// Registers a macro processor to be run on the given crate only.
+    pub fn add_macro_processor(
+        &mut self,
+        crate_id: CrateId,
+        macro_processor: &'static dyn MacroProcessor,
+    ) {
+        self.crate_macro_processors.entry(crate_id).or_default().push(macro_processor);
+    }
+
+    /// Removes the macro processors registered on individual crates, so that they
+    /// don't run when macros are disabled.
+    pub fn disable_crate_macro_processors(&mut self) {
+        self.crate_macro_processors.clear();
+    }
+
+    /// Returns the macro processors registered to run on the given crate only.
+    pub(crate) fn crate_macro_processors(
+        &self,
+        crate_id: &CrateId,
+    ) -> Vec<&'static dyn MacroProcessor> {
+        self.crate_macro_processors.get(crate_id).cloned().unwrap_or_default()
+    }
+
     pub fn root_crate_id(&self) -> &CrateId {
         self.crate_graph.root_crate_id()
     }
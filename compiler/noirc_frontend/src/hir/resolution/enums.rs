@@ -0,0 +1,123 @@
+use std::collections::{BTreeMap, HashSet};
+
+use fm::FileId;
+use iter_extended::vecmap;
+
+use crate::{
+    graph::CrateId,
+    hir::{
+        def_collector::dc_crate::{CompilationError, UnresolvedEnum},
+        def_map::ModuleId,
+        Context,
+    },
+    node_interner::{FuncId, StructId},
+    Type, TypeBinding, TypeVariableKind,
+};
+
+use super::{errors::ResolverError, path_resolver::StandardPathResolver, resolver::Resolver};
+
+/// Resolve the variants of each enum, then the constructor function of each variant.
+/// Returns the ids of the constructor functions so that they may be type checked later.
+pub(crate) fn resolve_enums(
+    context: &mut Context,
+    enums: BTreeMap<StructId, UnresolvedEnum>,
+    crate_id: CrateId,
+    errors: &mut Vec<(CompilationError, FileId)>,
+) -> Vec<(FileId, FuncId)> {
+    // Each enum should already be present in the NodeInterner after def collection.
+    let mut func_ids = Vec::new();
+    let mut enum_ids = Vec::with_capacity(enums.len());
+    for (enum_id, unresolved) in enums {
+        let file_id = unresolved.file_id;
+        enum_ids.push((enum_id, file_id));
+        let path_resolver =
+            StandardPathResolver::new(ModuleId { local_id: unresolved.module_id, krate: crate_id });
+
+        let resolver =
+            Resolver::new(&mut context.def_interner, &path_resolver, &context.def_maps, file_id);
+        let (generics, variants, resolver_errors) =
+            resolver.resolve_enum_variants(unresolved.enum_def);
+        errors.extend(vecmap(resolver_errors, |err| (err.into(), file_id)));
+
+        context.def_interner.update_struct(enum_id, |enum_def| {
+            enum_def.set_variants(variants);
+            enum_def.generics = generics;
+        });
+
+        let enum_type = context.def_interner.get_struct(enum_id);
+        for (variant_index, (func_id, constructor)) in
+            unresolved.variant_constructors.into_iter().enumerate()
+        {
+            let resolver = Resolver::new(
+                &mut context.def_interner,
+                &path_resolver,
+                &context.def_maps,
+                file_id,
+            );
+            let (hir_func, func_meta, resolver_errors) = resolver.resolve_enum_variant_constructor(
+                constructor,
+                func_id,
+                enum_type.clone(),
+                variant_index,
+            );
+            context.def_interner.push_fn_meta(func_meta, func_id);
+            context.def_interner.update_fn(func_id, hir_func);
+            errors.extend(vecmap(resolver_errors, |err| (err.into(), file_id)));
+            func_ids.push((file_id, func_id));
+        }
+    }
+
+    // Check for enums holding themselves only once the variants of every enum are known
+    for (enum_id, file_id) in enum_ids {
+        let enum_type = context.def_interner.get_struct(enum_id);
+        let enum_type = enum_type.borrow();
+        let generics = vecmap(&enum_type.generics, |(_, typevar)| {
+            Type::TypeVariable(typevar.clone(), TypeVariableKind::Normal)
+        });
+        let mut visited = HashSet::new();
+        let contains_itself = enum_type.get_variants(&generics).iter().any(|(_, params)| {
+            params.iter().any(|param| contains_type(param, enum_id, &mut visited))
+        });
+        if contains_itself {
+            let name = enum_type.name.clone();
+            errors.push((ResolverError::SelfReferentialEnum { name }.into(), file_id));
+        }
+    }
+    func_ids
+}
+
+/// Returns true if a value of type `typ` may hold a value of the type `target`.
+/// An enum holding itself would have an infinite size, so this is rejected.
+fn contains_type(typ: &Type, target: StructId, visited: &mut HashSet<Type>) -> bool {
+    if !visited.insert(typ.clone()) {
+        return false;
+    }
+
+    match typ {
+        Type::Struct(def, args) => {
+            let def = def.borrow();
+            if def.id == target {
+                return true;
+            }
+            let fields = if def.is_enum() {
+                def.get_variants(args).into_iter().flat_map(|(_, params)| params).collect()
+            } else {
+                vecmap(def.get_fields(args), |(_, field)| field)
+            };
+            fields.iter().any(|field| contains_type(field, target, visited))
+        }
+        Type::Array(_, element) | Type::MutableReference(element) => {
+            contains_type(element, target, visited)
+        }
+        Type::Tuple(elements) => {
+            elements.iter().any(|element| contains_type(element, target, visited))
+        }
+        Type::TypeVariable(typevar, _) | Type::NamedGeneric(typevar, _) => {
+            match &*typevar.borrow() {
+                TypeBinding::Bound(binding) => contains_type(binding, target, visited),
+                TypeBinding::Unbound(_) => false,
+            }
+        }
+        _ => false,
+    }
+}
@@ -1,8 +1,9 @@
+use iter_extended::vecmap;
 pub use noirc_errors::Span;
-use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic};
+use noirc_errors::{Applicability, CustomDiagnostic as Diagnostic, FileDiagnostic};
 use thiserror::Error;
 
-use crate::{parser::ParserError, Ident, Type};
+use crate::{lints::Lint, parser::ParserError, Ident, Type};
 
 use super::import::PathResolutionError;
 
@@ -18,8 +19,16 @@ pub enum PubPosition {
 pub enum ResolverError {
     #[error("Duplicate definition")]
     DuplicateDefinition { name: String, first_span: Span, second_span: Span },
+    /// `is_field_shorthand` is true if the variable was bound by the shorthand form of a
+    /// struct pattern such as `Foo { x }`, where it is named after the field it is bound to.
     #[error("Unused variable")]
-    UnusedVariable { ident: Ident },
+    UnusedVariable { ident: Ident, is_field_shorthand: bool, is_entry_point_parameter: bool },
+    #[error("Unused import")]
+    UnusedImport { ident: Ident },
+    #[error("Unused item")]
+    UnusedItem { ident: Ident, item_type: &'static str },
+    #[error("Unread struct field")]
+    UnreadField { field: Ident, struct_name: Ident },
     #[error("Could not find variable in this scope")]
     VariableNotDeclared { name: String, span: Span },
     #[error("path is not an identifier")]
@@ -32,14 +41,27 @@ pub enum ResolverError {
     DuplicateField { field: Ident },
     #[error("No such field in struct")]
     NoSuchField { field: Ident, struct_definition: Ident },
+    /// `fields_end` is an empty span after the last field of the constructor or pattern, or
+    /// before its closing brace if it has no fields, where the missing fields can be added.
     #[error("Missing fields from struct")]
-    MissingFields { span: Span, missing_fields: Vec<String>, struct_definition: Ident },
+    MissingFields {
+        span: Span,
+        missing_fields: Vec<String>,
+        struct_definition: Ident,
+        fields_end: Span,
+        has_fields: bool,
+        is_pattern: bool,
+    },
+    /// `second_mut` spans the redundant `mut` keyword along with the whitespace following it.
     #[error("Unneeded 'mut', pattern is already marked as mutable")]
     UnnecessaryMut { first_mut: Span, second_mut: Span },
+    /// `pub_span` spans the source which has to be removed along with the `pub` keyword: from
+    /// the end of the parameter's pattern to the start of its type, which is then replaced by
+    /// `: `, or from the `pub` keyword to the start of the return type.
     #[error("Unneeded 'pub', function is not the main method")]
-    UnnecessaryPub { ident: Ident, position: PubPosition },
+    UnnecessaryPub { ident: Ident, position: PubPosition, pub_span: Option<Span> },
     #[error("Required 'pub', main function must return public value")]
-    NecessaryPub { ident: Ident },
+    NecessaryPub { ident: Ident, return_type_span: Option<Span> },
     #[error("'distinct' keyword can only be used with main method")]
     DistinctNotAllowed { ident: Ident },
     #[error("Missing expression for declared constant")]
@@ -76,18 +98,95 @@ pub enum ResolverError {
     NumericConstantInFormatString { name: String, span: Span },
     #[error("Closure environment must be a tuple or unit type")]
     InvalidClosureEnvironment { typ: Type, span: Span },
-    #[error("{name} is private and not visible from the current module")]
-    PrivateFunctionCalled { name: String, span: Span },
-    #[error("{name} is not visible from the current crate")]
-    NonCrateFunctionCalled { name: String, span: Span },
     #[error("Only sized types may be used in the entry point to a program")]
     InvalidTypeForEntryPoint { span: Span },
+    #[error("Incorrect amount of arguments to enum variant pattern")]
+    IncorrectVariantArgumentCount { span: Span, variant: String, actual: usize, expected: usize },
+    #[error("Refutable pattern in a binding which must always match")]
+    RefutablePattern { span: Span },
+    #[error("`while` loops are only allowed in unconstrained functions")]
+    LoopInConstrainedFn { span: Span },
+    #[error("`break` and `continue` are only allowed in unconstrained functions")]
+    JumpInConstrainedFn { is_break: bool, span: Span },
+    #[error("`break` and `continue` are only allowed within loops")]
+    JumpOutsideLoop { is_break: bool, span: Span },
+    #[error("Unknown lint")]
+    UnknownLint { name: String, span: Span },
+    #[error("`#[inline(never)]` has no effect on calls from constrained code")]
+    InlineNeverInConstrainedFn { ident: Ident },
+    #[error("Self-referential enum")]
+    SelfReferentialEnum { name: Ident },
 }
 
 impl ResolverError {
     pub fn into_file_diagnostic(self, file: fm::FileId) -> FileDiagnostic {
         Diagnostic::from(self).in_file(file)
     }
+
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ResolverError::DuplicateDefinition { .. } => "E0200",
+            ResolverError::UnusedVariable { .. } => "E0201",
+            ResolverError::UnusedImport { .. } => "E0202",
+            ResolverError::UnusedItem { .. } => "E0203",
+            ResolverError::UnreadField { .. } => "E0204",
+            ResolverError::VariableNotDeclared { .. } => "E0205",
+            ResolverError::PathIsNotIdent { .. } => "E0206",
+            ResolverError::PathResolutionError(error) => error.code(),
+            ResolverError::Expected { .. } => "E0207",
+            ResolverError::DuplicateField { .. } => "E0208",
+            ResolverError::NoSuchField { .. } => "E0209",
+            ResolverError::MissingFields { .. } => "E0210",
+            ResolverError::UnnecessaryMut { .. } => "E0211",
+            ResolverError::UnnecessaryPub { .. } => "E0212",
+            ResolverError::NecessaryPub { .. } => "E0213",
+            ResolverError::DistinctNotAllowed { .. } => "E0214",
+            ResolverError::MissingRhsExpr { .. } => "E0215",
+            ResolverError::InvalidArrayLengthExpr { .. } => "E0216",
+            ResolverError::IntegerTooLarge { .. } => "E0217",
+            ResolverError::NoSuchNumericTypeVariable { .. } => "E0218",
+            ResolverError::CapturedMutableVariable { .. } => "E0219",
+            ResolverError::TestFunctionHasParameters { .. } => "E0220",
+            ResolverError::NonStructUsedInConstructor { .. } => "E0221",
+            ResolverError::NonStructWithGenerics { .. } => "E0222",
+            ResolverError::GenericsOnSelfType { .. } => "E0223",
+            ResolverError::IncorrectGenericCount { .. } => "E0224",
+            ResolverError::ParserError(error) => error.code(),
+            ResolverError::ContractFunctionTypeInNormalFunction { .. } => "E0225",
+            ResolverError::MutableReferenceToImmutableVariable { .. } => "E0226",
+            ResolverError::MutableReferenceToArrayElement { .. } => "E0227",
+            ResolverError::ContractFunctionInternalInNormalFunction { .. } => "E0228",
+            ResolverError::NumericConstantInFormatString { .. } => "E0229",
+            ResolverError::InvalidClosureEnvironment { .. } => "E0230",
+            ResolverError::InvalidTypeForEntryPoint { .. } => "E0231",
+            ResolverError::IncorrectVariantArgumentCount { .. } => "E0232",
+            ResolverError::RefutablePattern { .. } => "E0233",
+            ResolverError::LoopInConstrainedFn { .. } => "E0234",
+            ResolverError::JumpInConstrainedFn { .. } => "E0235",
+            ResolverError::JumpOutsideLoop { .. } => "E0236",
+            ResolverError::UnknownLint { .. } => "E0237",
+            ResolverError::InlineNeverInConstrainedFn { .. } => "E0238",
+            ResolverError::SelfReferentialEnum { .. } => "E0239",
+        }
+    }
+
+    /// The lint which controls this warning, along with the span it is reported at,
+    /// or `None` if this is an error or a warning which can't be allowed.
+    pub fn lint(&self) -> Option<(Lint, Span)> {
+        match self {
+            ResolverError::UnusedVariable { ident, .. } => {
+                Some((Lint::UnusedVariables, ident.span()))
+            }
+            ResolverError::UnusedImport { ident } => Some((Lint::UnusedImports, ident.span())),
+            ResolverError::UnusedItem { ident, .. } => Some((Lint::DeadCode, ident.span())),
+            ResolverError::UnreadField { field, .. } => Some((Lint::DeadCode, field.span())),
+            ResolverError::UnnecessaryPub { ident, .. } => {
+                Some((Lint::UnnecessaryPub, ident.span()))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl From<ResolverError> for Diagnostic {
@@ -95,7 +194,8 @@ impl From<ResolverError> for Diagnostic {
     /// ICEs will make the compiler panic, as they could affect the
     /// soundness of the generated program
     fn from(error: ResolverError) -> Diagnostic {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             ResolverError::DuplicateDefinition { name, first_span, second_span } => {
                 let mut diag = Diagnostic::simple_error(
                     format!("duplicate definitions of {name} found"),
@@ -105,15 +205,59 @@ impl From<ResolverError> for Diagnostic {
                 diag.add_secondary("second definition found here".to_string(), second_span);
                 diag
             }
-            ResolverError::UnusedVariable { ident } => {
+            ResolverError::UnusedVariable { ident, is_field_shorthand, is_entry_point_parameter } => {
                 let name = &ident.0.contents;
 
-                Diagnostic::simple_warning(
+                let mut diag = Diagnostic::simple_warning(
                     format!("unused variable {name}"),
                     "unused variable ".to_string(),
                     ident.span(),
+                );
+
+                // Renaming a parameter of an entry point renames an input of the program,
+                // which would no longer be found in the existing input files.
+                if is_entry_point_parameter {
+                    diag.add_note(format!(
+                        "{name} is an input of the program, so renaming it changes the program's ABI"
+                    ));
+                } else {
+                    let replacement = if is_field_shorthand {
+                        format!("{name}: _{name}")
+                    } else {
+                        format!("_{name}")
+                    };
+                    diag.add_suggestion(
+                        "if this is intentional, prefix it with an underscore".to_string(),
+                        ident.span(),
+                        replacement,
+                        Applicability::MachineApplicable,
+                    );
+                }
+                diag
+            }
+            ResolverError::UnusedImport { ident } => {
+                let name = &ident.0.contents;
+
+                Diagnostic::simple_warning(
+                    format!("unused import {name}"),
+                    "unused import".to_string(),
+                    ident.span(),
+                )
+            }
+            ResolverError::UnusedItem { ident, item_type } => {
+                let name = &ident.0.contents;
+
+                Diagnostic::simple_warning(
+                    format!("{item_type} {name} is never used"),
+                    format!("unused {item_type}"),
+                    ident.span(),
                 )
             }
+            ResolverError::UnreadField { field, struct_name } => Diagnostic::simple_warning(
+                format!("field {field} of struct {struct_name} is never read"),
+                "unread field".to_string(),
+                field.span(),
+            ),
             ResolverError::VariableNotDeclared { name, span } => Diagnostic::simple_error(
                 format!("cannot find `{name}` in this scope "),
                 "not found in this scope".to_string(),
@@ -148,10 +292,29 @@ impl From<ResolverError> for Diagnostic {
                 );
                 error
             }
-            ResolverError::MissingFields { span, missing_fields, struct_definition } => {
+            ResolverError::MissingFields {
+                span,
+                missing_fields,
+                struct_definition,
+                fields_end,
+                has_fields,
+                is_pattern,
+            } => {
                 let plural = if missing_fields.len() != 1 { "s" } else { "" };
-                let missing_fields = missing_fields.join(", ");
 
+                // Missing fields of a pattern can be ignored with a wildcard, whereas the
+                // values of missing fields in a constructor have to be filled in by the user.
+                let (placeholder, applicability) = if is_pattern {
+                    ("_", Applicability::MachineApplicable)
+                } else {
+                    ("std::default::Default::default()", Applicability::HasPlaceholders)
+                };
+                let new_fields = vecmap(&missing_fields, |field| format!("{field}: {placeholder}"));
+                let new_fields = new_fields.join(", ");
+                let replacement =
+                    if has_fields { format!(", {new_fields}") } else { format!(" {new_fields} ") };
+
+                let missing_fields = missing_fields.join(", ");
                 let mut error = Diagnostic::simple_error(
                     format!("missing field{plural}: {missing_fields}"),
                     String::new(),
@@ -162,6 +325,12 @@ impl From<ResolverError> for Diagnostic {
                     format!("{struct_definition} defined here"),
                     struct_definition.span(),
                 );
+                error.add_suggestion(
+                    format!("add the missing field{plural}"),
+                    fields_end,
+                    replacement,
+                    applicability,
+                );
                 error
             }
             ResolverError::UnnecessaryMut { first_mut, second_mut } => {
@@ -174,9 +343,15 @@ impl From<ResolverError> for Diagnostic {
                     "Pattern was already made mutable from this 'mut'".to_owned(),
                     first_mut,
                 );
+                error.add_suggestion(
+                    "remove the unnecessary 'mut'".to_owned(),
+                    second_mut,
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
                 error
             }
-            ResolverError::UnnecessaryPub { ident, position } => {
+            ResolverError::UnnecessaryPub { ident, position, pub_span } => {
                 let name = &ident.0.contents;
 
                 let mut diag = Diagnostic::simple_warning(
@@ -186,9 +361,21 @@ impl From<ResolverError> for Diagnostic {
                 );
 
                 diag.add_note("The `pub` keyword only has effects on arguments to the entry-point function of a program. Thus, adding it to other function parameters can be deceiving and should be removed".to_owned());
+                if let Some(pub_span) = pub_span {
+                    let replacement = match position {
+                        PubPosition::Parameter => ": ",
+                        PubPosition::ReturnType => "",
+                    };
+                    diag.add_suggestion(
+                        "remove the `pub` keyword".to_owned(),
+                        pub_span,
+                        replacement.to_owned(),
+                        Applicability::MachineApplicable,
+                    );
+                }
                 diag
             }
-            ResolverError::NecessaryPub { ident } => {
+            ResolverError::NecessaryPub { ident, return_type_span } => {
                 let name = &ident.0.contents;
 
                 let mut diag = Diagnostic::simple_error(
@@ -198,6 +385,14 @@ impl From<ResolverError> for Diagnostic {
                 );
 
                 diag.add_note("The `pub` keyword is mandatory for the entry-point function return type because the verifier cannot retrieve private witness and thus the function will not be able to return a 'priv' value".to_owned());
+                if let Some(return_type_span) = return_type_span {
+                    diag.add_suggestion(
+                        "add the `pub` keyword".to_owned(),
+                        Span::empty(return_type_span.start()),
+                        "pub ".to_owned(),
+                        Applicability::MachineApplicable,
+                    );
+                }
                 diag
             }
             ResolverError::DistinctNotAllowed { ident } => {
@@ -294,16 +489,69 @@ impl From<ResolverError> for Diagnostic {
             ResolverError::InvalidClosureEnvironment { span, typ } => Diagnostic::simple_error(
                 format!("{typ} is not a valid closure environment type"),
                 "Closure environment must be a tuple or unit type".to_string(), span),
-            // This will be upgraded to an error in future versions
-            ResolverError::PrivateFunctionCalled { span, name } => Diagnostic::simple_warning(
-                format!("{name} is private and not visible from the current module"),
-                format!("{name} is private"), span),
-            ResolverError::NonCrateFunctionCalled { span, name } => Diagnostic::simple_warning(
-                    format!("{name} is not visible from the current crate"),
-                    format!("{name} is only visible within its crate"), span),
             ResolverError::InvalidTypeForEntryPoint { span } => Diagnostic::simple_error(
                 "Only sized types may be used in the entry point to a program".to_string(),
                 "Slices, references, or any type containing them may not be used in main or a contract function".to_string(), span),
-        }
+            ResolverError::IncorrectVariantArgumentCount { span, variant, actual, expected } => {
+                let plural = if expected == 1 { "" } else { "s" };
+                Diagnostic::simple_error(
+                    format!("The variant {variant} holds {expected} value{plural} but this pattern has {actual}"),
+                    "Incorrect number of arguments".into(),
+                    span,
+                )
+            }
+            ResolverError::RefutablePattern { span } => Diagnostic::simple_error(
+                "Refutable pattern in a binding which must always match".into(),
+                "This pattern may not match every value of its type, try using `match` instead".into(),
+                span,
+            ),
+            ResolverError::LoopInConstrainedFn { span } => Diagnostic::simple_error(
+                "`while` loops are only allowed in unconstrained functions".into(),
+                "Constrained code must be unrolled; use a `for` loop with a constant range instead".into(),
+                span,
+            ),
+            ResolverError::JumpInConstrainedFn { is_break, span } => {
+                let item = if is_break { "break" } else { "continue" };
+                Diagnostic::simple_error(
+                    format!("`{item}` is only allowed in unconstrained functions"),
+                    "Constrained code must be unrolled so loops cannot exit early".into(),
+                    span,
+                )
+            }
+            ResolverError::JumpOutsideLoop { is_break, span } => {
+                let item = if is_break { "break" } else { "continue" };
+                Diagnostic::simple_error(
+                    format!("`{item}` is only allowed within loops"),
+                    String::new(),
+                    span,
+                )
+            }
+            ResolverError::UnknownLint { name, span } => {
+                let mut diag = Diagnostic::simple_warning(
+                    format!("unknown lint `{name}`"),
+                    "unknown lint".to_string(),
+                    span,
+                );
+                let lints = vecmap(Lint::ALL, |lint| format!("`{lint}`"));
+                diag.add_note(format!("the known lints are {}", lints.join(", ")));
+                diag
+            }
+            ResolverError::InlineNeverInConstrainedFn { ident } => {
+                let mut diag = Diagnostic::simple_warning(
+                    format!("`#[inline(never)]` has no effect on calls to `{ident}` from constrained code"),
+                    "this function is constrained".to_string(),
+                    ident.span(),
+                );
+                diag.add_note("ACIR cannot call functions, so calls between constrained functions are always inlined".to_string());
+                diag.add_note("the attribute only applies where the function is called from unconstrained code".to_string());
+                diag
+            }
+            ResolverError::SelfReferentialEnum { name } => Diagnostic::simple_error(
+                format!("Enum `{name}` contains itself"),
+                "An enum cannot hold a value of its own type, directly or through other types".into(),
+                name.span(),
+            ),
+        };
+        diagnostic.with_code(code)
     }
 }
@@ -24,13 +24,14 @@ pub(crate) fn resolve_function_set(
     mut unresolved_functions: UnresolvedFunctions,
     self_type: Option<Type>,
     trait_impl_id: Option<TraitImplId>,
+    associated_types: &[Type],
     impl_generics: Vec<(Rc<String>, TypeVariable, Span)>,
     errors: &mut Vec<(CompilationError, FileId)>,
 ) -> Vec<(FileId, FuncId)> {
     let file_id = unresolved_functions.file_id;
 
     let where_clause_errors =
-        unresolved_functions.resolve_trait_bounds_trait_ids(def_maps, crate_id);
+        unresolved_functions.resolve_trait_bounds_trait_ids(interner, def_maps, crate_id);
     errors.extend(where_clause_errors.iter().cloned().map(|e| (e.into(), file_id)));
 
     vecmap(unresolved_functions.functions, |(mod_id, func_id, func)| {
@@ -45,6 +46,9 @@ pub(crate) fn resolve_function_set(
         resolver.set_self_type(self_type.clone());
         resolver.set_trait_id(unresolved_functions.trait_id);
         resolver.set_trait_impl_id(trait_impl_id);
+        if let Some(trait_id) = unresolved_functions.trait_id {
+            resolver.add_impl_associated_types(trait_id, associated_types.to_vec());
+        }
 
         // Without this, impl methods can accidentally be placed in contracts. See #3254
         if self_type.is_some() {
@@ -77,6 +81,7 @@ pub(crate) fn resolve_free_functions(
                 unresolved_functions,
                 self_type.clone(),
                 None,
+                &[],
                 vec![], // no impl generics
                 errors,
             )
@@ -5,13 +5,16 @@ use crate::graph::CrateId;
 use std::collections::BTreeMap;
 
 use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleDefId, ModuleId, PerNs};
-use crate::{Ident, Path, PathKind};
+use crate::{Ident, ItemVisibility, Path, PathKind};
 
 #[derive(Debug, Clone)]
 pub struct ImportDirective {
     pub module_id: LocalModuleId,
     pub path: Path,
     pub alias: Option<Ident>,
+    /// The visibility of the imported name within `module_id`, which is only
+    /// visible outside of it when re-exported with `pub use`.
+    pub visibility: ItemVisibility,
     pub is_prelude: bool,
 }
 
@@ -21,6 +24,7 @@ pub type PathResolution = Result<PerNs, PathResolutionError>;
 pub enum PathResolutionError {
     Unresolved(Ident),
     ExternalContractUsed(Ident),
+    Private(Ident),
 }
 
 #[derive(Debug)]
@@ -31,12 +35,27 @@ pub struct ResolvedImport {
     pub resolved_namespace: PerNs,
     // The module which we must add the resolved namespace to
     pub module_scope: LocalModuleId,
+    // The path which was imported, so that the imports it goes through can be marked as used
+    pub path: Path,
+    pub visibility: ItemVisibility,
     pub is_prelude: bool,
 }
 
+impl PathResolutionError {
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            PathResolutionError::Unresolved(_) => "E0120",
+            PathResolutionError::ExternalContractUsed(_) => "E0121",
+            PathResolutionError::Private(_) => "E0122",
+        }
+    }
+}
+
 impl From<PathResolutionError> for CustomDiagnostic {
     fn from(error: PathResolutionError) -> Self {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             PathResolutionError::Unresolved(ident) => CustomDiagnostic::simple_error(
                 format!("Could not resolve '{ident}' in path"),
                 String::new(),
@@ -47,7 +66,13 @@ impl From<PathResolutionError> for CustomDiagnostic {
                 "Contracts may only be referenced from within a contract".to_string(),
                 ident.span(),
             ),
-        }
+            PathResolutionError::Private(ident) => CustomDiagnostic::simple_error(
+                format!("{ident} is private and not visible from the current module"),
+                format!("{ident} is private"),
+                ident.span(),
+            ),
+        };
+        diagnostic.with_code(code)
     }
 }
 
@@ -63,15 +88,23 @@ pub fn resolve_imports(
             allow_referencing_contracts(def_maps, crate_id, import_directive.module_id);
 
         let module_scope = import_directive.module_id;
-        let resolved_namespace =
-            resolve_path_to_ns(&import_directive, def_map, def_maps, allow_contracts)
-                .map_err(|error| (error, module_scope))?;
+        let importing_module = ModuleId { krate: crate_id, local_id: module_scope };
+        let resolved_namespace = resolve_path_to_ns(
+            &import_directive,
+            importing_module,
+            def_map,
+            def_maps,
+            allow_contracts,
+        )
+        .map_err(|error| (error, module_scope))?;
 
         let name = resolve_path_name(&import_directive);
         Ok(ResolvedImport {
             name,
             resolved_namespace,
             module_scope,
+            path: import_directive.path,
+            visibility: import_directive.visibility,
             is_prelude: import_directive.is_prelude,
         })
     })
@@ -85,8 +118,11 @@ pub(super) fn allow_referencing_contracts(
     ModuleId { krate, local_id }.module(def_maps).is_contract
 }
 
+/// Resolves the path of the given import directive. `importing_module` is the module the
+/// path is written in, which every item along the path must be visible from.
 pub fn resolve_path_to_ns(
     import_directive: &ImportDirective,
+    importing_module: ModuleId,
     def_map: &CrateDefMap,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
@@ -96,11 +132,21 @@ pub fn resolve_path_to_ns(
     match import_directive.path.kind {
         crate::ast::PathKind::Crate => {
             // Resolve from the root of the crate
-            resolve_path_from_crate_root(def_map, import_path, def_maps, allow_contracts)
-        }
-        crate::ast::PathKind::Dep => {
-            resolve_external_dep(def_map, import_directive, def_maps, allow_contracts)
+            resolve_path_from_crate_root(
+                def_map,
+                import_path,
+                importing_module,
+                def_maps,
+                allow_contracts,
+            )
         }
+        crate::ast::PathKind::Dep => resolve_external_dep(
+            def_map,
+            import_directive,
+            importing_module,
+            def_maps,
+            allow_contracts,
+        ),
         crate::ast::PathKind::Plain => {
             // Plain paths are only used to import children modules. It's possible to allow import of external deps, but maybe this distinction is better?
             // In Rust they can also point to external Dependencies, if no children can be found with the specified name
@@ -108,6 +154,7 @@ pub fn resolve_path_to_ns(
                 def_map,
                 import_path,
                 import_directive.module_id,
+                importing_module,
                 def_maps,
                 allow_contracts,
             )
@@ -118,16 +165,25 @@ pub fn resolve_path_to_ns(
 fn resolve_path_from_crate_root(
     def_map: &CrateDefMap,
     import_path: &[Ident],
+    importing_module: ModuleId,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
 ) -> PathResolution {
-    resolve_name_in_module(def_map, import_path, def_map.root, def_maps, allow_contracts)
+    resolve_name_in_module(
+        def_map,
+        import_path,
+        def_map.root,
+        importing_module,
+        def_maps,
+        allow_contracts,
+    )
 }
 
 fn resolve_name_in_module(
     def_map: &CrateDefMap,
     import_path: &[Ident],
     starting_mod: LocalModuleId,
+    importing_module: ModuleId,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
 ) -> PathResolution {
@@ -147,6 +203,9 @@ fn resolve_name_in_module(
         return Err(PathResolutionError::Unresolved(first_segment.clone()));
     }
 
+    let starting_mod = ModuleId { krate: def_map.krate, local_id: starting_mod };
+    check_visibility(first_segment, current_ns, starting_mod, importing_module, def_maps)?;
+
     for segment in import_path {
         let typ = match current_ns.take_types() {
             None => return Err(PathResolutionError::Unresolved(segment.clone())),
@@ -164,6 +223,19 @@ fn resolve_name_in_module(
             ModuleDefId::GlobalId(_) => panic!("globals cannot be in the type namespace"),
         };
 
+        // Methods and variants live in a module of their own, but their visibility is
+        // relative to the module the type was declared in.
+        let owning_module = match typ {
+            ModuleDefId::TypeId(_) | ModuleDefId::TraitId(_) => {
+                let parent = new_module_id.module(def_maps).parent;
+                ModuleId {
+                    krate: new_module_id.krate,
+                    local_id: parent.unwrap_or(new_module_id.local_id),
+                }
+            }
+            _ => new_module_id,
+        };
+
         current_mod = &def_maps[&new_module_id.krate].modules[new_module_id.local_id.0];
 
         // Check if namespace
@@ -172,6 +244,7 @@ fn resolve_name_in_module(
         if found_ns.is_none() {
             return Err(PathResolutionError::Unresolved(segment.clone()));
         }
+        check_visibility(segment, found_ns, owning_module, importing_module, def_maps)?;
         // Check if it is a contract and we're calling from a non-contract context
         if current_mod.is_contract && !allow_contracts {
             return Err(PathResolutionError::ExternalContractUsed(segment.clone()));
@@ -182,6 +255,54 @@ fn resolve_name_in_module(
     Ok(current_ns)
 }
 
+/// Issues an error if an item named `name` declared in `owning_module` with the visibility
+/// stored in `ns` may not be referred to from `importing_module`.
+fn check_visibility(
+    name: &Ident,
+    ns: PerNs,
+    owning_module: ModuleId,
+    importing_module: ModuleId,
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+) -> Result<(), PathResolutionError> {
+    let same_crate = owning_module.krate == importing_module.krate;
+
+    // If a name is used in both namespaces it is enough for either item to be visible
+    let visible = ns.iter_items().any(|(_, visibility, _)| match visibility {
+        ItemVisibility::Public => true,
+        ItemVisibility::PublicCrate => same_crate,
+        ItemVisibility::Private => {
+            same_crate
+                && module_descendent_of_target(
+                    &def_maps[&owning_module.krate],
+                    owning_module.local_id,
+                    importing_module.local_id,
+                )
+        }
+    });
+
+    if visible {
+        Ok(())
+    } else {
+        Err(PathResolutionError::Private(name.clone()))
+    }
+}
+
+/// Returns true if `current` is a (potentially nested) child module of `target`.
+/// This is also true if `current == target`.
+fn module_descendent_of_target(
+    def_map: &CrateDefMap,
+    target: LocalModuleId,
+    current: LocalModuleId,
+) -> bool {
+    if current == target {
+        return true;
+    }
+
+    def_map.modules[current.0]
+        .parent
+        .map_or(false, |parent| module_descendent_of_target(def_map, target, parent))
+}
+
 fn resolve_path_name(import_directive: &ImportDirective) -> Ident {
     match &import_directive.alias {
         None => import_directive.path.segments.last().unwrap().clone(),
@@ -192,6 +313,7 @@ fn resolve_path_name(import_directive: &ImportDirective) -> Ident {
 fn resolve_external_dep(
     current_def_map: &CrateDefMap,
     directive: &ImportDirective,
+    importing_module: ModuleId,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
 ) -> PathResolution {
@@ -218,10 +340,11 @@ fn resolve_external_dep(
         module_id: dep_module.local_id,
         path,
         alias: directive.alias.clone(),
+        visibility: directive.visibility,
         is_prelude: false,
     };
 
     let dep_def_map = def_maps.get(&dep_module.krate).unwrap();
 
-    resolve_path_to_ns(&dep_directive, dep_def_map, def_maps, allow_contracts)
+    resolve_path_to_ns(&dep_directive, importing_module, dep_def_map, def_maps, allow_contracts)
 }
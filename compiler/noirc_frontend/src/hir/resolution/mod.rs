@@ -10,6 +10,7 @@ pub mod import;
 pub mod path_resolver;
 pub mod resolver;
 
+mod enums;
 mod functions;
 mod globals;
 mod impls;
@@ -17,6 +18,7 @@ mod structs;
 mod traits;
 mod type_aliases;
 
+pub(crate) use enums::resolve_enums;
 pub(crate) use functions::resolve_free_functions;
 pub(crate) use globals::resolve_globals;
 pub(crate) use impls::{collect_impls, resolve_impls};
@@ -1,7 +1,7 @@
 use super::import::{
     allow_referencing_contracts, resolve_path_to_ns, ImportDirective, PathResolutionError,
 };
-use crate::Path;
+use crate::{ItemVisibility, Path};
 use std::collections::BTreeMap;
 
 use crate::graph::CrateId;
@@ -57,13 +57,19 @@ pub fn resolve_path(
     path: Path,
 ) -> Result<ModuleDefId, PathResolutionError> {
     // lets package up the path into an ImportDirective and resolve it using that
-    let import =
-        ImportDirective { module_id: module_id.local_id, path, alias: None, is_prelude: false };
+    let import = ImportDirective {
+        module_id: module_id.local_id,
+        path,
+        alias: None,
+        visibility: ItemVisibility::Private,
+        is_prelude: false,
+    };
     let allow_referencing_contracts =
         allow_referencing_contracts(def_maps, module_id.krate, module_id.local_id);
 
     let def_map = &def_maps[&module_id.krate];
-    let ns = resolve_path_to_ns(&import, def_map, def_maps, allow_referencing_contracts)?;
+    let ns =
+        resolve_path_to_ns(&import, module_id, def_map, def_maps, allow_referencing_contracts)?;
 
     let function = ns.values.map(|(id, _, _)| id);
     let id = function.or_else(|| ns.types.map(|(id, _, _)| id));
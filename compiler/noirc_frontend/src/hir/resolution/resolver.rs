@@ -13,20 +13,22 @@
 // XXX: Resolver does not check for unused functions
 use crate::hir_def::expr::{
     HirArrayLiteral, HirBinaryOp, HirBlockExpression, HirCallExpression, HirCapturedVar,
-    HirCastExpression, HirConstructorExpression, HirExpression, HirIdent, HirIfExpression,
-    HirIndexExpression, HirInfixExpression, HirLambda, HirLiteral, HirMemberAccess,
-    HirMethodCallExpression, HirPrefixExpression,
+    HirCastExpression, HirConstructorExpression, HirEnumConstructorExpression, HirExpression,
+    HirIdent, HirIfExpression, HirIndexExpression, HirInfixExpression, HirLambda, HirLiteral,
+    HirMatchExpression, HirMemberAccess, HirMethodCallExpression, HirPrefixExpression,
 };
 
 use crate::hir_def::traits::{Trait, TraitConstraint};
-use crate::token::{Attributes, FunctionAttribute};
+use crate::token::{Attributes, FunctionAttribute, InlineType, SecondaryAttribute};
 use regex::Regex;
 use std::collections::{BTreeMap, HashSet};
 use std::rc::Rc;
 
 use crate::graph::CrateId;
-use crate::hir::def_map::{LocalModuleId, ModuleDefId, TryFromModuleDefId, MAIN_FUNCTION};
-use crate::hir_def::stmt::{HirAssignStatement, HirForStatement, HirLValue, HirPattern};
+use crate::hir::def_map::{ModuleDefId, TryFromModuleDefId, MAIN_FUNCTION};
+use crate::hir_def::stmt::{
+    HirAssignStatement, HirForStatement, HirLValue, HirPattern, HirWhileStatement,
+};
 use crate::node_interner::{
     DefinitionId, DefinitionKind, ExprId, FuncId, NodeInterner, StmtId, StructId, TraitId,
     TraitImplId, TraitImplKind,
@@ -34,14 +36,14 @@ use crate::node_interner::{
 use crate::{
     hir::{def_map::CrateDefMap, resolution::path_resolver::PathResolver},
     BlockExpression, Expression, ExpressionKind, FunctionKind, Ident, Literal, NoirFunction,
-    StatementKind,
+    Statement, StatementKind,
 };
 use crate::{
-    ArrayLiteral, ContractFunctionType, Distinctness, ForRange, FunctionDefinition,
-    FunctionReturnType, FunctionVisibility, Generics, LValue, NoirStruct, NoirTypeAlias, Param,
-    Path, PathKind, Pattern, Shared, StructType, Type, TypeAliasType, TypeBinding, TypeVariable,
-    UnaryOp, UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData,
-    UnresolvedTypeExpression, Visibility, ERROR_IDENT,
+    ArrayLiteral, ContractFunctionType, Distinctness, EnumVariant, ForRange, FunctionDefinition,
+    FunctionReturnType, Generics, ItemVisibility, LValue, NoirEnum, NoirStruct, NoirTypeAlias,
+    Param, Path, PathKind, Pattern, Shared, StructType, Type, TypeAliasType, TypeBinding,
+    TypeVariable, TypeVariableKind, UnaryOp, UnresolvedGenerics, UnresolvedTraitConstraint,
+    UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression, Visibility, ERROR_IDENT,
 };
 use fm::FileId;
 use iter_extended::vecmap;
@@ -107,10 +109,38 @@ pub struct Resolver<'a> {
     /// were declared in.
     generics: Vec<(Rc<String>, TypeVariable, Span)>,
 
+    /// The associated types of traits which are in scope, keyed by their full name such as
+    /// `Self::Output` within a trait or its impls, or `H::Output` within a function with a
+    /// `where H: Hasher` clause. Within a trait impl these are the types assigned by the impl,
+    /// otherwise they are generics standing in for the types of whichever impl is used.
+    associated_types: Vec<(Rc<String>, Type)>,
+
+    /// The associated constants of traits which are in scope, in the same way as
+    /// `associated_types`. Their values are type-level integers so that they can be
+    /// used as array lengths as well as within expressions.
+    associated_constants: Vec<(Rc<String>, Type)>,
+
     /// When resolving lambda expressions, we need to keep track of the variables
     /// that are captured. We do this in order to create the hidden environment
     /// parameter for the lambda function.
     lambda_stack: Vec<LambdaContext>,
+
+    /// True if we're resolving the body of an unconstrained function or `comptime` code.
+    /// `while` loops, `break` and `continue` are only permitted in these
+    /// since constrained code requires every loop to be unrolled.
+    unbounded_loops_allowed: bool,
+
+    /// The number of loops enclosing the statement currently being resolved.
+    /// Used to reject `break` and `continue` outside of a loop.
+    loop_depth: usize,
+
+    /// The spans of variables bound by the shorthand form of a struct pattern such as
+    /// `Foo { x }`, which can't be renamed without also naming the field they are bound to.
+    field_shorthands: HashSet<Span>,
+
+    /// The spans of the parameters of entry point functions. Their names are the names of the
+    /// program's inputs, so renaming them would break any existing inputs to the program.
+    entry_point_parameters: Vec<Span>,
 }
 
 /// ResolverMetas are tagged onto each definition to track how many times they are used
@@ -145,11 +175,17 @@ impl<'a> Resolver<'a> {
             interner,
             self_type: None,
             generics: Vec::new(),
+            associated_types: Vec::new(),
+            associated_constants: Vec::new(),
             errors: Vec::new(),
             lambda_stack: Vec::new(),
             current_trait_impl: None,
             file,
             in_contract,
+            unbounded_loops_allowed: false,
+            loop_depth: 0,
+            field_shorthands: HashSet::new(),
+            entry_point_parameters: Vec::new(),
         }
     }
 
@@ -190,6 +226,11 @@ impl<'a> Resolver<'a> {
 
         self.add_generics(&func.def.generics);
         self.trait_bounds = func.def.where_clause.clone();
+        self.add_where_clause_associated_generics(&func.def.where_clause);
+
+        let name_span = func.name_ident().span();
+        let span = name_span.merge(func.def.span);
+        self.set_lint_levels(&func.def.attributes.secondary, span, name_span);
 
         let (hir_func, func_meta) = self.intern_function(func, func_id);
         let func_scope_tree = self.scopes.end_function();
@@ -214,6 +255,7 @@ impl<'a> Resolver<'a> {
         self.resolve_local_globals();
 
         self.trait_bounds = where_clause.to_vec();
+        self.add_where_clause_associated_generics(where_clause);
 
         let kind = FunctionKind::Normal;
         let def = FunctionDefinition {
@@ -222,8 +264,9 @@ impl<'a> Resolver<'a> {
             is_open: false,
             is_internal: false,
             is_unconstrained: false,
-            visibility: FunctionVisibility::Public, // Trait functions are always public
-            generics: Vec::new(),                   // self.generics should already be set
+            is_comptime: false,
+            visibility: ItemVisibility::Public, // Trait functions are always public
+            generics: Vec::new(),               // self.generics should already be set
             parameters: vecmap(parameters, |(name, typ)| Param {
                 visibility: Visibility::Private,
                 pattern: Pattern::Identifier(name.clone()),
@@ -235,6 +278,7 @@ impl<'a> Resolver<'a> {
             where_clause: where_clause.to_vec(),
             return_type: return_type.clone(),
             return_visibility: Visibility::Private,
+            return_visibility_span: None,
             return_distinctness: Distinctness::DuplicationAllowed,
         };
 
@@ -255,8 +299,16 @@ impl<'a> Resolver<'a> {
             if let Some(definition_info) = self.interner.try_definition(unused_var.id) {
                 let name = &definition_info.name;
                 if name != ERROR_IDENT && !definition_info.is_global() {
-                    let ident = Ident(Spanned::from(unused_var.location.span, name.to_owned()));
-                    self.push_err(ResolverError::UnusedVariable { ident });
+                    let span = unused_var.location.span;
+                    let ident = Ident(Spanned::from(span, name.to_owned()));
+                    let is_field_shorthand = self.field_shorthands.contains(&span);
+                    let is_entry_point_parameter =
+                        self.entry_point_parameters.iter().any(|param| param.contains(&span));
+                    self.push_err(ResolverError::UnusedVariable {
+                        ident,
+                        is_field_shorthand,
+                        is_entry_point_parameter,
+                    });
                 }
             }
         }
@@ -405,6 +457,8 @@ impl<'a> Resolver<'a> {
                 HirFunction::empty()
             }
             FunctionKind::Normal => {
+                self.unbounded_loops_allowed =
+                    func.def.is_unconstrained || func.def.is_open || func.def.is_comptime;
                 let expr_id = self.intern_block(func.def.body);
                 self.interner.push_expr_location(expr_id, func.def.span, self.file);
                 HirFunction::unchecked_from_expr(expr_id)
@@ -420,7 +474,7 @@ impl<'a> Resolver<'a> {
     ) -> Option<TraitConstraint> {
         let typ = self.resolve_type(constraint.typ);
         let trait_id = self.lookup_trait_or_error(constraint.trait_bound.trait_path)?.id;
-        Some(TraitConstraint { typ, trait_id })
+        Some(TraitConstraint::new(typ, trait_id))
     }
 
     /// Translates an UnresolvedType into a Type and appends any
@@ -602,10 +656,16 @@ impl<'a> Resolver<'a> {
             }
         }
 
+        if let Some(typ) = self.lookup_associated_type(path) {
+            return Some(typ);
+        }
+
         // If we cannot find a local generic of the same name, try to look up a global
-        match self.path_resolver.resolve(self.def_maps, path.clone()) {
+        match self.resolve_path(path.clone()) {
             Ok(ModuleDefId::GlobalId(id)) => {
-                Some(Type::Constant(self.eval_global_as_array_length(id)))
+                let definition_id = self.interner.let_statement(&id).ident().id;
+                self.interner.usage_tracker_mut().mark_definition_as_used(definition_id);
+                Some(self.eval_global_as_array_length(id))
             }
             _ => None,
         }
@@ -657,14 +717,35 @@ impl<'a> Resolver<'a> {
                     }
                 }
             }
+            UnresolvedTypeExpression::Comptime(block, span) => {
+                // Types may appear outside of functions, and comptime code within them
+                // cannot refer to the local variables around them anyway.
+                self.scopes.start_function();
+                let block = self.in_loop_context(true, |this| this.resolve_block(*block));
+                self.scopes.end_function();
+                let expression = self.interner.push_expr(HirExpression::Comptime(block));
+                self.interner.push_expr_location(expression, span, self.file);
+                self.comptime_array_length(expression, false)
+            }
         }
     }
 
+    /// The length of an array given by comptime code is evaluated once the comptime functions
+    /// it may call have been type checked, so it is a type variable until then.
+    fn comptime_array_length(&mut self, expression: ExprId, is_global: bool) -> Type {
+        let length = self.interner.comptime_array_length(expression, is_global);
+        Type::TypeVariable(length, TypeVariableKind::Normal)
+    }
+
     fn get_ident_from_path(&mut self, path: Path) -> (HirIdent, usize) {
         let location = Location::new(path.span(), self.file);
 
         let error = match path.as_ident().map(|ident| self.find_variable(ident)) {
-            Some(Ok(found)) => return found,
+            Some(Ok(found)) => {
+                // Globals of the current module are found in scope rather than by path
+                self.interner.usage_tracker_mut().mark_definition_as_used(found.0.id);
+                return found;
+            }
             // Try to look it up as a global, but still issue the first error if we fail
             Some(Err(error)) => match self.lookup_global(path) {
                 Ok(id) => return (HirIdent { location, id }, 0),
@@ -723,6 +804,132 @@ impl<'a> Resolver<'a> {
         }
     }
 
+    /// Brings `Self::Output` into scope for each associated type and constant of the given
+    /// trait while resolving the trait's own methods. Each impl binds these generics to the
+    /// types it assigns when its methods are checked against the trait's.
+    pub fn add_trait_associated_generics(&mut self, trait_id: TraitId) {
+        let the_trait = self.interner.get_trait(trait_id);
+        let types = vecmap(the_trait.associated_type_variables(), |(name, _, typevar)| {
+            Type::NamedGeneric(typevar, Rc::new(associated_item_name(SELF_TYPE_NAME, name)))
+        });
+        self.add_associated_types(trait_id, SELF_TYPE_NAME, types);
+    }
+
+    /// Brings `Self::Output` into scope for each associated type and constant of the given
+    /// trait, referring to the types assigned by the trait impl currently being resolved.
+    pub fn add_impl_associated_types(&mut self, trait_id: TraitId, types: Vec<Type>) {
+        self.add_associated_types(trait_id, SELF_TYPE_NAME, types);
+    }
+
+    /// Declares an implicit generic such as `H::Output` for each associated type and constant
+    /// of each trait which bounds a generic in the given where clause, e.g. `where H: Hasher`.
+    /// Since these are part of the function's generics they are instantiated at each call site,
+    /// where the type checker binds them to the types of the impl which is used.
+    fn add_where_clause_associated_generics(&mut self, where_clause: &[UnresolvedTraitConstraint]) {
+        for constraint in where_clause {
+            let (generic, span) = match &constraint.typ.typ {
+                UnresolvedTypeData::Named(path, args)
+                    if path.segments.len() == 1 && args.is_empty() =>
+                {
+                    (path.segments[0].0.contents.clone(), path.span())
+                }
+                _ => continue,
+            };
+
+            let the_trait = match constraint
+                .trait_bound
+                .trait_id
+                .and_then(|id| self.interner.try_get_trait(id))
+            {
+                Some(the_trait) => the_trait,
+                None => continue,
+            };
+
+            if self.find_generic(&generic).is_none() {
+                continue;
+            }
+
+            let trait_id = the_trait.id;
+            let names = vecmap(the_trait.associated_type_variables(), |(name, _, _)| {
+                Rc::new(associated_item_name(&generic, name))
+            });
+
+            let types = vecmap(names, |name| {
+                let id = self.interner.next_type_variable_id();
+                let typevar = TypeVariable::unbound(id);
+                self.generics.push((name.clone(), typevar.clone(), span));
+                Type::NamedGeneric(typevar, name)
+            });
+            self.add_associated_types(trait_id, &generic, types);
+        }
+    }
+
+    /// Brings the associated items of a trait into scope under the given object name, e.g.
+    /// `Self` or `H`. `types` contains the type of each associated type followed by the value
+    /// of each associated constant, in the order they are declared in the trait.
+    fn add_associated_types(&mut self, trait_id: TraitId, object: &str, mut types: Vec<Type>) {
+        let the_trait = self.interner.get_trait(trait_id);
+        let names = vecmap(the_trait.associated_type_variables(), |(name, _, _)| {
+            Rc::new(associated_item_name(object, name))
+        });
+
+        let type_count = the_trait.types.len().min(types.len());
+        let constants = types.split_off(type_count);
+        let (type_names, constant_names) = names.split_at(type_count);
+        self.associated_types.extend(type_names.iter().cloned().zip(types));
+        self.associated_constants.extend(constant_names.iter().cloned().zip(constants));
+    }
+
+    /// Looks up the associated type or constant referred to by a path such as `Self::Output`
+    fn lookup_associated_type(&self, path: &Path) -> Option<Type> {
+        let name = associated_item_path_name(path)?;
+        let mut items = self.associated_types.iter().chain(&self.associated_constants);
+        items.find(|(item, _)| item.as_ref() == &name).map(|(_, typ)| typ.clone())
+    }
+
+    /// Returns the types which the associated items of a trait bounding the given type must
+    /// be equal to, or an empty Vec if they are not in scope as they are for a generic type.
+    fn lookup_constraint_associated_types(
+        &self,
+        typ: &UnresolvedType,
+        trait_id: TraitId,
+    ) -> Vec<Type> {
+        let object = match &typ.typ {
+            UnresolvedTypeData::Named(path, args)
+                if path.segments.len() == 1 && args.is_empty() =>
+            {
+                &path.segments[0]
+            }
+            _ => return Vec::new(),
+        };
+
+        let the_trait = match self.interner.try_get_trait(trait_id) {
+            Some(the_trait) => the_trait,
+            None => return Vec::new(),
+        };
+
+        let types = the_trait.associated_type_variables().into_iter().map(|(name, _, _)| {
+            let path = Path {
+                segments: vec![object.clone(), name.clone()],
+                kind: PathKind::Plain,
+                span: object.span(),
+            };
+            self.lookup_associated_type(&path)
+        });
+        types.collect::<Option<Vec<_>>>().unwrap_or_default()
+    }
+
+    /// Resolves the value of an associated constant, which must be known at compile-time
+    /// in the same way as an array length.
+    pub fn resolve_associated_constant_value(&mut self, value: Expression) -> Type {
+        let span = value.span;
+        let value = UnresolvedTypeExpression::from_expr(value, span).unwrap_or_else(|error| {
+            self.errors.push(ResolverError::ParserError(Box::new(error)));
+            UnresolvedTypeExpression::Constant(0, span)
+        });
+        self.convert_expression_type(value)
+    }
+
     /// Add the given generics to scope.
     /// Each generic will have a fresh Shared<TypeBinding> associated with it.
     pub fn add_generics(&mut self, generics: &UnresolvedGenerics) -> Generics {
@@ -764,6 +971,70 @@ impl<'a> Resolver<'a> {
         (generics, fields, self.errors)
     }
 
+    pub fn resolve_enum_variants(
+        mut self,
+        unresolved: NoirEnum,
+    ) -> (Generics, Vec<EnumVariant>, Vec<ResolverError>) {
+        let generics = self.add_generics(&unresolved.generics);
+
+        // Check whether the enum definition has globals in the local module and add them to the scope
+        self.resolve_local_globals();
+
+        let variants = vecmap(unresolved.variants, |(name, params)| {
+            let params = vecmap(params, |typ| self.resolve_type(typ));
+            EnumVariant { name, params }
+        });
+
+        (generics, variants, self.errors)
+    }
+
+    /// Resolves the constructor function of an enum variant. These functions are
+    /// created during def collection and have no body, so the body is built here instead:
+    /// an `EnumConstructor` expression forwarding each parameter to the variant.
+    pub fn resolve_enum_variant_constructor(
+        mut self,
+        func: NoirFunction,
+        func_id: FuncId,
+        enum_type: Shared<StructType>,
+        variant_index: usize,
+    ) -> (HirFunction, FuncMeta, Vec<ResolverError>) {
+        self.scopes.start_function();
+        self.add_generics(&func.def.generics);
+
+        let func_meta = self.extract_meta(&func, func_id);
+
+        let arguments = vecmap(&func_meta.parameters.0, |(pattern, _, _)| match pattern {
+            HirPattern::Identifier(ident) => {
+                let expr_id = self.interner.push_expr(HirExpression::Ident(*ident));
+                self.interner.push_expr_location(expr_id, ident.location.span, self.file);
+                expr_id
+            }
+            _ => unreachable!("Enum variant constructors only have identifier parameters"),
+        });
+
+        let struct_generics = vecmap(&self.generics, |(name, typevar, _)| {
+            Type::NamedGeneric(typevar.clone(), name.clone())
+        });
+
+        let span = func.def.span;
+        let constructor =
+            self.interner.push_expr(HirExpression::EnumConstructor(HirEnumConstructorExpression {
+                r#type: enum_type,
+                struct_generics,
+                variant_index,
+                arguments,
+            }));
+        self.interner.push_expr_location(constructor, span, self.file);
+
+        let statement = self.interner.push_stmt(HirStatement::Expression(constructor));
+        let body =
+            self.interner.push_expr(HirExpression::Block(HirBlockExpression(vec![statement])));
+        self.interner.push_expr_location(body, span, self.file);
+
+        self.scopes.end_function();
+        (HirFunction::unchecked_from_expr(body), func_meta, self.errors)
+    }
+
     fn resolve_local_globals(&mut self) {
         for (stmt_id, global_info) in self.interner.get_all_globals() {
             if global_info.local_id == self.path_resolver.local_module_id() {
@@ -780,9 +1051,14 @@ impl<'a> Resolver<'a> {
         &mut self,
         where_clause: &Vec<UnresolvedTraitConstraint>,
     ) -> Vec<TraitConstraint> {
-        vecmap(where_clause, |constraint| TraitConstraint {
-            typ: self.resolve_type(constraint.typ.clone()),
-            trait_id: constraint.trait_bound.trait_id.unwrap_or_else(TraitId::dummy_id),
+        vecmap(where_clause, |constraint| {
+            let trait_id = constraint.trait_bound.trait_id.unwrap_or_else(TraitId::dummy_id);
+            TraitConstraint {
+                typ: self.resolve_type(constraint.typ.clone()),
+                trait_id,
+                associated_types: self
+                    .lookup_constraint_associated_types(&constraint.typ, trait_id),
+            }
         })
     }
 
@@ -810,14 +1086,17 @@ impl<'a> Resolver<'a> {
 
         for Param { visibility, pattern, typ, span: _ } in func.parameters().iter().cloned() {
             if visibility == Visibility::Public && !self.pub_allowed(func) {
+                let pattern_end = pattern.span().end();
                 self.push_err(ResolverError::UnnecessaryPub {
                     ident: func.name_ident().clone(),
                     position: PubPosition::Parameter,
+                    pub_span: typ.span.map(|typ_span| Span::from(pattern_end..typ_span.start())),
                 });
             }
 
             if self.is_entry_point_function(func) {
                 self.verify_type_valid_for_program_input(&typ);
+                self.entry_point_parameters.push(pattern.span());
             }
 
             let pattern = self.resolve_pattern(pattern, DefinitionKind::Local(None));
@@ -827,14 +1106,18 @@ impl<'a> Resolver<'a> {
             parameter_types.push(typ);
         }
 
+        let return_type_span = func.return_type().span;
         let return_type = Box::new(self.resolve_type(func.return_type()));
 
         self.declare_numeric_generics(&parameter_types, &return_type);
 
         if !self.pub_allowed(func) && func.def.return_visibility == Visibility::Public {
+            let pub_span = func.def.return_visibility_span.zip(return_type_span);
             self.push_err(ResolverError::UnnecessaryPub {
                 ident: func.name_ident().clone(),
                 position: PubPosition::ReturnType,
+                pub_span: pub_span
+                    .map(|(pub_span, typ_span)| Span::from(pub_span.start()..typ_span.start())),
             });
         }
 
@@ -843,7 +1126,10 @@ impl<'a> Resolver<'a> {
             && return_type.as_ref() != &Type::Unit
             && func.def.return_visibility == Visibility::Private
         {
-            self.push_err(ResolverError::NecessaryPub { ident: func.name_ident().clone() });
+            self.push_err(ResolverError::NecessaryPub {
+                ident: func.name_ident().clone(),
+                return_type_span,
+            });
         }
 
         if !self.distinct_allowed(func)
@@ -860,6 +1146,16 @@ impl<'a> Resolver<'a> {
             });
         }
 
+        // Open contract functions are compiled to Brillig, like unconstrained functions.
+        if attributes.inline_type() == Some(InlineType::Never)
+            && !func.def.is_unconstrained
+            && !func.def.is_open
+        {
+            self.push_err(ResolverError::InlineNeverInConstrainedFn {
+                ident: func.name_ident().clone(),
+            });
+        }
+
         let mut typ = Type::Function(parameter_types, return_type, Box::new(Type::Unit));
 
         if !generics.is_empty() {
@@ -1043,7 +1339,7 @@ impl<'a> Resolver<'a> {
         })
     }
 
-    pub fn resolve_stmt(&mut self, stmt: StatementKind) -> HirStatement {
+    pub fn resolve_stmt(&mut self, stmt: StatementKind, span: Span) -> HirStatement {
         match stmt {
             StatementKind::Let(let_stmt) => {
                 let expression = self.resolve_expression(let_stmt.expression);
@@ -1085,7 +1381,7 @@ impl<'a> Resolver<'a> {
                                 true,
                                 DefinitionKind::Local(None),
                             );
-                            (decl, this.resolve_expression(block))
+                            (decl, this.resolve_loop_body(block))
                         });
 
                         HirStatement::For(HirForStatement {
@@ -1098,19 +1394,84 @@ impl<'a> Resolver<'a> {
                     range @ ForRange::Array(_) => {
                         let for_stmt =
                             range.into_for(for_loop.identifier, for_loop.block, for_loop.span);
-                        self.resolve_stmt(for_stmt)
+                        self.resolve_stmt(for_stmt, span)
                     }
                 }
             }
+            StatementKind::While(while_loop) => {
+                if !self.unbounded_loops_allowed {
+                    self.push_err(ResolverError::LoopInConstrainedFn { span: while_loop.span });
+                }
+
+                let condition = self.resolve_expression(while_loop.condition);
+                let block = self.resolve_loop_body(while_loop.block);
+                HirStatement::While(HirWhileStatement { condition, block })
+            }
+            StatementKind::Break => {
+                self.check_loop_jump(true, span);
+                HirStatement::Break
+            }
+            StatementKind::Continue => {
+                self.check_loop_jump(false, span);
+                HirStatement::Continue
+            }
+            StatementKind::Attributed(attributes, statement) => {
+                self.set_lint_levels(&attributes, statement.span, span);
+                self.resolve_stmt(statement.kind, statement.span)
+            }
             StatementKind::Error => HirStatement::Error,
         }
     }
 
-    pub fn intern_stmt(&mut self, stmt: StatementKind) -> StmtId {
-        let hir_stmt = self.resolve_stmt(stmt);
+    /// Sets the lint levels given by any lint attributes in `attributes` for the code within
+    /// `span`, warning about any unknown lints at `warning_span`.
+    fn set_lint_levels(
+        &mut self,
+        attributes: &[SecondaryAttribute],
+        span: Span,
+        warning_span: Span,
+    ) {
+        let location = Location::new(span, self.file);
+        let unknown_lints =
+            self.interner.lint_levels_mut().set_from_attributes(attributes, location);
+        for name in unknown_lints {
+            self.push_err(ResolverError::UnknownLint { name, span: warning_span });
+        }
+    }
+
+    pub fn intern_stmt(&mut self, stmt: Statement) -> StmtId {
+        let hir_stmt = self.resolve_stmt(stmt.kind, stmt.span);
         self.interner.push_stmt(hir_stmt)
     }
 
+    fn resolve_loop_body(&mut self, body: Expression) -> ExprId {
+        self.loop_depth += 1;
+        let body = self.resolve_expression(body);
+        self.loop_depth -= 1;
+        body
+    }
+
+    /// Resolve `f` outside of any enclosing loop, with unbounded loops allowed or disallowed.
+    /// The previous loop context is restored afterward.
+    fn in_loop_context<T>(&mut self, allow_unbounded: bool, f: impl FnOnce(&mut Self) -> T) -> T {
+        let allowed = std::mem::replace(&mut self.unbounded_loops_allowed, allow_unbounded);
+        let loop_depth = std::mem::take(&mut self.loop_depth);
+        let result = f(self);
+        self.unbounded_loops_allowed = allowed;
+        self.loop_depth = loop_depth;
+        result
+    }
+
+    /// Issue an error if a `break` or `continue` is used outside of a loop
+    /// or within a constrained function.
+    fn check_loop_jump(&mut self, is_break: bool, span: Span) {
+        if !self.unbounded_loops_allowed {
+            self.push_err(ResolverError::JumpInConstrainedFn { is_break, span });
+        } else if self.loop_depth == 0 {
+            self.push_err(ResolverError::JumpOutsideLoop { is_break, span });
+        }
+    }
+
     fn resolve_lvalue(&mut self, lvalue: LValue) -> HirLValue {
         match lvalue {
             LValue::Ident(ident) => {
@@ -1135,59 +1496,6 @@ impl<'a> Resolver<'a> {
         }
     }
 
-    // Issue an error if the given private function is being called from a non-child module, or
-    // if the given pub(crate) function is being called from another crate
-    fn check_can_reference_function(
-        &mut self,
-        func: FuncId,
-        span: Span,
-        visibility: FunctionVisibility,
-    ) {
-        let function_module = self.interner.function_module(func);
-        let current_module = self.path_resolver.module_id();
-
-        let same_crate = function_module.krate == current_module.krate;
-        let krate = function_module.krate;
-        let current_module = current_module.local_id;
-        let name = self.interner.function_name(&func).to_string();
-        match visibility {
-            FunctionVisibility::Public => (),
-            FunctionVisibility::Private => {
-                if !same_crate
-                    || !self.module_descendent_of_target(
-                        krate,
-                        function_module.local_id,
-                        current_module,
-                    )
-                {
-                    self.errors.push(ResolverError::PrivateFunctionCalled { span, name });
-                }
-            }
-            FunctionVisibility::PublicCrate => {
-                if !same_crate {
-                    self.errors.push(ResolverError::NonCrateFunctionCalled { span, name });
-                }
-            }
-        }
-    }
-
-    // Returns true if `current` is a (potentially nested) child module of `target`.
-    // This is also true if `current == target`.
-    fn module_descendent_of_target(
-        &self,
-        krate: CrateId,
-        target: LocalModuleId,
-        current: LocalModuleId,
-    ) -> bool {
-        if current == target {
-            return true;
-        }
-
-        self.def_maps[&krate].modules[current.0]
-            .parent
-            .map_or(false, |parent| self.module_descendent_of_target(krate, target, parent))
-    }
-
     fn resolve_local_variable(&mut self, hir_ident: HirIdent, var_scope_index: usize) {
         let mut transitive_capture_index: Option<usize> = None;
 
@@ -1250,6 +1558,10 @@ impl<'a> Resolver<'a> {
                 Literal::Unit => HirLiteral::Unit,
             }),
             ExpressionKind::Variable(path) => {
+                if let Some(expr_id) = self.resolve_associated_constant(&path, expr.span) {
+                    return expr_id;
+                }
+
                 if let Some((hir_expr, object_type)) = self.resolve_trait_generic_path(&path) {
                     let expr_id = self.interner.push_expr(hir_expr);
                     self.interner.push_expr_location(expr_id, expr.span, self.file);
@@ -1266,15 +1578,10 @@ impl<'a> Resolver<'a> {
                     if hir_ident.id != DefinitionId::dummy_id() {
                         match self.interner.definition(hir_ident.id).kind {
                             DefinitionKind::Function(id) => {
-                                if self.interner.function_visibility(id)
-                                    != FunctionVisibility::Public
-                                {
-                                    let span = hir_ident.location.span;
-                                    self.check_can_reference_function(
-                                        id,
-                                        span,
-                                        self.interner.function_visibility(id),
-                                    );
+                                if let Some(variant) = self.resolve_unit_enum_variant(id) {
+                                    let expr_id = self.interner.push_expr(variant);
+                                    self.interner.push_expr_location(expr_id, expr.span, self.file);
+                                    return expr_id;
                                 }
                             }
                             DefinitionKind::Global(_) => {}
@@ -1349,21 +1656,46 @@ impl<'a> Resolver<'a> {
                 consequence: self.resolve_expression(if_expr.consequence),
                 alternative: if_expr.alternative.map(|e| self.resolve_expression(e)),
             }),
+            ExpressionKind::Match(match_expr) => {
+                let expression = self.resolve_expression(match_expr.expression);
+                let rules = vecmap(match_expr.rules, |(pattern, branch)| {
+                    self.in_new_scope(|this| {
+                        let definition = DefinitionKind::Local(None);
+                        let pattern = this.resolve_pattern_mutable(pattern, None, definition);
+                        (pattern, this.resolve_expression(branch))
+                    })
+                });
+                HirExpression::Match(HirMatchExpression { expression, rules })
+            }
             ExpressionKind::Index(indexed_expr) => HirExpression::Index(HirIndexExpression {
                 collection: self.resolve_expression(indexed_expr.collection),
                 index: self.resolve_expression(indexed_expr.index),
             }),
-            ExpressionKind::Block(block_expr) => self.resolve_block(block_expr),
+            ExpressionKind::Block(block_expr) => {
+                HirExpression::Block(self.resolve_block(block_expr))
+            }
+            // Comptime code is interpreted so any loop is allowed within it, but a `break`
+            // or `continue` still can't jump to a runtime loop outside of the block.
+            ExpressionKind::Comptime(block_expr) => HirExpression::Comptime(
+                self.in_loop_context(true, |this| this.resolve_block(block_expr)),
+            ),
             ExpressionKind::Constructor(constructor) => {
                 let span = constructor.type_name.span();
 
                 match self.lookup_type_or_error(constructor.type_name) {
-                    Some(Type::Struct(r#type, struct_generics)) => {
+                    Some(Type::Struct(r#type, struct_generics)) if !r#type.borrow().is_enum() => {
                         let typ = r#type.clone();
                         let fields = constructor.fields;
+                        let fields_end = fields_end(&fields, expr.span, |expr| expr.span);
                         let resolve_expr = Resolver::resolve_expression;
-                        let fields =
-                            self.resolve_constructor_fields(typ, fields, span, resolve_expr);
+                        let fields = self.resolve_constructor_fields(
+                            typ,
+                            fields,
+                            span,
+                            fields_end,
+                            false,
+                            resolve_expr,
+                        );
                         HirExpression::Constructor(HirConstructorExpression {
                             fields,
                             r#type,
@@ -1403,7 +1735,12 @@ impl<'a> Resolver<'a> {
                 });
 
                 let return_type = this.resolve_inferred_type(lambda.return_type);
-                let body = this.resolve_expression(lambda.body);
+
+                // A lambda may use unbounded loops where its enclosing function may, but a
+                // `break` or `continue` can't jump out of it, so its body is outside of any loop.
+                let allow_unbounded = this.unbounded_loops_allowed;
+                let body = this
+                    .in_loop_context(allow_unbounded, |this| this.resolve_expression(lambda.body));
 
                 let lambda_context = this.lambda_stack.pop().unwrap();
 
@@ -1424,8 +1761,14 @@ impl<'a> Resolver<'a> {
         expr_id
     }
 
+    /// Resolves a pattern which must always match, such as the pattern of a let statement
+    /// or of a function parameter. Use `resolve_pattern_mutable` for the arms of a match instead.
     fn resolve_pattern(&mut self, pattern: Pattern, definition: DefinitionKind) -> HirPattern {
-        self.resolve_pattern_mutable(pattern, None, definition)
+        let pattern = self.resolve_pattern_mutable(pattern, None, definition);
+        if let Some(span) = pattern.find_refutable_span() {
+            self.push_err(ResolverError::RefutablePattern { span });
+        }
+        pattern
     }
 
     fn resolve_pattern_mutable(
@@ -1447,7 +1790,8 @@ impl<'a> Resolver<'a> {
             }
             Pattern::Mutable(pattern, span) => {
                 if let Some(first_mut) = mutable {
-                    self.push_err(ResolverError::UnnecessaryMut { first_mut, second_mut: span });
+                    let second_mut = Span::from(span.start()..pattern.span().start());
+                    self.push_err(ResolverError::UnnecessaryMut { first_mut, second_mut });
                 }
 
                 let pattern = self.resolve_pattern_mutable(*pattern, Some(span), definition);
@@ -1460,21 +1804,16 @@ impl<'a> Resolver<'a> {
                 HirPattern::Tuple(fields, span)
             }
             Pattern::Struct(name, fields, span) => {
-                let error_identifier = |this: &mut Self| {
-                    // Must create a name here to return a HirPattern::Identifier. Allowing
-                    // shadowing here lets us avoid further errors if we define ERROR_IDENT
-                    // multiple times.
-                    let name = ERROR_IDENT.into();
-                    let identifier = this.add_variable_decl(name, false, true, definition.clone());
-                    HirPattern::Identifier(identifier)
-                };
-
                 let (struct_type, generics) = match self.lookup_type_or_error(name) {
-                    Some(Type::Struct(struct_type, generics)) => (struct_type, generics),
-                    None => return error_identifier(self),
+                    Some(Type::Struct(struct_type, generics))
+                        if !struct_type.borrow().is_enum() =>
+                    {
+                        (struct_type, generics)
+                    }
+                    None => return self.error_pattern(definition),
                     Some(typ) => {
                         self.push_err(ResolverError::NonStructUsedInConstructor { typ, span });
-                        return error_identifier(self);
+                        return self.error_pattern(definition);
                     }
                 };
 
@@ -1482,15 +1821,128 @@ impl<'a> Resolver<'a> {
                     this.resolve_pattern_mutable(pattern, mutable, definition.clone())
                 };
 
+                let struct_id = struct_type.borrow().id;
+                for (field, pattern) in &fields {
+                    self.interner
+                        .usage_tracker_mut()
+                        .mark_field_as_read(struct_id, &field.0.contents);
+
+                    if matches!(pattern, Pattern::Identifier(name) if name.span() == field.span()) {
+                        self.field_shorthands.insert(field.span());
+                    }
+                }
+
                 let typ = struct_type.clone();
-                let fields = self.resolve_constructor_fields(typ, fields, span, resolve_field);
+                let fields_end = fields_end(&fields, span, Pattern::span);
+                let fields = self.resolve_constructor_fields(
+                    typ,
+                    fields,
+                    span,
+                    fields_end,
+                    true,
+                    resolve_field,
+                );
 
                 let typ = Type::Struct(struct_type, generics);
                 HirPattern::Struct(typ, fields, span)
             }
+            Pattern::EnumVariant(variant, arguments, span) => {
+                let variant_name = variant.to_string();
+                let (enum_type, variant_index) = match self.lookup_enum_variant_or_error(variant) {
+                    Some(variant) => variant,
+                    None => return self.error_pattern(definition),
+                };
+
+                let expected = enum_type.borrow().variant_param_count(variant_index);
+                if arguments.len() != expected {
+                    self.push_err(ResolverError::IncorrectVariantArgumentCount {
+                        span,
+                        variant: variant_name,
+                        actual: arguments.len(),
+                        expected,
+                    });
+                }
+
+                let arguments = vecmap(arguments, |argument| {
+                    self.resolve_pattern_mutable(argument, mutable, definition.clone())
+                });
+
+                let generics = enum_type.borrow().instantiate(self.interner);
+                let typ = Type::Struct(enum_type, generics);
+                HirPattern::EnumVariant(typ, variant_index, arguments, span)
+            }
+            Pattern::Wildcard(span) => HirPattern::Wildcard(span),
+            Pattern::Literal(literal, span) => {
+                let expr = Expression::new(ExpressionKind::Literal(literal), span);
+                HirPattern::Literal(self.resolve_expression(expr), span)
+            }
+            Pattern::Array(elements, rest, span) => {
+                // Without a rest pattern the array must have exactly as many elements as the
+                // pattern. Otherwise its length is only known once the pattern is type checked.
+                let length = match rest {
+                    None => Type::Constant(elements.len() as u64),
+                    Some(_) => self.interner.next_type_variable(),
+                };
+                let element_type = self.interner.next_type_variable();
+                let typ = Type::Array(Box::new(length), Box::new(element_type));
+
+                let elements = vecmap(elements, |element| {
+                    self.resolve_pattern_mutable(element, mutable, definition.clone())
+                });
+                HirPattern::Array(typ, elements, rest, span)
+            }
         }
     }
 
+    fn error_pattern(&mut self, definition: DefinitionKind) -> HirPattern {
+        // Must create a name here to return a HirPattern::Identifier. Allowing
+        // shadowing here lets us avoid further errors if we define ERROR_IDENT
+        // multiple times.
+        let name = ERROR_IDENT.into();
+        let identifier = self.add_variable_decl(name, false, true, definition);
+        HirPattern::Identifier(identifier)
+    }
+
+    /// Lookup the enum variant the given path refers to, returning its enum and variant index.
+    fn lookup_enum_variant_or_error(&mut self, path: Path) -> Option<(Shared<StructType>, usize)> {
+        let span = path.span();
+        let func_id: FuncId = match self.lookup(path) {
+            Ok(func_id) => func_id,
+            Err(error) => {
+                self.push_err(error);
+                return None;
+            }
+        };
+
+        match self.interner.get_enum_variant(func_id) {
+            Some((enum_id, variant_index)) => Some((self.get_struct(enum_id), variant_index)),
+            None => {
+                let expected = "enum variant".into();
+                let got = "function".into();
+                self.push_err(ResolverError::Expected { span, expected, got });
+                None
+            }
+        }
+    }
+
+    /// A variant holding no data such as `Option::None` is a value of its enum on its own,
+    /// rather than a function which must be called to construct the enum.
+    fn resolve_unit_enum_variant(&mut self, func_id: FuncId) -> Option<HirExpression> {
+        let (enum_id, variant_index) = self.interner.get_enum_variant(func_id)?;
+        let enum_type = self.get_struct(enum_id);
+        if enum_type.borrow().variant_param_count(variant_index) != 0 {
+            return None;
+        }
+
+        let struct_generics = enum_type.borrow().instantiate(self.interner);
+        Some(HirExpression::EnumConstructor(HirEnumConstructorExpression {
+            r#type: enum_type,
+            struct_generics,
+            variant_index,
+            arguments: Vec::new(),
+        }))
+    }
+
     /// Resolve all the fields of a struct constructor expression.
     /// Ensures all fields are present, none are repeated, and all
     /// are part of the struct.
@@ -1502,8 +1954,11 @@ impl<'a> Resolver<'a> {
         struct_type: Shared<StructType>,
         fields: Vec<(Ident, T)>,
         span: Span,
+        fields_end: Span,
+        is_pattern: bool,
         mut resolve_function: impl FnMut(&mut Self, T) -> U,
     ) -> Vec<(Ident, U)> {
+        let has_fields = !fields.is_empty();
         let mut ret = Vec::with_capacity(fields.len());
         let mut seen_fields = HashSet::new();
         let mut unseen_fields = struct_type.borrow().field_names();
@@ -1533,6 +1988,9 @@ impl<'a> Resolver<'a> {
                 span,
                 missing_fields: unseen_fields.into_iter().map(|field| field.to_string()).collect(),
                 struct_definition: struct_type.borrow().name.clone(),
+                fields_end,
+                has_fields,
+                is_pattern,
             });
         }
 
@@ -1561,13 +2019,17 @@ impl<'a> Resolver<'a> {
         let span = path.span();
         let id = self.resolve_path(path)?;
 
-        if let Some(function) = TryFromModuleDefId::try_from(id) {
-            return Ok(self.interner.function_definition_id(function));
-        }
+        let definition_id = if let Some(function) = TryFromModuleDefId::try_from(id) {
+            Some(self.interner.function_definition_id(function))
+        } else if let Some(global) = TryFromModuleDefId::try_from(id) {
+            Some(self.interner.let_statement(&global).ident().id)
+        } else {
+            None
+        };
 
-        if let Some(global) = TryFromModuleDefId::try_from(id) {
-            let let_stmt = self.interner.let_statement(&global);
-            return Ok(let_stmt.ident().id);
+        if let Some(definition_id) = definition_id {
+            self.interner.usage_tracker_mut().mark_definition_as_used(definition_id);
+            return Ok(definition_id);
         }
 
         let expected = "global variable".into();
@@ -1624,6 +2086,34 @@ impl<'a> Resolver<'a> {
         self.lookup(path).ok().map(|id| self.interner.get_type_alias(id))
     }
 
+    /// Resolves a reference to an associated constant such as `Self::N` or `H::N` within an
+    /// expression. Like numeric generics, the constant is referred to through a type variable
+    /// which is bound to its value by the time the program is monomorphized.
+    fn resolve_associated_constant(&mut self, path: &Path, span: Span) -> Option<ExprId> {
+        let name = associated_item_path_name(path)?;
+        let (_, value) =
+            self.associated_constants.iter().find(|(item, _)| item.as_ref() == &name)?;
+
+        let type_variable = match value.clone() {
+            Type::NamedGeneric(type_variable, _) => type_variable,
+            value => {
+                let type_variable = TypeVariable::unbound(self.interner.next_type_variable_id());
+                type_variable.bind(value);
+                type_variable
+            }
+        };
+
+        let location = Location::new(span, self.file);
+        let definition = DefinitionKind::GenericType(type_variable);
+        let id = self.interner.push_definition(name, false, definition, location);
+        let typ = Type::polymorphic_integer(self.interner);
+        self.interner.push_definition_type(id, typ);
+
+        let expr_id = self.interner.push_expr(HirExpression::Ident(HirIdent { location, id }));
+        self.interner.push_expr_location(expr_id, span, self.file);
+        Some(expr_id)
+    }
+
     // this resolves Self::some_static_method, inside an impl block (where we don't have a concrete self_type)
     fn resolve_trait_static_method_by_self(
         &mut self,
@@ -1645,10 +2135,11 @@ impl<'a> Resolver<'a> {
         None
     }
 
-    // this resolves TraitName::some_static_method
+    // this resolves TraitName::some_static_method, where TraitName may also be a path
+    // such as `dep::std::default::Default::default`
     fn resolve_trait_static_method(&mut self, path: &Path) -> Option<(HirExpression, Type)> {
-        if path.kind == PathKind::Plain && path.segments.len() == 2 {
-            let method = &path.segments[1];
+        if path.segments.len() >= 2 {
+            let method = path.last_segment();
 
             let mut trait_path = path.clone();
             trait_path.pop();
@@ -1681,7 +2172,7 @@ impl<'a> Resolver<'a> {
                 }
 
                 if let Ok(ModuleDefId::TraitId(trait_id)) =
-                    self.path_resolver.resolve(self.def_maps, trait_bound.trait_path.clone())
+                    self.resolve_path(trait_bound.trait_path.clone())
                 {
                     let the_trait = self.interner.get_trait(trait_id);
                     if let Some(method) =
@@ -1703,37 +2194,64 @@ impl<'a> Resolver<'a> {
     }
 
     fn resolve_path(&mut self, path: Path) -> Result<ModuleDefId, ResolverError> {
+        let module_id = self.path_resolver.module_id();
+        self.interner.usage_tracker_mut().mark_path_as_used(self.def_maps, module_id, &path);
         self.path_resolver.resolve(self.def_maps, path).map_err(ResolverError::PathResolutionError)
     }
 
-    fn resolve_block(&mut self, block_expr: BlockExpression) -> HirExpression {
+    fn resolve_block(&mut self, block_expr: BlockExpression) -> HirBlockExpression {
         let statements =
-            self.in_new_scope(|this| vecmap(block_expr.0, |stmt| this.intern_stmt(stmt.kind)));
-        HirExpression::Block(HirBlockExpression(statements))
+            self.in_new_scope(|this| vecmap(block_expr.0, |stmt| this.intern_stmt(stmt)));
+        HirBlockExpression(statements)
     }
 
     pub fn intern_block(&mut self, block: BlockExpression) -> ExprId {
         let hir_block = self.resolve_block(block);
-        self.interner.push_expr(hir_block)
+        self.interner.push_expr(HirExpression::Block(hir_block))
     }
 
-    fn eval_global_as_array_length(&mut self, global: StmtId) -> u64 {
+    fn eval_global_as_array_length(&mut self, global: StmtId) -> Type {
         let stmt = match self.interner.statement(&global) {
             HirStatement::Let(let_expr) => let_expr,
-            _ => return 0,
+            _ => return Type::Constant(0),
         };
 
         let length = stmt.expression;
+        if self.is_comptime_code(length) {
+            return self.comptime_array_length(length, true);
+        }
+
         let span = self.interner.expr_span(&length);
         let result = self.try_eval_array_length_id(length, span);
 
         match result.map(|length| length.try_into()) {
-            Ok(Ok(length_value)) => return length_value,
+            Ok(Ok(length_value)) => return Type::Constant(length_value),
             Ok(Err(_cast_err)) => self.push_err(ResolverError::IntegerTooLarge { span }),
             Err(Some(error)) => self.push_err(error),
             Err(None) => (),
         }
-        0
+        Type::Constant(0)
+    }
+
+    /// Whether the expression is a comptime block or a call to a comptime function
+    fn is_comptime_code(&self, expression: ExprId) -> bool {
+        match self.interner.expression(&expression) {
+            HirExpression::Comptime(_) => true,
+            HirExpression::Call(call) => self.is_comptime_function(call.func),
+            _ => false,
+        }
+    }
+
+    fn is_comptime_function(&self, function: ExprId) -> bool {
+        match self.interner.expression(&function) {
+            HirExpression::Ident(ident) => match self.interner.definition(ident.id).kind {
+                DefinitionKind::Function(function) => {
+                    self.interner.function_modifiers(&function).is_comptime
+                }
+                _ => false,
+            },
+            _ => false,
+        }
     }
 
     fn try_eval_array_length_id(
@@ -1851,7 +2369,9 @@ impl<'a> Resolver<'a> {
                 let resolved = self.resolve_named_type(path.clone(), vec![], &mut vec![]);
                 self.errors.truncate(error_count);
 
-                if !resolved.is_valid_for_program_input() {
+                // Globals initialized by comptime code are type variables until evaluated
+                let is_comptime_length = matches!(resolved, Type::TypeVariable(..));
+                if !is_comptime_length && !resolved.is_valid_for_program_input() {
                     self.push_err(ResolverError::InvalidTypeForEntryPoint { span: path.span() });
                 }
             }
@@ -1859,10 +2379,32 @@ impl<'a> Resolver<'a> {
                 self.verify_type_expression_valid_for_program_input(lhs);
                 self.verify_type_expression_valid_for_program_input(rhs);
             }
+            UnresolvedTypeExpression::Comptime(..) => (),
         }
     }
 }
 
+/// The position after the last field of a struct constructor or pattern spanning `span`, or just
+/// before its closing brace if it has no fields.
+fn fields_end<T>(fields: &[(Ident, T)], span: Span, field_span: impl Fn(&T) -> Span) -> Span {
+    let end = fields.last().map_or(span.end() - 1, |(_, field)| field_span(field).end());
+    Span::empty(end)
+}
+
+/// The name an associated type or constant is referred to by, such as `Self::Output`
+fn associated_item_name(object: &str, item: &Ident) -> String {
+    format!("{object}::{item}")
+}
+
+/// Returns the name of the associated type or constant a path such as `H::Output` may refer to
+fn associated_item_path_name(path: &Path) -> Option<String> {
+    if path.kind == PathKind::Plain && path.segments.len() == 2 {
+        Some(associated_item_name(&path.segments[0].0.contents, &path.segments[1]))
+    } else {
+        None
+    }
+}
+
 /// Gives an error if a user tries to create a mutable reference
 /// to an immutable variable.
 pub fn verify_mutable_reference(interner: &NodeInterner, rhs: ExprId) -> Result<(), ResolverError> {
@@ -14,11 +14,13 @@ use crate::{
             errors::{DefCollectorErrorKind, DuplicateType},
         },
         def_map::{CrateDefMap, ModuleDefId, ModuleId},
+        type_check::TypeCheckError,
         Context,
     },
     hir_def::traits::{TraitConstant, TraitFunction, TraitImpl, TraitType},
     node_interner::{FuncId, NodeInterner, TraitId},
-    Path, Shared, TraitItem, Type, TypeBinding, TypeVariableKind,
+    Ident, ItemVisibility, Path, Shared, TraitItem, Type, TypeBinding, TypeVariable,
+    TypeVariableKind,
 };
 
 use super::{
@@ -39,12 +41,22 @@ pub(crate) fn resolve_traits(
         context.def_interner.push_empty_trait(*trait_id, unresolved_trait);
     }
     let mut res: Vec<(CompilationError, FileId)> = vec![];
-    for (trait_id, unresolved_trait) in traits {
-        // Resolve order
+
+    // Associated types and constants are resolved for every trait before any methods, since
+    // a method may refer to the associated items of another trait within its where clause.
+    for (trait_id, unresolved_trait) in &traits {
         // 1. Trait Types ( Trait constants can have a trait type, therefore types before constants)
-        let _ = resolve_trait_types(context, crate_id, &unresolved_trait);
+        let types = resolve_trait_types(context, unresolved_trait);
         // 2. Trait Constants ( Trait's methods can use trait types & constants, therefore they should be after)
-        let _ = resolve_trait_constants(context, crate_id, &unresolved_trait);
+        let (constants, errors) = resolve_trait_constants(context, crate_id, unresolved_trait);
+        res.extend(errors);
+        context.def_interner.update_trait(*trait_id, |trait_def| {
+            trait_def.types = types;
+            trait_def.constants = constants;
+        });
+    }
+
+    for (trait_id, unresolved_trait) in traits {
         // 3. Trait Methods
         let (methods, errors) =
             resolve_trait_methods(context, trait_id, crate_id, &unresolved_trait);
@@ -57,20 +69,64 @@ pub(crate) fn resolve_traits(
 }
 
 fn resolve_trait_types(
-    _context: &mut Context,
-    _crate_id: CrateId,
-    _unresolved_trait: &UnresolvedTrait,
-) -> (Vec<TraitType>, Vec<(CompilationError, FileId)>) {
-    // TODO
-    (vec![], vec![])
+    context: &mut Context,
+    unresolved_trait: &UnresolvedTrait,
+) -> Vec<TraitType> {
+    let interner = &mut context.def_interner;
+
+    let types = unresolved_trait.trait_def.items.iter().filter_map(|item| match item {
+        TraitItem::Type { name } => {
+            let typevar_id = interner.next_type_variable_id();
+            let typevar = TypeVariable::unbound(typevar_id);
+            Some(TraitType { name: name.clone(), typevar_id, typevar, span: name.span() })
+        }
+        _ => None,
+    });
+    types.collect()
 }
+
 fn resolve_trait_constants(
-    _context: &mut Context,
-    _crate_id: CrateId,
-    _unresolved_trait: &UnresolvedTrait,
+    context: &mut Context,
+    crate_id: CrateId,
+    unresolved_trait: &UnresolvedTrait,
 ) -> (Vec<TraitConstant>, Vec<(CompilationError, FileId)>) {
-    // TODO
-    (vec![], vec![])
+    let interner = &mut context.def_interner;
+    let def_maps = &mut context.def_maps;
+
+    let path_resolver = StandardPathResolver::new(ModuleId {
+        local_id: unresolved_trait.module_id,
+        krate: crate_id,
+    });
+    let file = def_maps[&crate_id].file_id(unresolved_trait.module_id);
+
+    let mut constants = vec![];
+    let mut resolver_errors = vec![];
+
+    for item in &unresolved_trait.trait_def.items {
+        if let TraitItem::Constant { name, typ, default_value } = item {
+            let mut resolver = Resolver::new(interner, &path_resolver, def_maps, file);
+            let ty = resolver.resolve_type(typ.clone());
+            let default_value = default_value
+                .clone()
+                .map(|value| resolver.resolve_associated_constant_value(value));
+
+            let typevar_id = resolver.interner.next_type_variable_id();
+            let typevar = TypeVariable::unbound(typevar_id);
+            let span = name.span();
+            constants.push(TraitConstant {
+                name: name.clone(),
+                ty,
+                typevar_id,
+                typevar,
+                default_value,
+                span,
+            });
+
+            let errors = resolver.take_errors().into_iter();
+            resolver_errors.extend(errors.map(|resolution_error| (resolution_error.into(), file)));
+        }
+    }
+    (constants, resolver_errors)
 }
 
 fn resolve_trait_methods(
@@ -108,6 +164,7 @@ fn resolve_trait_methods(
             let mut resolver = Resolver::new(interner, &path_resolver, def_maps, file);
             resolver.add_generics(generics);
             resolver.set_self_type(Some(self_type));
+            resolver.add_trait_associated_generics(trait_id);
 
             let func_id = unresolved_trait.method_ids[&name.0.contents];
             let (_, func_meta) = resolver.resolve_trait_function(
@@ -129,9 +186,12 @@ fn resolve_trait_methods(
                 TypeBinding::Bound(binding) => unreachable!("Trait generic was bound to {binding}"),
             });
 
-            // Ensure the trait is generic over the Self type as well
+            // Ensure the trait is generic over the Self type and its associated items as well
             let the_trait = resolver.interner.get_trait(trait_id);
             generics.push((the_trait.self_type_typevar_id, the_trait.self_type_typevar.clone()));
+            for (_, typevar_id, typevar) in the_trait.associated_type_variables() {
+                generics.push((typevar_id, typevar));
+            }
 
             let default_impl_list: Vec<_> = unresolved_trait
                 .fns_with_default_impl
@@ -265,7 +325,7 @@ fn collect_trait_impl(
     let unresolved_type = trait_impl.object_type.clone();
     let module = ModuleId { local_id: trait_impl.module_id, krate: crate_id };
     trait_impl.trait_id =
-        match resolve_trait_by_path(def_maps, module, trait_impl.trait_path.clone()) {
+        match resolve_trait_by_path(interner, def_maps, module, trait_impl.trait_path.clone()) {
             Ok(trait_id) => Some(trait_id),
             Err(error) => {
                 errors.push((error.into(), trait_impl.file_id));
@@ -273,10 +333,25 @@ fn collect_trait_impl(
             }
         };
 
+    // The traits in the where clause are resolved from the impl's module here since the clause
+    // also applies to default methods, which are otherwise resolved from the trait's module.
+    // Any trait which cannot be found is reported once the impl itself is resolved.
+    for bound in &mut trait_impl.where_clause {
+        let trait_path = bound.trait_bound.trait_path.clone();
+        bound.trait_bound.trait_id =
+            resolve_trait_by_path(interner, def_maps, module, trait_path).ok();
+    }
+
     if let Some(trait_id) = trait_impl.trait_id {
         errors
             .extend(collect_trait_impl_methods(interner, def_maps, crate_id, trait_id, trait_impl));
 
+        let where_clause =
+            trait_impl.where_clause.iter().filter(|bound| bound.trait_bound.trait_id.is_some());
+        for (_, _, method) in &mut trait_impl.methods.functions {
+            method.def.where_clause.extend(where_clause.clone().cloned());
+        }
+
         let path_resolver = StandardPathResolver::new(module);
         let file = def_maps[&crate_id].file_id(trait_impl.module_id);
         let mut resolver = Resolver::new(interner, &path_resolver, def_maps, file);
@@ -293,7 +368,8 @@ fn collect_trait_impl(
                 // be accessed with the `TypeName::method` syntax. We'll check later whether the
                 // object types in each method overlap or not. If they do, we issue an error.
                 // If not, that is specialization which is allowed.
-                if module.declare_function(method.name_ident().clone(), *method_id).is_err() {
+                let name = method.name_ident().clone();
+                if module.declare_function(name, ItemVisibility::Public, *method_id).is_err() {
                     module.remove_function(method.name_ident());
                 }
             }
@@ -344,11 +420,13 @@ fn check_trait_impl_crate_coherence(
 }
 
 pub(crate) fn resolve_trait_by_path(
+    interner: &mut NodeInterner,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     module: ModuleId,
     path: Path,
 ) -> Result<TraitId, DefCollectorErrorKind> {
     let path_resolver = StandardPathResolver::new(module);
+    interner.usage_tracker_mut().mark_path_as_used(def_maps, module, &path);
 
     match path_resolver.resolve(def_maps, path.clone()) {
         Ok(ModuleDefId::TraitId(trait_id)) => Ok(trait_id),
@@ -356,6 +434,93 @@ pub(crate) fn resolve_trait_by_path(
         Err(_) => Err(DefCollectorErrorKind::TraitNotFound { trait_path: path }),
     }
 }
+
+/// Resolves the type an impl assigns to each associated type of its trait, followed by the
+/// value it assigns to each associated constant, in the order they are declared in the trait.
+fn resolve_impl_associated_types(
+    mut resolver: Resolver,
+    trait_id: TraitId,
+    trait_impl: &UnresolvedTraitImpl,
+    errors: &mut Vec<(CompilationError, FileId)>,
+) -> Vec<Type> {
+    let the_trait = resolver.interner.get_trait(trait_id);
+    let trait_name = the_trait.name.clone();
+    let trait_types = vecmap(&the_trait.types, |typ| typ.name.clone());
+    let trait_constants = the_trait.constants.clone();
+
+    let file = trait_impl.file_id;
+    let trait_impl_span = trait_impl.object_type.span.expect("type must have a span");
+    let mut item_errors = Vec::new();
+
+    // Check for duplicate items and for items which are not part of the trait
+    let impl_types = vecmap(&trait_impl.associated_types, |(name, _)| name.clone());
+    let impl_constants = vecmap(&trait_impl.associated_constants, |(name, _, _)| name.clone());
+    let trait_constant_names = vecmap(&trait_constants, |constant| constant.name.clone());
+
+    for (impl_names, trait_names, typ) in [
+        (impl_types, &trait_types, DuplicateType::TraitAssociatedType),
+        (impl_constants, &trait_constant_names, DuplicateType::TraitAssociatedConst),
+    ] {
+        for (index, name) in impl_names.iter().enumerate() {
+            if let Some(first_def) = impl_names[..index].iter().find(|first| *first == name) {
+                item_errors.push(DefCollectorErrorKind::Duplicate {
+                    typ: typ.clone(),
+                    first_def: first_def.clone(),
+                    second_def: name.clone(),
+                });
+            } else if !trait_names.contains(name) {
+                item_errors.push(DefCollectorErrorKind::AssociatedItemNotInTrait {
+                    trait_name: trait_name.clone(),
+                    item_name: name.clone(),
+                });
+            }
+        }
+    }
+
+    let mut missing_item = |item_name: &Ident| {
+        item_errors.push(DefCollectorErrorKind::TraitMissingAssociatedItem {
+            trait_name: trait_name.clone(),
+            item_name: item_name.clone(),
+            trait_impl_span,
+        });
+        Type::Error
+    };
+
+    let mut associated_types = Vec::new();
+    for name in &trait_types {
+        match trait_impl.associated_types.iter().find(|(item, _)| item == name) {
+            Some((_, typ)) => associated_types.push(resolver.resolve_type(typ.clone())),
+            None => associated_types.push(missing_item(name)),
+        }
+    }
+
+    let mut type_errors = Vec::new();
+    for constant in &trait_constants {
+        let item =
+            trait_impl.associated_constants.iter().find(|(item, _, _)| *item == constant.name);
+
+        let value = match (item, &constant.default_value) {
+            (Some((item, typ, value)), _) => {
+                let typ = resolver.resolve_type(typ.clone());
+                typ.unify(&constant.ty, &mut type_errors, || TypeCheckError::TypeMismatch {
+                    expected_typ: constant.ty.to_string(),
+                    expr_typ: typ.to_string(),
+                    expr_span: item.span(),
+                });
+                resolver.resolve_associated_constant_value(value.clone())
+            }
+            (None, Some(default_value)) => default_value.clone(),
+            (None, None) => missing_item(&constant.name),
+        };
+        associated_types.push(value);
+    }
+
+    errors.extend(item_errors.into_iter().map(|error| (error.into(), file)));
+    errors.extend(type_errors.into_iter().map(|error| (error.into(), file)));
+    errors.extend(take_errors(file, resolver));
+    associated_types
+}
+
 pub(crate) fn resolve_trait_impls(
     context: &mut Context,
     traits: Vec<UnresolvedTraitImpl>,
@@ -381,6 +546,17 @@ pub(crate) fn resolve_trait_impls(
 
         let impl_id = interner.next_trait_impl_id();
 
+        let associated_types = match trait_impl.trait_id {
+            Some(trait_id) => {
+                let mut resolver =
+                    Resolver::new(interner, &path_resolver, &context.def_maps, trait_impl.file_id);
+                resolver.set_generics(generics.clone());
+                resolver.set_self_type(Some(self_type.clone()));
+                resolve_impl_associated_types(resolver, trait_id, &trait_impl, errors)
+            }
+            None => Vec::new(),
+        };
+
         let mut impl_methods = functions::resolve_function_set(
             interner,
             crate_id,
@@ -388,6 +564,7 @@ pub(crate) fn resolve_trait_impls(
             trait_impl.methods.clone(),
             Some(self_type.clone()),
             Some(impl_id),
+            &associated_types,
             generics.clone(),
             errors,
         );
@@ -417,6 +594,7 @@ pub(crate) fn resolve_trait_impls(
                 &impl_methods,
                 trait_id,
                 trait_impl.generics.len(),
+                &associated_types,
                 errors,
             );
 
@@ -433,6 +611,7 @@ pub(crate) fn resolve_trait_impls(
                 file: trait_impl.file_id,
                 where_clause,
                 methods: vecmap(&impl_methods, |(_, func_id)| *func_id),
+                associated_types,
             });
 
             if let Err((prev_span, prev_file)) = interner.add_trait_implementation(
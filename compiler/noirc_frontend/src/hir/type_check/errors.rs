@@ -6,6 +6,7 @@ use thiserror::Error;
 use crate::hir::resolution::errors::ResolverError;
 use crate::hir_def::expr::HirBinaryOp;
 use crate::hir_def::types::Type;
+use crate::lints::Lint;
 use crate::BinaryOpKind;
 use crate::FunctionReturnType;
 use crate::Signedness;
@@ -86,6 +87,15 @@ pub enum TypeCheckError {
     AmbiguousBitWidth { span: Span },
     #[error("Error with additional context")]
     Context { err: Box<TypeCheckError>, ctx: &'static str },
+    /// A type mismatch along with the parts of each type which differ, when they are nested
+    /// within the types, and the type and span of the expression the mismatching part was
+    /// inferred from, if known.
+    #[error("{err}")]
+    ExplainedTypeMismatch {
+        err: Box<TypeCheckError>,
+        diff: Option<(String, String)>,
+        origin: Option<(Type, Span)>,
+    },
     #[error("Array is not homogeneous")]
     NonHomogeneousArray {
         first_span: Span,
@@ -115,17 +125,104 @@ pub enum TypeCheckError {
     NoMatchingImplFound { constraints: Vec<(Type, String)>, span: Span },
     #[error("Constraint for `{typ}: {trait_name}` is not needed, another matching impl is already in scope")]
     UnneededTraitConstraint { trait_name: String, typ: Type, span: Span },
+    #[error("Non-exhaustive match, `{missing}` is not covered")]
+    NonExhaustiveMatch { missing: String, span: Span },
+    #[error("Unreachable match arm")]
+    UnreachableMatchArm { span: Span },
+    #[error("Array pattern with {patterns} elements cannot match an array of length {length}")]
+    ArrayPatternTooLong { patterns: usize, length: u64, span: Span },
+    #[error("The length of {typ} must be known to match it against a pattern using `..`")]
+    ArrayPatternUnknownLength { typ: Type, span: Span },
 }
 
 impl TypeCheckError {
     pub fn add_context(self, ctx: &'static str) -> Self {
         TypeCheckError::Context { err: Box::new(self), ctx }
     }
+
+    /// The span a type mismatch error is reported at, or `None` if this is another error.
+    pub fn type_mismatch_span(&self) -> Option<Span> {
+        match self {
+            TypeCheckError::TypeMismatch { expr_span: span, .. }
+            | TypeCheckError::TypeMismatchWithSource { span, .. } => Some(*span),
+            TypeCheckError::Context { err, .. }
+            | TypeCheckError::ExplainedTypeMismatch { err, .. } => err.type_mismatch_span(),
+            _ => None,
+        }
+    }
+
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            TypeCheckError::OpCannotBeUsed { .. } => "E0300",
+            TypeCheckError::OverflowingAssignment { .. } => "E0301",
+            TypeCheckError::TypeCannotBeUsed { .. } => "E0302",
+            TypeCheckError::TypeMismatch { .. } => "E0303",
+            TypeCheckError::TypeMismatchWithSource { .. } => "E0304",
+            TypeCheckError::ArityMisMatch { .. } => "E0305",
+            TypeCheckError::PublicReturnType { .. } => "E0306",
+            TypeCheckError::InvalidCast { .. } => "E0307",
+            TypeCheckError::ExpectedFunction { .. } => "E0308",
+            TypeCheckError::AccessUnknownMember { .. } => "E0309",
+            TypeCheckError::ParameterCountMismatch { .. } => "E0310",
+            TypeCheckError::UnsupportedCast { .. } => "E0311",
+            TypeCheckError::TupleIndexOutOfBounds { .. } => "E0312",
+            TypeCheckError::VariableMustBeMutable { .. } => "E0313",
+            TypeCheckError::UnresolvedMethodCall { .. } => "E0314",
+            TypeCheckError::InvalidComparisonOnField { .. } => "E0315",
+            TypeCheckError::IntegerSignedness { .. } => "E0316",
+            TypeCheckError::IntegerBitWidth { .. } => "E0317",
+            TypeCheckError::InvalidInfixOp { .. } => "E0318",
+            TypeCheckError::InvalidUnaryOp { .. } => "E0319",
+            TypeCheckError::InvalidBitwiseOperationOnField { .. } => "E0320",
+            TypeCheckError::IntegerTypeMismatch { .. } => "E0321",
+            TypeCheckError::IntegerAndFieldBinaryOperation { .. } => "E0322",
+            TypeCheckError::FieldModulo { .. } => "E0323",
+            TypeCheckError::FieldComparison { .. } => "E0324",
+            TypeCheckError::AmbiguousBitWidth { .. } => "E0325",
+            TypeCheckError::Context { err, .. }
+            | TypeCheckError::ExplainedTypeMismatch { err, .. } => err.code(),
+            TypeCheckError::NonHomogeneousArray { .. } => "E0326",
+            TypeCheckError::TypeAnnotationsNeeded { .. } => "E0327",
+            TypeCheckError::CallDeprecated { .. } => "E0328",
+            TypeCheckError::ResolverError(error) => error.code(),
+            TypeCheckError::UnusedResultError { .. } => "E0329",
+            TypeCheckError::TraitMethodParameterTypeMismatch { .. } => "E0330",
+            TypeCheckError::NoMatchingImplFound { .. } => "E0331",
+            TypeCheckError::UnneededTraitConstraint { .. } => "E0332",
+            TypeCheckError::NonExhaustiveMatch { .. } => "E0333",
+            TypeCheckError::UnreachableMatchArm { .. } => "E0334",
+            TypeCheckError::ArrayPatternTooLong { .. } => "E0335",
+            TypeCheckError::ArrayPatternUnknownLength { .. } => "E0336",
+        }
+    }
+
+    /// The lint which controls this warning, along with the span it is reported at,
+    /// or `None` if this is an error.
+    pub fn lint(&self) -> Option<(Lint, Span)> {
+        match self {
+            TypeCheckError::CallDeprecated { span, .. } => Some((Lint::Deprecated, *span)),
+            TypeCheckError::UnusedResultError { expr_span, .. } => {
+                Some((Lint::UnusedResults, *expr_span))
+            }
+            TypeCheckError::UnneededTraitConstraint { span, .. } => {
+                Some((Lint::UnneededTraitConstraints, *span))
+            }
+            TypeCheckError::UnreachableMatchArm { span } => {
+                Some((Lint::UnreachablePatterns, *span))
+            }
+            TypeCheckError::Context { err, .. }
+            | TypeCheckError::ExplainedTypeMismatch { err, .. } => err.lint(),
+            TypeCheckError::ResolverError(error) => error.lint(),
+            _ => None,
+        }
+    }
 }
 
 impl From<TypeCheckError> for Diagnostic {
     fn from(error: TypeCheckError) -> Diagnostic {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             TypeCheckError::TypeCannotBeUsed { typ, place, span } => Diagnostic::simple_error(
                 format!("The type {} cannot be used in a {}", &typ, place),
                 String::new(),
@@ -136,6 +233,16 @@ impl From<TypeCheckError> for Diagnostic {
                 diag.add_note(ctx.to_owned());
                 diag
             }
+            TypeCheckError::ExplainedTypeMismatch { err, diff, origin } => {
+                let mut diag = Diagnostic::from(*err);
+                if let Some((typ, span)) = origin {
+                    diag.add_secondary(format!("`{typ}` was inferred from this expression"), span);
+                }
+                if let Some((expected, found)) = diff {
+                    diag.add_note(format!("expected `{expected}`\n   found `{found}`"));
+                }
+                diag
+            }
             TypeCheckError::OpCannotBeUsed { op, place, span } => Diagnostic::simple_error(
                 format!("The operator {op:?} cannot be used in a {place}"),
                 String::new(),
@@ -202,6 +309,8 @@ impl From<TypeCheckError> for Diagnostic {
             | TypeCheckError::AmbiguousBitWidth { span, .. }
             | TypeCheckError::IntegerAndFieldBinaryOperation { span }
             | TypeCheckError::OverflowingAssignment { span, .. }
+            | TypeCheckError::ArrayPatternTooLong { span, .. }
+            | TypeCheckError::ArrayPatternUnknownLength { span, .. }
             | TypeCheckError::FieldModulo { span } => {
                 Diagnostic::simple_error(error.to_string(), String::new(), span)
             }
@@ -241,7 +350,7 @@ impl From<TypeCheckError> for Diagnostic {
 
                         diagnostic.add_secondary(format!("{actual} returned here"), expr_span);
 
-                        return diagnostic
+                        return diagnostic.with_code(code)
                     },
                 };
 
@@ -275,6 +384,17 @@ impl From<TypeCheckError> for Diagnostic {
                 let msg = format!("Constraint for `{typ}: {trait_name}` is not needed, another matching impl is already in scope");
                 Diagnostic::simple_warning(msg, "Unnecessary trait constraint in where clause".into(), span)
             }
-        }
+            TypeCheckError::NonExhaustiveMatch { missing, span } => Diagnostic::simple_error(
+                format!("Non-exhaustive match, `{missing}` is not covered"),
+                format!("Pattern `{missing}` not covered"),
+                span,
+            ),
+            TypeCheckError::UnreachableMatchArm { span } => Diagnostic::simple_warning(
+                "Unreachable match arm".into(),
+                "Every value this pattern matches is matched by a previous arm".into(),
+                span,
+            ),
+        };
+        diagnostic.with_code(code)
     }
 }
@@ -0,0 +1,312 @@
+//! Checks that the arms of a `match` expression cover every possible value and that
+//! each arm is reachable. This follows the usefulness algorithm described in
+//! "Warnings for pattern matching" by Luc Maranget.
+//!
+//! Patterns are first lowered into `Pat`s where any pattern which matches everything
+//! (an identifier or `_`) becomes a wildcard and tuples, structs, arrays, enum variants
+//! and literals become constructors applied to their sub-patterns.
+use acvm::FieldElement;
+use iter_extended::vecmap;
+
+use crate::hir_def::expr::{HirExpression, HirLiteral};
+use crate::node_interner::NodeInterner;
+use crate::{hir_def::stmt::HirPattern, Type};
+
+/// The result of checking the arms of a match expression.
+pub(super) struct MatchCheck {
+    /// An example of a value no arm matches, if there is one
+    pub(super) missing_pattern: Option<String>,
+
+    /// The indices of each arm which only matches values already matched by a previous arm
+    pub(super) unreachable_arms: Vec<usize>,
+}
+
+pub(super) fn check_match(
+    scrutinee: &Type,
+    patterns: &[&HirPattern],
+    interner: &NodeInterner,
+) -> MatchCheck {
+    let types = [scrutinee.clone()];
+    let mut rows = Vec::with_capacity(patterns.len());
+    let mut unreachable_arms = Vec::new();
+
+    for (i, pattern) in patterns.iter().enumerate() {
+        let row = vec![Pat::from_hir(pattern, interner)];
+        if !is_useful(&rows, &row, &types) {
+            unreachable_arms.push(i);
+        }
+        rows.push(row);
+    }
+
+    let missing_pattern = find_witness(&rows, &types).map(|witness| witness[0].to_string());
+    MatchCheck { missing_pattern, unreachable_arms }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Constructor {
+    /// The only constructor of a tuple, struct, array, or unit type
+    Single,
+    /// The enum variant at the given index
+    Variant(usize),
+    Bool(bool),
+    /// An integer literal along with whether it is negative. Integer types are never
+    /// considered complete so these are only ever compared against each other.
+    Integer(FieldElement, bool),
+}
+
+#[derive(Debug, Clone)]
+enum Pat {
+    Wildcard,
+    Constructor(Constructor, Vec<Pat>),
+}
+
+type Row = Vec<Pat>;
+
+impl Pat {
+    fn from_hir(pattern: &HirPattern, interner: &NodeInterner) -> Pat {
+        let from_hir = |pattern: &HirPattern| Pat::from_hir(pattern, interner);
+        match pattern {
+            HirPattern::Identifier(_) | HirPattern::Wildcard(_) => Pat::Wildcard,
+            HirPattern::Mutable(pattern, _) => Pat::from_hir(pattern, interner),
+            HirPattern::Tuple(fields, _) => {
+                Pat::Constructor(Constructor::Single, vecmap(fields, from_hir))
+            }
+            HirPattern::Struct(_, fields, _) => {
+                // Struct fields are always deconstructed in order of their names
+                let mut fields: Vec<_> = fields.iter().collect();
+                fields.sort_by(|(a, _), (b, _)| a.0.contents.cmp(&b.0.contents));
+                let fields = vecmap(fields, |(_, field)| from_hir(field));
+                Pat::Constructor(Constructor::Single, fields)
+            }
+            HirPattern::EnumVariant(_, index, arguments, _) => {
+                Pat::Constructor(Constructor::Variant(*index), vecmap(arguments, from_hir))
+            }
+            HirPattern::Literal(literal, _) => match interner.expression(literal) {
+                HirExpression::Literal(HirLiteral::Bool(value)) => {
+                    Pat::Constructor(Constructor::Bool(value), Vec::new())
+                }
+                HirExpression::Literal(HirLiteral::Integer(value, negative)) => {
+                    let negative = negative && !value.is_zero();
+                    Pat::Constructor(Constructor::Integer(value, negative), Vec::new())
+                }
+                other => {
+                    unreachable!("Expected a bool or integer literal pattern, found {other:?}")
+                }
+            },
+            HirPattern::Array(typ, elements, rest, _) => {
+                let mut elements = vecmap(elements, from_hir);
+
+                // Expand `..` into as many wildcards as the elements it stands for
+                if let Some(rest) = rest {
+                    let length = match typ.follow_bindings() {
+                        Type::Array(length, _) => length.evaluate_to_u64(),
+                        _ => None,
+                    };
+                    // An unknown length was already reported as an error
+                    let length = match length {
+                        Some(length) => length as usize,
+                        None => return Pat::Wildcard,
+                    };
+                    let wildcards = length.saturating_sub(elements.len());
+                    let after = elements.split_off(*rest);
+                    elements.extend(std::iter::repeat(Pat::Wildcard).take(wildcards));
+                    elements.extend(after);
+                }
+
+                Pat::Constructor(Constructor::Single, elements)
+            }
+        }
+    }
+}
+
+/// A value which is not matched by a set of patterns, used for error messages.
+#[derive(Debug, Clone)]
+enum Witness {
+    Wildcard,
+    Constructor(Type, Constructor, Vec<Witness>),
+}
+
+impl std::fmt::Display for Witness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (typ, constructor, fields) = match self {
+            Witness::Wildcard => return write!(f, "_"),
+            Witness::Constructor(typ, constructor, fields) => (typ, constructor, fields),
+        };
+
+        let fields = vecmap(fields, ToString::to_string);
+        match (typ.follow_bindings(), constructor) {
+            (Type::Struct(definition, generics), Constructor::Variant(index)) => {
+                let definition = definition.borrow();
+                let (variant, _) = definition.get_variant(*index, &generics);
+                if fields.is_empty() {
+                    write!(f, "{}::{variant}", definition.name)
+                } else {
+                    write!(f, "{}::{variant}({})", definition.name, fields.join(", "))
+                }
+            }
+            (_, Constructor::Bool(value)) => write!(f, "{value}"),
+            (_, Constructor::Integer(value, negative)) => {
+                let sign = if *negative { "-" } else { "" };
+                write!(f, "{sign}{}", value.to_u128())
+            }
+            (Type::Array(..), Constructor::Single) => write!(f, "[{}]", fields.join(", ")),
+            (Type::Struct(definition, generics), Constructor::Single) => {
+                let definition = definition.borrow();
+                let mut names = vecmap(definition.get_fields(&generics), |(name, _)| name);
+                names.sort();
+
+                let fields =
+                    vecmap(names.iter().zip(fields), |(name, field)| format!("{name}: {field}"));
+                write!(f, "{} {{ {} }}", definition.name, fields.join(", "))
+            }
+            _ => write!(f, "({})", fields.join(", ")),
+        }
+    }
+}
+
+/// Returns every constructor of the given type along with the types of their fields.
+/// Returns None for types whose values cannot be listed, such as integers.
+fn constructors(typ: &Type) -> Option<Vec<(Constructor, Vec<Type>)>> {
+    match typ.follow_bindings() {
+        Type::Unit => Some(vec![(Constructor::Single, Vec::new())]),
+        Type::Bool => Some(vec![
+            (Constructor::Bool(false), Vec::new()),
+            (Constructor::Bool(true), Vec::new()),
+        ]),
+        Type::Array(length, element) => {
+            let length = length.evaluate_to_u64()?;
+            Some(vec![(Constructor::Single, vec![*element; length as usize])])
+        }
+        Type::Tuple(fields) => Some(vec![(Constructor::Single, fields)]),
+        Type::Struct(definition, generics) => {
+            let definition = definition.borrow();
+            if definition.is_enum() {
+                let variants = definition.get_variants(&generics).into_iter().enumerate();
+                Some(vecmap(variants, |(index, (_, params))| (Constructor::Variant(index), params)))
+            } else {
+                let mut fields = definition.get_fields(&generics);
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Some(vec![(Constructor::Single, vecmap(fields, |(_, typ)| typ))])
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the types of the fields of the given constructor. If the constructor does not
+/// belong to the type, a type error has already been issued and `arity` error types are returned.
+fn field_types(typ: &Type, constructor: Constructor, arity: usize) -> Vec<Type> {
+    constructors(typ)
+        .and_then(|constructors| constructors.into_iter().find(|(c, _)| *c == constructor))
+        .map_or_else(|| vec![Type::Error; arity], |(_, fields)| fields)
+}
+
+/// True if the first column of these rows mentions every one of the given constructors.
+fn is_complete(rows: &[Row], constructors: &[(Constructor, Vec<Type>)]) -> bool {
+    constructors.iter().all(|(constructor, _)| {
+        rows.iter().any(|row| matches!(&row[0], Pat::Constructor(c, _) if c == constructor))
+    })
+}
+
+/// Keep only the rows which match the given constructor in their first column,
+/// replacing that column with the constructor's fields.
+fn specialize(rows: &[Row], constructor: Constructor, arity: usize) -> Vec<Row> {
+    rows.iter()
+        .filter_map(|row| {
+            let mut fields = match &row[0] {
+                Pat::Wildcard => Vec::new(),
+                Pat::Constructor(c, fields) if *c == constructor => fields.clone(),
+                Pat::Constructor(..) => return None,
+            };
+            // The wrong number of fields is reported as an error elsewhere,
+            // pad or truncate them here so that the analysis may continue.
+            fields.resize(arity, Pat::Wildcard);
+            fields.extend_from_slice(&row[1..]);
+            Some(fields)
+        })
+        .collect()
+}
+
+/// Keep only the rows which match anything in their first column, removing that column.
+fn default_rows(rows: &[Row]) -> Vec<Row> {
+    let rows = rows.iter().filter(|row| matches!(row[0], Pat::Wildcard));
+    rows.map(|row| row[1..].to_vec()).collect()
+}
+
+/// True if `vector` matches some value which none of `rows` match.
+fn is_useful(rows: &[Row], vector: &[Pat], types: &[Type]) -> bool {
+    let (head, rest) = match vector.split_first() {
+        Some(split) => split,
+        None => return rows.is_empty(),
+    };
+    let (typ, rest_types) = types.split_first().expect("Expected a type for each column");
+
+    let specialize_vector = |fields: Vec<Pat>, constructor: Constructor| {
+        let field_types = field_types(typ, constructor, fields.len());
+        let mut fields = fields;
+        fields.resize(field_types.len(), Pat::Wildcard);
+
+        let rows = specialize(rows, constructor, field_types.len());
+        let vector: Vec<_> = fields.into_iter().chain(rest.iter().cloned()).collect();
+        let types: Vec<_> = field_types.into_iter().chain(rest_types.iter().cloned()).collect();
+        is_useful(&rows, &vector, &types)
+    };
+
+    match head {
+        Pat::Constructor(constructor, fields) => specialize_vector(fields.clone(), *constructor),
+        Pat::Wildcard => match constructors(typ) {
+            Some(constructors) if is_complete(rows, &constructors) => {
+                constructors.into_iter().any(|(constructor, fields)| {
+                    specialize_vector(vec![Pat::Wildcard; fields.len()], constructor)
+                })
+            }
+            _ => is_useful(&default_rows(rows), rest, rest_types),
+        },
+    }
+}
+
+/// Returns a value for each of `types` which none of the rows match,
+/// or None if the rows match every value.
+fn find_witness(rows: &[Row], types: &[Type]) -> Option<Vec<Witness>> {
+    let (typ, rest_types) = match types.split_first() {
+        Some(split) => split,
+        None => return if rows.is_empty() { Some(Vec::new()) } else { None },
+    };
+
+    match constructors(typ) {
+        Some(constructors) if is_complete(rows, &constructors) => {
+            constructors.into_iter().find_map(|(constructor, field_types)| {
+                let arity = field_types.len();
+                let rows = specialize(rows, constructor, arity);
+                let types: Vec<_> =
+                    field_types.into_iter().chain(rest_types.iter().cloned()).collect();
+
+                let mut fields = find_witness(&rows, &types)?;
+                let rest = fields.split_off(arity);
+                let mut witness = vec![Witness::Constructor(typ.clone(), constructor, fields)];
+                witness.extend(rest);
+                Some(witness)
+            })
+        }
+        constructors => {
+            let mut witness = find_witness(&default_rows(rows), rest_types)?;
+
+            // Prefer naming a missing constructor over a wildcard in error messages
+            let missing = constructors.into_iter().flatten().find(|(constructor, _)| {
+                !rows
+                    .iter()
+                    .any(|row| matches!(&row[0], Pat::Constructor(c, _) if c == constructor))
+            });
+
+            let head = match missing {
+                Some((constructor, fields)) => {
+                    let fields = vec![Witness::Wildcard; fields.len()];
+                    Witness::Constructor(typ.clone(), constructor, fields)
+                }
+                None => Witness::Wildcard,
+            };
+            witness.insert(0, head);
+            Some(witness)
+        }
+    }
+}
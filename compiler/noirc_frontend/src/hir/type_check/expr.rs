@@ -8,13 +8,16 @@ use crate::{
             self, HirArrayLiteral, HirBinaryOp, HirExpression, HirLiteral, HirMethodCallExpression,
             HirMethodReference, HirPrefixExpression,
         },
+        traits::TraitConstraint,
         types::Type,
     },
-    node_interner::{DefinitionKind, ExprId, FuncId, TraitId, TraitImplKind, TraitMethodId},
+    node_interner::{
+        DefinitionKind, ExprId, FuncId, StructId, TraitId, TraitImplKind, TraitMethodId,
+    },
     BinaryOpKind, TypeBinding, TypeBindings, TypeVariableKind, UnaryOp,
 };
 
-use super::{errors::TypeCheckError, TypeChecker};
+use super::{errors::TypeCheckError, exhaustiveness, TypeChecker};
 
 impl<'interner> TypeChecker<'interner> {
     fn check_if_deprecated(&mut self, expr: &ExprId) {
@@ -61,6 +64,10 @@ impl<'interner> TypeChecker<'interner> {
                         let function = self.interner.function_meta(&function);
                         for mut constraint in function.trait_constraints.clone() {
                             constraint.typ = constraint.typ.substitute(&bindings);
+                            constraint.associated_types =
+                                vecmap(&constraint.associated_types, |typ| {
+                                    typ.substitute(&bindings)
+                                });
                             self.trait_constraints.push((constraint, *expr_id));
                         }
                     }
@@ -153,7 +160,9 @@ impl<'interner> TypeChecker<'interner> {
                     (typ, *arg, self.interner.expr_span(arg))
                 });
                 let span = self.interner.expr_span(expr_id);
-                self.bind_function_type(function, args, span)
+                let return_type = self.bind_function_type(function, args, span);
+                self.bind_associated_types();
+                return_type
             }
             HirExpression::MethodCall(mut method_call) => {
                 let object_type = self.check_expression(&method_call.object).follow_bindings();
@@ -207,6 +216,7 @@ impl<'interner> TypeChecker<'interner> {
 
                         let span = self.interner.expr_span(expr_id);
                         let ret = self.check_method_call(&function_id, method_ref, args, span);
+                        self.bind_associated_types();
 
                         if let Some(trait_id) = trait_id {
                             self.verify_trait_constraint(&object_type, trait_id, function_id, span);
@@ -224,33 +234,8 @@ impl<'interner> TypeChecker<'interner> {
                 let span = self.interner.expr_span(expr_id);
                 self.check_cast(lhs_type, cast_expr.r#type, span)
             }
-            HirExpression::Block(block_expr) => {
-                let mut block_type = Type::Unit;
-
-                let statements = block_expr.statements();
-                for (i, stmt) in statements.iter().enumerate() {
-                    let expr_type = self.check_statement(stmt);
-
-                    if let crate::hir_def::stmt::HirStatement::Semi(expr) =
-                        self.interner.statement(stmt)
-                    {
-                        let inner_expr_type = self.interner.id_type(expr);
-                        let span = self.interner.expr_span(&expr);
-
-                        self.unify(&inner_expr_type, &Type::Unit, || {
-                            TypeCheckError::UnusedResultError {
-                                expr_type: inner_expr_type.clone(),
-                                expr_span: span,
-                            }
-                        });
-                    }
-
-                    if i + 1 == statements.len() {
-                        block_type = expr_type;
-                    }
-                }
-
-                block_type
+            HirExpression::Block(block_expr) | HirExpression::Comptime(block_expr) => {
+                self.check_block(block_expr)
             }
             HirExpression::Prefix(prefix_expr) => {
                 let rhs_type = self.check_expression(&prefix_expr.rhs);
@@ -259,6 +244,10 @@ impl<'interner> TypeChecker<'interner> {
             }
             HirExpression::If(if_expr) => self.check_if_expr(&if_expr, expr_id),
             HirExpression::Constructor(constructor) => self.check_constructor(constructor, expr_id),
+            HirExpression::EnumConstructor(constructor) => {
+                self.check_enum_constructor(constructor, expr_id)
+            }
+            HirExpression::Match(match_expr) => self.check_match(&match_expr, expr_id),
             HirExpression::MemberAccess(access) => self.check_member_access(access, *expr_id),
             HirExpression::Error => Type::Error,
             HirExpression::Tuple(elements) => {
@@ -279,10 +268,12 @@ impl<'interner> TypeChecker<'interner> {
                 let actual_return = self.check_expression(&lambda.body);
 
                 let span = self.interner.expr_span(&lambda.body);
-                self.unify(&actual_return, &lambda.return_type, || TypeCheckError::TypeMismatch {
-                    expected_typ: lambda.return_type.to_string(),
-                    expr_typ: actual_return.to_string(),
-                    expr_span: span,
+                self.unify(&actual_return, &lambda.return_type, span, || {
+                    TypeCheckError::TypeMismatch {
+                        expected_typ: lambda.return_type.to_string(),
+                        expr_typ: actual_return.to_string(),
+                        expr_span: span,
+                    }
                 });
 
                 Type::Function(params, Box::new(lambda.return_type), Box::new(env_type))
@@ -306,6 +297,7 @@ impl<'interner> TypeChecker<'interner> {
                         .select_impl_for_ident(*expr_id, TraitImplKind::Assumed { object_type });
                 }
 
+                self.push_associated_type_constraint(method.trait_id, &bindings, *expr_id);
                 self.interner.store_instantiation_bindings(*expr_id, bindings);
                 typ
             }
@@ -342,6 +334,85 @@ impl<'interner> TypeChecker<'interner> {
         }
     }
 
+    /// Trait methods are generic over the associated types and constants of their trait, which
+    /// are instantiated to fresh type variables at each use of the method. This records that
+    /// these variables must equal the types assigned by the impl which is eventually selected.
+    fn push_associated_type_constraint(
+        &mut self,
+        trait_id: TraitId,
+        bindings: &TypeBindings,
+        expr_id: ExprId,
+    ) {
+        let the_trait = self.interner.get_trait(trait_id);
+        let associated_type_variables = the_trait.associated_type_variables();
+        if associated_type_variables.is_empty() {
+            return;
+        }
+
+        let instantiated = |id| bindings.get(&id).map(|(_, typ): &(_, Type)| typ.clone());
+        if let Some(typ) = instantiated(the_trait.self_type_typevar_id) {
+            let associated_types =
+                associated_type_variables.into_iter().filter_map(|(_, id, _)| instantiated(id));
+            let associated_types = associated_types.collect();
+            let constraint = TraitConstraint { typ, trait_id, associated_types };
+            self.trait_constraints.push((constraint, expr_id));
+        }
+    }
+
+    /// Binds the associated types of each pending trait constraint whose object type is known
+    /// to the types assigned by the matching impl. This is done eagerly after each call so that
+    /// the remainder of the function can make use of the resulting types. Any constraint which
+    /// cannot be satisfied is reported once the whole function has been type checked.
+    pub(super) fn bind_associated_types(&mut self) {
+        for index in 0..self.trait_constraints.len() {
+            let (constraint, expr_id) = &mut self.trait_constraints[index];
+            let object_type = constraint.typ.follow_bindings();
+            if constraint.associated_types.is_empty()
+                || matches!(object_type, Type::TypeVariable(..))
+            {
+                continue;
+            }
+
+            let (trait_id, expr_id) = (constraint.trait_id, *expr_id);
+            let associated_types = std::mem::take(&mut constraint.associated_types);
+
+            let impl_types = match self.interner.lookup_trait_implementation(&object_type, trait_id)
+            {
+                Ok(TraitImplKind::Normal(impl_id)) => {
+                    self.interner.get_associated_types(impl_id, &object_type)
+                }
+                Ok(TraitImplKind::Assumed { .. }) => {
+                    self.assumed_associated_types(&object_type, trait_id)
+                }
+                Err(_) => continue,
+            };
+
+            let span = self.interner.expr_span(&expr_id);
+            for (expected, actual) in associated_types.iter().zip(impl_types) {
+                self.unify(&actual, expected, span, || TypeCheckError::TypeMismatch {
+                    expected_typ: expected.to_string(),
+                    expr_typ: actual.to_string(),
+                    expr_span: span,
+                });
+            }
+        }
+    }
+
+    /// Returns the associated types of an impl assumed to exist by the where clause
+    /// of the current function, such as `H::Output` for `where H: Hasher`.
+    fn assumed_associated_types(&self, object_type: &Type, trait_id: TraitId) -> Vec<Type> {
+        let function = match self.current_function {
+            Some(function) => function,
+            None => return Vec::new(),
+        };
+
+        let constraints = self.interner.function_meta(&function).trait_constraints;
+        let constraint = constraints
+            .into_iter()
+            .find(|constraint| constraint.trait_id == trait_id && constraint.typ == *object_type);
+        constraint.map(|constraint| constraint.associated_types).unwrap_or_default()
+    }
+
     /// Check if the given method type requires a mutable reference to the object type, and check
     /// if the given object type is already a mutable reference. If not, add one.
     /// This is used to automatically transform a method call: `foo.bar()` into a function
@@ -570,18 +641,50 @@ impl<'interner> TypeChecker<'interner> {
 
         let (function_type, instantiation_bindings) = fn_typ.instantiate(self.interner);
 
+        if let HirMethodReference::TraitMethodId(method) = method_ref {
+            let bindings = &instantiation_bindings;
+            self.push_associated_type_constraint(method.trait_id, bindings, *function_ident_id);
+        }
+
         self.interner.store_instantiation_bindings(*function_ident_id, instantiation_bindings);
         self.interner.push_expr_type(function_ident_id, function_type.clone());
         self.bind_function_type(function_type, arguments, span)
     }
 
+    fn check_block(&mut self, block_expr: expr::HirBlockExpression) -> Type {
+        let mut block_type = Type::Unit;
+
+        let statements = block_expr.statements();
+        for (i, stmt) in statements.iter().enumerate() {
+            let expr_type = self.check_statement(stmt);
+
+            if let crate::hir_def::stmt::HirStatement::Semi(expr) = self.interner.statement(stmt) {
+                let inner_expr_type = self.interner.id_type(expr);
+                let span = self.interner.expr_span(&expr);
+
+                self.unify(&inner_expr_type, &Type::Unit, span, || {
+                    TypeCheckError::UnusedResultError {
+                        expr_type: inner_expr_type.clone(),
+                        expr_span: span,
+                    }
+                });
+            }
+
+            if i + 1 == statements.len() {
+                block_type = expr_type;
+            }
+        }
+
+        block_type
+    }
+
     fn check_if_expr(&mut self, if_expr: &expr::HirIfExpression, expr_id: &ExprId) -> Type {
         let cond_type = self.check_expression(&if_expr.condition);
         let then_type = self.check_expression(&if_expr.consequence);
 
         let expr_span = self.interner.expr_span(&if_expr.condition);
 
-        self.unify(&cond_type, &Type::Bool, || TypeCheckError::TypeMismatch {
+        self.unify(&cond_type, &Type::Bool, expr_span, || TypeCheckError::TypeMismatch {
             expected_typ: Type::Bool.to_string(),
             expr_typ: cond_type.to_string(),
             expr_span,
@@ -593,7 +696,7 @@ impl<'interner> TypeChecker<'interner> {
                 let else_type = self.check_expression(&alternative);
 
                 let expr_span = self.interner.expr_span(expr_id);
-                self.unify(&then_type, &else_type, || {
+                self.unify(&then_type, &else_type, expr_span, || {
                     let err = TypeCheckError::TypeMismatch {
                         expected_typ: then_type.to_string(),
                         expr_typ: else_type.to_string(),
@@ -655,6 +758,75 @@ impl<'interner> TypeChecker<'interner> {
         Type::Struct(typ, generics)
     }
 
+    fn check_enum_constructor(
+        &mut self,
+        constructor: expr::HirEnumConstructorExpression,
+        expr_id: &ExprId,
+    ) -> Type {
+        let typ = constructor.r#type;
+        let generics = constructor.struct_generics;
+        let (_, params) = typ.borrow().get_variant(constructor.variant_index, &generics);
+
+        for (arg, param_type) in constructor.arguments.into_iter().zip(params) {
+            let arg_type = self.check_expression(&arg);
+
+            let span = self.interner.expr_span(expr_id);
+            self.unify_with_coercions(&arg_type, &param_type, arg, || {
+                TypeCheckError::TypeMismatch {
+                    expected_typ: param_type.to_string(),
+                    expr_typ: arg_type.to_string(),
+                    expr_span: span,
+                }
+            });
+        }
+
+        Type::Struct(typ, generics)
+    }
+
+    fn check_match(&mut self, match_expr: &expr::HirMatchExpression, expr_id: &ExprId) -> Type {
+        let expression_type = self.check_expression(&match_expr.expression);
+        let errors_before = self.errors.len();
+
+        let mut result_type = None;
+        for (pattern, branch) in &match_expr.rules {
+            self.bind_pattern(pattern, expression_type.clone());
+            let branch_type = self.check_expression(branch);
+
+            match &result_type {
+                None => result_type = Some(branch_type),
+                Some(expected) => {
+                    let expr_span = self.interner.expr_span(branch);
+                    self.unify(&branch_type, expected, expr_span, || {
+                        TypeCheckError::TypeMismatch {
+                            expected_typ: expected.to_string(),
+                            expr_typ: branch_type.to_string(),
+                            expr_span,
+                        }
+                        .add_context("Expected the types of all match arms to be equal")
+                    });
+                }
+            }
+        }
+
+        // Patterns of the wrong type could not be meaningfully checked
+        if self.errors.len() == errors_before {
+            let patterns = vecmap(&match_expr.rules, |(pattern, _)| pattern);
+            let check = exhaustiveness::check_match(&expression_type, &patterns, self.interner);
+
+            if let Some(missing) = check.missing_pattern {
+                let span = self.interner.expr_span(expr_id);
+                self.errors.push(TypeCheckError::NonExhaustiveMatch { missing, span });
+            }
+
+            for index in check.unreachable_arms {
+                let span = match_expr.rules[index].0.span();
+                self.errors.push(TypeCheckError::UnreachableMatchArm { span });
+            }
+        }
+
+        result_type.unwrap_or(Type::Unit)
+    }
+
     fn check_member_access(&mut self, mut access: expr::HirMemberAccess, expr_id: ExprId) -> Type {
         let lhs_type = self.check_expression(&access.lhs).follow_bindings();
         let span = self.interner.expr_span(&expr_id);
@@ -675,6 +847,10 @@ impl<'interner> TypeChecker<'interner> {
 
         match self.check_field_access(&lhs_type, &access.rhs.0.contents, span, dereference_lhs) {
             Some((element_type, index)) => {
+                if let Some(struct_id) = struct_id_of(&lhs_type) {
+                    let tracker = self.interner.usage_tracker_mut();
+                    tracker.mark_field_as_read(struct_id, &access.rhs.0.contents);
+                }
                 self.interner.set_field_index(expr_id, index);
                 // We must update `access` in case we added any dereferences to it
                 self.interner.replace_expr(&expr_id, HirExpression::MemberAccess(access));
@@ -839,18 +1015,22 @@ impl<'interner> TypeChecker<'interner> {
             (Array(x_size, x_type), Array(y_size, y_type))
                 if matches!(op.kind, Equal | NotEqual) =>
             {
-                self.unify(x_type, y_type, || TypeCheckError::TypeMismatchWithSource {
-                    expected: lhs_type.clone(),
-                    actual: rhs_type.clone(),
-                    source: Source::ArrayElements,
-                    span: op.location.span,
+                self.unify(x_type, y_type, op.location.span, || {
+                    TypeCheckError::TypeMismatchWithSource {
+                        expected: lhs_type.clone(),
+                        actual: rhs_type.clone(),
+                        source: Source::ArrayElements,
+                        span: op.location.span,
+                    }
                 });
 
-                self.unify(x_size, y_size, || TypeCheckError::TypeMismatchWithSource {
-                    expected: lhs_type.clone(),
-                    actual: rhs_type.clone(),
-                    source: Source::ArrayLen,
-                    span: op.location.span,
+                self.unify(x_size, y_size, op.location.span, || {
+                    TypeCheckError::TypeMismatchWithSource {
+                        expected: lhs_type.clone(),
+                        actual: rhs_type.clone(),
+                        source: Source::ArrayLen,
+                        span: op.location.span,
+                    }
                 });
 
                 Ok(Bool)
@@ -867,11 +1047,13 @@ impl<'interner> TypeChecker<'interner> {
                 })
             }
             (String(x_size), String(y_size)) => {
-                self.unify(x_size, y_size, || TypeCheckError::TypeMismatchWithSource {
-                    expected: *x_size.clone(),
-                    actual: *y_size.clone(),
-                    span: op.location.span,
-                    source: Source::StringLen,
+                self.unify(x_size, y_size, op.location.span, || {
+                    TypeCheckError::TypeMismatchWithSource {
+                        expected: *x_size.clone(),
+                        actual: *y_size.clone(),
+                        span: op.location.span,
+                        source: Source::StringLen,
+                    }
                 });
 
                 Ok(Bool)
@@ -937,6 +1119,12 @@ impl<'interner> TypeChecker<'interner> {
                     }
                 }
 
+                if let Some(method_id) =
+                    self.interner.lookup_generic_method(object_type, method_name)
+                {
+                    return Some(HirMethodReference::FuncId(method_id));
+                }
+
                 self.errors.push(TypeCheckError::UnresolvedMethodCall {
                     method_name: method_name.to_string(),
                     object_type: object_type.clone(),
@@ -994,7 +1182,7 @@ impl<'interner> TypeChecker<'interner> {
         }
 
         for (param, (arg, _, arg_span)) in fn_params.iter().zip(callsite_args) {
-            self.unify(arg, param, || TypeCheckError::TypeMismatch {
+            self.unify(arg, param, *arg_span, || TypeCheckError::TypeMismatch {
                 expected_typ: param.to_string(),
                 expr_typ: arg.to_string(),
                 expr_span: *arg_span,
@@ -1212,6 +1400,16 @@ impl<'interner> TypeChecker<'interner> {
     }
 }
 
+/// Returns the struct whose field is accessed on a value of the given type, looking through any
+/// mutable references since those are dereferenced automatically.
+fn struct_id_of(typ: &Type) -> Option<StructId> {
+    match typ.follow_bindings() {
+        Type::Struct(struct_type, _) => Some(struct_type.borrow().id),
+        Type::MutableReference(element) => struct_id_of(&element),
+        _ => None,
+    }
+}
+
 /// Taken from: https://stackoverflow.com/a/47127500
 fn sort_by_key_ref<T, F, K>(xs: &mut [T], key: F)
 where
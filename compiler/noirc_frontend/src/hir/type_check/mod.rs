@@ -8,13 +8,22 @@
 //! as all functions are required to give their full signatures. Closures are inferred but are
 //! never generalized and thus cannot be used polymorphically.
 mod errors;
+mod exhaustiveness;
 mod expr;
 mod stmt;
 
 pub use errors::TypeCheckError;
 
+use fm::FileId;
+use noirc_errors::{Location, Span};
+
 use crate::{
-    hir_def::{expr::HirExpression, stmt::HirStatement, traits::TraitConstraint},
+    hir_def::{
+        expr::HirExpression,
+        stmt::HirStatement,
+        traits::TraitConstraint,
+        types::{TypeBindings, TypeDiffPart, UnificationError},
+    },
     node_interner::{ExprId, FuncId, NodeInterner, StmtId},
     Type,
 };
@@ -84,6 +93,9 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<Type
         }
     }
 
+    // Bind the associated types of any constraints whose object type was only known at the end
+    type_checker.bind_associated_types();
+
     // Verify any remaining trait constraints arising from the function body
     for (constraint, expr_id) in std::mem::take(&mut type_checker.trait_constraints) {
         let span = type_checker.interner.expr_span(&expr_id);
@@ -112,11 +124,12 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<Type
                 errors.push(error);
             }
         } else {
-            function_last_type.unify_with_coercions(
+            let mut type_checker = TypeChecker::new(interner);
+            type_checker.current_function = Some(func_id);
+            type_checker.unify_with_coercions(
+                &function_last_type,
                 &declared_return_type,
                 *function_body_id,
-                interner,
-                &mut errors,
                 || {
                     let mut error = TypeCheckError::TypeMismatchWithSource {
                         expected: declared_return_type.clone(),
@@ -133,6 +146,7 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<Type
                     error
                 },
             );
+            errors.append(&mut type_checker.errors);
         }
     }
 
@@ -190,14 +204,40 @@ impl<'interner> TypeChecker<'interner> {
         this.errors
     }
 
-    /// Wrapper of Type::unify using self.errors
+    /// Type checks comptime code giving the length of an array, which is evaluated
+    /// before the code using the array is type checked.
+    pub fn check_comptime_array_length(
+        id: &ExprId,
+        interner: &'interner mut NodeInterner,
+    ) -> Vec<TypeCheckError> {
+        let mut this = Self {
+            delayed_type_checks: Vec::new(),
+            interner,
+            errors: Vec::new(),
+            trait_constraints: Vec::new(),
+            current_function: None,
+        };
+        this.check_expression(id);
+        this.errors
+    }
+
+    /// Wrapper of Type::unify using self.errors. Any type variables bound in the process
+    /// remember `span` as the expression they were inferred from.
     fn unify(
         &mut self,
         actual: &Type,
         expected: &Type,
+        span: Span,
         make_error: impl FnOnce() -> TypeCheckError,
     ) {
-        actual.unify(expected, &mut self.errors, make_error);
+        let mut bindings = TypeBindings::new();
+        match actual.try_unify(expected, &mut bindings) {
+            Ok(()) => self.apply_type_bindings(bindings, span),
+            Err(UnificationError) => {
+                let error = self.explain_type_mismatch(make_error(), actual, expected);
+                self.errors.push(error);
+            }
+        }
     }
 
     /// Wrapper of Type::unify_with_coercions using self.errors
@@ -208,13 +248,72 @@ impl<'interner> TypeChecker<'interner> {
         expression: ExprId,
         make_error: impl FnOnce() -> TypeCheckError,
     ) {
-        actual.unify_with_coercions(
-            expected,
-            expression,
-            self.interner,
-            &mut self.errors,
-            make_error,
-        );
+        match actual.try_unify_with_coercions(expected, expression, self.interner) {
+            Ok(bindings) => {
+                let span = self.interner.expr_span(&expression);
+                self.apply_type_bindings(bindings, span);
+            }
+            Err(UnificationError) => {
+                let error = self.explain_type_mismatch(make_error(), actual, expected);
+                self.errors.push(error);
+            }
+        }
+    }
+
+    fn apply_type_bindings(&mut self, bindings: TypeBindings, span: Span) {
+        match self.current_file() {
+            Some(file) => {
+                let origin = Location::new(span, file);
+                Type::apply_type_bindings_with_origin(bindings, origin, self.interner);
+            }
+            None => Type::apply_type_bindings(bindings),
+        }
+    }
+
+    /// The file of the function being type checked, if any.
+    fn current_file(&self) -> Option<FileId> {
+        let function = self.current_function?;
+        let definition = self.interner.function_definition_id(function);
+        Some(self.interner.definition(definition).location.file)
+    }
+
+    /// Adds the parts of `actual` and `expected` which differ to a type mismatch error, along
+    /// with the expression the mismatching part of either type was inferred from, if known.
+    fn explain_type_mismatch(
+        &self,
+        error: TypeCheckError,
+        actual: &Type,
+        expected: &Type,
+    ) -> TypeCheckError {
+        let (diff, error_span) = match (actual.diff(expected), error.type_mismatch_span()) {
+            (Some(diff), Some(span)) => (diff, span),
+            _ => return error,
+        };
+
+        // An origin within the span of the error would only point at the same expression again
+        let origin = [&diff.found_part, &diff.expected_part]
+            .into_iter()
+            .filter_map(|part| self.type_origin(part))
+            .find(|(_, span)| !error_span.contains(span));
+
+        // Only show the diff if it leaves out some of the types, as it would otherwise
+        // repeat the types given by the error itself.
+        let is_partial = diff.found != actual.to_string() || diff.expected != expected.to_string();
+        let diff = is_partial.then_some((diff.expected, diff.found));
+
+        if diff.is_none() && origin.is_none() {
+            return error;
+        }
+        TypeCheckError::ExplainedTypeMismatch { err: Box::new(error), diff, origin }
+    }
+
+    /// The span of the expression a part of a type was inferred from, if it is known and
+    /// within the function being type checked.
+    fn type_origin(&self, part: &TypeDiffPart) -> Option<(Type, Span)> {
+        let file = self.current_file()?;
+        let origin =
+            part.variables.iter().rev().find_map(|id| self.interner.type_variable_origin(*id))?;
+        (origin.file == file).then(|| (part.typ.clone(), origin.span))
     }
 }
 
@@ -1,10 +1,11 @@
+use acvm::FieldElement;
 use iter_extended::vecmap;
 use noirc_errors::{Location, Span};
 
 use crate::hir_def::expr::{HirExpression, HirIdent, HirLiteral};
 use crate::hir_def::stmt::{
     HirAssignStatement, HirConstrainStatement, HirForStatement, HirLValue, HirLetStatement,
-    HirPattern, HirStatement,
+    HirPattern, HirStatement, HirWhileStatement,
 };
 use crate::hir_def::types::Type;
 use crate::node_interner::{DefinitionId, ExprId, StmtId};
@@ -50,7 +51,8 @@ impl<'interner> TypeChecker<'interner> {
             HirStatement::Constrain(constrain_stmt) => self.check_constrain_stmt(constrain_stmt),
             HirStatement::Assign(assign_stmt) => self.check_assign_stmt(assign_stmt, stmt_id),
             HirStatement::For(for_loop) => self.check_for_loop(for_loop),
-            HirStatement::Error => (),
+            HirStatement::While(while_loop) => self.check_while_loop(while_loop),
+            HirStatement::Break | HirStatement::Continue | HirStatement::Error => (),
         }
         Type::Unit
     }
@@ -64,15 +66,17 @@ impl<'interner> TypeChecker<'interner> {
 
         // Check that start range and end range have the same types
         let range_span = start_span.merge(end_span);
-        self.unify(&start_range_type, &end_range_type, || TypeCheckError::TypeMismatch {
-            expected_typ: start_range_type.to_string(),
-            expr_typ: end_range_type.to_string(),
-            expr_span: range_span,
+        self.unify(&start_range_type, &end_range_type, range_span, || {
+            TypeCheckError::TypeMismatch {
+                expected_typ: start_range_type.to_string(),
+                expr_typ: end_range_type.to_string(),
+                expr_span: range_span,
+            }
         });
 
         let expected_type = Type::polymorphic_integer(self.interner);
 
-        self.unify(&start_range_type, &expected_type, || {
+        self.unify(&start_range_type, &expected_type, range_span, || {
             TypeCheckError::TypeCannotBeUsed {
                 typ: start_range_type.clone(),
                 place: "for loop",
@@ -86,6 +90,19 @@ impl<'interner> TypeChecker<'interner> {
         self.check_expression(&for_loop.block);
     }
 
+    fn check_while_loop(&mut self, while_loop: HirWhileStatement) {
+        let condition_type = self.check_expression(&while_loop.condition);
+        let expr_span = self.interner.expr_span(&while_loop.condition);
+
+        self.unify(&condition_type, &Type::Bool, expr_span, || TypeCheckError::TypeMismatch {
+            expected_typ: Type::Bool.to_string(),
+            expr_typ: condition_type.to_string(),
+            expr_span,
+        });
+
+        self.check_expression(&while_loop.block);
+    }
+
     /// Associate a given HirPattern with the given Type, and remember
     /// this association in the NodeInterner.
     pub(crate) fn bind_pattern(&mut self, pattern: &HirPattern, typ: Type) {
@@ -112,7 +129,7 @@ impl<'interner> TypeChecker<'interner> {
                 }
             },
             HirPattern::Struct(struct_type, fields, span) => {
-                self.unify(struct_type, &typ, || TypeCheckError::TypeMismatchWithSource {
+                self.unify(struct_type, &typ, *span, || TypeCheckError::TypeMismatchWithSource {
                     expected: struct_type.clone(),
                     actual: typ.clone(),
                     span: *span,
@@ -131,6 +148,67 @@ impl<'interner> TypeChecker<'interner> {
                     }
                 }
             }
+            HirPattern::EnumVariant(enum_type, variant_index, arguments, span) => {
+                self.unify(enum_type, &typ, *span, || TypeCheckError::TypeMismatchWithSource {
+                    expected: enum_type.clone(),
+                    actual: typ.clone(),
+                    span: *span,
+                    source: Source::Assignment,
+                });
+
+                if let Type::Struct(enum_type, generics) = enum_type {
+                    let (_, params) = enum_type.borrow().get_variant(*variant_index, generics);
+
+                    // A mismatch in the number of arguments was already reported by the resolver
+                    for (argument, param) in arguments.iter().zip(params) {
+                        self.bind_pattern(argument, param);
+                    }
+                }
+            }
+            HirPattern::Wildcard(_) => (),
+            HirPattern::Literal(literal, span) => {
+                let literal_type = self.check_expression(literal);
+                self.unify(&literal_type, &typ, *span, || TypeCheckError::TypeMismatchWithSource {
+                    expected: typ.clone(),
+                    actual: literal_type.clone(),
+                    span: *span,
+                    source: Source::Assignment,
+                });
+            }
+            HirPattern::Array(array_type, elements, rest, span) => {
+                self.unify(array_type, &typ, *span, || TypeCheckError::TypeMismatchWithSource {
+                    expected: array_type.clone(),
+                    actual: typ.clone(),
+                    span: *span,
+                    source: Source::Assignment,
+                });
+
+                if let Type::Array(length, element_type) = array_type {
+                    // Without a `..` the length was already fixed by the resolver
+                    if rest.is_some() && !matches!(typ, Type::Error) {
+                        match length.evaluate_to_u64() {
+                            Some(length) if length < elements.len() as u64 => {
+                                self.errors.push(TypeCheckError::ArrayPatternTooLong {
+                                    patterns: elements.len(),
+                                    length,
+                                    span: *span,
+                                });
+                            }
+                            Some(_) => (),
+                            None => {
+                                self.errors.push(TypeCheckError::ArrayPatternUnknownLength {
+                                    typ: typ.clone(),
+                                    span: *span,
+                                });
+                            }
+                        }
+                    }
+
+                    for element in elements {
+                        self.bind_pattern(element, element_type.as_ref().clone());
+                    }
+                }
+            }
         }
     }
 
@@ -282,10 +360,12 @@ impl<'interner> TypeChecker<'interner> {
                 let element_type = Type::type_variable(self.interner.next_type_variable_id());
                 let expected_type = Type::MutableReference(Box::new(element_type.clone()));
 
-                self.unify(&reference_type, &expected_type, || TypeCheckError::TypeMismatch {
-                    expected_typ: expected_type.to_string(),
-                    expr_typ: reference_type.to_string(),
-                    expr_span: assign_span,
+                self.unify(&reference_type, &expected_type, assign_span, || {
+                    TypeCheckError::TypeMismatch {
+                        expected_typ: expected_type.to_string(),
+                        expr_typ: reference_type.to_string(),
+                        expr_span: assign_span,
+                    }
                 });
 
                 // Dereferences are always mutable since we already type checked against a &mut T
@@ -305,7 +385,7 @@ impl<'interner> TypeChecker<'interner> {
         let expr_type = self.check_expression(&stmt.0);
         let expr_span = self.interner.expr_span(&stmt.0);
 
-        self.unify(&expr_type, &Type::Bool, || TypeCheckError::TypeMismatch {
+        self.unify(&expr_type, &Type::Bool, expr_span, || TypeCheckError::TypeMismatch {
             expr_typ: expr_type.to_string(),
             expected_typ: Type::Bool.to_string(),
             expr_span,
@@ -348,14 +428,15 @@ impl<'interner> TypeChecker<'interner> {
         let span = self.interner.expr_span(rhs_expr);
         match expr {
             HirExpression::Literal(HirLiteral::Integer(value, false)) => {
-                let v = value.to_u128();
                 if let Type::Integer(_, bit_count) = annotated_type {
-                    let max = 1 << bit_count;
-                    if v >= max {
+                    if value.num_bits() > *bit_count {
+                        let max = FieldElement::from(2_u128)
+                            .pow(&FieldElement::from(*bit_count as u128))
+                            - FieldElement::one();
                         self.errors.push(TypeCheckError::OverflowingAssignment {
                             expr: value,
                             ty: annotated_type.clone(),
-                            range: format!("0..={}", max - 1),
+                            range: format!("0..={max}"),
                             span,
                         });
                     };
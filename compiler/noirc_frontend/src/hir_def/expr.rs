@@ -22,14 +22,19 @@ pub enum HirExpression {
     Infix(HirInfixExpression),
     Index(HirIndexExpression),
     Constructor(HirConstructorExpression),
+    EnumConstructor(HirEnumConstructorExpression),
     MemberAccess(HirMemberAccess),
     Call(HirCallExpression),
     MethodCall(HirMethodCallExpression),
     Cast(HirCastExpression),
     If(HirIfExpression),
+    Match(HirMatchExpression),
     Tuple(Vec<ExprId>),
     Lambda(HirLambda),
     TraitMethodReference(TraitMethodId),
+    /// A `comptime { ... }` block. These are evaluated after type checking
+    /// and replaced with the literal value they evaluate to.
+    Comptime(HirBlockExpression),
     Error,
 }
 
@@ -120,6 +125,12 @@ pub struct HirIfExpression {
     pub alternative: Option<ExprId>,
 }
 
+#[derive(Debug, Clone)]
+pub struct HirMatchExpression {
+    pub expression: ExprId,
+    pub rules: Vec<(HirPattern, ExprId)>,
+}
+
 // `lhs as type` in the source code
 #[derive(Debug, Clone)]
 pub struct HirCastExpression {
@@ -172,6 +183,7 @@ impl HirMethodCallExpression {
         let expr = match method {
             HirMethodReference::FuncId(func_id) => {
                 let id = interner.function_definition_id(func_id);
+                interner.usage_tracker_mut().mark_definition_as_used(id);
                 HirExpression::Ident(HirIdent { location, id })
             }
             HirMethodReference::TraitMethodId(method_id) => {
@@ -196,6 +208,17 @@ pub struct HirConstructorExpression {
     pub fields: Vec<(Ident, ExprId)>,
 }
 
+/// Corresponds to a call to an enum variant's constructor, e.g. `Option::Some(3)`.
+/// These are only created by the compiler-generated constructor function of each
+/// variant or when a variant without parameters is used as a value.
+#[derive(Debug, Clone)]
+pub struct HirEnumConstructorExpression {
+    pub r#type: Shared<StructType>,
+    pub struct_generics: Vec<Type>,
+    pub variant_index: usize,
+    pub arguments: Vec<ExprId>,
+}
+
 /// Indexing, as in `array[index]`
 #[derive(Debug, Clone)]
 pub struct HirIndexExpression {
@@ -2,6 +2,7 @@ use super::expr::HirIdent;
 use crate::node_interner::ExprId;
 use crate::{Ident, Type};
 use fm::FileId;
+use iter_extended::vecmap;
 use noirc_errors::Span;
 
 /// A HirStatement is the result of performing name resolution on
@@ -14,6 +15,9 @@ pub enum HirStatement {
     Constrain(HirConstrainStatement),
     Assign(HirAssignStatement),
     For(HirForStatement),
+    While(HirWhileStatement),
+    Break,
+    Continue,
     Expression(ExprId),
     Semi(ExprId),
     Error,
@@ -43,6 +47,14 @@ pub struct HirForStatement {
     pub block: ExprId,
 }
 
+/// Corresponds to `while condition { block }` in the source code.
+/// While loops are only permitted within unconstrained functions.
+#[derive(Debug, Clone)]
+pub struct HirWhileStatement {
+    pub condition: ExprId,
+    pub block: ExprId,
+}
+
 /// Corresponds to `lvalue = expression;` in the source code
 #[derive(Debug, Clone)]
 pub struct HirAssignStatement {
@@ -63,15 +75,25 @@ pub enum HirPattern {
     Mutable(Box<HirPattern>, Span),
     Tuple(Vec<HirPattern>, Span),
     Struct(Type, Vec<(Ident, HirPattern)>, Span),
+    /// An enum variant pattern. The usize is the index of the variant within the enum.
+    EnumVariant(Type, usize, Vec<HirPattern>, Span),
+    Wildcard(Span),
+    /// A literal pattern. The expression is always an integer or boolean literal.
+    Literal(ExprId, Span),
+    /// An array pattern. The usize is the position of the rest pattern `..` among the
+    /// elements, if there is one. The type is the array type the pattern matches.
+    Array(Type, Vec<HirPattern>, Option<usize>, Span),
 }
 
 impl HirPattern {
     pub fn field_count(&self) -> usize {
         match self {
-            HirPattern::Identifier(_) => 0,
+            HirPattern::Identifier(_) | HirPattern::Wildcard(_) | HirPattern::Literal(..) => 0,
             HirPattern::Mutable(pattern, _) => pattern.field_count(),
             HirPattern::Tuple(fields, _) => fields.len(),
             HirPattern::Struct(_, fields, _) => fields.len(),
+            HirPattern::EnumVariant(_, _, arguments, _) => arguments.len(),
+            HirPattern::Array(_, elements, _, _) => elements.len(),
         }
     }
 
@@ -89,12 +111,45 @@ impl HirPattern {
         }
     }
 
+    /// Returns the span of the first sub-pattern which may fail to match, if any.
+    /// Only literals and enum variant patterns can fail to match, the latter only if
+    /// their enum has other variants. The length of an array pattern is checked by its type.
+    pub fn find_refutable_span(&self) -> Option<Span> {
+        match self {
+            HirPattern::Identifier(_) | HirPattern::Wildcard(_) => None,
+            HirPattern::Literal(_, span) => Some(*span),
+            HirPattern::Mutable(pattern, _) => pattern.find_refutable_span(),
+            HirPattern::Tuple(fields, _) | HirPattern::Array(_, fields, _, _) => {
+                fields.iter().find_map(HirPattern::find_refutable_span)
+            }
+            HirPattern::Struct(_, fields, _) => {
+                fields.iter().find_map(|(_, field)| field.find_refutable_span())
+            }
+            HirPattern::EnumVariant(typ, _, arguments, span) => {
+                let variant_count = match typ {
+                    Type::Struct(definition, _) => definition.borrow().variant_count(),
+                    _ => 0,
+                };
+
+                if variant_count > 1 {
+                    Some(*span)
+                } else {
+                    arguments.iter().find_map(HirPattern::find_refutable_span)
+                }
+            }
+        }
+    }
+
     pub fn span(&self) -> Span {
         match self {
             HirPattern::Identifier(ident) => ident.location.span,
             HirPattern::Mutable(_, span)
             | HirPattern::Tuple(_, span)
-            | HirPattern::Struct(_, _, span) => *span,
+            | HirPattern::Struct(_, _, span)
+            | HirPattern::EnumVariant(_, _, _, span)
+            | HirPattern::Wildcard(span)
+            | HirPattern::Literal(_, span)
+            | HirPattern::Array(_, _, _, span) => *span,
         }
     }
 }
@@ -120,3 +175,11 @@ pub enum HirLValue {
         element_type: Type,
     },
 }
+
+/// Returns the index within an array of the given length of each element of an array
+/// pattern with `element_count` elements and a `..` before the element at `rest`, if any.
+pub fn array_pattern_indices(length: u64, element_count: usize, rest: Option<usize>) -> Vec<u64> {
+    let rest = rest.unwrap_or(element_count);
+    let index = |i: usize| if i < rest { i as u64 } else { length - (element_count - i) as u64 };
+    vecmap(0..element_count, index)
+}
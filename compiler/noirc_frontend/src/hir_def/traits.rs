@@ -18,17 +18,31 @@ pub struct TraitFunction {
     pub default_impl_module_id: crate::hir::def_map::LocalModuleId,
 }
 
+/// An associated constant of a trait: `let N: u64;`
+///
+/// The value of an associated constant is a type-level integer so that it may be used as an
+/// array length. Within the trait, `Self::N` refers to `typevar` which each impl binds to the
+/// value it assigns to the constant.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TraitConstant {
     pub name: Ident,
     pub ty: Type,
+    pub typevar_id: TypeVariableId,
+    pub typevar: TypeVariable,
+    /// The value used by impls which do not assign one themselves
+    pub default_value: Option<Type>,
     pub span: Span,
 }
 
+/// An associated type of a trait: `type Output;`
+///
+/// Within the trait, `Self::Output` refers to `typevar` which each impl binds to the type
+/// it assigns to `Output`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TraitType {
     pub name: Ident,
-    pub ty: Type,
+    pub typevar_id: TypeVariableId,
+    pub typevar: TypeVariable,
     pub span: Span,
 }
 
@@ -73,6 +87,10 @@ pub struct TraitImpl {
     pub file: FileId,
     pub methods: Vec<FuncId>, // methods[i] is the implementation of trait.methods[i] for Type typ
 
+    /// The type assigned to each associated type of the trait, followed by the value
+    /// assigned to each associated constant, in the order they are declared in the trait.
+    pub associated_types: Vec<Type>,
+
     /// The where clause, if present, contains each trait requirement which must
     /// be satisfied for this impl to be selected. E.g. in `impl Eq for [T] where T: Eq`,
     /// `where_clause` would contain the one `T: Eq` constraint. If there is no where clause,
@@ -85,11 +103,16 @@ pub struct TraitConstraint {
     pub typ: Type,
     pub trait_id: TraitId,
     // pub trait_generics: Generics, TODO
+    /// The types which the associated types and constants of the trait must be equal to,
+    /// in the same order as `TraitImpl::associated_types`. For a `where H: Hasher` clause
+    /// these are the implicit generics `H::Output` and `H::N` of the function. This is
+    /// empty if the trait has no associated items.
+    pub associated_types: Vec<Type>,
 }
 
 impl TraitConstraint {
     pub fn new(typ: Type, trait_id: TraitId) -> Self {
-        Self { typ, trait_id }
+        Self { typ, trait_id, associated_types: Vec::new() }
     }
 }
 
@@ -118,6 +141,17 @@ impl Trait {
         }
         None
     }
+
+    /// Returns the name and type variable of each associated type of this trait followed by
+    /// each associated constant. Trait impls assign a type to each in the same order.
+    pub fn associated_type_variables(&self) -> Vec<(&Ident, TypeVariableId, TypeVariable)> {
+        let types = self.types.iter().map(|typ| (&typ.name, typ.typevar_id, typ.typevar.clone()));
+        let constants = self
+            .constants
+            .iter()
+            .map(|constant| (&constant.name, constant.typevar_id, constant.typevar.clone()));
+        types.chain(constants).collect()
+    }
 }
 
 impl std::fmt::Display for Trait {
@@ -117,6 +117,13 @@ impl Type {
             }
             Type::Struct(ref def, args) => {
                 let struct_type = def.borrow();
+                if struct_type.is_enum() {
+                    // One field for the tag, followed by the parameters of every variant
+                    let variants = struct_type.get_variants(args);
+                    return variants.iter().fold(1, |acc, (_, params)| {
+                        acc + params.iter().map(Type::field_count).sum::<u32>()
+                    });
+                }
                 let fields = struct_type.get_fields(args);
                 fields.iter().fold(0, |acc, (_, field_type)| acc + field_type.field_count())
             }
@@ -165,10 +172,21 @@ pub struct StructType {
     /// since these will handle applying generic arguments to fields as well.
     fields: Vec<(Ident, Type)>,
 
+    /// The variants of this type if it was declared as an `enum`.
+    /// Like fields, these are private since they need generic arguments applied.
+    variants: Option<Vec<EnumVariant>>,
+
     pub generics: Generics,
     pub location: Location,
 }
 
+/// A single variant of an enum, e.g. `Some(T)` in `enum Option<T> { None, Some(T) }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumVariant {
+    pub name: Ident,
+    pub params: Vec<Type>,
+}
+
 /// Corresponds to generic lists such as `<T, U>` in the source
 /// program. The `TypeVariableId` portion is used to match two
 /// type variables to check for equality, while the `TypeVariable` is
@@ -196,7 +214,65 @@ impl StructType {
         fields: Vec<(Ident, Type)>,
         generics: Generics,
     ) -> StructType {
-        StructType { id, fields, name, location, generics }
+        StructType { id, fields, name, location, generics, variants: None }
+    }
+
+    /// Create a new enum type. Its variants are set later on via `set_variants`
+    /// once the types they refer to have been resolved.
+    pub fn new_enum(
+        id: StructId,
+        name: Ident,
+        location: Location,
+        generics: Generics,
+    ) -> StructType {
+        StructType { id, fields: Vec::new(), name, location, generics, variants: Some(Vec::new()) }
+    }
+
+    pub fn is_enum(&self) -> bool {
+        self.variants.is_some()
+    }
+
+    /// Equivalent to `set_fields` for enum types.
+    pub fn set_variants(&mut self, variants: Vec<EnumVariant>) {
+        assert!(self.is_enum());
+        self.variants = Some(variants);
+    }
+
+    /// Returns the number of variants of this enum, or 0 if this is not an enum.
+    pub fn variant_count(&self) -> usize {
+        self.variants.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Returns the number of parameters the variant at the given index holds.
+    /// Panics if this type is not an enum.
+    pub fn variant_param_count(&self, index: usize) -> usize {
+        self.variants.as_ref().expect("Expected an enum type")[index].params.len()
+    }
+
+    /// Returns the name and parameter types of the variant at the given index,
+    /// after being applied to the given generic arguments.
+    /// Panics if this type is not an enum.
+    pub fn get_variant(&self, index: usize, generic_args: &[Type]) -> (String, Vec<Type>) {
+        assert_eq!(self.generics.len(), generic_args.len());
+        let substitutions = self.substitutions(generic_args);
+
+        let variant = &self.variants.as_ref().expect("Expected an enum type")[index];
+        let params = vecmap(&variant.params, |param| param.substitute(&substitutions));
+        (variant.name.0.contents.clone(), params)
+    }
+
+    /// Returns all the variants of this enum, after being applied to the given generic arguments.
+    /// Returns an empty Vec if this type is not an enum.
+    pub fn get_variants(&self, generic_args: &[Type]) -> Vec<(String, Vec<Type>)> {
+        vecmap(0..self.variant_count(), |index| self.get_variant(index, generic_args))
+    }
+
+    fn substitutions(&self, generic_args: &[Type]) -> TypeBindings {
+        self.generics
+            .iter()
+            .zip(generic_args)
+            .map(|((old_id, old_var), new)| (*old_id, (old_var.clone(), new.clone())))
+            .collect()
     }
 
     /// To account for cyclic references between structs, a struct's
@@ -256,7 +332,12 @@ impl StructType {
     /// This is needed because we infer type kinds in Noir and don't have extensive kind checking.
     pub fn generic_is_numeric(&self, index_of_generic: usize) -> bool {
         let target_id = self.generics[index_of_generic].0;
-        self.fields.iter().any(|(_, field)| field.contains_numeric_typevar(target_id))
+        let variant_params = self.variants.iter().flatten().flat_map(|variant| &variant.params);
+        self.fields
+            .iter()
+            .map(|(_, field)| field)
+            .chain(variant_params)
+            .any(|typ| typ.contains_numeric_typevar(target_id))
     }
 
     /// Instantiate this struct type, returning a Vec of the new generic args (in
@@ -414,12 +495,17 @@ pub enum TypeVariableKind {
 
 /// A TypeVariable is a mutable reference that is either
 /// bound to some type, or unbound with a given TypeVariableId.
+/// The TypeVariableId is kept once the variable is bound so that it can still be identified.
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub struct TypeVariable(Shared<TypeBinding>);
+pub struct TypeVariable(Shared<TypeBinding>, TypeVariableId);
 
 impl TypeVariable {
     pub fn unbound(id: TypeVariableId) -> Self {
-        TypeVariable(Shared::new(TypeBinding::Unbound(id)))
+        TypeVariable(Shared::new(TypeBinding::Unbound(id)), id)
+    }
+
+    pub fn id(&self) -> TypeVariableId {
+        self.1
     }
 
     /// Bind this type variable to a value.
@@ -503,7 +589,7 @@ impl Type {
     }
 
     pub fn type_variable(id: TypeVariableId) -> Type {
-        let var = TypeVariable(Shared::new(TypeBinding::Unbound(id)));
+        let var = TypeVariable::unbound(id);
         Type::TypeVariable(var, TypeVariableKind::Normal)
     }
 
@@ -512,14 +598,14 @@ impl Type {
     pub fn constant_variable(length: u64, interner: &mut NodeInterner) -> Type {
         let id = interner.next_type_variable_id();
         let kind = TypeVariableKind::Constant(length);
-        let var = TypeVariable(Shared::new(TypeBinding::Unbound(id)));
+        let var = TypeVariable::unbound(id);
         Type::TypeVariable(var, kind)
     }
 
     pub fn polymorphic_integer(interner: &mut NodeInterner) -> Type {
         let id = interner.next_type_variable_id();
         let kind = TypeVariableKind::IntegerOrField;
-        let var = TypeVariable(Shared::new(TypeBinding::Unbound(id)));
+        let var = TypeVariable::unbound(id);
         Type::TypeVariable(var, kind)
     }
 
@@ -639,6 +725,8 @@ impl Type {
             }
             Type::String(length) => length.is_valid_for_program_input(),
             Type::Tuple(elements) => elements.iter().all(|elem| elem.is_valid_for_program_input()),
+            // Enums have no ABI representation yet
+            Type::Struct(definition, _) if definition.borrow().is_enum() => false,
             Type::Struct(definition, generics) => definition
                 .borrow()
                 .get_fields(generics)
@@ -785,6 +873,174 @@ impl std::fmt::Display for TypeBinding {
 
 pub struct UnificationError;
 
+/// The parts of two types which differ, found after they failed to unify.
+#[derive(Debug, Clone)]
+pub struct TypeDiff {
+    /// The expected type, with each part it has in common with the found type shown as `_`.
+    pub expected: String,
+    /// The found type, with each part it has in common with the expected type shown as `_`.
+    pub found: String,
+    /// The first part of the expected type which differs from the found type.
+    pub expected_part: TypeDiffPart,
+    /// The first part of the found type which differs from the expected type.
+    pub found_part: TypeDiffPart,
+}
+
+/// A part of a type which differs from the corresponding part of another type.
+#[derive(Debug, Clone)]
+pub struct TypeDiffPart {
+    pub typ: Type,
+    /// The type variables which were bound to this part, directly or through the types
+    /// containing it, from the outermost to the innermost.
+    pub variables: Vec<TypeVariableId>,
+}
+
+/// Walks two types which failed to unify in parallel, rendering the parts they have in
+/// common as `_` so that the parts which differ stand out.
+#[derive(Default)]
+struct TypeDiffer {
+    expected_variables: Vec<TypeVariableId>,
+    found_variables: Vec<TypeVariableId>,
+    first_difference: Option<(TypeDiffPart, TypeDiffPart)>,
+    differences: usize,
+}
+
+impl TypeDiffer {
+    /// Renders both types with their common parts shown as `_`, or returns `None` if they unify.
+    fn diff(&mut self, expected: &Type, found: &Type) -> Option<(String, String)> {
+        let expected_depth = self.expected_variables.len();
+        let found_depth = self.found_variables.len();
+
+        let expected = Self::follow_variables(expected, &mut self.expected_variables);
+        let found = Self::follow_variables(found, &mut self.found_variables);
+        let result = self.diff_followed(&expected, &found);
+
+        self.expected_variables.truncate(expected_depth);
+        self.found_variables.truncate(found_depth);
+        result
+    }
+
+    fn diff_followed(&mut self, expected: &Type, found: &Type) -> Option<(String, String)> {
+        if expected.try_unify(found, &mut TypeBindings::new()).is_ok() {
+            return None;
+        }
+
+        let differences = self.differences;
+        let texts = match (expected, found) {
+            (Type::Array(len_a, elem_a), Type::Array(len_b, elem_b)) => {
+                let (len_a_text, len_b_text) = self.diff_part(len_a, len_b);
+                let (elem_a_text, elem_b_text) = self.diff_part(elem_a, elem_b);
+                let array = |len: &Type, len_text: String, elem_text: String| {
+                    if matches!(len.follow_bindings(), Type::NotConstant) {
+                        format!("[{elem_text}]")
+                    } else {
+                        format!("[{elem_text}; {len_text}]")
+                    }
+                };
+                Some((array(len_a, len_a_text, elem_a_text), array(len_b, len_b_text, elem_b_text)))
+            }
+            (Type::String(len_a), Type::String(len_b)) => {
+                let (len_a, len_b) = self.diff_part(len_a, len_b);
+                Some((format!("str<{len_a}>"), format!("str<{len_b}>")))
+            }
+            (Type::FmtString(len_a, elements_a), Type::FmtString(len_b, elements_b)) => {
+                let (len_a, len_b) = self.diff_part(len_a, len_b);
+                let (elements_a, elements_b) = self.diff_part(elements_a, elements_b);
+                Some((
+                    format!("fmtstr<{len_a}, {elements_a}>"),
+                    format!("fmtstr<{len_b}, {elements_b}>"),
+                ))
+            }
+            (Type::Tuple(elements_a), Type::Tuple(elements_b))
+                if elements_a.len() == elements_b.len() =>
+            {
+                let (elements_a, elements_b) = self.diff_parts(elements_a, elements_b);
+                Some((
+                    format!("({})", elements_a.join(", ")),
+                    format!("({})", elements_b.join(", ")),
+                ))
+            }
+            (Type::Struct(struct_a, args_a), Type::Struct(struct_b, args_b))
+                if struct_a == struct_b && !args_a.is_empty() && args_a.len() == args_b.len() =>
+            {
+                let name = struct_a.borrow().to_string();
+                let (args_a, args_b) = self.diff_parts(args_a, args_b);
+                Some((
+                    format!("{name}<{}>", args_a.join(", ")),
+                    format!("{name}<{}>", args_b.join(", ")),
+                ))
+            }
+            (Type::Function(params_a, ret_a, env_a), Type::Function(params_b, ret_b, env_b))
+                if params_a.len() == params_b.len() =>
+            {
+                let (params_a, params_b) = self.diff_parts(params_a, params_b);
+                let (ret_a, ret_b) = self.diff_part(ret_a, ret_b);
+                let (env_a_text, env_b_text) = self.diff_part(env_a, env_b);
+                let function = |params: Vec<String>, ret: String, env: &Type, env_text: String| {
+                    let params = params.join(", ");
+                    match env.follow_bindings() {
+                        Type::Unit => format!("fn({params}) -> {ret}"),
+                        _ => format!("fn({params}) -> {ret} with closure environment {env_text}"),
+                    }
+                };
+                Some((
+                    function(params_a, ret_a, env_a, env_a_text),
+                    function(params_b, ret_b, env_b, env_b_text),
+                ))
+            }
+            (Type::MutableReference(elem_a), Type::MutableReference(elem_b)) => {
+                let (elem_a, elem_b) = self.diff_part(elem_a, elem_b);
+                Some((format!("&mut {elem_a}"), format!("&mut {elem_b}")))
+            }
+            _ => None,
+        };
+
+        match texts {
+            // Only highlight the parts within these types if any of them differ on their own
+            Some(texts) if self.differences > differences => Some(texts),
+            _ => {
+                self.add_difference(expected, found);
+                Some((expected.to_string(), found.to_string()))
+            }
+        }
+    }
+
+    /// Diffs two corresponding parts of a type, rendering them as `_` if they don't differ.
+    fn diff_part(&mut self, expected: &Type, found: &Type) -> (String, String) {
+        self.diff(expected, found).unwrap_or_else(|| ("_".to_string(), "_".to_string()))
+    }
+
+    fn diff_parts(&mut self, expected: &[Type], found: &[Type]) -> (Vec<String>, Vec<String>) {
+        expected.iter().zip(found).map(|(expected, found)| self.diff_part(expected, found)).unzip()
+    }
+
+    fn add_difference(&mut self, expected: &Type, found: &Type) {
+        self.differences += 1;
+        if self.first_difference.is_none() {
+            let expected =
+                TypeDiffPart { typ: expected.clone(), variables: self.expected_variables.clone() };
+            let found =
+                TypeDiffPart { typ: found.clone(), variables: self.found_variables.clone() };
+            self.first_difference = Some((expected, found));
+        }
+    }
+
+    /// Follows the bindings of any type variables at the top of `typ`, pushing their ids
+    /// onto `variables`.
+    fn follow_variables(typ: &Type, variables: &mut Vec<TypeVariableId>) -> Type {
+        match typ {
+            Type::TypeVariable(var, _) | Type::NamedGeneric(var, _) => match &*var.borrow() {
+                TypeBinding::Bound(binding) => {
+                    variables.push(var.id());
+                    Self::follow_variables(binding, variables)
+                }
+                TypeBinding::Unbound(_) => typ.clone(),
+            },
+            _ => typ.clone(),
+        }
+    }
+}
+
 impl Type {
     /// Try to bind a MaybeConstant variable to self, succeeding if self is a Constant,
     /// MaybeConstant, or type variable. If successful, the binding is placed in the
@@ -1130,25 +1386,40 @@ impl Type {
         errors: &mut Vec<TypeCheckError>,
         make_error: impl FnOnce() -> TypeCheckError,
     ) {
+        match self.try_unify_with_coercions(expected, expression, interner) {
+            Ok(bindings) => Type::apply_type_bindings(bindings),
+            Err(UnificationError) => errors.push(make_error()),
+        }
+    }
+
+    /// Similar to `try_unify` but if the check fails this will attempt to coerce the
+    /// argument to the target type, as in `unify_with_coercions`. On success, this returns
+    /// the type bindings needed to unify the types, which have not been applied yet.
+    pub fn try_unify_with_coercions(
+        &self,
+        expected: &Type,
+        expression: ExprId,
+        interner: &mut NodeInterner,
+    ) -> Result<TypeBindings, UnificationError> {
         let mut bindings = TypeBindings::new();
 
-        if let Err(UnificationError) = self.try_unify(expected, &mut bindings) {
-            if !self.try_array_to_slice_coercion(expected, expression, interner) {
-                errors.push(make_error());
+        match self.try_unify(expected, &mut bindings) {
+            Ok(()) => Ok(bindings),
+            Err(UnificationError) => {
+                self.try_array_to_slice_coercion(expected, expression, interner)
             }
-        } else {
-            Type::apply_type_bindings(bindings);
         }
     }
 
     /// Try to apply the array to slice coercion to this given type pair and expression.
-    /// If self can be converted to target this way, do so and return true to indicate success.
+    /// If self can be converted to target this way, do so and return the type bindings
+    /// needed for the element types to match.
     fn try_array_to_slice_coercion(
         &self,
         target: &Type,
         expression: ExprId,
         interner: &mut NodeInterner,
-    ) -> bool {
+    ) -> Result<TypeBindings, UnificationError> {
         let this = self.follow_bindings();
         let target = target.follow_bindings();
 
@@ -1163,12 +1434,11 @@ impl Type {
                 let mut bindings = TypeBindings::new();
                 if element1.try_unify(element2, &mut bindings).is_ok() {
                     convert_array_expression_to_slice(expression, this, target, interner);
-                    Self::apply_type_bindings(bindings);
-                    return true;
+                    return Ok(bindings);
                 }
             }
         }
-        false
+        Err(UnificationError)
     }
 
     /// Apply the given type bindings, making them permanently visible for each
@@ -1179,6 +1449,30 @@ impl Type {
         }
     }
 
+    /// Apply the given type bindings as in `apply_type_bindings`, recording `origin` as the
+    /// location of the expression which caused each type variable to be bound. This is used
+    /// to point at where a type was inferred when it later fails to unify with another type.
+    pub fn apply_type_bindings_with_origin(
+        bindings: TypeBindings,
+        origin: Location,
+        interner: &mut NodeInterner,
+    ) {
+        for id in bindings.keys() {
+            interner.record_type_variable_origin(*id, origin);
+        }
+        Self::apply_type_bindings(bindings);
+    }
+
+    /// Compares this type against `expected` after the two failed to unify, finding the parts
+    /// of each type which differ. Returns `None` if the types do unify.
+    pub fn diff(&self, expected: &Type) -> Option<TypeDiff> {
+        let mut differ = TypeDiffer::default();
+        let (expected_text, found_text) = differ.diff(expected, self)?;
+        let (expected_part, found_part) = differ.first_difference?;
+
+        Some(TypeDiff { expected: expected_text, found: found_text, expected_part, found_part })
+    }
+
     /// If this type is a Type::Constant (used in array lengths), or is bound
     /// to a Type::Constant, return the constant as a u64.
     pub fn evaluate_to_u64(&self) -> Option<u64> {
@@ -1584,6 +1878,13 @@ impl From<&Type> for PrintableType {
             Type::Error => unreachable!(),
             Type::Unit => unreachable!(),
             Type::Constant(_) => unreachable!(),
+            Type::Struct(def, ref args) if def.borrow().is_enum() => {
+                let enum_type = def.borrow();
+                let variants = vecmap(enum_type.get_variants(args), |(name, params)| {
+                    (name, vecmap(params, Into::into))
+                });
+                PrintableType::Enum { variants, name: enum_type.name.to_string() }
+            }
             Type::Struct(def, ref args) => {
                 let struct_type = def.borrow();
                 let fields = struct_type.get_fields(args);
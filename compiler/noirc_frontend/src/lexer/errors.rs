@@ -53,6 +53,21 @@ impl LexerErrorKind {
         }
     }
 
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LexerErrorKind::UnexpectedCharacter { .. } => "E0001",
+            LexerErrorKind::NotADoubleChar { .. } => "E0002",
+            LexerErrorKind::InvalidIntegerLiteral { .. } => "E0003",
+            LexerErrorKind::MalformedFuncAttribute { .. } => "E0004",
+            LexerErrorKind::TooManyBits { .. } => "E0005",
+            LexerErrorKind::LogicalAnd { .. } => "E0006",
+            LexerErrorKind::UnterminatedBlockComment { .. } => "E0007",
+            LexerErrorKind::UnterminatedStringLiteral { .. } => "E0008",
+            LexerErrorKind::InvalidEscape { .. } => "E0009",
+        }
+    }
+
     fn parts(&self) -> (String, String, Span) {
         match self {
             LexerErrorKind::UnexpectedCharacter {
@@ -88,7 +103,7 @@ impl LexerErrorKind {
             LexerErrorKind::TooManyBits { span, max, got } => (
                 "Integer literal too large".to_string(),
                 format!(
-                    "The maximum number of bits needed to represent a field is {max}, This integer type needs {got} bits"
+                    "The maximum number of bits supported for this integer type is {max}, This integer type needs {got} bits"
                 ),
                 *span,
             ),
@@ -109,7 +124,7 @@ impl LexerErrorKind {
 impl From<LexerErrorKind> for Diagnostic {
     fn from(error: LexerErrorKind) -> Diagnostic {
         let (primary, secondary, span) = error.parts();
-        Diagnostic::simple_error(primary, secondary, span)
+        Diagnostic::simple_error(primary, secondary, span).with_code(error.code())
     }
 }
 
@@ -193,7 +193,18 @@ impl<'a> Lexer<'a> {
                 }
             }
             Token::Bang => self.single_double_peek_token('=', prev_token, Token::NotEqual),
-            Token::Assign => self.single_double_peek_token('=', prev_token, Token::Equal),
+            Token::Assign => {
+                let start = self.position;
+                if self.peek_char_is('=') {
+                    self.next_char();
+                    Ok(Token::Equal.into_span(start, start + 1))
+                } else if self.peek_char_is('>') {
+                    self.next_char();
+                    Ok(Token::FatArrow.into_span(start, start + 1))
+                } else {
+                    Ok(prev_token.into_single_span(start))
+                }
+            }
             Token::Minus => self.single_double_peek_token('>', prev_token, Token::Arrow),
             Token::Colon => self.single_double_peek_token(':', prev_token, Token::DoubleColon),
             Token::Slash => {
@@ -581,10 +592,11 @@ impl<'a> Iterator for Lexer<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::token::{FunctionAttribute, SecondaryAttribute, TestScope};
+    use crate::lints::LintLevel;
+    use crate::token::{FunctionAttribute, InlineType, SecondaryAttribute, TestScope};
     #[test]
     fn test_single_double_char() {
-        let input = "! != + ( ) { } [ ] | , ; : :: < <= > >= & - -> . .. % / * = == << >>";
+        let input = "! != + ( ) { } [ ] | , ; : :: < <= > >= & - -> . .. % / * = == => << >>";
 
         let expected = vec![
             Token::Bang,
@@ -615,6 +627,7 @@ mod tests {
             Token::Star,
             Token::Assign,
             Token::Equal,
+            Token::FatArrow,
             Token::ShiftLeft,
             Token::Greater,
             Token::Greater,
@@ -714,6 +727,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn derive_attribute() {
+        let input = r#"#[derive(Eq, Default)]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Secondary(SecondaryAttribute::Derive(vec![
+                "Eq".to_string(),
+                "Default".to_string()
+            ])))
+        );
+    }
+
+    #[test]
+    fn lint_attribute() {
+        let input = r#"#[allow(unused_variables, dead_code)]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Secondary(SecondaryAttribute::Lint(
+                LintLevel::Allow,
+                vec!["unused_variables".to_string(), "dead_code".to_string()]
+            )))
+        );
+    }
+
+    #[test]
+    fn inline_attribute() {
+        let input = r#"#[inline(never)]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Secondary(SecondaryAttribute::Inline(InlineType::Never)))
+        );
+
+        let mut lexer = Lexer::new(r#"#[inline(sometimes)]"#);
+        assert!(matches!(lexer.next(), Some(Err(LexerErrorKind::MalformedFuncAttribute { .. }))));
+    }
+
     #[test]
     fn test_attribute() {
         let input = r#"#[test]"#;
@@ -806,6 +864,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wide_int_type() {
+        let mut lexer = Lexer::new("u128 u252 i127");
+        assert_eq!(lexer.next_token().unwrap(), Token::IntType(IntType::Unsigned(128)));
+        assert_eq!(lexer.next_token().unwrap(), Token::IntType(IntType::Unsigned(252)));
+        assert_eq!(lexer.next_token().unwrap(), Token::IntType(IntType::Signed(127)));
+
+        for input in ["u253", "i128"] {
+            let mut lexer = Lexer::new(input);
+            match lexer.next_token() {
+                Err(LexerErrorKind::TooManyBits { .. }) => (),
+                other => panic!("expected {input} to have too many bits, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn test_arithmetic_sugar() {
         let input = "+= -= *= /= %=";
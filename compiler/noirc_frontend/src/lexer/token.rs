@@ -1,8 +1,10 @@
 use acvm::FieldElement;
+use iter_extended::vecmap;
 use noirc_errors::{Position, Span, Spanned};
 use std::{fmt, iter::Map, vec::IntoIter};
 
 use crate::lexer::errors::LexerErrorKind;
+use crate::lints::LintLevel;
 
 /// Represents a token in noir's grammar - a word, number,
 /// or symbol that can be used in noir's syntax. This is the
@@ -70,6 +72,8 @@ pub enum Token {
     RightBracket,
     /// ->
     Arrow,
+    /// =>
+    FatArrow,
     /// |
     Pipe,
     /// #
@@ -191,6 +195,7 @@ impl fmt::Display for Token {
             Token::LeftBracket => write!(f, "["),
             Token::RightBracket => write!(f, "]"),
             Token::Arrow => write!(f, "->"),
+            Token::FatArrow => write!(f, "=>"),
             Token::Pipe => write!(f, "|"),
             Token::Pound => write!(f, "#"),
             Token::Comma => write!(f, ","),
@@ -324,7 +329,14 @@ impl IntType {
             Err(_) => return Ok(None),
         };
 
-        let max_bits = FieldElement::max_num_bits() / 2;
+        // Signed integers are limited to half the field's bit size so that the product of two
+        // of them still fits within a field element. Unsigned integers are instead multiplied by
+        // splitting them into limbs, so only the sum of two of them needs to fit.
+        let max_bits = if is_signed {
+            FieldElement::max_num_bits() / 2
+        } else {
+            FieldElement::max_num_bits() - 2
+        };
 
         if str_as_u32 > max_bits {
             return Err(LexerErrorKind::TooManyBits { span, max: max_bits, got: str_as_u32 });
@@ -380,6 +392,33 @@ impl fmt::Display for TestScope {
     }
 }
 
+/// Overrides the decision of the compiler on whether to inline calls to a function,
+/// as in `#[inline(never)]`
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, PartialOrd, Ord)]
+pub enum InlineType {
+    Always,
+    Never,
+}
+
+impl InlineType {
+    fn lookup_str(string: &str) -> Option<InlineType> {
+        match string.trim() {
+            "always" => Some(InlineType::Always),
+            "never" => Some(InlineType::Never),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for InlineType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InlineType::Always => write!(f, "always"),
+            InlineType::Never => write!(f, "never"),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord)]
 // Attributes are special language markers in the target language
 // An example of one is `#[SHA256]` . Currently only Foreign attributes are supported
@@ -425,6 +464,14 @@ impl Attributes {
         })
     }
 
+    /// Returns the inlining behavior requested by an `#[inline(..)]` attribute, if any
+    pub fn inline_type(&self) -> Option<InlineType> {
+        self.secondary.iter().find_map(|attr| match attr {
+            SecondaryAttribute::Inline(inline_type) => Some(*inline_type),
+            _ => None,
+        })
+    }
+
     pub fn get_field_attribute(&self) -> Option<String> {
         for secondary in &self.secondary {
             if let SecondaryAttribute::Field(field) = secondary {
@@ -510,6 +557,38 @@ impl Attribute {
                 Attribute::Secondary(SecondaryAttribute::ContractLibraryMethod)
             }
             ["event"] => Attribute::Secondary(SecondaryAttribute::Event),
+            ["derive", traits] => {
+                validate(traits)?;
+                let traits = vecmap(traits.split(','), |name| name.trim().to_string());
+                if traits.iter().any(|name| name.is_empty()) {
+                    return Err(LexerErrorKind::MalformedFuncAttribute {
+                        span,
+                        found: word.to_owned(),
+                    });
+                }
+                Attribute::Secondary(SecondaryAttribute::Derive(traits))
+            }
+            [level @ ("allow" | "warn" | "deny"), lints] => {
+                validate(lints)?;
+                let level = LintLevel::from_attribute_name(level).expect("Matched a lint level");
+                let lints = vecmap(lints.split(','), |name| name.trim().to_string());
+                if lints.iter().any(|name| name.is_empty()) {
+                    return Err(LexerErrorKind::MalformedFuncAttribute {
+                        span,
+                        found: word.to_owned(),
+                    });
+                }
+                Attribute::Secondary(SecondaryAttribute::Lint(level, lints))
+            }
+            ["inline", inline_type] => match InlineType::lookup_str(inline_type) {
+                Some(inline_type) => Attribute::Secondary(SecondaryAttribute::Inline(inline_type)),
+                None => {
+                    return Err(LexerErrorKind::MalformedFuncAttribute {
+                        span,
+                        found: word.to_owned(),
+                    })
+                }
+            },
             ["deprecated", name] => {
                 if !name.starts_with('"') && !name.ends_with('"') {
                     return Err(LexerErrorKind::MalformedFuncAttribute {
@@ -589,6 +668,12 @@ pub enum SecondaryAttribute {
     ContractLibraryMethod,
     Event,
     Field(String),
+    /// The names of the traits to generate impls for, as in `#[derive(Eq, Default)]`
+    Derive(Vec<String>),
+    /// The level to set for each of the named lints, as in `#[allow(unused_variables)]`
+    Lint(LintLevel, Vec<String>),
+    /// Whether calls to the function should always or never be inlined, as in `#[inline(never)]`
+    Inline(InlineType),
     Custom(String),
 }
 
@@ -603,6 +688,9 @@ impl fmt::Display for SecondaryAttribute {
             SecondaryAttribute::ContractLibraryMethod => write!(f, "#[contract_library_method]"),
             SecondaryAttribute::Event => write!(f, "#[event]"),
             SecondaryAttribute::Field(ref k) => write!(f, "#[field({k})]"),
+            SecondaryAttribute::Derive(traits) => write!(f, "#[derive({})]", traits.join(", ")),
+            SecondaryAttribute::Lint(level, lints) => write!(f, "#[{level}({})]", lints.join(", ")),
+            SecondaryAttribute::Inline(inline_type) => write!(f, "#[inline({inline_type})]"),
         }
     }
 }
@@ -626,6 +714,9 @@ impl AsRef<str> for SecondaryAttribute {
             SecondaryAttribute::Custom(string) | SecondaryAttribute::Field(string) => string,
             SecondaryAttribute::ContractLibraryMethod => "",
             SecondaryAttribute::Event => "",
+            SecondaryAttribute::Derive(_) => "",
+            SecondaryAttribute::Lint(..) => "",
+            SecondaryAttribute::Inline(_) => "",
         }
     }
 }
@@ -639,15 +730,18 @@ pub enum Keyword {
     Assert,
     AssertEq,
     Bool,
+    Break,
     CallData,
     Char,
     CompTime,
     Constrain,
+    Continue,
     Contract,
     Crate,
     Dep,
     Distinct,
     Else,
+    Enum,
     Field,
     Fn,
     For,
@@ -658,6 +752,7 @@ pub enum Keyword {
     In,
     Internal,
     Let,
+    Match,
     Mod,
     Mut,
     Open,
@@ -681,15 +776,18 @@ impl fmt::Display for Keyword {
             Keyword::Assert => write!(f, "assert"),
             Keyword::AssertEq => write!(f, "assert_eq"),
             Keyword::Bool => write!(f, "bool"),
+            Keyword::Break => write!(f, "break"),
             Keyword::Char => write!(f, "char"),
             Keyword::CallData => write!(f, "call_data"),
             Keyword::CompTime => write!(f, "comptime"),
             Keyword::Constrain => write!(f, "constrain"),
+            Keyword::Continue => write!(f, "continue"),
             Keyword::Contract => write!(f, "contract"),
             Keyword::Crate => write!(f, "crate"),
             Keyword::Dep => write!(f, "dep"),
             Keyword::Distinct => write!(f, "distinct"),
             Keyword::Else => write!(f, "else"),
+            Keyword::Enum => write!(f, "enum"),
             Keyword::Field => write!(f, "Field"),
             Keyword::Fn => write!(f, "fn"),
             Keyword::For => write!(f, "for"),
@@ -700,6 +798,7 @@ impl fmt::Display for Keyword {
             Keyword::In => write!(f, "in"),
             Keyword::Internal => write!(f, "internal"),
             Keyword::Let => write!(f, "let"),
+            Keyword::Match => write!(f, "match"),
             Keyword::Mod => write!(f, "mod"),
             Keyword::Mut => write!(f, "mut"),
             Keyword::Open => write!(f, "open"),
@@ -726,15 +825,18 @@ impl Keyword {
             "assert" => Keyword::Assert,
             "assert_eq" => Keyword::AssertEq,
             "bool" => Keyword::Bool,
+            "break" => Keyword::Break,
             "call_data" => Keyword::CallData,
             "char" => Keyword::Char,
             "comptime" => Keyword::CompTime,
             "constrain" => Keyword::Constrain,
+            "continue" => Keyword::Continue,
             "contract" => Keyword::Contract,
             "crate" => Keyword::Crate,
             "dep" => Keyword::Dep,
             "distinct" => Keyword::Distinct,
             "else" => Keyword::Else,
+            "enum" => Keyword::Enum,
             "Field" => Keyword::Field,
             "fn" => Keyword::Fn,
             "for" => Keyword::For,
@@ -745,6 +847,7 @@ impl Keyword {
             "in" => Keyword::In,
             "internal" => Keyword::Internal,
             "let" => Keyword::Let,
+            "match" => Keyword::Match,
             "mod" => Keyword::Mod,
             "mut" => Keyword::Mut,
             "open" => Keyword::Open,
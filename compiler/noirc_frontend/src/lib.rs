@@ -13,9 +13,11 @@
 pub mod ast;
 pub mod graph;
 pub mod lexer;
+pub mod lints;
 pub mod monomorphization;
 pub mod node_interner;
 pub mod parser;
+pub mod usage_tracker;
 
 pub mod hir;
 pub mod hir_def;
@@ -58,7 +60,7 @@ pub mod macros_api {
         Pattern, Statement, UnresolvedType, UnresolvedTypeData, Visibility,
     };
     pub use crate::{
-        ForLoopStatement, ForRange, FunctionDefinition, FunctionVisibility, ImportStatement,
+        ForLoopStatement, ForRange, FunctionDefinition, ImportStatement, ItemVisibility,
         NoirStruct, Param, PrefixExpression, Signedness, StatementKind, StructType, Type, TypeImpl,
         UnaryOp,
     };
@@ -72,6 +74,21 @@ pub mod macros_api {
             crate_id: &CrateId,
             context: &HirContext,
         ) -> Result<SortedModule, (MacroError, FileId)>;
+        /// Function to manipulate the AST of a module declared in its own file, such as
+        /// `foo/bar.nr` for the module `foo::bar`, before type checking has been completed.
+        /// This is only called for the macro processors registered on a single crate with
+        /// [HirContext::add_macro_processor], as the root module of the crate is given to
+        /// [MacroProcessor::process_untyped_ast] instead. By default the AST is unchanged.
+        fn process_untyped_module_file(
+            &self,
+            ast: SortedModule,
+            _crate_id: &CrateId,
+            _file_id: FileId,
+            _module_path: &str,
+            _context: &HirContext,
+        ) -> Result<SortedModule, (MacroError, FileId)> {
+            Ok(ast)
+        }
         /// Function to manipulate the AST after type checking has been completed.
         /// The AST after type checking has been done is called the HIR.
         fn process_typed_ast(&self, crate_id: &CrateId, context: &mut HirContext);
@@ -0,0 +1,191 @@
+//! Lints are the warnings of the compiler which can be allowed, or turned into errors, within
+//! part of a program using the `#[allow(...)]`, `#[warn(...)]` and `#[deny(...)]` attributes.
+//!
+//! These attributes can be placed on module declarations, functions, structs and statements.
+//! A level set on a module declaration applies to the whole module, including any modules
+//! declared within it, and the level set by the innermost item or statement takes precedence.
+use std::collections::HashMap;
+
+use fm::FileId;
+use noirc_errors::{Location, Span};
+
+use crate::token::SecondaryAttribute;
+
+/// A kind of warning which can be allowed or denied by name.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// Every other lint at once, as in `#[allow(warnings)]`.
+    Warnings,
+    UnusedVariables,
+    UnusedImports,
+    /// Functions, globals and struct fields which are never used.
+    DeadCode,
+    UnnecessaryPub,
+    Deprecated,
+    UnusedResults,
+    UnneededTraitConstraints,
+    UnreachablePatterns,
+    /// Integer operations on constants which are known to overflow their type.
+    ArithmeticOverflow,
+    /// Returning a constant value from `main`.
+    ReturnConstant,
+    /// Calls to `std::verify_proof`, which do not verify the proof by themselves.
+    VerifyProof,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 12] = [
+        Lint::Warnings,
+        Lint::UnusedVariables,
+        Lint::UnusedImports,
+        Lint::DeadCode,
+        Lint::UnnecessaryPub,
+        Lint::Deprecated,
+        Lint::UnusedResults,
+        Lint::UnneededTraitConstraints,
+        Lint::UnreachablePatterns,
+        Lint::ArithmeticOverflow,
+        Lint::ReturnConstant,
+        Lint::VerifyProof,
+    ];
+
+    /// The name of this lint, as used in attributes.
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::Warnings => "warnings",
+            Lint::UnusedVariables => "unused_variables",
+            Lint::UnusedImports => "unused_imports",
+            Lint::DeadCode => "dead_code",
+            Lint::UnnecessaryPub => "unnecessary_pub",
+            Lint::Deprecated => "deprecated",
+            Lint::UnusedResults => "unused_results",
+            Lint::UnneededTraitConstraints => "unneeded_trait_constraints",
+            Lint::UnreachablePatterns => "unreachable_patterns",
+            Lint::ArithmeticOverflow => "arithmetic_overflow",
+            Lint::ReturnConstant => "return_constant",
+            Lint::VerifyProof => "verify_proof",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+}
+
+impl std::fmt::Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// How a lint is reported where it applies.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LintLevel {
+    /// The lint is not reported.
+    Allow,
+    /// The lint is reported as a warning. This is the default level of every lint.
+    Warn,
+    /// The lint is reported as an error.
+    Deny,
+}
+
+impl LintLevel {
+    pub fn from_attribute_name(name: &str) -> Option<LintLevel> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warn"),
+            LintLevel::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// The lint levels set by the attributes of the items and statements of a program.
+#[derive(Debug, Default)]
+pub struct LintLevels {
+    /// The levels set within each file, along with the span of the code they apply to.
+    /// Levels which a file inherits from the declaration of its module span the whole file.
+    scopes: HashMap<FileId, Vec<LintScope>>,
+}
+
+#[derive(Debug, Clone)]
+struct LintScope {
+    span: Span,
+    lint: Lint,
+    level: LintLevel,
+}
+
+impl LintLevels {
+    /// Sets the levels given by any lint attributes in `attributes` for the code at `location`,
+    /// returning the names of the lints which are not known.
+    pub(crate) fn set_from_attributes(
+        &mut self,
+        attributes: &[SecondaryAttribute],
+        location: Location,
+    ) -> Vec<String> {
+        let mut unknown_lints = Vec::new();
+        for attribute in attributes {
+            if let SecondaryAttribute::Lint(level, names) = attribute {
+                for name in names {
+                    match Lint::from_name(name) {
+                        Some(lint) => self.set(location, lint, *level),
+                        None => unknown_lints.push(name.clone()),
+                    }
+                }
+            }
+        }
+        unknown_lints
+    }
+
+    /// Makes all of `file` inherit the levels in effect at `declaration`, where the module
+    /// contained in `file` is declared.
+    pub(crate) fn inherit(&mut self, file: FileId, declaration: Location) {
+        // The `warnings` group is not inherited itself, since a lint set within its scope
+        // takes precedence over it but would not once both span the whole file.
+        let levels: Vec<_> = Lint::ALL
+            .into_iter()
+            .filter(|lint| *lint != Lint::Warnings)
+            .map(|lint| (lint, self.level(lint, declaration)))
+            .collect();
+
+        if levels.iter().any(|(_, level)| *level != LintLevel::Warn) {
+            let location = Location::new(Span::from(0..u32::MAX), file);
+            for (lint, level) in levels {
+                self.set(location, lint, level);
+            }
+        }
+    }
+
+    fn set(&mut self, location: Location, lint: Lint, level: LintLevel) {
+        let scope = LintScope { span: location.span, lint, level };
+        self.scopes.entry(location.file).or_default().push(scope);
+    }
+
+    /// The level of `lint` at `location`. This is set by the innermost item or statement
+    /// containing `location` with an attribute for `lint`, or for the `warnings` group.
+    /// When the same item sets a level more than once, the last attribute takes precedence.
+    pub fn level(&self, lint: Lint, location: Location) -> LintLevel {
+        let scopes = match self.scopes.get(&location.file) {
+            Some(scopes) => scopes,
+            None => return LintLevel::Warn,
+        };
+
+        scopes
+            .iter()
+            .rev()
+            .filter(|scope| scope.lint == lint || scope.lint == Lint::Warnings)
+            .filter(|scope| scope.span.contains(&location.span))
+            .min_by_key(|scope| scope.span.end() - scope.span.start())
+            .map_or(LintLevel::Warn, |scope| scope.level)
+    }
+}
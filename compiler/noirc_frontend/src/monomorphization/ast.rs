@@ -3,7 +3,8 @@ use iter_extended::vecmap;
 use noirc_errors::Location;
 
 use crate::{
-    hir_def::function::FunctionSignature, BinaryOpKind, Distinctness, Signedness, Visibility,
+    hir_def::function::FunctionSignature, token::InlineType, BinaryOpKind, Distinctness,
+    Signedness, Visibility,
 };
 
 /// The monomorphized AST is expression-based, all statements are also
@@ -26,6 +27,7 @@ pub enum Expression {
     Index(Index),
     Cast(Cast),
     For(For),
+    While(While),
     If(If),
     Tuple(Vec<Expression>),
     ExtractTupleField(Box<Expression>, usize),
@@ -34,6 +36,8 @@ pub enum Expression {
     Constrain(Box<Expression>, Location, Option<String>),
     Assign(Assign),
     Semi(Box<Expression>),
+    Break,
+    Continue,
 }
 
 /// A definition is either a local (variable), function, or is a built-in
@@ -80,6 +84,12 @@ pub struct For {
     pub end_range_location: Location,
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct While {
+    pub condition: Box<Expression>,
+    pub body: Box<Expression>,
+}
+
 #[derive(Debug, Clone, Hash)]
 pub enum Literal {
     Array(ArrayLiteral),
@@ -204,6 +214,8 @@ pub struct Function {
 
     pub return_type: Type,
     pub unconstrained: bool,
+    /// Set by an `#[inline(..)]` attribute. Otherwise, the compiler decides whether to inline calls.
+    pub inline_type: Option<InlineType>,
 }
 
 /// Compared to hir_def::types::Type, this monomorphized Type has:
@@ -21,13 +21,16 @@ use crate::{
     hir_def::{
         expr::*,
         function::{FuncMeta, FunctionSignature, Parameters},
-        stmt::{HirAssignStatement, HirLValue, HirLetStatement, HirPattern, HirStatement},
+        stmt::{
+            array_pattern_indices, HirAssignStatement, HirLValue, HirLetStatement, HirPattern,
+            HirStatement,
+        },
         types,
     },
     node_interner::{self, DefinitionKind, NodeInterner, StmtId, TraitImplKind, TraitMethodId},
     token::FunctionAttribute,
-    ContractFunctionType, FunctionKind, Type, TypeBinding, TypeBindings, TypeVariableKind,
-    Visibility,
+    BinaryOpKind, ContractFunctionType, FunctionKind, Type, TypeBinding, TypeBindings,
+    TypeVariableKind, Visibility,
 };
 
 use self::ast::{Definition, FuncId, Function, LocalId, Program};
@@ -40,6 +43,20 @@ struct LambdaContext {
     captures: Vec<HirCapturedVar>,
 }
 
+/// Statements unpacking the array patterns of a function's parameters
+struct UnpackedParameters(Vec<ast::Expression>);
+
+impl UnpackedParameters {
+    fn with_body(mut self, body: ast::Expression) -> ast::Expression {
+        if self.0.is_empty() {
+            body
+        } else {
+            self.0.push(body);
+            ast::Expression::Block(self.0)
+        }
+    }
+}
+
 /// The context struct for the monomorphization pass.
 ///
 /// This struct holds the FIFO queue of functions to monomorphize, which is added to
@@ -237,12 +254,14 @@ impl<'interner> Monomorphizer<'interner> {
             _ => meta.return_type(),
         });
 
-        let parameters = self.parameters(meta.parameters);
-        let body = self.expr(body_expr_id);
+        let (parameters, unpacked_parameters) = self.parameters(meta.parameters);
+        let body = unpacked_parameters.with_body(self.expr(body_expr_id));
         let unconstrained = modifiers.is_unconstrained
             || matches!(modifiers.contract_function_type, Some(ContractFunctionType::Open));
+        let inline_type = modifiers.attributes.inline_type();
 
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function =
+            ast::Function { id, name, parameters, body, return_type, unconstrained, inline_type };
         self.push_function(id, function);
     }
 
@@ -252,13 +271,19 @@ impl<'interner> Monomorphizer<'interner> {
     }
 
     /// Monomorphize each parameter, expanding tuple/struct patterns into multiple parameters
-    /// and binding any generic types found.
-    fn parameters(&mut self, params: Parameters) -> Vec<(ast::LocalId, bool, String, ast::Type)> {
+    /// and binding any generic types found. Array patterns cannot be expanded since arrays
+    /// are passed as a single value, so their elements are instead unpacked by the returned
+    /// statements which must be placed at the start of the function body.
+    fn parameters(
+        &mut self,
+        params: Parameters,
+    ) -> (Vec<(ast::LocalId, bool, String, ast::Type)>, UnpackedParameters) {
         let mut new_params = Vec::with_capacity(params.len());
+        let mut unpacked = UnpackedParameters(Vec::new());
         for parameter in params {
-            self.parameter(parameter.0, &parameter.1, &mut new_params);
+            self.parameter(parameter.0, &parameter.1, &mut new_params, &mut unpacked);
         }
-        new_params
+        (new_params, unpacked)
     }
 
     fn parameter(
@@ -266,6 +291,7 @@ impl<'interner> Monomorphizer<'interner> {
         param: HirPattern,
         typ: &HirType,
         new_params: &mut Vec<(ast::LocalId, bool, String, ast::Type)>,
+        unpacked: &mut UnpackedParameters,
     ) {
         match param {
             HirPattern::Identifier(ident) => {
@@ -275,12 +301,14 @@ impl<'interner> Monomorphizer<'interner> {
                 new_params.push((new_id, definition.mutable, name, self.convert_type(typ)));
                 self.define_local(ident.id, new_id);
             }
-            HirPattern::Mutable(pattern, _) => self.parameter(*pattern, typ, new_params),
+            HirPattern::Mutable(pattern, _) => {
+                self.parameter(*pattern, typ, new_params, unpacked);
+            }
             HirPattern::Tuple(fields, _) => {
                 let tuple_field_types = unwrap_tuple_type(typ);
 
                 for (field, typ) in fields.into_iter().zip(tuple_field_types) {
-                    self.parameter(field, &typ, new_params);
+                    self.parameter(field, &typ, new_params, unpacked);
                 }
             }
             HirPattern::Struct(_, fields, _) => {
@@ -296,9 +324,45 @@ impl<'interner> Monomorphizer<'interner> {
                         unreachable!("Expected a field named '{field_name}' in the struct pattern")
                     });
 
-                    self.parameter(field, &field_type, new_params);
+                    self.parameter(field, &field_type, new_params, unpacked);
                 }
             }
+            HirPattern::EnumVariant(_, _, arguments, _) => {
+                // Only enums with a single variant may be destructured in a parameter,
+                // so the tag is never needed, but must still be present in the parameter list.
+                let tag_id = self.next_local_id();
+                new_params.push((tag_id, false, "_".into(), ast::Type::Field));
+
+                let variants = unwrap_enum_type(typ);
+                assert_eq!(variants.len(), 1);
+                let (_, param_types) = variants.into_iter().next().unwrap();
+
+                for (argument, typ) in arguments.into_iter().zip(param_types) {
+                    self.parameter(argument, &typ, new_params, unpacked);
+                }
+            }
+            HirPattern::Wildcard(_) => {
+                let new_id = self.next_local_id();
+                new_params.push((new_id, false, "_".into(), self.convert_type(typ)));
+            }
+            HirPattern::Array(_, elements, rest, _) => {
+                let new_id = self.next_local_id();
+                let array_type = self.convert_type(typ);
+                new_params.push((new_id, false, "_".into(), array_type.clone()));
+
+                let array = ast::Expression::Ident(ast::Ident {
+                    location: None,
+                    mutable: false,
+                    definition: Definition::Local(new_id),
+                    name: "_".into(),
+                    typ: array_type,
+                });
+                let unpack = self.unpack_array_pattern(array, elements, rest, typ);
+                unpacked.0.push(unpack);
+            }
+            HirPattern::Literal(..) => {
+                unreachable!("Refutable patterns are not allowed in function parameters")
+            }
         }
     }
 
@@ -325,8 +389,9 @@ impl<'interner> Monomorphizer<'interner> {
                     match typ {
                         ast::Type::Field => Literal(Integer(-value, typ, location)),
                         ast::Type::Integer(_, bit_size) => {
-                            let base = 1_u128 << bit_size;
-                            Literal(Integer(FieldElement::from(base) - value, typ, location))
+                            let base = FieldElement::from(2_u128)
+                                .pow(&FieldElement::from(bit_size as u128));
+                            Literal(Integer(base - value, typ, location))
                         }
                         _ => unreachable!("Integer literal must be numeric"),
                     }
@@ -396,6 +461,8 @@ impl<'interner> Monomorphizer<'interner> {
                 ast::Expression::Tuple(fields)
             }
             HirExpression::Constructor(constructor) => self.constructor(constructor, expr),
+            HirExpression::EnumConstructor(constructor) => self.enum_constructor(constructor, expr),
+            HirExpression::Match(match_expr) => self.match_expr(match_expr, expr),
 
             HirExpression::Lambda(lambda) => self.lambda(lambda, expr),
 
@@ -412,6 +479,9 @@ impl<'interner> Monomorphizer<'interner> {
             HirExpression::MethodCall(hir_method_call) => {
                 unreachable!("Encountered HirExpression::MethodCall during monomorphization {hir_method_call:?}")
             }
+            HirExpression::Comptime(_) => {
+                unreachable!("comptime blocks should be evaluated before monomorphization")
+            }
             HirExpression::Error => unreachable!("Encountered Error node during monomorphization"),
         }
     }
@@ -481,6 +551,13 @@ impl<'interner> Monomorphizer<'interner> {
                     block,
                 })
             }
+            HirStatement::While(while_loop) => {
+                let condition = Box::new(self.expr(while_loop.condition));
+                let body = Box::new(self.expr(while_loop.block));
+                ast::Expression::While(ast::While { condition, body })
+            }
+            HirStatement::Break => ast::Expression::Break,
+            HirStatement::Continue => ast::Expression::Continue,
             HirStatement::Expression(expr) => self.expr(expr),
             HirStatement::Semi(expr) => ast::Expression::Semi(Box::new(self.expr(expr))),
             HirStatement::Error => unreachable!(),
@@ -542,6 +619,202 @@ impl<'interner> Monomorphizer<'interner> {
         ast::Expression::Block(new_exprs)
     }
 
+    /// Enums are represented as a tuple of their tag, the index of the active variant,
+    /// followed by a tuple of values for each variant. Only the active variant's values
+    /// are meaningful, the values of every other variant are zeroed.
+    fn enum_constructor(
+        &mut self,
+        constructor: HirEnumConstructorExpression,
+        id: node_interner::ExprId,
+    ) -> ast::Expression {
+        let typ = self.interner.id_type(id);
+        let location = self.interner.expr_location(&id);
+
+        let tag = FieldElement::from(constructor.variant_index as u128);
+        let mut fields =
+            vec![ast::Expression::Literal(ast::Literal::Integer(tag, ast::Type::Field, location))];
+
+        let mut arguments = Some(vecmap(constructor.arguments, |arg| self.expr(arg)));
+
+        for (index, (_, param_types)) in unwrap_enum_type(&typ).into_iter().enumerate() {
+            if index == constructor.variant_index {
+                fields.push(ast::Expression::Tuple(arguments.take().unwrap()));
+            } else {
+                let variant_type =
+                    ast::Type::Tuple(vecmap(param_types, |typ| self.convert_type(&typ)));
+                fields.push(self.zeroed_value_of_type(&variant_type, location));
+            }
+        }
+
+        ast::Expression::Tuple(fields)
+    }
+
+    /// A match is lowered into a chain of if-else expressions, one for each arm,
+    /// checking the tag of each enum variant in that arm's pattern.
+    fn match_expr(
+        &mut self,
+        match_expr: HirMatchExpression,
+        id: node_interner::ExprId,
+    ) -> ast::Expression {
+        let location = self.interner.expr_location(&id);
+        let result_type = self.convert_type(&self.interner.id_type(id));
+        let scrutinee_type = self.interner.id_type(match_expr.expression);
+        let scrutinee = self.expr(match_expr.expression);
+
+        let scrutinee_id = self.next_local_id();
+        let name = "$match".to_string();
+        let scrutinee_let = ast::Expression::Let(ast::Let {
+            id: scrutinee_id,
+            mutable: false,
+            name: name.clone(),
+            expression: Box::new(scrutinee),
+        });
+
+        let scrutinee = ast::Ident {
+            location: None,
+            mutable: false,
+            definition: Definition::Local(scrutinee_id),
+            name,
+            typ: self.convert_type(&scrutinee_type),
+        };
+
+        // The chain is built starting from the last arm so each arm can be given
+        // the expression for all arms after it as its else branch.
+        let mut result = None;
+        for (pattern, branch) in match_expr.rules.into_iter().rev() {
+            let value = ast::Expression::Ident(scrutinee.clone());
+            let mut conditions = Vec::new();
+            self.pattern_conditions(
+                &pattern,
+                value.clone(),
+                &scrutinee_type,
+                location,
+                &mut conditions,
+            );
+
+            let bindings = self.unpack_pattern(pattern, value, &scrutinee_type);
+            let branch = ast::Expression::Block(vec![bindings, self.expr(branch)]);
+
+            let condition = conditions.into_iter().reduce(|lhs, rhs| {
+                ast::Expression::Binary(ast::Binary {
+                    lhs: Box::new(lhs),
+                    rhs: Box::new(rhs),
+                    operator: BinaryOpKind::And,
+                    location,
+                })
+            });
+
+            // If this is the last arm, exhaustiveness checking guarantees it matches when reached
+            result = Some(match (condition, result) {
+                (Some(condition), Some(alternative)) => ast::Expression::If(ast::If {
+                    condition: Box::new(condition),
+                    consequence: Box::new(branch),
+                    alternative: Some(Box::new(alternative)),
+                    typ: result_type.clone(),
+                }),
+                _ => branch,
+            });
+        }
+
+        let result = result.unwrap_or_else(|| ast::Expression::Block(vec![]));
+        ast::Expression::Block(vec![scrutinee_let, result])
+    }
+
+    /// Push a boolean condition onto `conditions` for each check needed to
+    /// determine whether `value` matches the given pattern.
+    fn pattern_conditions(
+        &mut self,
+        pattern: &HirPattern,
+        value: ast::Expression,
+        typ: &HirType,
+        location: Location,
+        conditions: &mut Vec<ast::Expression>,
+    ) {
+        match pattern {
+            HirPattern::Identifier(_) | HirPattern::Wildcard(_) => (),
+            HirPattern::Mutable(pattern, _) => {
+                self.pattern_conditions(pattern, value, typ, location, conditions);
+            }
+            HirPattern::Literal(literal, _) => {
+                conditions.push(ast::Expression::Binary(ast::Binary {
+                    lhs: Box::new(value),
+                    rhs: Box::new(self.expr(*literal)),
+                    operator: BinaryOpKind::Equal,
+                    location,
+                }));
+            }
+            HirPattern::Array(_, elements, rest, _) => {
+                let (length, element_type) = unwrap_array_type(typ);
+                let indices = array_pattern_indices(length, elements.len(), *rest);
+
+                for (element, index) in elements.iter().zip(indices) {
+                    let element_value = self.index_constant(value.clone(), index, &element_type);
+                    self.pattern_conditions(
+                        element,
+                        element_value,
+                        &element_type,
+                        location,
+                        conditions,
+                    );
+                }
+            }
+            HirPattern::Tuple(fields, _) => {
+                let field_types = unwrap_tuple_type(typ);
+                for (i, (field, field_type)) in fields.iter().zip(field_types).enumerate() {
+                    let field_value =
+                        ast::Expression::ExtractTupleField(Box::new(value.clone()), i);
+                    self.pattern_conditions(field, field_value, &field_type, location, conditions);
+                }
+            }
+            HirPattern::Struct(_, fields, _) => {
+                let field_types = unwrap_struct_type(typ);
+                for (i, (field_name, field_type)) in field_types.into_iter().enumerate() {
+                    let field = fields.iter().find(|(name, _)| name.0.contents == field_name);
+                    if let Some((_, field)) = field {
+                        let field_value =
+                            ast::Expression::ExtractTupleField(Box::new(value.clone()), i);
+                        self.pattern_conditions(
+                            field,
+                            field_value,
+                            &field_type,
+                            location,
+                            conditions,
+                        );
+                    }
+                }
+            }
+            HirPattern::EnumVariant(_, variant_index, arguments, _) => {
+                let tag = ast::Expression::ExtractTupleField(Box::new(value.clone()), 0);
+                let expected = FieldElement::from(*variant_index as u128);
+                conditions.push(ast::Expression::Binary(ast::Binary {
+                    lhs: Box::new(tag),
+                    rhs: Box::new(ast::Expression::Literal(ast::Literal::Integer(
+                        expected,
+                        ast::Type::Field,
+                        location,
+                    ))),
+                    operator: BinaryOpKind::Equal,
+                    location,
+                }));
+
+                let (_, param_types) = unwrap_enum_type(typ).swap_remove(*variant_index);
+                let variant =
+                    ast::Expression::ExtractTupleField(Box::new(value), variant_index + 1);
+                for (i, (argument, param_type)) in arguments.iter().zip(param_types).enumerate() {
+                    let argument_value =
+                        ast::Expression::ExtractTupleField(Box::new(variant.clone()), i);
+                    self.pattern_conditions(
+                        argument,
+                        argument_value,
+                        &param_type,
+                        location,
+                        conditions,
+                    );
+                }
+            }
+        }
+    }
+
     fn block(&mut self, statement_ids: Vec<StmtId>) -> ast::Expression {
         ast::Expression::Block(vecmap(statement_ids, |id| self.statement(id)))
     }
@@ -585,9 +858,87 @@ impl<'interner> Monomorphizer<'interner> {
 
                 self.unpack_tuple_pattern(value, patterns_iter)
             }
+            HirPattern::EnumVariant(_, variant_index, patterns, _) => {
+                // Whether this is the correct variant has already been checked, if needed
+                let (_, param_types) = unwrap_enum_type(typ).swap_remove(variant_index);
+                let variant =
+                    ast::Expression::ExtractTupleField(Box::new(value), variant_index + 1);
+                self.unpack_tuple_pattern(variant, patterns.into_iter().zip(param_types))
+            }
+            HirPattern::Wildcard(_) => {
+                // The value is still evaluated for any side effects it may have
+                ast::Expression::Let(ast::Let {
+                    id: self.next_local_id(),
+                    mutable: false,
+                    name: "_".into(),
+                    expression: Box::new(value),
+                })
+            }
+            HirPattern::Array(_, elements, rest, _) => {
+                self.unpack_array_pattern(value, elements, rest, typ)
+            }
+            HirPattern::Literal(..) => {
+                // Whether the value equals the literal has already been checked
+                ast::Expression::Block(Vec::new())
+            }
         }
     }
 
+    fn unpack_array_pattern(
+        &mut self,
+        value: ast::Expression,
+        elements: Vec<HirPattern>,
+        rest: Option<usize>,
+        typ: &HirType,
+    ) -> ast::Expression {
+        let fresh_id = self.next_local_id();
+
+        let mut definitions = vec![ast::Expression::Let(ast::Let {
+            id: fresh_id,
+            mutable: false,
+            name: "_".into(),
+            expression: Box::new(value),
+        })];
+
+        let (length, element_type) = unwrap_array_type(typ);
+        let indices = array_pattern_indices(length, elements.len(), rest);
+
+        for (element, index) in elements.into_iter().zip(indices) {
+            let array = ast::Expression::Ident(ast::Ident {
+                location: None,
+                mutable: false,
+                definition: Definition::Local(fresh_id),
+                name: "_".into(),
+                typ: self.convert_type(typ),
+            });
+
+            let element_value = self.index_constant(array, index, &element_type);
+            definitions.push(self.unpack_pattern(element, element_value, &element_type));
+        }
+
+        ast::Expression::Block(definitions)
+    }
+
+    /// Index `array` at a position known at compile-time
+    fn index_constant(
+        &mut self,
+        array: ast::Expression,
+        index: u64,
+        element_type: &HirType,
+    ) -> ast::Expression {
+        let location = Location::dummy();
+        let index = FieldElement::from(index as u128);
+        let index =
+            ast::Expression::Literal(ast::Literal::Integer(index, ast::Type::Field, location));
+
+        ast::Expression::Index(ast::Index {
+            collection: Box::new(array),
+            index: Box::new(index),
+            element_type: self.convert_type(element_type),
+            location,
+        })
+    }
+
     fn unpack_tuple_pattern(
         &mut self,
         value: ast::Expression,
@@ -695,7 +1046,8 @@ impl<'interner> Monomorphizer<'interner> {
 
                 let value = FieldElement::from(value as u128);
                 let location = self.interner.id_location(expr_id);
-                ast::Expression::Literal(ast::Literal::Integer(value, ast::Type::Field, location))
+                let typ = self.convert_type(&self.interner.id_type(expr_id));
+                ast::Expression::Literal(ast::Literal::Integer(value, typ, location))
             }
         }
     }
@@ -757,6 +1109,15 @@ impl<'interner> Monomorphizer<'interner> {
                 monomorphized_default
             }
 
+            HirType::Struct(def, args) if def.borrow().is_enum() => {
+                let variants = def.borrow().get_variants(args);
+                let mut fields = vec![ast::Type::Field];
+                fields.extend(variants.into_iter().map(|(_, params)| {
+                    ast::Type::Tuple(vecmap(params, |param| self.convert_type(&param)))
+                }));
+                ast::Type::Tuple(fields)
+            }
+
             HirType::Struct(def, args) => {
                 let fields = def.borrow().get_fields(args);
                 let fields = vecmap(fields, |(_, field)| self.convert_type(&field));
@@ -1162,15 +1523,23 @@ impl<'interner> Monomorphizer<'interner> {
         let parameters =
             vecmap(lambda.parameters, |(pattern, typ)| (pattern, typ, Visibility::Private)).into();
 
-        let parameters = self.parameters(parameters);
-        let body = self.expr(lambda.body);
+        let (parameters, unpacked_parameters) = self.parameters(parameters);
+        let body = unpacked_parameters.with_body(self.expr(lambda.body));
 
         let id = self.next_function_id();
         let return_type = ret_type.clone();
         let name = lambda_name.to_owned();
         let unconstrained = false;
 
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function = ast::Function {
+            id,
+            name,
+            parameters,
+            body,
+            return_type,
+            unconstrained,
+            inline_type: None,
+        };
         self.push_function(id, function);
 
         let typ =
@@ -1213,7 +1582,7 @@ impl<'interner> Monomorphizer<'interner> {
         let parameters =
             vecmap(lambda.parameters, |(pattern, typ)| (pattern, typ, Visibility::Private)).into();
 
-        let mut converted_parameters = self.parameters(parameters);
+        let (mut converted_parameters, unpacked_parameters) = self.parameters(parameters);
 
         let id = self.next_function_id();
         let name = lambda_name.to_owned();
@@ -1266,7 +1635,7 @@ impl<'interner> Monomorphizer<'interner> {
 
         self.lambda_envs_stack
             .push(LambdaContext { env_ident: env_ident.clone(), captures: lambda.captures });
-        let body = self.expr(lambda.body);
+        let body = unpacked_parameters.with_body(self.expr(lambda.body));
         self.lambda_envs_stack.pop();
 
         let lambda_fn_typ: ast::Type =
@@ -1284,7 +1653,15 @@ impl<'interner> Monomorphizer<'interner> {
         parameters.append(&mut converted_parameters);
 
         let unconstrained = false;
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function = ast::Function {
+            id,
+            name,
+            parameters,
+            body,
+            return_type,
+            unconstrained,
+            inline_type: None,
+        };
         self.push_function(id, function);
 
         let lambda_value =
@@ -1402,7 +1779,15 @@ impl<'interner> Monomorphizer<'interner> {
         let name = lambda_name.to_owned();
 
         let unconstrained = false;
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function = ast::Function {
+            id,
+            name,
+            parameters,
+            body,
+            return_type,
+            unconstrained,
+            inline_type: None,
+        };
         self.push_function(id, function);
 
         ast::Expression::Ident(ast::Ident {
@@ -1430,6 +1815,18 @@ fn unwrap_tuple_type(typ: &HirType) -> Vec<HirType> {
     }
 }
 
+fn unwrap_array_type(typ: &HirType) -> (u64, HirType) {
+    match typ.follow_bindings() {
+        HirType::Array(length, element) => {
+            let length = length.evaluate_to_u64().unwrap_or_else(|| {
+                unreachable!("unwrap_array_type: expected an array with a known length")
+            });
+            (length, *element)
+        }
+        other => unreachable!("unwrap_array_type: expected array, found {:?}", other),
+    }
+}
+
 fn unwrap_struct_type(typ: &HirType) -> Vec<(String, HirType)> {
     match typ {
         HirType::Struct(def, args) => def.borrow().get_fields(args),
@@ -1441,6 +1838,17 @@ fn unwrap_struct_type(typ: &HirType) -> Vec<(String, HirType)> {
     }
 }
 
+fn unwrap_enum_type(typ: &HirType) -> Vec<(String, Vec<HirType>)> {
+    match typ {
+        HirType::Struct(def, args) => def.borrow().get_variants(args),
+        HirType::TypeVariable(binding, TypeVariableKind::Normal) => match &*binding.borrow() {
+            TypeBinding::Bound(binding) => unwrap_enum_type(binding),
+            TypeBinding::Unbound(_) => unreachable!(),
+        },
+        other => unreachable!("unwrap_enum_type: expected enum, found {:?}", other),
+    }
+}
+
 fn perform_instantiation_bindings(bindings: &TypeBindings) {
     for (var, binding) in bindings.values() {
         var.force_bind(binding.clone());
@@ -7,7 +7,9 @@ use noirc_errors::{Location, Span, Spanned};
 
 use crate::ast::Ident;
 use crate::graph::CrateId;
-use crate::hir::def_collector::dc_crate::{UnresolvedStruct, UnresolvedTrait, UnresolvedTypeAlias};
+use crate::hir::def_collector::dc_crate::{
+    UnresolvedEnum, UnresolvedStruct, UnresolvedTrait, UnresolvedTypeAlias,
+};
 use crate::hir::def_map::{LocalModuleId, ModuleId};
 
 use crate::hir_def::stmt::HirLetStatement;
@@ -19,9 +21,11 @@ use crate::hir_def::{
     function::{FuncMeta, HirFunction},
     stmt::HirStatement,
 };
+use crate::lints::LintLevels;
 use crate::token::{Attributes, SecondaryAttribute};
+use crate::usage_tracker::UsageTracker;
 use crate::{
-    ContractFunctionType, FunctionDefinition, FunctionVisibility, Generics, Shared, TypeAliasType,
+    ContractFunctionType, FunctionDefinition, Generics, ItemVisibility, Shared, TypeAliasType,
     TypeBindings, TypeVariable, TypeVariableId, TypeVariableKind,
 };
 
@@ -123,6 +127,10 @@ pub struct NodeInterner {
 
     next_type_variable_id: std::cell::Cell<usize>,
 
+    /// The location of the expression which caused each type variable to be bound during
+    /// type checking, used to explain where a type was inferred in type mismatch errors.
+    type_variable_origins: HashMap<TypeVariableId, Location>,
+
     /// A map from a struct type and method name to a function id for the method.
     /// This can resolve to potentially multiple methods if the same method name is
     /// specialized for different generics on the same type. E.g. for `Struct<T>`, we
@@ -136,6 +144,19 @@ pub struct NodeInterner {
 
     // For trait implementation functions, this is their self type and trait they belong to
     func_id_to_trait: HashMap<FuncId, (Type, TraitId)>,
+
+    /// Maps the constructor function of each enum variant to its enum and variant index.
+    enum_variants: HashMap<FuncId, (StructId, usize)>,
+
+    /// Tracks which imports, functions, globals and struct fields are used, in order to warn
+    /// about those which never are.
+    usage_tracker: UsageTracker,
+
+    /// The lint levels set by `#[allow(...)]`, `#[warn(...)]` and `#[deny(...)]` attributes.
+    lint_levels: LintLevels,
+
+    /// The array lengths given by comptime code, which are evaluated before type checking.
+    comptime_array_lengths: Vec<ComptimeArrayLength>,
 }
 
 /// A trait implementation is either a normal implementation that is present in the source
@@ -172,12 +193,16 @@ pub struct FunctionModifiers {
     pub name: String,
 
     /// Whether the function is `pub` or not.
-    pub visibility: FunctionVisibility,
+    pub visibility: ItemVisibility,
 
     pub attributes: Attributes,
 
     pub is_unconstrained: bool,
 
+    /// Whether the function is `comptime`. Calls to these functions are always
+    /// evaluated during compilation and replaced with their result.
+    pub is_comptime: bool,
+
     /// This function's type in its contract.
     /// If this function is not in a contract, this is always 'Secret'.
     pub contract_function_type: Option<ContractFunctionType>,
@@ -195,9 +220,10 @@ impl FunctionModifiers {
     pub fn new() -> Self {
         Self {
             name: String::new(),
-            visibility: FunctionVisibility::Public,
+            visibility: ItemVisibility::Public,
             attributes: Attributes::empty(),
             is_unconstrained: false,
+            is_comptime: false,
             is_internal: None,
             contract_function_type: None,
         }
@@ -404,6 +430,19 @@ pub struct GlobalInfo {
     pub local_id: LocalModuleId,
 }
 
+/// An array length given by comptime code, such as `[Field; comptime { 2 + 3 }]` or a global
+/// initialized by a call to a comptime function. The type of the array refers to `length`,
+/// which is bound to the value of `expression` once it has been evaluated.
+#[derive(Debug, Clone)]
+pub struct ComptimeArrayLength {
+    pub expression: ExprId,
+    pub length: TypeVariable,
+
+    /// Globals are type checked along with the other globals, while comptime blocks
+    /// within a type must be type checked before they are evaluated.
+    pub is_global: bool,
+}
+
 impl Default for NodeInterner {
     fn default() -> Self {
         let mut interner = NodeInterner {
@@ -426,9 +465,14 @@ impl Default for NodeInterner {
             instantiation_bindings: HashMap::new(),
             field_indices: HashMap::new(),
             next_type_variable_id: std::cell::Cell::new(0),
+            type_variable_origins: HashMap::new(),
             globals: HashMap::new(),
             struct_methods: HashMap::new(),
             primitive_methods: HashMap::new(),
+            enum_variants: HashMap::new(),
+            usage_tracker: UsageTracker::default(),
+            lint_levels: LintLevels::default(),
+            comptime_array_lengths: Vec::new(),
         };
 
         // An empty block expression is used often, we add this into the `node` on startup
@@ -544,6 +588,39 @@ impl NodeInterner {
         struct_id
     }
 
+    pub fn new_enum(
+        &mut self,
+        typ: &UnresolvedEnum,
+        krate: CrateId,
+        local_id: LocalModuleId,
+        file_id: FileId,
+    ) -> StructId {
+        let enum_id = StructId(ModuleId { krate, local_id });
+        let name = typ.enum_def.name.clone();
+
+        // Variants will be filled in later, same as with struct generics
+        let generics = vecmap(&typ.enum_def.generics, |_| {
+            let id = TypeVariableId(0);
+            (id, TypeVariable::unbound(id))
+        });
+
+        let location = Location::new(typ.enum_def.span, file_id);
+        let new_enum = StructType::new_enum(enum_id, name, location, generics);
+        self.structs.insert(enum_id, Shared::new(new_enum));
+        self.struct_attributes.insert(enum_id, typ.enum_def.attributes.clone());
+        enum_id
+    }
+
+    /// Records `func_id` as the constructor of the variant at `variant_index` of the given enum.
+    pub fn push_enum_variant(&mut self, func_id: FuncId, enum_id: StructId, variant_index: usize) {
+        self.enum_variants.insert(func_id, (enum_id, variant_index));
+    }
+
+    /// Returns the enum and variant index `func_id` constructs, if it is an enum variant.
+    pub fn get_enum_variant(&self, func_id: FuncId) -> Option<(StructId, usize)> {
+        self.enum_variants.get(&func_id).copied()
+    }
+
     pub fn push_type_alias(&mut self, typ: &UnresolvedTypeAlias) -> TypeAliasId {
         let type_id = TypeAliasId(self.type_aliases.len());
 
@@ -706,6 +783,7 @@ impl NodeInterner {
             visibility: function.visibility,
             attributes: function.attributes.clone(),
             is_unconstrained: function.is_unconstrained,
+            is_comptime: function.is_comptime,
             contract_function_type: Some(if function.is_open { Open } else { Secret }),
             is_internal: Some(function.is_internal),
         };
@@ -737,7 +815,7 @@ impl NodeInterner {
     ///
     /// The underlying function_visibilities map is populated during def collection,
     /// so this function can be called anytime afterward.
-    pub fn function_visibility(&self, func: FuncId) -> FunctionVisibility {
+    pub fn function_visibility(&self, func: FuncId) -> ItemVisibility {
         self.function_modifiers[&func].visibility
     }
 
@@ -933,6 +1011,36 @@ impl NodeInterner {
         Type::type_variable(self.next_type_variable_id())
     }
 
+    /// Returns the type variable standing for the array length given by the comptime code
+    /// `expression`, which is bound once the expression is evaluated.
+    pub fn comptime_array_length(&mut self, expression: ExprId, is_global: bool) -> TypeVariable {
+        let existing =
+            self.comptime_array_lengths.iter().find(|length| length.expression == expression);
+        if let Some(existing) = existing {
+            return existing.length.clone();
+        }
+
+        let length = TypeVariable::unbound(self.next_type_variable_id());
+        let array_length = ComptimeArrayLength { expression, length: length.clone(), is_global };
+        self.comptime_array_lengths.push(array_length);
+        length
+    }
+
+    /// Takes the comptime array lengths which have yet to be evaluated.
+    pub fn take_comptime_array_lengths(&mut self) -> Vec<ComptimeArrayLength> {
+        std::mem::take(&mut self.comptime_array_lengths)
+    }
+
+    /// Records the location of the expression which caused a type variable to be bound.
+    /// Only the first location is kept since a type variable is only bound once.
+    pub fn record_type_variable_origin(&mut self, id: TypeVariableId, location: Location) {
+        self.type_variable_origins.entry(id).or_insert(location);
+    }
+
+    pub fn type_variable_origin(&self, id: TypeVariableId) -> Option<Location> {
+        self.type_variable_origins.get(&id).copied()
+    }
+
     pub fn store_instantiation_bindings(
         &mut self,
         expr_id: ExprId,
@@ -957,6 +1065,22 @@ impl NodeInterner {
         self.function_definition_ids[&function]
     }
 
+    pub(crate) fn usage_tracker(&self) -> &UsageTracker {
+        &self.usage_tracker
+    }
+
+    pub(crate) fn usage_tracker_mut(&mut self) -> &mut UsageTracker {
+        &mut self.usage_tracker
+    }
+
+    pub fn lint_levels(&self) -> &LintLevels {
+        &self.lint_levels
+    }
+
+    pub(crate) fn lint_levels_mut(&mut self) -> &mut LintLevels {
+        &mut self.lint_levels
+    }
+
     /// Adds a non-trait method to a type.
     ///
     /// Returns `Some(duplicate)` if a matching method was already defined.
@@ -971,12 +1095,17 @@ impl NodeInterner {
         match self_type {
             Type::Struct(struct_type, _generics) => {
                 let id = struct_type.borrow().id;
+                let key = (id, method_name);
 
-                if let Some(existing) = self.lookup_method(self_type, id, &method_name, true) {
+                // Only the struct's own methods are checked here. A method of the same name
+                // from an impl for all types `T` is shadowed by this one rather than a duplicate.
+                let methods = self.struct_methods.get(&key);
+                if let Some(existing) =
+                    methods.and_then(|m| m.find_matching_method(self_type, self))
+                {
                     return Some(existing);
                 }
 
-                let key = (id, method_name);
                 self.struct_methods.entry(key).or_default().add_method(method_id, is_trait_method);
                 None
             }
@@ -1053,6 +1182,11 @@ impl NodeInterner {
         let impls =
             self.trait_implementation_map.get(&trait_id).ok_or_else(|| vec![make_constraint()])?;
 
+        // The errors from the first impl whose object type matched but whose where clause did not
+        // hold. These are only reported if no other impl, such as one assumed by a where clause,
+        // applies.
+        let mut where_clause_errors = None;
+
         for (existing_object_type, impl_kind) in impls {
             let (existing_object_type, instantiation_bindings) =
                 existing_object_type.instantiate(self);
@@ -1061,7 +1195,8 @@ impl NodeInterner {
 
             if object_type.try_unify(&existing_object_type, &mut fresh_bindings).is_ok() {
                 // The unification was successful so we can append fresh_bindings to our bindings list
-                type_bindings.extend(fresh_bindings);
+                let mut candidate_bindings = type_bindings.clone();
+                candidate_bindings.extend(fresh_bindings);
 
                 if let TraitImplKind::Normal(impl_id) = impl_kind {
                     let trait_impl = self.get_trait_implementation(*impl_id);
@@ -1069,20 +1204,22 @@ impl NodeInterner {
 
                     if let Err(mut errors) = self.validate_where_clause(
                         &trait_impl.where_clause,
-                        type_bindings,
+                        &mut candidate_bindings,
                         &instantiation_bindings,
                         recursion_limit,
                     ) {
                         errors.push(make_constraint());
-                        return Err(errors);
+                        where_clause_errors.get_or_insert(errors);
+                        continue;
                     }
                 }
 
+                *type_bindings = candidate_bindings;
                 return Ok(impl_kind.clone());
             }
         }
 
-        Err(vec![make_constraint()])
+        Err(where_clause_errors.unwrap_or_else(|| vec![make_constraint()]))
     }
 
     /// Verifies that each constraint in the given where clause is valid.
@@ -1110,6 +1247,21 @@ impl NodeInterner {
         Ok(())
     }
 
+    /// Returns the types the given trait impl assigns to each associated type and constant of
+    /// its trait, with any generics of the impl instantiated to match the given object type.
+    pub fn get_associated_types(&self, impl_id: TraitImplId, object_type: &Type) -> Vec<Type> {
+        let trait_impl = self.get_trait_implementation(impl_id);
+        let trait_impl = trait_impl.borrow();
+
+        let (impl_type, substitutions) = trait_impl.typ.instantiate_type_variables(self);
+        let mut bindings = TypeBindings::new();
+        if impl_type.try_unify(object_type, &mut bindings).is_ok() {
+            Type::apply_type_bindings(bindings);
+        }
+
+        vecmap(&trait_impl.associated_types, |typ| typ.substitute(&substitutions))
+    }
+
     /// Adds an "assumed" trait implementation to the currently known trait implementations.
     /// Unlike normal trait implementations, these are only assumed to exist. They often correspond
     /// to `where` clauses in functions where we assume there is some `T: Eq` even though we do
@@ -1152,9 +1304,7 @@ impl NodeInterner {
         let (instantiated_object_type, substitutions) =
             object_type.instantiate_type_variables(self);
 
-        if let Ok((TraitImplKind::Normal(existing), _)) =
-            self.try_lookup_trait_implementation(&instantiated_object_type, trait_id)
-        {
+        if let Some(existing) = self.find_overlapping_impl(&instantiated_object_type, trait_id) {
             let existing_impl = self.get_trait_implementation(existing);
             let existing_impl = existing_impl.borrow();
             return Err((existing_impl.ident.span(), existing_impl.file));
@@ -1173,6 +1323,27 @@ impl NodeInterner {
         Ok(())
     }
 
+    /// Returns an impl of the given trait whose object type unifies with `object_type`.
+    ///
+    /// Where clauses are ignored here: impls whose where clauses currently rule each other out
+    /// would both apply to a type once it implements the traits they require, making the choice
+    /// between them depend on the order they were declared in.
+    fn find_overlapping_impl(&self, object_type: &Type, trait_id: TraitId) -> Option<TraitImplId> {
+        let impls = self.trait_implementation_map.get(&trait_id)?;
+
+        impls.iter().find_map(|(existing_object_type, impl_kind)| match impl_kind {
+            TraitImplKind::Normal(impl_id) => {
+                let (existing_object_type, _) = existing_object_type.instantiate(self);
+                let mut bindings = TypeBindings::new();
+                object_type
+                    .try_unify(&existing_object_type, &mut bindings)
+                    .is_ok()
+                    .then_some(*impl_id)
+            }
+            TraitImplKind::Assumed { .. } => None,
+        })
+    }
+
     /// Search by name for a method on the given struct.
     ///
     /// If `check_type` is true, this will force `lookup_method` to check the type
@@ -1191,7 +1362,10 @@ impl NodeInterner {
         method_name: &str,
         force_type_check: bool,
     ) -> Option<FuncId> {
-        let methods = self.struct_methods.get(&(id, method_name.to_owned()))?;
+        let methods = match self.struct_methods.get(&(id, method_name.to_owned())) {
+            Some(methods) => methods,
+            None => return self.lookup_generic_method(typ, method_name),
+        };
 
         // If there is only one method, just return it immediately.
         // It will still be typechecked later.
@@ -1216,17 +1390,25 @@ impl NodeInterner {
         } else {
             // Failed to find a match for the type in question, switch to looking at impls
             // for all types `T`, e.g. `impl<T> Foo for T`
-            let key = &(TypeMethodKey::Generic, method_name.to_owned());
-            let global_methods = self.primitive_methods.get(key)?;
-            global_methods.find_matching_method(typ, self)
+            self.lookup_generic_method(typ, method_name)
         }
     }
 
     /// Looks up a given method name on the given primitive type.
     pub fn lookup_primitive_method(&self, typ: &Type, method_name: &str) -> Option<FuncId> {
         let key = get_type_method_key(typ)?;
-        let methods = self.primitive_methods.get(&(key, method_name.to_owned()))?;
-        self.find_matching_method(typ, methods, method_name)
+        match self.primitive_methods.get(&(key, method_name.to_owned())) {
+            Some(methods) => self.find_matching_method(typ, methods, method_name),
+            None => self.lookup_generic_method(typ, method_name),
+        }
+    }
+
+    /// Looks up a method from an impl for all types `T`, e.g. `impl<T> Foo for T where T: Bar`.
+    /// Whether the where clause of the impl holds for the given type is checked by the caller.
+    pub fn lookup_generic_method(&self, typ: &Type, method_name: &str) -> Option<FuncId> {
+        let key = &(TypeMethodKey::Generic, method_name.to_owned());
+        let global_methods = self.primitive_methods.get(key)?;
+        global_methods.find_matching_method(typ, self)
     }
 
     pub fn lookup_primitive_trait_method_mut(
@@ -36,8 +36,16 @@ pub enum ParserErrorReason {
     MultipleFunctionAttributesFound,
     #[error("A function attribute cannot be placed on a struct")]
     NoFunctionAttributesAllowedOnStruct,
+    #[error("A function attribute cannot be placed on a module")]
+    NoFunctionAttributesAllowedOnModule,
+    #[error("A function attribute cannot be placed on a statement")]
+    NoFunctionAttributesAllowedOnStatement,
     #[error("Assert statements can only accept string literals")]
     AssertMessageNotString,
+    #[error("Only integer and boolean literals may be used in patterns")]
+    InvalidLiteralPattern,
+    #[error("`..` may only be used once in an array pattern")]
+    MultipleRestPatterns,
     #[error("{0}")]
     Lexer(LexerErrorKind),
 }
@@ -101,6 +109,39 @@ impl ParserError {
     pub fn is_warning(&self) -> bool {
         matches!(self.reason(), Some(ParserErrorReason::ExperimentalFeature(_)))
     }
+
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match &self.reason {
+            Some(reason) => reason.code(),
+            None => "E0010",
+        }
+    }
+}
+
+impl ParserErrorReason {
+    /// The stable code of this error, which `nargo explain` describes in more detail.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParserErrorReason::ExpectedFieldName(_) => "E0011",
+            ParserErrorReason::ExpectedPatternButFoundType(_) => "E0012",
+            ParserErrorReason::MissingSeparatingSemi => "E0013",
+            ParserErrorReason::ConstrainDeprecated => "E0014",
+            ParserErrorReason::InvalidArrayLengthExpression(_) => "E0015",
+            ParserErrorReason::EarlyReturn => "E0016",
+            ParserErrorReason::PatternInTraitFunctionParameter => "E0017",
+            ParserErrorReason::ComptimeDeprecated => "E0018",
+            ParserErrorReason::ExperimentalFeature(_) => "E0019",
+            ParserErrorReason::MultipleFunctionAttributesFound => "E0020",
+            ParserErrorReason::NoFunctionAttributesAllowedOnStruct => "E0021",
+            ParserErrorReason::AssertMessageNotString => "E0022",
+            ParserErrorReason::InvalidLiteralPattern => "E0023",
+            ParserErrorReason::MultipleRestPatterns => "E0024",
+            ParserErrorReason::NoFunctionAttributesAllowedOnModule => "E0025",
+            ParserErrorReason::NoFunctionAttributesAllowedOnStatement => "E0026",
+            ParserErrorReason::Lexer(error) => error.code(),
+        }
+    }
 }
 
 impl std::fmt::Display for ParserError {
@@ -130,7 +171,8 @@ impl std::fmt::Display for ParserError {
 
 impl From<ParserError> for Diagnostic {
     fn from(error: ParserError) -> Diagnostic {
-        match error.reason {
+        let code = error.code();
+        let diagnostic = match error.reason {
             Some(reason) => {
                 match reason {
                     ParserErrorReason::ConstrainDeprecated => Diagnostic::simple_error(
@@ -161,7 +203,8 @@ impl From<ParserError> for Diagnostic {
                 let primary = error.to_string();
                 Diagnostic::simple_error(primary, String::new(), error.span)
             }
-        }
+        };
+        diagnostic.with_code(code)
     }
 }
 
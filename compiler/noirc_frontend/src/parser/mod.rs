@@ -11,11 +11,11 @@ mod labels;
 #[allow(clippy::module_inception)]
 mod parser;
 
-use crate::token::{Keyword, Token};
-use crate::{ast::ImportStatement, Expression, NoirStruct};
+use crate::token::{Keyword, SecondaryAttribute, Token};
+use crate::{ast::ImportStatement, Expression, NoirEnum, NoirStruct};
 use crate::{
-    Ident, LetStatement, NoirFunction, NoirTrait, NoirTraitImpl, NoirTypeAlias, Recoverable,
-    StatementKind, TypeImpl, UseTree,
+    Ident, ItemVisibility, LetStatement, NoirFunction, NoirTrait, NoirTraitImpl, NoirTypeAlias,
+    Recoverable, StatementKind, TypeImpl, UseTree,
 };
 
 use chumsky::prelude::*;
@@ -28,15 +28,16 @@ pub use parser::parse_program;
 #[derive(Debug, Clone)]
 pub(crate) enum TopLevelStatement {
     Function(NoirFunction),
-    Module(Ident),
-    Import(UseTree),
+    Module(ModuleDeclaration),
+    Import(UseTree, ItemVisibility),
     Struct(NoirStruct),
+    Enum(NoirEnum),
     Trait(NoirTrait),
     TraitImpl(NoirTraitImpl),
     Impl(TypeImpl),
     TypeAlias(NoirTypeAlias),
     SubModule(ParsedSubModule),
-    Global(LetStatement),
+    Global(LetStatement, ItemVisibility),
     Error,
 }
 
@@ -213,14 +214,15 @@ pub struct SortedModule {
     pub imports: Vec<ImportStatement>,
     pub functions: Vec<NoirFunction>,
     pub types: Vec<NoirStruct>,
+    pub enums: Vec<NoirEnum>,
     pub traits: Vec<NoirTrait>,
     pub trait_impls: Vec<NoirTraitImpl>,
     pub impls: Vec<TypeImpl>,
     pub type_aliases: Vec<NoirTypeAlias>,
-    pub globals: Vec<LetStatement>,
+    pub globals: Vec<(LetStatement, ItemVisibility)>,
 
     /// Module declarations like `mod foo;`
-    pub module_decls: Vec<Ident>,
+    pub module_decls: Vec<ModuleDeclaration>,
 
     /// Full submodules as in `mod foo { ... definitions ... }`
     pub submodules: Vec<SortedSubModule>,
@@ -229,21 +231,25 @@ pub struct SortedModule {
 impl std::fmt::Display for SortedModule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for decl in &self.module_decls {
-            writeln!(f, "mod {decl};")?;
+            writeln!(f, "{decl};")?;
         }
 
         for import in &self.imports {
             write!(f, "{import}")?;
         }
 
-        for global_const in &self.globals {
-            write!(f, "{global_const}")?;
+        for (global_const, visibility) in &self.globals {
+            write!(f, "{visibility}{global_const}")?;
         }
 
         for type_ in &self.types {
             write!(f, "{type_}")?;
         }
 
+        for enum_ in &self.enums {
+            write!(f, "{enum_}")?;
+        }
+
         for function in &self.functions {
             write!(f, "{function}")?;
         }
@@ -276,15 +282,16 @@ impl ParsedModule {
 
         for item in self.items {
             match item.kind {
-                ItemKind::Import(import) => module.push_import(import),
+                ItemKind::Import(import, visibility) => module.push_import(import, visibility),
                 ItemKind::Function(func) => module.push_function(func),
                 ItemKind::Struct(typ) => module.push_type(typ),
+                ItemKind::Enum(typ) => module.push_enum(typ),
                 ItemKind::Trait(noir_trait) => module.push_trait(noir_trait),
                 ItemKind::TraitImpl(trait_impl) => module.push_trait_impl(trait_impl),
                 ItemKind::Impl(r#impl) => module.push_impl(r#impl),
                 ItemKind::TypeAlias(type_alias) => module.push_type_alias(type_alias),
-                ItemKind::Global(global) => module.push_global(global),
-                ItemKind::ModuleDecl(mod_name) => module.push_module_decl(mod_name),
+                ItemKind::Global(global, visibility) => module.push_global(global, visibility),
+                ItemKind::ModuleDecl(mod_decl) => module.push_module_decl(mod_decl),
                 ItemKind::Submodules(submodule) => module.push_submodule(submodule.into_sorted()),
             }
         }
@@ -301,18 +308,35 @@ pub struct Item {
 
 #[derive(Clone, Debug)]
 pub enum ItemKind {
-    Import(UseTree),
+    Import(UseTree, ItemVisibility),
     Function(NoirFunction),
     Struct(NoirStruct),
+    Enum(NoirEnum),
     Trait(NoirTrait),
     TraitImpl(NoirTraitImpl),
     Impl(TypeImpl),
     TypeAlias(NoirTypeAlias),
-    Global(LetStatement),
-    ModuleDecl(Ident),
+    Global(LetStatement, ItemVisibility),
+    ModuleDecl(ModuleDeclaration),
     Submodules(ParsedSubModule),
 }
 
+/// A module declaration like `mod foo;`, whose contents are in a separate file.
+#[derive(Clone, Debug)]
+pub struct ModuleDeclaration {
+    pub ident: Ident,
+    pub attributes: Vec<SecondaryAttribute>,
+}
+
+impl std::fmt::Display for ModuleDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for attribute in &self.attributes {
+            write!(f, "{attribute} ")?;
+        }
+        write!(f, "mod {}", self.ident)
+    }
+}
+
 /// A submodule defined via `mod name { contents }` in some larger file.
 /// These submodules always share the same file as some larger ParsedModule
 #[derive(Clone, Debug)]
@@ -320,6 +344,8 @@ pub struct ParsedSubModule {
     pub name: Ident,
     pub contents: ParsedModule,
     pub is_contract: bool,
+    pub attributes: Vec<SecondaryAttribute>,
+    pub span: Span,
 }
 
 impl ParsedSubModule {
@@ -328,12 +354,17 @@ impl ParsedSubModule {
             name: self.name,
             contents: self.contents.into_sorted(),
             is_contract: self.is_contract,
+            attributes: self.attributes,
+            span: self.span,
         }
     }
 }
 
 impl std::fmt::Display for SortedSubModule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for attribute in &self.attributes {
+            writeln!(f, "{attribute}")?;
+        }
         write!(f, "mod {} {{", self.name)?;
 
         for line in self.contents.to_string().lines() {
@@ -348,6 +379,8 @@ pub struct SortedSubModule {
     pub name: Ident,
     pub contents: SortedModule,
     pub is_contract: bool,
+    pub attributes: Vec<SecondaryAttribute>,
+    pub span: Span,
 }
 
 impl SortedModule {
@@ -359,6 +392,10 @@ impl SortedModule {
         self.types.push(typ);
     }
 
+    fn push_enum(&mut self, typ: NoirEnum) {
+        self.enums.push(typ);
+    }
+
     fn push_trait(&mut self, noir_trait: NoirTrait) {
         self.traits.push(noir_trait);
     }
@@ -375,20 +412,49 @@ impl SortedModule {
         self.type_aliases.push(type_alias);
     }
 
-    fn push_import(&mut self, import_stmt: UseTree) {
-        self.imports.extend(import_stmt.desugar(None));
+    fn push_import(&mut self, import_stmt: UseTree, visibility: ItemVisibility) {
+        self.imports.extend(import_stmt.desugar(None, visibility));
     }
 
-    fn push_module_decl(&mut self, mod_name: Ident) {
-        self.module_decls.push(mod_name);
+    fn push_module_decl(&mut self, mod_decl: ModuleDeclaration) {
+        self.module_decls.push(mod_decl);
     }
 
     fn push_submodule(&mut self, submodule: SortedSubModule) {
         self.submodules.push(submodule);
     }
 
-    fn push_global(&mut self, global: LetStatement) {
-        self.globals.push(global);
+    fn push_global(&mut self, global: LetStatement, visibility: ItemVisibility) {
+        self.globals.push((global, visibility));
+    }
+
+    /// Appends all the items of another module to this one, such as the code generated by a macro.
+    pub fn extend(&mut self, other: SortedModule) {
+        let SortedModule {
+            imports,
+            functions,
+            types,
+            enums,
+            traits,
+            trait_impls,
+            impls,
+            type_aliases,
+            globals,
+            module_decls,
+            submodules,
+        } = other;
+
+        self.imports.extend(imports);
+        self.functions.extend(functions);
+        self.types.extend(types);
+        self.enums.extend(enums);
+        self.traits.extend(traits);
+        self.trait_impls.extend(trait_impls);
+        self.impls.extend(impls);
+        self.type_aliases.extend(type_aliases);
+        self.globals.extend(globals);
+        self.module_decls.extend(module_decls);
+        self.submodules.extend(submodules);
     }
 }
 
@@ -473,15 +539,15 @@ impl std::fmt::Display for TopLevelStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TopLevelStatement::Function(fun) => fun.fmt(f),
-            TopLevelStatement::Module(m) => write!(f, "mod {m}"),
-            TopLevelStatement::Import(tree) => write!(f, "use {tree}"),
+            TopLevelStatement::Module(m) => m.fmt(f),
+            TopLevelStatement::Import(tree, visibility) => write!(f, "{visibility}use {tree}"),
             TopLevelStatement::Trait(t) => t.fmt(f),
             TopLevelStatement::TraitImpl(i) => i.fmt(f),
             TopLevelStatement::Struct(s) => s.fmt(f),
             TopLevelStatement::Impl(i) => i.fmt(f),
             TopLevelStatement::TypeAlias(t) => t.fmt(f),
             TopLevelStatement::SubModule(s) => s.fmt(f),
-            TopLevelStatement::Global(c) => c.fmt(f),
+            TopLevelStatement::Global(c, visibility) => write!(f, "{visibility}{c}"),
             TopLevelStatement::Error => write!(f, "error"),
         }
     }
@@ -26,8 +26,8 @@
 use super::{
     foldl_with_span, labels::ParsingRuleLabel, parameter_name_recovery, parameter_recovery,
     parenthesized, then_commit, then_commit_ignore, top_level_statement_recovery, ExprParser,
-    NoirParser, ParsedModule, ParsedSubModule, ParserError, ParserErrorReason, Precedence,
-    TopLevelStatement,
+    ModuleDeclaration, NoirParser, ParsedModule, ParsedSubModule, ParserError, ParserErrorReason,
+    Precedence, TopLevelStatement,
 };
 use super::{spanned, Item, ItemKind};
 use crate::ast::{
@@ -38,11 +38,12 @@ use crate::parser::{force, ignore_then_commit, statement_recovery};
 use crate::token::{Attribute, Attributes, Keyword, SecondaryAttribute, Token, TokenKind};
 use crate::{
     BinaryOp, BinaryOpKind, BlockExpression, ConstrainKind, ConstrainStatement, Distinctness,
-    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, FunctionVisibility, Ident,
-    IfExpression, InfixExpression, LValue, Lambda, Literal, NoirFunction, NoirStruct, NoirTrait,
-    NoirTraitImpl, NoirTypeAlias, Param, Path, PathKind, Pattern, Recoverable, Statement,
-    TraitBound, TraitImplItem, TraitItem, TypeImpl, UnaryOp, UnresolvedTraitConstraint,
-    UnresolvedTypeExpression, UseTree, UseTreeKind, Visibility,
+    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, Ident, IfExpression,
+    InfixExpression, ItemVisibility, LValue, Lambda, Literal, MatchExpression, NoirEnum,
+    NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl, NoirTypeAlias, Param, Path, PathKind,
+    Pattern, Recoverable, Statement, TraitBound, TraitImplItem, TraitItem, TypeImpl, UnaryOp,
+    UnresolvedTraitConstraint, UnresolvedTypeExpression, UseTree, UseTreeKind, Visibility,
+    WhileStatement,
 };
 
 use chumsky::prelude::*;
@@ -82,14 +83,19 @@ fn module() -> impl NoirParser<ParsedModule> {
                 match statement {
                     TopLevelStatement::Function(f) => push_item(ItemKind::Function(f)),
                     TopLevelStatement::Module(m) => push_item(ItemKind::ModuleDecl(m)),
-                    TopLevelStatement::Import(i) => push_item(ItemKind::Import(i)),
+                    TopLevelStatement::Import(i, visibility) => {
+                        push_item(ItemKind::Import(i, visibility));
+                    }
                     TopLevelStatement::Struct(s) => push_item(ItemKind::Struct(s)),
+                    TopLevelStatement::Enum(e) => push_item(ItemKind::Enum(e)),
                     TopLevelStatement::Trait(t) => push_item(ItemKind::Trait(t)),
                     TopLevelStatement::TraitImpl(t) => push_item(ItemKind::TraitImpl(t)),
                     TopLevelStatement::Impl(i) => push_item(ItemKind::Impl(i)),
                     TopLevelStatement::TypeAlias(t) => push_item(ItemKind::TypeAlias(t)),
                     TopLevelStatement::SubModule(s) => push_item(ItemKind::Submodules(s)),
-                    TopLevelStatement::Global(c) => push_item(ItemKind::Global(c)),
+                    TopLevelStatement::Global(c, visibility) => {
+                        push_item(ItemKind::Global(c, visibility));
+                    }
                     TopLevelStatement::Error => (),
                 }
                 program
@@ -99,6 +105,7 @@ fn module() -> impl NoirParser<ParsedModule> {
 
 /// top_level_statement: function_definition
 ///                    | struct_definition
+///                    | enum_definition
 ///                    | trait_definition
 ///                    | implementation
 ///                    | submodule
@@ -111,6 +118,7 @@ fn top_level_statement(
     choice((
         function_definition(false).map(TopLevelStatement::Function),
         struct_definition(),
+        enum_definition(),
         trait_definition(),
         trait_implementation(),
         implementation(),
@@ -124,39 +132,70 @@ fn top_level_statement(
     .recover_via(top_level_statement_recovery())
 }
 
-/// global_declaration: 'global' ident global_type_annotation '=' literal
+/// global_declaration: item_visibility 'global' ident global_type_annotation '=' literal
 fn global_declaration() -> impl NoirParser<TopLevelStatement> {
-    let p = ignore_then_commit(
+    let p = item_visibility().then(ignore_then_commit(
         keyword(Keyword::Global).labelled(ParsingRuleLabel::Global),
         ident().map(Pattern::Identifier),
-    );
-    let p = then_commit(p, optional_type_annotation());
+    ));
+    let p = then_commit(p, optional_type_annotation(parse_type()));
     let p = then_commit_ignore(p, just(Token::Assign));
     let p = then_commit(p, literal_or_collection(expression()).map_with_span(Expression::new));
-    p.map(LetStatement::new_let).map(TopLevelStatement::Global)
+    p.map(|(((visibility, pattern), typ), expression)| {
+        let global = LetStatement::new_let(((pattern, typ), expression));
+        TopLevelStatement::Global(global, visibility)
+    })
 }
 
-/// submodule: 'mod' ident '{' module '}'
+/// item_visibility: 'pub(crate)' | 'pub' | %empty
+fn item_visibility() -> impl NoirParser<ItemVisibility> {
+    let pub_crate = keyword(Keyword::Pub)
+        .then(just(Token::LeftParen))
+        .then(keyword(Keyword::Crate))
+        .then(just(Token::RightParen))
+        .to(ItemVisibility::PublicCrate);
+
+    let public = keyword(Keyword::Pub).to(ItemVisibility::Public);
+    pub_crate.or(public).or_not().map(|visibility| visibility.unwrap_or(ItemVisibility::Private))
+}
+
+/// submodule: attributes 'mod' ident '{' module '}'
 fn submodule(module_parser: impl NoirParser<ParsedModule>) -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Mod)
-        .ignore_then(ident())
+    attributes()
+        .then_ignore(keyword(Keyword::Mod))
+        .then(ident())
         .then_ignore(just(Token::LeftBrace))
         .then(module_parser)
         .then_ignore(just(Token::RightBrace))
-        .map(|(name, contents)| {
-            TopLevelStatement::SubModule(ParsedSubModule { name, contents, is_contract: false })
+        .validate(|((attributes, name), contents), span, emit| {
+            let attributes = validate_module_attributes(attributes, span, emit);
+            TopLevelStatement::SubModule(ParsedSubModule {
+                name,
+                contents,
+                is_contract: false,
+                attributes,
+                span,
+            })
         })
 }
 
-/// contract: 'contract' ident '{' module '}'
+/// contract: attributes 'contract' ident '{' module '}'
 fn contract(module_parser: impl NoirParser<ParsedModule>) -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Contract)
-        .ignore_then(ident())
+    attributes()
+        .then_ignore(keyword(Keyword::Contract))
+        .then(ident())
         .then_ignore(just(Token::LeftBrace))
         .then(module_parser)
         .then_ignore(just(Token::RightBrace))
-        .map(|(name, contents)| {
-            TopLevelStatement::SubModule(ParsedSubModule { name, contents, is_contract: true })
+        .validate(|((attributes, name), contents), span, emit| {
+            let attributes = validate_module_attributes(attributes, span, emit);
+            TopLevelStatement::SubModule(ParsedSubModule {
+                name,
+                contents,
+                is_contract: true,
+                attributes,
+                span,
+            })
         })
 }
 
@@ -185,12 +224,13 @@ fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunction> {
                 is_unconstrained: modifiers.0,
                 is_open: modifiers.2,
                 is_internal: modifiers.3,
+                is_comptime: modifiers.5,
                 visibility: if modifiers.1 {
-                    FunctionVisibility::PublicCrate
+                    ItemVisibility::PublicCrate
                 } else if modifiers.4 {
-                    FunctionVisibility::Public
+                    ItemVisibility::Public
                 } else {
-                    FunctionVisibility::Private
+                    ItemVisibility::Private
                 },
                 generics,
                 parameters,
@@ -198,29 +238,32 @@ fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunction> {
                 where_clause,
                 return_type: ret.1,
                 return_visibility: ret.0 .1,
+                return_visibility_span: ret.0 .2,
                 return_distinctness: ret.0 .0,
             }
             .into()
         })
 }
 
-/// function_modifiers: 'unconstrained'? 'pub(crate)'? 'pub'? 'open'? 'internal'?
+/// function_modifiers: 'unconstrained'? 'pub(crate)'? 'pub'? 'open'? 'internal'? 'comptime'?
 ///
-/// returns (is_unconstrained, is_pub_crate, is_open, is_internal, is_pub) for whether each keyword was present
-fn function_modifiers() -> impl NoirParser<(bool, bool, bool, bool, bool)> {
+/// returns (is_unconstrained, is_pub_crate, is_open, is_internal, is_pub, is_comptime) for whether each keyword was present
+fn function_modifiers() -> impl NoirParser<(bool, bool, bool, bool, bool, bool)> {
     keyword(Keyword::Unconstrained)
         .or_not()
         .then(is_pub_crate())
         .then(keyword(Keyword::Pub).or_not())
         .then(keyword(Keyword::Open).or_not())
         .then(keyword(Keyword::Internal).or_not())
-        .map(|((((unconstrained, pub_crate), public), open), internal)| {
+        .then(keyword(Keyword::CompTime).or_not())
+        .map(|(((((unconstrained, pub_crate), public), open), internal), comptime)| {
             (
                 unconstrained.is_some(),
                 pub_crate,
                 open.is_some(),
                 internal.is_some(),
                 public.is_some(),
+                comptime.is_some(),
             )
         })
 }
@@ -264,26 +307,69 @@ fn struct_definition() -> impl NoirParser<TopLevelStatement> {
 
     attributes()
         .or_not()
+        .then(item_visibility())
         .then_ignore(keyword(Struct))
         .then(ident())
         .then(generics())
         .then(fields)
-        .validate(|(((raw_attributes, name), generics), fields), span, emit| {
+        .validate(|((((raw_attributes, visibility), name), generics), fields), span, emit| {
+            let attributes = validate_struct_attributes(raw_attributes, span, emit);
+            let noir_struct = NoirStruct { name, attributes, visibility, generics, fields, span };
+            TopLevelStatement::Struct(noir_struct)
+        })
+}
+
+/// enum_definition: attributes 'enum' ident generics '{' enum_variants '}'
+fn enum_definition() -> impl NoirParser<TopLevelStatement> {
+    use self::Keyword::Enum;
+    use Token::*;
+
+    let variants = enum_variants().delimited_by(just(LeftBrace), just(RightBrace)).recover_with(
+        nested_delimiters(
+            LeftBrace,
+            RightBrace,
+            [(LeftParen, RightParen), (LeftBracket, RightBracket)],
+            |_| vec![],
+        ),
+    );
+
+    attributes()
+        .or_not()
+        .then(item_visibility())
+        .then_ignore(keyword(Enum))
+        .then(ident())
+        .then(generics())
+        .then(variants)
+        .validate(|((((raw_attributes, visibility), name), generics), variants), span, emit| {
             let attributes = validate_struct_attributes(raw_attributes, span, emit);
-            TopLevelStatement::Struct(NoirStruct { name, attributes, generics, fields, span })
+            let noir_enum = NoirEnum { name, attributes, visibility, generics, variants, span };
+            TopLevelStatement::Enum(noir_enum)
         })
 }
 
+/// enum_variants: (ident ('(' type (',' type)* ')')?) (',' ...)* ','?
+fn enum_variants() -> impl NoirParser<Vec<(Ident, Vec<UnresolvedType>)>> {
+    let parameters = parse_type()
+        .separated_by(just(Token::Comma))
+        .allow_trailing()
+        .delimited_by(just(Token::LeftParen), just(Token::RightParen));
+
+    ident()
+        .then(parameters.or_not().map(Option::unwrap_or_default))
+        .separated_by(just(Token::Comma))
+        .allow_trailing()
+}
+
 fn type_alias_definition() -> impl NoirParser<TopLevelStatement> {
     use self::Keyword::Type;
 
-    let p = ignore_then_commit(keyword(Type), ident());
+    let p = item_visibility().then(ignore_then_commit(keyword(Type), ident()));
     let p = then_commit(p, generics());
     let p = then_commit_ignore(p, just(Token::Assign));
     let p = then_commit(p, parse_type());
 
-    p.map_with_span(|((name, generics), typ), span| {
-        TopLevelStatement::TypeAlias(NoirTypeAlias { name, generics, typ, span })
+    p.map_with_span(|(((visibility, name), generics), typ), span| {
+        TopLevelStatement::TypeAlias(NoirTypeAlias { name, visibility, generics, typ, span })
     })
 }
 
@@ -294,16 +380,25 @@ fn lambda_return_type() -> impl NoirParser<UnresolvedType> {
         .map(|ret| ret.unwrap_or_else(UnresolvedType::unspecified))
 }
 
-fn function_return_type() -> impl NoirParser<((Distinctness, Visibility), FunctionReturnType)> {
+/// function_return_type: ('->' distinctness? visibility? type)?
+///
+/// Along with the return type this returns its distinctness, its visibility and, if a
+/// visibility keyword was written, the span of that keyword.
+fn function_return_type(
+) -> impl NoirParser<((Distinctness, Visibility, Option<Span>), FunctionReturnType)> {
     just(Token::Arrow)
         .ignore_then(optional_distinctness())
-        .then(optional_visibility())
+        .then(spanned(optional_visibility()))
         .then(spanned(parse_type()))
         .or_not()
         .map_with_span(|ret, span| match ret {
-            Some((head, (ty, _))) => (head, FunctionReturnType::Ty(ty)),
+            Some(((distinctness, (visibility, visibility_span)), (ty, _))) => {
+                let visibility_span =
+                    (visibility != Visibility::Private).then_some(visibility_span);
+                ((distinctness, visibility, visibility_span), FunctionReturnType::Ty(ty))
+            }
             None => (
-                (Distinctness::DuplicationAllowed, Visibility::Private),
+                (Distinctness::DuplicationAllowed, Visibility::Private, None),
                 FunctionReturnType::Default(span),
             ),
         })
@@ -406,21 +501,23 @@ fn self_parameter() -> impl NoirParser<Param> {
 }
 
 fn trait_definition() -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Trait)
-        .ignore_then(ident())
+    item_visibility()
+        .then_ignore(keyword(Keyword::Trait))
+        .then(ident())
         .then(generics())
         .then(where_clause())
         .then_ignore(just(Token::LeftBrace))
         .then(trait_body())
         .then_ignore(just(Token::RightBrace))
-        .validate(|(((name, generics), where_clause), items), span, emit| {
+        .validate(|((((visibility, name), generics), where_clause), items), span, emit| {
             if !generics.is_empty() {
                 emit(ParserError::with_reason(
                     ParserErrorReason::ExperimentalFeature("Generic traits"),
                     span,
                 ));
             }
-            TopLevelStatement::Trait(NoirTrait { name, generics, where_clause, span, items })
+            let noir_trait = NoirTrait { name, visibility, generics, where_clause, span, items };
+            TopLevelStatement::Trait(noir_trait)
         })
 }
 
@@ -442,13 +539,7 @@ fn trait_constant_declaration() -> impl NoirParser<TraitItem> {
         .then(parse_type())
         .then(optional_default_value())
         .then_ignore(just(Token::Semicolon))
-        .validate(|((name, typ), default_value), span, emit| {
-            emit(ParserError::with_reason(
-                ParserErrorReason::ExperimentalFeature("Associated constants"),
-                span,
-            ));
-            TraitItem::Constant { name, typ, default_value }
-        })
+        .map(|((name, typ), default_value)| TraitItem::Constant { name, typ, default_value })
 }
 
 /// trait_function_declaration: 'fn' ident generics '(' declaration_parameters ')' function_return_type
@@ -506,21 +597,39 @@ fn validate_struct_attributes(
     emit: &mut dyn FnMut(ParserError),
 ) -> Vec<SecondaryAttribute> {
     let attrs = attributes.unwrap_or_default();
-    let mut struct_attributes = vec![];
+    let reason = ParserErrorReason::NoFunctionAttributesAllowedOnStruct;
+    validate_secondary_attributes(attrs, reason, span, emit)
+}
 
-    for attribute in attrs {
+fn validate_module_attributes(
+    attributes: Vec<Attribute>,
+    span: Span,
+    emit: &mut dyn FnMut(ParserError),
+) -> Vec<SecondaryAttribute> {
+    let reason = ParserErrorReason::NoFunctionAttributesAllowedOnModule;
+    validate_secondary_attributes(attributes, reason, span, emit)
+}
+
+/// Filters out any function attributes, which are not allowed on items other than
+/// functions, emitting an error with the given `reason` for each of them.
+fn validate_secondary_attributes(
+    attributes: Vec<Attribute>,
+    reason: ParserErrorReason,
+    span: Span,
+    emit: &mut dyn FnMut(ParserError),
+) -> Vec<SecondaryAttribute> {
+    let mut secondary_attributes = vec![];
+
+    for attribute in attributes {
         match attribute {
             Attribute::Function(..) => {
-                emit(ParserError::with_reason(
-                    ParserErrorReason::NoFunctionAttributesAllowedOnStruct,
-                    span,
-                ));
+                emit(ParserError::with_reason(reason.clone(), span));
             }
-            Attribute::Secondary(attr) => struct_attributes.push(attr),
+            Attribute::Secondary(attr) => secondary_attributes.push(attr),
         }
     }
 
-    struct_attributes
+    secondary_attributes
 }
 
 /// Function declaration parameters differ from other parameters in that parameter
@@ -555,15 +664,10 @@ fn function_declaration_parameters() -> impl NoirParser<Vec<(Ident, UnresolvedTy
 
 /// trait_type_declaration: 'type' ident generics
 fn trait_type_declaration() -> impl NoirParser<TraitItem> {
-    keyword(Keyword::Type).ignore_then(ident()).then_ignore(just(Token::Semicolon)).validate(
-        |name, span, emit| {
-            emit(ParserError::with_reason(
-                ParserErrorReason::ExperimentalFeature("Associated types"),
-                span,
-            ));
-            TraitItem::Type { name }
-        },
-    )
+    keyword(Keyword::Type)
+        .ignore_then(ident())
+        .then_ignore(just(Token::Semicolon))
+        .map(|name| TraitItem::Type { name })
 }
 
 /// Parses a non-trait implementation, adding a set of methods to a type.
@@ -622,7 +726,16 @@ fn trait_implementation_body() -> impl NoirParser<Vec<TraitImplItem>> {
         .then_ignore(just(Token::Semicolon))
         .map(|(name, alias)| TraitImplItem::Type { name, alias });
 
-    function.or(alias).repeated()
+    let constant = keyword(Keyword::Let)
+        .ignore_then(ident())
+        .then_ignore(just(Token::Colon))
+        .then(parse_type())
+        .then_ignore(just(Token::Assign))
+        .then(expression())
+        .then_ignore(just(Token::Semicolon))
+        .map(|((name, typ), value)| TraitImplItem::Constant(name, typ, value));
+
+    function.or(alias).or(constant).repeated()
 }
 
 fn where_clause() -> impl NoirParser<Vec<UnresolvedTraitConstraint>> {
@@ -676,10 +789,20 @@ fn block<'a>(
     statement: impl NoirParser<StatementKind> + 'a,
 ) -> impl NoirParser<BlockExpression> + 'a {
     use Token::*;
-    statement
-        .recover_via(statement_recovery())
+    attributes()
+        .then(spanned(statement.recover_via(statement_recovery())))
         .then(just(Semicolon).or_not().map_with_span(|s, span| (s, span)))
-        .map_with_span(|(kind, rest), span| (Statement { kind, span }, rest))
+        .validate(|((attributes, (kind, kind_span)), rest), span, emit| {
+            let reason = ParserErrorReason::NoFunctionAttributesAllowedOnStatement;
+            let attributes = validate_secondary_attributes(attributes, reason, span, emit);
+            if attributes.is_empty() {
+                return (Statement { kind, span }, rest);
+            }
+
+            let statement = Statement { kind, span: Span::from(kind_span.start()..span.end()) };
+            let kind = StatementKind::Attributed(attributes, Box::new(statement));
+            (Statement { kind, span }, rest)
+        })
         .repeated()
         .validate(check_statements_require_semicolon)
         .delimited_by(just(LeftBrace), just(RightBrace))
@@ -705,18 +828,30 @@ fn check_statements_require_semicolon(
 }
 
 /// Parse an optional ': type'
-fn optional_type_annotation<'a>() -> impl NoirParser<UnresolvedType> + 'a {
-    ignore_then_commit(just(Token::Colon), parse_type())
+fn optional_type_annotation<'a>(
+    type_parser: impl NoirParser<UnresolvedType> + 'a,
+) -> impl NoirParser<UnresolvedType> + 'a {
+    ignore_then_commit(just(Token::Colon), type_parser)
         .or_not()
         .map(|r#type| r#type.unwrap_or_else(UnresolvedType::unspecified))
 }
 
+/// module_declaration: attributes 'mod' ident
 fn module_declaration() -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Mod).ignore_then(ident()).map(TopLevelStatement::Module)
+    attributes().then_ignore(keyword(Keyword::Mod)).then(ident()).validate(
+        |(attributes, ident), span, emit| {
+            let attributes = validate_module_attributes(attributes, span, emit);
+            TopLevelStatement::Module(ModuleDeclaration { ident, attributes })
+        },
+    )
 }
 
+/// use_statement: item_visibility 'use' use_tree
 fn use_statement() -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Use).ignore_then(use_tree()).map(TopLevelStatement::Import)
+    item_visibility()
+        .then_ignore(keyword(Keyword::Use))
+        .then(use_tree())
+        .map(|(visibility, tree)| TopLevelStatement::Import(tree, visibility))
 }
 
 fn keyword(keyword: Keyword) -> impl NoirParser<Token> {
@@ -801,9 +936,12 @@ where
             constrain(expr_parser.clone()),
             assertion(expr_parser.clone()),
             assertion_eq(expr_parser.clone()),
-            declaration(expr_parser.clone()),
+            declaration(expr_parser.clone(), statement.clone()),
             assignment(expr_parser.clone()),
-            for_loop(expr_no_constructors, statement),
+            for_loop(expr_no_constructors.clone(), statement.clone()),
+            while_loop(expr_no_constructors, statement),
+            break_statement(),
+            continue_statement(),
             return_statement(expr_parser.clone()),
             expr_parser.map(StatementKind::Expression),
         ))
@@ -893,13 +1031,14 @@ where
         })
 }
 
-fn declaration<'a, P>(expr_parser: P) -> impl NoirParser<StatementKind> + 'a
+fn declaration<'a, P, S>(expr_parser: P, statement: S) -> impl NoirParser<StatementKind> + 'a
 where
     P: ExprParser + 'a,
+    S: NoirParser<StatementKind> + 'a,
 {
     let p =
         ignore_then_commit(keyword(Keyword::Let).labelled(ParsingRuleLabel::Statement), pattern());
-    let p = p.then(optional_type_annotation());
+    let p = p.then(optional_type_annotation(parse_type_with_comptime_lengths(statement)));
     let p = then_commit_ignore(p, just(Token::Assign));
     let p = then_commit(p, expr_parser);
     p.map(StatementKind::new_let)
@@ -907,16 +1046,21 @@ where
 
 fn pattern() -> impl NoirParser<Pattern> {
     recursive(|pattern| {
-        let ident_pattern = ident().map(Pattern::Identifier).map_err(|mut error| {
-            if matches!(error.found(), Token::IntType(..)) {
-                error = ParserError::with_reason(
-                    ParserErrorReason::ExpectedPatternButFoundType(error.found().clone()),
-                    error.span(),
-                );
-            }
+        let ident_pattern = ident()
+            .map(|name| match name.0.contents.as_str() {
+                "_" => Pattern::Wildcard(name.span()),
+                _ => Pattern::Identifier(name),
+            })
+            .map_err(|mut error| {
+                if matches!(error.found(), Token::IntType(..)) {
+                    error = ParserError::with_reason(
+                        ParserErrorReason::ExpectedPatternButFoundType(error.found().clone()),
+                        error.span(),
+                    );
+                }
 
-            error
-        });
+                error
+            });
 
         let mut_pattern = keyword(Keyword::Mut)
             .ignore_then(pattern.clone())
@@ -934,12 +1078,84 @@ fn pattern() -> impl NoirParser<Pattern> {
             .then(struct_pattern_fields)
             .map_with_span(|(typename, fields), span| Pattern::Struct(typename, fields, span));
 
+        let enum_variant_arguments = pattern
+            .clone()
+            .separated_by(just(Token::Comma))
+            .allow_trailing()
+            .delimited_by(just(Token::LeftParen), just(Token::RightParen));
+
+        // A variant with arguments: `Option::Some(x)` or `Some(x)`
+        let enum_variant_pattern =
+            path().then(enum_variant_arguments).map_with_span(|(variant, arguments), span| {
+                Pattern::EnumVariant(variant, arguments, span)
+            });
+
+        // A unit variant must be written as a path to distinguish it from a new binding: `Option::None`
+        let unit_variant_pattern = path().try_map(|variant, span| {
+            if variant.segments.len() > 1 || variant.kind != PathKind::Plain {
+                Ok(Pattern::EnumVariant(variant, Vec::new(), span))
+            } else {
+                Err(ParserError::empty(Token::DoubleColon, span))
+            }
+        });
+
+        let literal_pattern =
+            just(Token::Minus).or_not().then(literal()).validate(|(minus, literal), span, emit| {
+                match literal {
+                    ExpressionKind::Literal(Literal::Integer(value, _)) => {
+                        Pattern::Literal(Literal::Integer(value, minus.is_some()), span)
+                    }
+                    ExpressionKind::Literal(Literal::Bool(value)) if minus.is_none() => {
+                        Pattern::Literal(Literal::Bool(value), span)
+                    }
+                    _ => {
+                        emit(ParserError::with_reason(
+                            ParserErrorReason::InvalidLiteralPattern,
+                            span,
+                        ));
+                        Pattern::Wildcard(span)
+                    }
+                }
+            });
+
+        // Each `..` is parsed as None
+        let array_pattern_element = just(Token::DoubleDot).to(None).or(pattern.clone().map(Some));
+        let array_pattern = array_pattern_element
+            .separated_by(just(Token::Comma))
+            .allow_trailing()
+            .delimited_by(just(Token::LeftBracket), just(Token::RightBracket))
+            .validate(|elements, span, emit| {
+                let mut rest = None;
+                let mut patterns = Vec::with_capacity(elements.len());
+
+                for element in elements {
+                    match element {
+                        Some(pattern) => patterns.push(pattern),
+                        None if rest.is_none() => rest = Some(patterns.len()),
+                        None => emit(ParserError::with_reason(
+                            ParserErrorReason::MultipleRestPatterns,
+                            span,
+                        )),
+                    }
+                }
+                Pattern::Array(patterns, rest, span)
+            });
+
         let tuple_pattern = pattern
             .separated_by(just(Token::Comma))
             .delimited_by(just(Token::LeftParen), just(Token::RightParen))
             .map_with_span(Pattern::Tuple);
 
-        choice((mut_pattern, tuple_pattern, struct_pattern, ident_pattern))
+        choice((
+            mut_pattern,
+            tuple_pattern,
+            array_pattern,
+            literal_pattern,
+            struct_pattern,
+            enum_variant_pattern,
+            unit_variant_pattern,
+            ident_pattern,
+        ))
     })
     .labelled(ParsingRuleLabel::Pattern)
 }
@@ -1013,11 +1229,22 @@ where
 }
 
 fn parse_type<'a>() -> impl NoirParser<UnresolvedType> + 'a {
-    recursive(parse_type_inner)
+    recursive(|type_parser| parse_type_inner(type_parser, nothing()))
+}
+
+/// Parses a type whose array lengths may also be given by comptime blocks, such as
+/// `[Field; comptime { 2 + 3 }]`. The statements of these blocks are parsed by `statement`.
+fn parse_type_with_comptime_lengths<'a, S>(statement: S) -> impl NoirParser<UnresolvedType> + 'a
+where
+    S: NoirParser<StatementKind> + 'a,
+{
+    let comptime_length = comptime_expr(statement).map_with_span(Expression::new);
+    recursive(move |type_parser| parse_type_inner(type_parser, comptime_length.clone()))
 }
 
 fn parse_type_inner(
     recursive_type_parser: impl NoirParser<UnresolvedType>,
+    comptime_length: impl NoirParser<Expression>,
 ) -> impl NoirParser<UnresolvedType> {
     choice((
         field_type(),
@@ -1027,7 +1254,7 @@ fn parse_type_inner(
         format_string_type(recursive_type_parser.clone()),
         named_type(recursive_type_parser.clone()),
         named_trait(recursive_type_parser.clone()),
-        array_type(recursive_type_parser.clone()),
+        array_type(recursive_type_parser.clone(), comptime_length),
         parenthesized_type(recursive_type_parser.clone()),
         tuple_type(recursive_type_parser.clone()),
         function_type(recursive_type_parser.clone()),
@@ -1153,10 +1380,14 @@ fn generic_type_args(
         .map(Option::unwrap_or_default)
 }
 
-fn array_type(type_parser: impl NoirParser<UnresolvedType>) -> impl NoirParser<UnresolvedType> {
+fn array_type(
+    type_parser: impl NoirParser<UnresolvedType>,
+    comptime_length: impl NoirParser<Expression>,
+) -> impl NoirParser<UnresolvedType> {
+    let length = comptime_length.try_map(UnresolvedTypeExpression::from_expr).or(type_expression());
     just(Token::LeftBracket)
         .ignore_then(type_parser)
-        .then(just(Token::Semicolon).ignore_then(type_expression()).or_not())
+        .then(just(Token::Semicolon).ignore_then(length).or_not())
         .then_ignore(just(Token::RightBracket))
         .map_with_span(|(element_type, size), span| {
             UnresolvedTypeData::Array(size, Box::new(element_type)).with_span(span)
@@ -1474,6 +1705,35 @@ where
     })
 }
 
+/// match_expr: 'match' expression_no_constructors '{' (pattern '=>' expression ','?)* '}'
+fn match_expr<'a, P, P2>(
+    expr_parser: P,
+    expr_no_constructors: P2,
+) -> impl NoirParser<ExpressionKind> + 'a
+where
+    P: ExprParser + 'a,
+    P2: ExprParser + 'a,
+{
+    let rule = pattern().then_ignore(just(Token::FatArrow)).then(expr_parser);
+    let rules = rule
+        .then_ignore(just(Token::Comma).or_not())
+        .repeated()
+        .delimited_by(just(Token::LeftBrace), just(Token::RightBrace));
+
+    keyword(Keyword::Match).ignore_then(expr_no_constructors).then(rules).map(
+        |(expression, rules)| {
+            ExpressionKind::Match(Box::new(MatchExpression { expression, rules }))
+        },
+    )
+}
+
+fn comptime_expr<'a, S>(statement: S) -> impl NoirParser<ExpressionKind> + 'a
+where
+    S: NoirParser<StatementKind> + 'a,
+{
+    keyword(Keyword::CompTime).ignore_then(block(statement)).map(ExpressionKind::Comptime)
+}
+
 fn lambda<'a>(
     expr_parser: impl NoirParser<Expression> + 'a,
 ) -> impl NoirParser<ExpressionKind> + 'a {
@@ -1501,6 +1761,32 @@ where
         })
 }
 
+fn while_loop<'a, P, S>(
+    expr_no_constructors: P,
+    statement: S,
+) -> impl NoirParser<StatementKind> + 'a
+where
+    P: ExprParser + 'a,
+    S: NoirParser<StatementKind> + 'a,
+{
+    keyword(Keyword::While)
+        .ignore_then(expr_no_constructors)
+        .then(block_expr(statement))
+        .map_with_span(|(condition, block), span| {
+            StatementKind::While(WhileStatement { condition, block, span })
+        })
+}
+
+fn break_statement() -> impl NoirParser<StatementKind> {
+    keyword(Keyword::Break).map(|_| StatementKind::Break).labelled(ParsingRuleLabel::Statement)
+}
+
+fn continue_statement() -> impl NoirParser<StatementKind> {
+    keyword(Keyword::Continue)
+        .map(|_| StatementKind::Continue)
+        .labelled(ParsingRuleLabel::Statement)
+}
+
 /// The 'range' of a for loop. Either an actual range `start .. end` or an array expression.
 fn for_range<P>(expr_no_constructors: P) -> impl NoirParser<ForRange>
 where
@@ -1600,7 +1886,8 @@ where
     S: NoirParser<StatementKind> + 'a,
 {
     choice((
-        if_expr(expr_no_constructors, statement.clone()),
+        if_expr(expr_no_constructors.clone(), statement.clone()),
+        match_expr(expr_parser.clone(), expr_no_constructors),
         array_expr(expr_parser.clone()),
         if allow_constructors {
             constructor(expr_parser.clone()).boxed()
@@ -1608,6 +1895,7 @@ where
             nothing().boxed()
         },
         lambda(expr_parser.clone()),
+        comptime_expr(statement.clone()),
         block(statement).map(ExpressionKind::Block),
         variable(),
         literal(),
@@ -1713,6 +2001,7 @@ mod test {
     use noirc_errors::CustomDiagnostic;
 
     use super::*;
+    use crate::lints::LintLevel;
     use crate::{ArrayLiteral, Literal};
 
     fn parse_with<P, T>(parser: P, program: &str) -> Result<T, Vec<CustomDiagnostic>>
@@ -2135,7 +2424,10 @@ mod test {
         // Let statements are not type checked here, so the parser will accept as
         // long as it is a type. Other statements such as Public are type checked
         // Because for now, they can only have one type
-        parse_all(declaration(expression()), vec!["let _ = 42", "let x = y", "let x : u8 = y"]);
+        parse_all(
+            declaration(expression(), fresh_statement()),
+            vec!["let _ = 42", "let x = y", "let x : u8 = y", "let x : [u8; comptime { 2 }] = y"],
+        );
     }
 
     #[test]
@@ -2161,6 +2453,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_while_loop() {
+        parse_all(
+            while_loop(expression_no_constructors(expression()), fresh_statement()),
+            vec![
+                "while x < 10 {}",
+                "while true { x += 1; }",
+                "while i != j { if i == 3 { break; } continue }",
+            ],
+        );
+
+        parse_all_failing(
+            while_loop(expression_no_constructors(expression()), fresh_statement()),
+            vec![
+                "while {}",         // Missing the condition
+                "while x < 10",     // Missing the body
+                "while Foo {} { }", // Constructors are not allowed in the condition
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_break_and_continue() {
+        parse_all(fresh_statement(), vec!["break", "continue"]);
+        parse_all_failing(fresh_statement(), vec!["break 1", "continue x"]);
+    }
+
     #[test]
     fn parse_function() {
         parse_all(
@@ -2192,6 +2511,8 @@ mod test {
                 "fn func_name<T>(f: Field, y : T) where T: SomeTrait + {}",
                 // The following should produce compile error on later stage. From the parser's perspective it's fine
                 "fn func_name<A>(f: Field, y : Field, z : Field) where T: SomeTrait {}",
+                "comptime fn f(x: Field) -> Field { x * 2 }",
+                "pub comptime fn f<N>() -> [Field; N] { [0; N] }",
             ],
         );
 
@@ -2239,6 +2560,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_trait_impl_with_associated_items() {
+        parse_all(
+            trait_implementation(),
+            vec![
+                "impl Hasher for Poseidon { type Output = Field; }",
+                "impl Hasher for Poseidon { let N: u64 = 2; }",
+                "impl<T> Container for Wrapper<T> { type Item = T; let Size: u64 = 3; fn get(self) -> T { self.0 } }",
+            ],
+        );
+
+        parse_all_failing(
+            trait_implementation(),
+            vec![
+                "impl Hasher for Poseidon { type Output; }",
+                "impl Hasher for Poseidon { let N: u64; }",
+                "impl Hasher for Poseidon { let N = 2; }",
+            ],
+        );
+    }
+
     #[test]
     fn parse_parenthesized_expression() {
         parse_all(
@@ -2314,6 +2656,34 @@ mod test {
     fn parse_module_declaration() {
         parse_with(module_declaration(), "mod foo").unwrap();
         parse_with(module_declaration(), "mod 1").unwrap_err();
+
+        let decl = parse_with(module_declaration(), "#[allow(dead_code)] mod foo").unwrap();
+        match decl {
+            TopLevelStatement::Module(decl) => {
+                let lints = vec!["dead_code".to_string()];
+                assert_eq!(
+                    decl.attributes,
+                    vec![SecondaryAttribute::Lint(LintLevel::Allow, lints)]
+                );
+            }
+            _ => unreachable!(),
+        }
+        parse_with(module_declaration(), "#[test] mod foo").unwrap_err();
+    }
+
+    #[test]
+    fn parse_attributed_statement() {
+        let src = "{ #[allow(unused_variables)] let x = 1; x }";
+        let block = parse_with(block(fresh_statement()), src).unwrap();
+        match &block.0[0].kind {
+            StatementKind::Attributed(attributes, statement) => {
+                assert_eq!(attributes.len(), 1);
+                assert!(matches!(statement.kind, StatementKind::Let(_)));
+            }
+            kind => panic!("Expected an attributed statement, got: {kind:?}"),
+        }
+
+        parse_with(block(fresh_statement()), "{ #[test] let x = 1; x }").unwrap_err();
     }
 
     #[test]
@@ -2382,6 +2752,8 @@ mod test {
                 "use foo::{bar as bar2, hello}",
                 "use foo::{bar as bar2, hello::{foo}, nested::{foo, bar}}",
                 "use dep::{std::println, bar::baz}",
+                "pub use foo::bar",
+                "pub(crate) use foo::{bar, baz}",
             ],
         );
 
@@ -2406,6 +2778,9 @@ mod test {
             "struct Bar { ident: Field, }",
             "struct Baz { ident: Field, other: Field }",
             "#[attribute] struct Baz { ident: Field, other: Field }",
+            "pub struct Foo { }",
+            "pub(crate) struct Foo { }",
+            "#[attribute] pub struct Baz { ident: Field }",
         ];
         parse_all(struct_definition(), cases);
 
@@ -2418,15 +2793,106 @@ mod test {
         parse_all_failing(struct_definition(), failing);
     }
 
+    #[test]
+    fn parse_enums() {
+        let cases = vec![
+            "enum Foo { }",
+            "enum Foo { A }",
+            "enum Foo { A, B(Field), }",
+            "enum Option<T> { None, Some(T) }",
+            "#[attribute] enum Baz { A(Field, u8), B((Field, Field)) }",
+        ];
+        parse_all(enum_definition(), cases);
+
+        let failing = vec!["enum {  }", "enum Foo { A: Field }", "enum Foo { A(pub Field) }"];
+        parse_all_failing(enum_definition(), failing);
+    }
+
+    #[test]
+    fn parse_match_expr() {
+        let cases = vec![
+            "match x { }",
+            "match x { a => 1 }",
+            "match x { Option::Some(y) => y, Option::None => 0, }",
+            "match (a, b) { (Foo::A, _b) => { 1 } (_a, Foo::B(x)) => x }",
+            "match foo() { Bar { x, y: Baz::C } => x }",
+        ];
+        parse_all(expression(), cases);
+
+        let failing = vec!["match x { a }", "match { a => 1 }", "match x { a => }"];
+        parse_all_failing(expression(), failing);
+    }
+
+    #[test]
+    fn parse_patterns() {
+        let cases = vec![
+            "_",
+            "(_, x)",
+            "1",
+            "-1",
+            "true",
+            "[a, b, c]",
+            "[first, ..]",
+            "[.., last]",
+            "[first, .., last]",
+            "[..]",
+            "[]",
+            "Foo { x: [_, y], z: 0 }",
+            "Option::Some((true, _))",
+        ];
+        parse_all(pattern(), cases);
+
+        let failing = vec!["[a, .., b, ..]", "\"foo\"", "-true", "[a b]"];
+        parse_all_failing(pattern(), failing);
+    }
+
+    #[test]
+    fn parse_comptime_expr() {
+        let cases = vec!["comptime { 1 + 2 }", "comptime { let x = 3; x * x }", "comptime {}"];
+        parse_all(expression(), cases);
+
+        let failing = vec!["comptime 1 + 2", "comptime (x)"];
+        parse_all_failing(expression(), failing);
+    }
+
     #[test]
     fn parse_type_aliases() {
-        let cases = vec!["type foo = u8", "type bar = String", "type baz<T> = Vec<T>"];
+        let cases = vec![
+            "type foo = u8",
+            "type bar = String",
+            "type baz<T> = Vec<T>",
+            "pub type foo = u8",
+            "pub(crate) type foo = u8",
+        ];
         parse_all(type_alias_definition(), cases);
 
-        let failing = vec!["type = u8", "type foo", "type foo = 1"];
+        let failing = vec!["type = u8", "type foo", "type foo = 1", "pub(foo) type foo = u8"];
         parse_all_failing(type_alias_definition(), failing);
     }
 
+    #[test]
+    fn parse_item_visibility() {
+        let cases = vec![
+            ("global N = 1;", ItemVisibility::Private),
+            ("pub global N = 1;", ItemVisibility::Public),
+            ("pub(crate) global N = 1;", ItemVisibility::PublicCrate),
+        ];
+
+        for (src, expected) in cases {
+            match parse_with(global_declaration(), src).unwrap() {
+                TopLevelStatement::Global(_, visibility) => assert_eq!(visibility, expected),
+                other => panic!("Expected a global, found {other}"),
+            }
+        }
+
+        match parse_with(trait_definition(), "pub(crate) trait Foo {}").unwrap() {
+            TopLevelStatement::Trait(noir_trait) => {
+                assert_eq!(noir_trait.visibility, ItemVisibility::PublicCrate);
+            }
+            other => panic!("Expected a trait, found {other}"),
+        }
+    }
+
     #[test]
     fn parse_member_access() {
         let cases = vec!["a.b", "a + b.c", "foo.bar as u32"];
@@ -10,8 +10,9 @@ mod test {
     use fm::FileId;
 
     use iter_extended::vecmap;
-    use noirc_errors::Location;
+    use noirc_errors::{Applicability, CustomDiagnostic, Location};
 
+    use crate::hir::comptime::InterpreterError;
     use crate::hir::def_collector::dc_crate::CompilationError;
     use crate::hir::def_collector::errors::{DefCollectorErrorKind, DuplicateType};
     use crate::hir::def_map::ModuleData;
@@ -22,8 +23,9 @@ mod test {
     use crate::node_interner::{NodeInterner, StmtId};
 
     use crate::hir::def_collector::dc_crate::DefCollector;
-    use crate::hir_def::expr::HirExpression;
+    use crate::hir_def::expr::{HirArrayLiteral, HirExpression, HirLiteral};
     use crate::hir_def::stmt::HirStatement;
+    use crate::lints::Lint;
     use crate::monomorphization::monomorphize;
     use crate::parser::ParserErrorReason;
     use crate::ParsedModule;
@@ -47,8 +49,31 @@ mod test {
         });
     }
 
+    /// Warnings about unused items are removed from the errors of most tests, since their
+    /// programs often declare items only to check how they are resolved.
+    pub(crate) fn remove_unused_item_warnings(errors: &mut Vec<(CompilationError, FileId)>) {
+        errors.retain(|(error, _)| {
+            !matches!(
+                error,
+                CompilationError::ResolverError(
+                    ResolverError::UnusedImport { .. }
+                        | ResolverError::UnusedItem { .. }
+                        | ResolverError::UnreadField { .. }
+                )
+            )
+        });
+    }
+
     pub(crate) fn get_program(
         src: &str,
+    ) -> (ParsedModule, Context, Vec<(CompilationError, FileId)>) {
+        let (program, context, mut errors) = get_program_with_unused_item_warnings(src);
+        remove_unused_item_warnings(&mut errors);
+        (program, context, errors)
+    }
+
+    pub(crate) fn get_program_with_unused_item_warnings(
+        src: &str,
     ) -> (ParsedModule, Context, Vec<(CompilationError, FileId)>) {
         let root = std::path::Path::new("/");
         let fm = FileManager::new(root);
@@ -726,6 +751,8 @@ mod test {
                 HirStatement::Constrain(constr_stmt) => constr_stmt.0,
                 HirStatement::Semi(semi_expr) => semi_expr,
                 HirStatement::For(for_loop) => for_loop.block,
+                HirStatement::While(while_loop) => while_loop.block,
+                HirStatement::Break | HirStatement::Continue => continue,
                 HirStatement::Error => panic!("Invalid HirStatement!"),
             };
             let expr = interner.expression(&expr_id);
@@ -787,7 +814,7 @@ mod test {
         assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
         // It should be regarding the unused variable
         match &errors[0].0 {
-            CompilationError::ResolverError(ResolverError::UnusedVariable { ident }) => {
+            CompilationError::ResolverError(ResolverError::UnusedVariable { ident, .. }) => {
                 assert_eq!(&ident.0.contents, "y");
             }
             _ => unreachable!("we should only have an unused var error"),
@@ -871,7 +898,7 @@ mod test {
             match compilation_error {
                 CompilationError::ResolverError(err) => {
                     match err {
-                        ResolverError::UnusedVariable { ident } => {
+                        ResolverError::UnusedVariable { ident, .. } => {
                             assert_eq!(&ident.0.contents, "z");
                         }
                         ResolverError::VariableNotDeclared { name, .. } => {
@@ -1111,4 +1138,1565 @@ fn lambda$f1(mut env$l1: (Field)) -> Field {
 "#;
         check_rewrite(src, expected_rewrite);
     }
+
+    #[test]
+    fn resolve_enum_and_match() {
+        let src = r#"
+            enum Option<T> {
+                None,
+                Some(T),
+            }
+
+            enum Shape {
+                Point,
+                Circle(Field),
+                Rectangle(Field, Field),
+            }
+
+            fn area(shape: Shape) -> Field {
+                match shape {
+                    Shape::Point => 0,
+                    Shape::Circle(radius) => 3 * radius * radius,
+                    Shape::Rectangle(width, height) => width * height,
+                }
+            }
+
+            fn main(x: Field) {
+                let shape = Shape::Rectangle(x, 2);
+                let maybe = Option::Some(area(shape));
+                let none: Option<Field> = Option::None;
+                let result = match (maybe, none) {
+                    (Option::Some(a), Option::Some(b)) => a + b,
+                    (Option::Some(a), _) => a,
+                    (_, _) => 0,
+                };
+                assert(result == x * 2);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn self_referential_enums() {
+        let src = r#"
+            enum Option<T> {
+                None,
+                Some(T),
+            }
+
+            enum List {
+                Nil,
+                Cons(Field, List),
+            }
+
+            enum Tree {
+                Leaf(Field),
+                Node(Branch),
+            }
+
+            struct Branch {
+                left: Tree,
+                right: Tree,
+            }
+
+            enum Chain {
+                End,
+                Link([Option<Chain>; 1]),
+            }
+
+            enum Flat {
+                Empty,
+                Some(Option<Field>, (Field, Field)),
+            }
+
+            fn main() {}
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 3, "Expected 3 errors, got: {:?}", errors);
+
+        let names = vecmap(&errors, |(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::SelfReferentialEnum { name }) => {
+                name.0.contents.clone()
+            }
+            other => panic!("Expected a self-referential enum error, got {other:?}"),
+        });
+        assert_eq!(names, ["List", "Tree", "Chain"]);
+    }
+
+    #[test]
+    fn non_exhaustive_match() {
+        let src = r#"
+            enum Shape {
+                Point,
+                Circle(Field),
+                Rectangle(Field, Field),
+            }
+
+            fn main(x: Field) {
+                let shape = Shape::Circle(x);
+                let _area = match shape {
+                    Shape::Point => 0,
+                    Shape::Rectangle(width, height) => width * height,
+                };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::NonExhaustiveMatch { missing, .. }) => {
+                assert_eq!(missing, "Shape::Circle(_)");
+            }
+            _ => panic!("Expected a non-exhaustive match error, got {:?}", errors[0].0),
+        }
+    }
+
+    #[test]
+    fn unreachable_match_arm() {
+        let src = r#"
+            enum Bit {
+                Zero,
+                One,
+            }
+
+            fn main() {
+                let bit = Bit::One;
+                let _value = match bit {
+                    Bit::Zero => 0,
+                    _ => 1,
+                    Bit::One => 2,
+                };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::TypeError(TypeCheckError::UnreachableMatchArm { .. })
+        ));
+    }
+
+    #[test]
+    fn refutable_pattern_in_let() {
+        let src = r#"
+            enum Option<T> {
+                None,
+                Some(T),
+            }
+
+            enum Wrapper {
+                Wrap(Field),
+            }
+
+            fn main(x: Field) {
+                let Wrapper::Wrap(y) = Wrapper::Wrap(x);
+                let Option::Some(z) = Option::Some(y);
+                assert(z == x);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::ResolverError(ResolverError::RefutablePattern { .. })
+        ));
+    }
+
+    #[test]
+    fn enum_variant_pattern_argument_count() {
+        let src = r#"
+            enum Shape {
+                Point,
+                Circle(Field),
+            }
+
+            fn main() {
+                let _radius = match Shape::Point {
+                    Shape::Circle(radius, _extra) => radius,
+                    Shape::Point => 0,
+                };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::IncorrectVariantArgumentCount {
+                actual,
+                expected,
+                ..
+            }) => {
+                assert_eq!(*actual, 2);
+                assert_eq!(*expected, 1);
+            }
+            _ => panic!("Expected an argument count error, got {:?}", errors[0].0),
+        }
+    }
+
+    #[test]
+    fn resolve_nested_patterns() {
+        let src = r#"
+            struct Point {
+                x: Field,
+                y: Field,
+            }
+
+            fn sum_corners([first, .., last]: [Field; 4]) -> Field {
+                first + last
+            }
+
+            fn main(points: [Point; 3], flag: bool) {
+                let [Point { x, y: _ }, _, Point { x: _, y }] = points;
+                let (_, [a, b]) = (flag, [x, y]);
+                let _sum = sum_corners([a, b, 1, 2]);
+
+                let _value = match (flag, [x, y]) {
+                    (true, [0, _]) => 1,
+                    (false, [_, 0]) => 2,
+                    (_, [first, ..]) => first,
+                };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn refutable_literal_pattern_in_let() {
+        let src = r#"
+            fn main(x: (Field, Field)) {
+                let (0, y) = x;
+                assert(y == 1);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::ResolverError(ResolverError::RefutablePattern { .. })
+        ));
+    }
+
+    #[test]
+    fn non_exhaustive_literal_match() {
+        let src = r#"
+            fn main(x: bool, y: u8) {
+                let _a = match (x, y) {
+                    (true, 0) => 0,
+                    (true, _) => 1,
+                };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::NonExhaustiveMatch { missing, .. }) => {
+                assert_eq!(missing, "(false, _)");
+            }
+            _ => panic!("Expected a non-exhaustive match error, got {:?}", errors[0].0),
+        }
+    }
+
+    #[test]
+    fn array_pattern_length_mismatch() {
+        let src = r#"
+            fn main(x: [Field; 2]) {
+                let [_a, _b, _c] = x;
+                let [_d, .., _e, _f] = x;
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 2, "Expected 2 errors, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::ExplainedTypeMismatch {
+                err,
+                diff,
+                ..
+            }) => {
+                assert!(matches!(**err, TypeCheckError::TypeMismatchWithSource { .. }));
+                assert_eq!(diff, &Some(("[_; 2]".to_string(), "[_; 3]".to_string())));
+            }
+            error => panic!("Expected an explained type mismatch, got: {error:?}"),
+        }
+        assert!(matches!(
+            errors[1].0,
+            CompilationError::TypeError(TypeCheckError::ArrayPatternTooLong {
+                patterns: 3,
+                length: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn resolve_while_loop_in_unconstrained_fn() {
+        let src = r#"
+            unconstrained fn count(limit: u32) -> u32 {
+                let mut i = 0;
+                while i < limit {
+                    i += 1;
+                    if i == 3 {
+                        continue;
+                    }
+                    if i == 5 {
+                        break;
+                    }
+                }
+                for j in 0..limit {
+                    if j == i {
+                        break;
+                    }
+                }
+                i
+            }
+
+            fn main(x: u32) {
+                assert(count(x) != 0);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn while_loop_in_constrained_fn() {
+        let src = r#"
+            fn main(x: u32) {
+                let mut i = 0;
+                while i < x {
+                    i += 1;
+                }
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::ResolverError(ResolverError::LoopInConstrainedFn { .. })
+        ));
+    }
+
+    #[test]
+    fn inline_never_on_constrained_fn() {
+        let src = r#"
+            #[inline(never)]
+            fn double(x: Field) -> Field {
+                x * 2
+            }
+
+            #[inline(never)]
+            unconstrained fn triple(x: Field) -> Field {
+                x * 3
+            }
+
+            fn main(x: Field) -> pub Field {
+                double(x) + triple(x)
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::InlineNeverInConstrainedFn {
+                ident,
+            }) => {
+                assert_eq!(ident.0.contents, "double");
+            }
+            error => panic!("Expected an inline(never) warning, got: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn break_in_constrained_fn() {
+        let src = r#"
+            fn main(x: u32) {
+                for i in 0..10 {
+                    if i == x {
+                        break;
+                    }
+                }
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::ResolverError(ResolverError::JumpInConstrainedFn {
+                is_break: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn continue_outside_loop() {
+        let src = r#"
+            unconstrained fn main() {
+                continue;
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::ResolverError(ResolverError::JumpOutsideLoop { is_break: false, .. })
+        ));
+    }
+
+    #[test]
+    fn loops_in_lambdas() {
+        let src = r#"
+            unconstrained fn main(x: u32) -> pub u32 {
+                let count_up = |limit: u32| {
+                    let mut i = 0;
+                    while true {
+                        if i == limit {
+                            break;
+                        }
+                        i += 1;
+                    }
+                    i
+                };
+
+                for _ in 0..3 {
+                    let skip = || {
+                        continue;
+                    };
+                    skip();
+                }
+                count_up(x)
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::ResolverError(ResolverError::JumpOutsideLoop { is_break: false, .. })
+        ));
+    }
+
+    /// Returns the expression each `let` statement in `main` is initialized with.
+    fn main_let_expressions(context: &Context) -> Vec<HirExpression> {
+        let interner = &context.def_interner;
+        let main = interner.find_function("main").unwrap();
+        let statements = interner.function(&main).block(interner).0;
+        statements
+            .iter()
+            .filter_map(|statement| match interner.statement(statement) {
+                HirStatement::Let(let_statement) => {
+                    Some(interner.expression(&let_statement.expression))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn evaluate_comptime_block() {
+        let src = r#"
+            fn main() -> pub u64 {
+                let sum = comptime {
+                    let mut sum = 0;
+                    for i in 0..5 {
+                        sum += i;
+                    }
+                    sum
+                };
+                sum
+            }
+        "#;
+        let (_program, context, errors) = get_program(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+
+        match &main_let_expressions(&context)[0] {
+            HirExpression::Literal(HirLiteral::Integer(value, false)) => {
+                assert_eq!(*value, 10_u128.into());
+            }
+            other => panic!("Expected the comptime block to be replaced by 10, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn evaluate_generic_comptime_function() {
+        let src = r#"
+            comptime fn squares<N>() -> [u32; N] {
+                let mut table = [0; N];
+                for i in 0..N {
+                    table[i] = (i * i) as u32;
+                }
+                table
+            }
+
+            fn main(x: u32) {
+                let table: [u32; 4] = squares();
+                assert(table[3] == x);
+            }
+        "#;
+        let (_program, context, errors) = get_program(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+
+        match &main_let_expressions(&context)[0] {
+            HirExpression::Literal(HirLiteral::Array(HirArrayLiteral::Standard(elements))) => {
+                assert_eq!(elements.len(), 4);
+            }
+            other => panic!("Expected the call to be replaced by an array, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn comptime_block_using_runtime_variable() {
+        let src = r#"
+            fn main(x: Field) {
+                let _y = comptime { x + 1 };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::InterpreterError(InterpreterError::NonComptimeVariable {
+                name,
+                ..
+            }) => assert_eq!(name, "x"),
+            other => panic!("Expected a non-comptime variable error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn comptime_global_as_array_length() {
+        let src = r#"
+            comptime fn five() -> u32 {
+                5
+            }
+
+            global N = comptime { 2 + 3 };
+            global M = five();
+
+            fn main() {
+                let a: [Field; comptime { let mut n = 0; for i in 0..3 { n += i; } n }] = [0; 3];
+                let b: [Field; comptime { five() - 2 }] = [1, 2, 3];
+                let c: [Field; N] = [0; 5];
+                let d: [Field; M] = [0; 5];
+                assert(a.len() + b.len() + c.len() + d.len() == 16);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn comptime_array_length_mismatch() {
+        let src = r#"
+            fn main() {
+                let _a: [Field; comptime { 2 + 1 }] = [1, 2];
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(
+            matches!(errors[0].0, CompilationError::TypeError(TypeCheckError::TypeMismatch { .. })),
+            "Expected a type mismatch, got {:?}",
+            errors[0].0
+        );
+    }
+
+    #[test]
+    fn comptime_array_length_calls_non_comptime_function() {
+        let src = r#"
+            fn three() -> u32 {
+                3
+            }
+
+            fn main() {
+                let _a: [Field; comptime { three() }] = [1, 2, 3];
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::InterpreterError(InterpreterError::UncheckedFunction {
+                name,
+                ..
+            }) => assert_eq!(name, "three"),
+            other => panic!("Expected an unchecked function error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn comptime_assertion_failure() {
+        let src = r#"
+            comptime fn non_zero(x: Field) -> Field {
+                assert(x != 0, "value is zero");
+                x
+            }
+
+            fn main() {
+                let _y = non_zero(0);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::InterpreterError(InterpreterError::FailedAssertion {
+                message,
+                ..
+            }) => assert_eq!(message.as_deref(), Some("value is zero")),
+            other => panic!("Expected a failed assertion, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn derive_unsupported_trait() {
+        let src = r#"
+            #[derive(Hash)]
+            struct Foo {
+                x: Field,
+            }
+
+            fn main() {}
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::DefinitionError(DefCollectorErrorKind::UnsupportedDerive {
+                trait_name,
+                ..
+            }) => assert_eq!(trait_name, "Hash"),
+            other => panic!("Expected an unsupported derive error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn derive_on_enum() {
+        let src = r#"
+            #[derive(Eq)]
+            enum Foo {
+                A,
+                B(Field),
+            }
+
+            fn main() {}
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::DefinitionError(DefCollectorErrorKind::DeriveOnNonStruct { .. })
+        ));
+    }
+
+    #[test]
+    fn associated_types_and_constants() {
+        let src = r#"
+            trait Hasher {
+                type Output;
+                let N: u64;
+
+                fn hash(self, input: [u8; Self::N]) -> Self::Output;
+            }
+
+            struct Sum {}
+
+            impl Hasher for Sum {
+                type Output = Field;
+                let N: u64 = 2;
+
+                fn hash(self, input: [u8; 2]) -> Field {
+                    input[0] as Field + input[1] as Field
+                }
+            }
+
+            fn hash_twice<H>(hasher: H, input: [u8; H::N]) -> [H::Output; 2] where H: Hasher {
+                [hasher.hash(input), hasher.hash(input)]
+            }
+
+            fn main() {
+                let hashes = hash_twice(Sum {}, [1, 2]);
+                let total: Field = hashes[0] + hashes[1];
+                assert(total == 6);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn associated_type_mismatch() {
+        let src = r#"
+            trait Hasher {
+                type Output;
+
+                fn hash(self) -> Self::Output;
+            }
+
+            struct Sum {}
+
+            impl Hasher for Sum {
+                type Output = Field;
+
+                fn hash(self) -> Field {
+                    0
+                }
+            }
+
+            fn main() {
+                let hash: bool = Sum {}.hash();
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::TypeError(TypeCheckError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn trait_impl_missing_associated_item() {
+        let src = r#"
+            trait Hasher {
+                type Output;
+                let N: u64;
+            }
+
+            struct Sum {}
+
+            impl Hasher for Sum {
+                type Output = Field;
+            }
+
+            fn main() {}
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::DefinitionError(
+                DefCollectorErrorKind::TraitMissingAssociatedItem { trait_name, item_name, .. },
+            ) => {
+                assert_eq!(trait_name.0.contents, "Hasher");
+                assert_eq!(item_name.0.contents, "N");
+            }
+            other => panic!("Expected a missing associated item error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn trait_impl_associated_item_not_in_trait() {
+        let src = r#"
+            trait Hasher {
+                type Output;
+            }
+
+            struct Sum {}
+
+            impl Hasher for Sum {
+                type Output = Field;
+                type Input = u8;
+            }
+
+            fn main() {}
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::DefinitionError(
+                DefCollectorErrorKind::AssociatedItemNotInTrait { item_name, .. },
+            ) => assert_eq!(item_name.0.contents, "Input"),
+            other => panic!("Expected an associated item not in trait error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn blanket_impl_with_where_clause() {
+        let src = r#"
+            trait Double {
+                fn double(self) -> Self;
+            }
+
+            impl Double for Field {
+                fn double(self) -> Field {
+                    self * 2
+                }
+            }
+
+            trait Quadruple {
+                fn quadruple(self) -> Self;
+            }
+
+            impl<T> Quadruple for T where T: Double {
+                fn quadruple(self) -> T {
+                    self.double().double()
+                }
+            }
+
+            fn main() {
+                let x: Field = 3;
+                assert(x.quadruple() == 12);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn blanket_impl_overlaps_regardless_of_where_clause() {
+        // Foo does not implement Double, but it could later on, so both orders are rejected
+        let blanket_impl = "
+            impl<T> Quadruple for T where T: Double {
+                fn quadruple(self) -> T {
+                    self.double().double()
+                }
+            }
+        ";
+        let foo_impl = "
+            impl Quadruple for Foo {
+                fn quadruple(self) -> Foo {
+                    self
+                }
+            }
+        ";
+
+        for (first_impl, second_impl) in [(blanket_impl, foo_impl), (foo_impl, blanket_impl)] {
+            let src = format!(
+                "
+                trait Double {{
+                    fn double(self) -> Self;
+                }}
+
+                trait Quadruple {{
+                    fn quadruple(self) -> Self;
+                }}
+
+                struct Foo {{}}
+
+                {first_impl}
+                {second_impl}
+
+                fn main() {{}}
+                "
+            );
+            let errors = get_program_errors(&src);
+            assert!(errors.len() == 2, "Expected 2 errors, got: {:?}", errors);
+            assert!(matches!(
+                errors[0].0,
+                CompilationError::DefinitionError(DefCollectorErrorKind::OverlappingImpl { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn blanket_impl_where_clause_not_satisfied() {
+        let src = r#"
+            trait Double {
+                fn double(self) -> Self;
+            }
+
+            trait Quadruple {
+                fn quadruple(self) -> Self;
+            }
+
+            impl<T> Quadruple for T where T: Double {
+                fn quadruple(self) -> T {
+                    self.double().double()
+                }
+            }
+
+            fn main() {
+                let x: bool = true;
+                let _ = x.quadruple();
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::TypeError(TypeCheckError::NoMatchingImplFound { .. })
+        ));
+    }
+
+    #[test]
+    fn default_method_uses_impl_where_clause() {
+        let src = r#"
+            trait MyEq {
+                fn my_eq(self, other: Self) -> bool;
+
+                fn my_ne(self, other: Self) -> bool {
+                    !self.my_eq(other)
+                }
+            }
+
+            impl MyEq for Field {
+                fn my_eq(self, other: Field) -> bool {
+                    self == other
+                }
+            }
+
+            struct Wrapper<T> {
+                inner: T,
+            }
+
+            impl<T> MyEq for Wrapper<T> where T: MyEq {
+                fn my_eq(self, other: Self) -> bool {
+                    self.inner.my_eq(other.inner)
+                }
+            }
+
+            fn main() {
+                let a = Wrapper { inner: 1 };
+                assert(a.my_ne(Wrapper { inner: 2 }));
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn private_items_are_not_visible_outside_their_module() {
+        let src = r#"
+            mod foo {
+                struct Private {}
+                pub struct Public {}
+                global SECRET: Field = 1;
+
+                fn private_fn() {}
+            }
+
+            use foo::Private;
+
+            fn main() {
+                let _ = foo::Public {};
+                let _ = foo::SECRET;
+                foo::private_fn();
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 3, "Expected 3 errors, got: {:?}", errors);
+
+        let mut private_names = vecmap(errors, |(error, _)| match error {
+            CompilationError::DefinitionError(DefCollectorErrorKind::PathResolutionError(
+                PathResolutionError::Private(ident),
+            ))
+            | CompilationError::ResolverError(ResolverError::PathResolutionError(
+                PathResolutionError::Private(ident),
+            )) => ident.0.contents,
+            other => panic!("Expected a private item error, found {other:?}"),
+        });
+        private_names.sort();
+        assert_eq!(private_names, vec!["Private", "SECRET", "private_fn"]);
+    }
+
+    #[test]
+    fn private_items_are_visible_from_child_modules() {
+        let src = r#"
+            struct Foo {}
+
+            impl Foo {
+                fn new() -> Foo {
+                    Foo {}
+                }
+            }
+
+            global N: Field = 1;
+
+            mod child {
+                pub(crate) fn make() -> crate::Foo {
+                    assert(crate::N == 1);
+                    crate::Foo::new()
+                }
+            }
+
+            fn main() {
+                let _ = child::make();
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn private_methods_are_not_visible_outside_the_types_module() {
+        let src = r#"
+            mod foo {
+                pub struct Foo {}
+
+                impl Foo {
+                    pub fn new() -> Foo {
+                        Foo {}
+                    }
+
+                    fn secret() -> Field {
+                        1
+                    }
+                }
+            }
+
+            fn main() {
+                let _ = foo::Foo::new();
+                let _ = foo::Foo::secret();
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            &errors[0].0,
+            CompilationError::ResolverError(ResolverError::PathResolutionError(
+                PathResolutionError::Private(ident),
+            )) if ident.0.contents == "secret"
+        ));
+    }
+
+    #[test]
+    fn imports_are_private_by_default() {
+        let src = r#"
+            fn secret() -> Field {
+                1
+            }
+
+            mod a {
+                use crate::secret;
+
+                pub fn reveal() -> Field {
+                    secret()
+                }
+            }
+
+            fn main() {
+                let _ = a::reveal();
+                let _ = a::secret();
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            &errors[0].0,
+            CompilationError::ResolverError(ResolverError::PathResolutionError(
+                PathResolutionError::Private(ident),
+            )) if ident.0.contents == "secret"
+        ));
+    }
+
+    #[test]
+    fn pub_use_reexports_items() {
+        let src = r#"
+            mod internal {
+                pub fn helper() -> Field {
+                    1
+                }
+            }
+
+            mod api {
+                pub use crate::internal::helper;
+                pub(crate) use crate::internal::helper as crate_helper;
+            }
+
+            fn main() {
+                let _ = api::helper() + api::crate_helper();
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+
+        // Re-exports are not reported as unused imports even if the crate never uses them
+        let src = r#"
+            mod internal {
+                pub fn helper() {}
+            }
+
+            mod api {
+                pub use crate::internal::helper;
+            }
+
+            fn main() {}
+        "#;
+        assert!(get_unused_item_warnings(src).is_empty());
+    }
+    fn get_unused_item_warnings(src: &str) -> Vec<String> {
+        let errors = get_program_with_unused_item_warnings(src).2;
+        vecmap(errors, |(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::UnusedImport { ident }) => {
+                format!("import {ident}")
+            }
+            CompilationError::ResolverError(ResolverError::UnusedItem { ident, item_type }) => {
+                format!("{item_type} {ident}")
+            }
+            CompilationError::ResolverError(ResolverError::UnreadField { field, struct_name }) => {
+                format!("field {struct_name}.{field}")
+            }
+            other => panic!("Expected only unused item warnings, found {other:?}"),
+        })
+    }
+
+    #[test]
+    fn warns_about_unused_imports_functions_and_globals() {
+        let src = r#"
+            mod foo {
+                pub fn used() -> Field {
+                    1
+                }
+
+                pub fn not_imported() -> Field {
+                    2
+                }
+            }
+
+            use foo::used;
+            use foo::not_imported;
+
+            global USED: Field = 1;
+            global UNUSED: Field = 2;
+
+            fn helper() -> Field {
+                USED
+            }
+
+            fn unused_helper() -> Field {
+                3
+            }
+
+            fn main() {
+                assert(used() + helper() == 2);
+            }
+        "#;
+        let warnings = get_unused_item_warnings(src);
+        assert_eq!(
+            warnings,
+            vec!["import not_imported", "global UNUSED", "function unused_helper"]
+        );
+    }
+
+    #[test]
+    fn warns_about_struct_fields_which_are_never_read() {
+        let src = r#"
+            struct Foo {
+                x: Field,
+                y: Field,
+                z: Field,
+            }
+
+            pub struct Bar {
+                w: Field,
+            }
+
+            fn main(a: Field) {
+                let foo = Foo { x: a, y: a, z: a };
+                assert(foo.x == a);
+                let _ = Bar { w: a };
+            }
+        "#;
+        let warnings = get_unused_item_warnings(src);
+        assert_eq!(warnings, vec!["field Foo.y", "field Foo.z"]);
+    }
+
+    #[test]
+    fn method_calls_and_patterns_count_as_uses() {
+        let src = r#"
+            struct Foo {
+                x: Field,
+                y: Field,
+            }
+
+            impl Foo {
+                fn sum(self) -> Field {
+                    let Foo { x: a, y: b } = self;
+                    a + b
+                }
+
+                fn unused(self) -> Field {
+                    self.x
+                }
+            }
+
+            fn main(a: Field) {
+                let foo = Foo { x: a, y: a };
+                assert(foo.sum() == 2 * a);
+            }
+        "#;
+        let warnings = get_unused_item_warnings(src);
+        assert_eq!(warnings, vec!["function unused"]);
+    }
+
+    #[test]
+    fn items_used_through_paths_from_other_modules_are_used() {
+        let src = r#"
+            mod foo {
+                use crate::bar::value;
+
+                pub(crate) fn get() -> Field {
+                    value()
+                }
+            }
+
+            mod bar {
+                pub(crate) fn value() -> Field {
+                    1
+                }
+            }
+
+            fn main() {
+                assert(crate::foo::get() == 1);
+            }
+        "#;
+        let warnings = get_unused_item_warnings(src);
+        assert!(warnings.is_empty(), "Expected no warnings, got: {:?}", warnings);
+    }
+
+    #[test]
+    fn imports_used_only_by_other_imports_are_used() {
+        let src = r#"
+            mod x {
+                pub fn bar() -> Field {
+                    1
+                }
+
+                pub fn baz() -> Field {
+                    2
+                }
+            }
+
+            mod foo {
+                use crate::x::bar;
+                use crate::x::baz;
+                use crate::x;
+
+                mod inner {
+                    use crate::foo::{bar, baz};
+
+                    pub(crate) fn get() -> Field {
+                        bar() + baz()
+                    }
+                }
+
+                pub(crate) fn get() -> Field {
+                    inner::get()
+                }
+            }
+
+            fn main() {
+                assert(foo::get() == 3);
+            }
+        "#;
+        let warnings = get_unused_item_warnings(src);
+        assert_eq!(warnings, vec!["import x"]);
+    }
+
+    /// Applies the machine-applicable suggestions of the errors found in `src`.
+    fn apply_suggestions(src: &str) -> String {
+        let mut suggestions: Vec<_> = get_program_errors(src)
+            .into_iter()
+            .flat_map(|(error, _)| CustomDiagnostic::from(error).suggestions)
+            .filter(|suggestion| suggestion.applicability == Applicability::MachineApplicable)
+            .collect();
+        suggestions.sort_by_key(|suggestion| std::cmp::Reverse(suggestion.span.start()));
+
+        let mut fixed = src.to_string();
+        for suggestion in suggestions {
+            let range = suggestion.span.start() as usize..suggestion.span.end() as usize;
+            fixed.replace_range(range, &suggestion.replacement);
+        }
+        fixed
+    }
+
+    #[test]
+    fn suggestions_fix_unused_variables_and_misplaced_keywords() {
+        let src = r#"
+            struct Foo {
+                x: Field,
+                y: Field,
+            }
+
+            fn helper(a: pub Field, foo: Foo, unused: Field) -> pub Field {
+                let Foo { x, y } = foo;
+                let mut (mut b, c) = (a, y);
+                b = c;
+                b
+            }
+
+            fn main(a: Field) -> Field {
+                helper(a, Foo { x: a, y: a }, a)
+            }
+        "#;
+        let expected = r#"
+            struct Foo {
+                x: Field,
+                y: Field,
+            }
+
+            fn helper(a: Field, foo: Foo, _unused: Field) -> Field {
+                let Foo { x: _x, y } = foo;
+                let mut (b, c) = (a, y);
+                b = c;
+                b
+            }
+
+            fn main(a: Field) -> pub Field {
+                helper(a, Foo { x: a, y: a }, a)
+            }
+        "#;
+        assert_eq!(apply_suggestions(src), expected);
+    }
+
+    #[test]
+    fn suggestions_add_missing_fields_to_patterns() {
+        let src = r#"
+            struct Foo {
+                x: Field,
+                y: Field,
+            }
+
+            fn main(foo: Foo, bar: Foo) {
+                let Foo { x } = foo;
+                let Foo {} = bar;
+                assert(x == 0);
+            }
+        "#;
+        let expected = r#"
+            struct Foo {
+                x: Field,
+                y: Field,
+            }
+
+            fn main(foo: Foo, bar: Foo) {
+                let Foo { x, y: _ } = foo;
+                let Foo { x: _, y: _ } = bar;
+                assert(x == 0);
+            }
+        "#;
+        assert_eq!(apply_suggestions(src), expected);
+    }
+
+    #[test]
+    fn missing_fields_of_constructors_are_suggested_with_placeholders() {
+        let src = r#"
+            struct Foo {
+                x: Field,
+                y: Field,
+            }
+
+            fn main() {
+                let _ = Foo { x: 1 };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+
+        let diagnostic = CustomDiagnostic::from(errors[0].0.clone());
+        assert_eq!(diagnostic.suggestions.len(), 1);
+        let suggestion = &diagnostic.suggestions[0];
+        assert_eq!(suggestion.replacement, ", y: std::default::Default::default()");
+        assert_eq!(suggestion.applicability, Applicability::HasPlaceholders);
+        assert!(src[..suggestion.span.start() as usize].ends_with("Foo { x: 1"));
+    }
+
+    #[test]
+    fn unused_entry_point_parameters_are_not_renamed() {
+        let src = r#"
+            fn helper(unused: Field) {}
+
+            fn main(x: Field, y: pub Field) {
+                helper(x);
+            }
+        "#;
+        let expected = r#"
+            fn helper(_unused: Field) {}
+
+            fn main(x: Field, y: pub Field) {
+                helper(x);
+            }
+        "#;
+        assert_eq!(apply_suggestions(src), expected);
+
+        let errors = get_program_errors(src);
+        let diagnostic = errors
+            .into_iter()
+            .map(|(error, _)| CustomDiagnostic::from(error))
+            .find(|diagnostic| diagnostic.message == "unused variable y")
+            .expect("Expected an unused variable warning for y");
+        assert!(diagnostic.suggestions.is_empty());
+    }
+
+    #[test]
+    fn allow_attribute_on_function_suppresses_warnings() {
+        let src = r#"
+            #[allow(unused_variables)]
+            fn main(x: Field) {
+                let y = x;
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn allow_attribute_on_statement_suppresses_warnings() {
+        let src = r#"
+            fn main(x: Field) {
+                #[allow(unused_variables)]
+                let y = x;
+                let z = x;
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::UnusedVariable { ident, .. }) => {
+                assert_eq!(ident.0.contents, "z");
+            }
+            error => panic!("Expected an unused variable warning, got: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn allow_attribute_on_module_suppresses_warnings() {
+        let src = r#"
+            #[allow(dead_code)]
+            mod foo {
+                fn unused() {}
+
+                mod bar {
+                    fn unused() {}
+                }
+            }
+
+            fn unused() {}
+
+            fn main() {}
+        "#;
+        let (_, _, errors) = get_program_with_unused_item_warnings(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            &errors[0].0,
+            CompilationError::ResolverError(ResolverError::UnusedItem { .. })
+        ));
+    }
+
+    #[test]
+    fn deny_attribute_reports_warnings_as_errors() {
+        let src = r#"
+            #[deny(unused_variables)]
+            fn main(x: Field) {
+                let y = x;
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::DeniedLint(error, Lint::UnusedVariables) => {
+                assert!(matches!(
+                    **error,
+                    CompilationError::ResolverError(ResolverError::UnusedVariable { .. })
+                ));
+            }
+            error => panic!("Expected a denied lint, got: {error:?}"),
+        }
+        assert!(CustomDiagnostic::from(errors[0].0.clone()).is_error());
+    }
+
+    #[test]
+    fn innermost_lint_attribute_takes_precedence() {
+        let src = r#"
+            #[allow(warnings)]
+            mod foo {
+                #[warn(unused_variables)]
+                fn bar(x: Field) {
+                    let y = x;
+                    let _ = baz(x);
+                }
+
+                fn baz(x: Field) -> Field {
+                    let z = x;
+                    x
+                }
+            }
+
+            fn main() {}
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::UnusedVariable { ident, .. }) => {
+                assert_eq!(ident.0.contents, "y");
+            }
+            error => panic!("Expected an unused variable warning, got: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn unknown_lints_are_reported() {
+        let src = r#"
+            #[allow(unused_varaibles)]
+            fn main() {}
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::UnknownLint { name, .. }) => {
+                assert_eq!(name, "unused_varaibles");
+            }
+            error => panic!("Expected an unknown lint warning, got: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn type_mismatch_shows_differing_parts_of_nested_types() {
+        let src = r#"
+            struct Foo<T> {
+                x: T,
+            }
+
+            fn main() {
+                let foo: Foo<[u8; 2]> = Foo { x: [1, 2] };
+                let _bar: Foo<[Field; 2]> = foo;
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::ExplainedTypeMismatch {
+                err,
+                diff,
+                origin,
+            }) => {
+                assert!(matches!(**err, TypeCheckError::TypeMismatch { .. }));
+                let diff = diff.as_ref().expect("Expected a diff of the types");
+                assert_eq!(diff.0, "Foo<[Field; _]>");
+                assert_eq!(diff.1, "Foo<[u8; _]>");
+                assert!(origin.is_none());
+            }
+            error => panic!("Expected an explained type mismatch, got: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn type_mismatch_points_at_where_type_was_inferred() {
+        let src = r#"
+            fn main() {
+                let mut array = [0, 0];
+                array[0] = 1 as u8;
+                let _x: [Field; 2] = array;
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::ExplainedTypeMismatch {
+                diff,
+                origin,
+                ..
+            }) => {
+                assert_eq!(diff, &Some(("[Field; _]".to_string(), "[u8; _]".to_string())));
+                let (typ, span) = origin.as_ref().expect("Expected the origin of the type");
+                assert_eq!(typ.to_string(), "u8");
+                assert_eq!(&src[span.start() as usize..span.end() as usize], "1 as u8");
+            }
+            error => panic!("Expected an explained type mismatch, got: {error:?}"),
+        }
+    }
 }
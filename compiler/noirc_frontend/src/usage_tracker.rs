@@ -0,0 +1,110 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{
+    graph::CrateId,
+    hir::def_map::{CrateDefMap, ModuleDefId, ModuleId},
+    node_interner::{DefinitionId, StructId},
+    Ident, Path, PathKind,
+};
+
+/// Records which items of the program are referenced while it is resolved and type checked,
+/// so that items which are never used can be reported once a crate has been fully checked.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    /// Imports which have not been referred to yet, by the module they were imported into.
+    unused_imports: HashMap<ModuleId, HashMap<String, Ident>>,
+
+    /// Functions and globals which have been referred to at least once.
+    used_definitions: HashSet<DefinitionId>,
+
+    /// Struct fields which are read at least once, either by a member access or a pattern.
+    read_fields: HashSet<(StructId, String)>,
+}
+
+impl UsageTracker {
+    pub(crate) fn add_unused_import(&mut self, module_id: ModuleId, name: Ident) {
+        self.unused_imports.entry(module_id).or_default().insert(name.0.contents.clone(), name);
+    }
+
+    /// Marks each name along `path`, as written in `module_id`, as used. An import only needs
+    /// to be referred to by the first segment of a path for it to be used, but the remaining
+    /// segments may refer to imports of other modules, such as `crate::foo::imported_item`.
+    pub(crate) fn mark_path_as_used(
+        &mut self,
+        def_maps: &BTreeMap<CrateId, CrateDefMap>,
+        module_id: ModuleId,
+        path: &Path,
+    ) {
+        let mut current_module = match path.kind {
+            PathKind::Plain => module_id,
+            PathKind::Crate => {
+                ModuleId { krate: module_id.krate, local_id: def_maps[&module_id.krate].root }
+            }
+            PathKind::Dep => {
+                let extern_prelude = &def_maps[&module_id.krate].extern_prelude;
+                match path.segments.first().and_then(|dep| extern_prelude.get(&dep.0.contents)) {
+                    Some(dependency_root) => *dependency_root,
+                    None => return,
+                }
+            }
+        };
+
+        // The first segment of a `dep::` path names the dependency rather than an item.
+        let segments =
+            if path.kind == PathKind::Dep { &path.segments[1..] } else { &path.segments[..] };
+        for segment in segments {
+            if let Some(imports) = self.unused_imports.get_mut(&current_module) {
+                imports.remove(&segment.0.contents);
+            }
+
+            current_module = match current_module.module(def_maps).find_name(segment).types {
+                Some((ModuleDefId::ModuleId(id), _, _)) => id,
+                Some((ModuleDefId::TypeId(id), _, _)) => id.module_id(),
+                Some((ModuleDefId::TraitId(id), _, _)) => id.0,
+                _ => return,
+            };
+        }
+    }
+
+    /// Marks the imports along the path of the import `name` in `module_id` as used. The import
+    /// does not use itself, even when its path starts with its own name.
+    pub(crate) fn mark_import_path_as_used(
+        &mut self,
+        def_maps: &BTreeMap<CrateId, CrateDefMap>,
+        module_id: ModuleId,
+        path: &Path,
+        name: &Ident,
+    ) {
+        let own_import = self
+            .unused_imports
+            .get_mut(&module_id)
+            .and_then(|imports| imports.remove(&name.0.contents));
+
+        self.mark_path_as_used(def_maps, module_id, path);
+
+        if let Some(own_import) = own_import {
+            self.add_unused_import(module_id, own_import);
+        }
+    }
+
+    /// Returns the imports of `module_id` which were never referred to.
+    pub(crate) fn unused_imports(&self, module_id: &ModuleId) -> impl Iterator<Item = &Ident> {
+        self.unused_imports.get(module_id).into_iter().flat_map(|imports| imports.values())
+    }
+
+    pub(crate) fn mark_definition_as_used(&mut self, id: DefinitionId) {
+        self.used_definitions.insert(id);
+    }
+
+    pub(crate) fn is_definition_used(&self, id: &DefinitionId) -> bool {
+        self.used_definitions.contains(id)
+    }
+
+    pub(crate) fn mark_field_as_read(&mut self, struct_id: StructId, field_name: &str) {
+        self.read_fields.insert((struct_id, field_name.to_string()));
+    }
+
+    pub(crate) fn is_field_read(&self, struct_id: StructId, field_name: &str) -> bool {
+        self.read_fields.contains(&(struct_id, field_name.to_string()))
+    }
+}
@@ -29,6 +29,10 @@ pub enum PrintableType {
     String {
         length: u64,
     },
+    Enum {
+        name: String,
+        variants: Vec<(String, Vec<PrintableType>)>,
+    },
 }
 
 impl PrintableType {
@@ -44,6 +48,9 @@ impl PrintableType {
                 fields.iter().fold(0, |acc, (_, field_type)| acc + field_type.field_count())
             }
             Self::String { length } => *length as u32,
+            Self::Enum { variants, .. } => variants.iter().fold(1, |acc, (_, params)| {
+                acc + params.iter().map(PrintableType::field_count).sum::<u32>()
+            }),
         }
     }
 }
@@ -57,6 +64,8 @@ pub enum PrintableValue {
     String(String),
     Vec(Vec<PrintableValue>),
     Struct(BTreeMap<String, PrintableValue>),
+    /// The index of the active variant along with the values it holds
+    Variant(usize, Vec<PrintableValue>),
 }
 
 /// In order to display a `PrintableValue` we need a `PrintableType` to accurately
@@ -233,6 +242,18 @@ fn to_string(value: &PrintableValue, typ: &PrintableType) -> Option<String> {
             output.push_str(" }");
         }
 
+        (PrintableValue::Variant(index, values), PrintableType::Enum { name, variants }) => {
+            let (variant_name, param_types) = &variants[*index];
+            output.push_str(&format!("{name}::{variant_name}"));
+
+            if !values.is_empty() {
+                let values = values.iter().zip(param_types).map(|(value, typ)| {
+                    PrintableValueDisplay::Plain(value.clone(), typ.clone()).to_string()
+                });
+                output.push_str(&format!("({})", values.collect::<Vec<_>>().join(", ")));
+            }
+        }
+
         _ => return None,
     };
 
@@ -333,6 +354,20 @@ fn decode_value(
 
             PrintableValue::Struct(struct_map)
         }
+        PrintableType::Enum { variants, .. } => {
+            let tag = field_iterator.next().unwrap().to_u128() as usize;
+
+            // Every variant's values are present in the encoding, only the active one is kept
+            let mut active_values = Vec::new();
+            for (index, (_, param_types)) in variants.iter().enumerate() {
+                let values = vecmap(param_types, |typ| decode_value(field_iterator, typ));
+                if index == tag {
+                    active_values = values;
+                }
+            }
+
+            PrintableValue::Variant(tag, active_values)
+        }
     }
 }
 
@@ -34,8 +34,8 @@ use notifications::{
     on_did_open_text_document, on_did_save_text_document, on_exit, on_initialized,
 };
 use requests::{
-    on_code_lens_request, on_formatting, on_goto_definition_request, on_initialize,
-    on_profile_run_request, on_shutdown, on_test_run_request, on_tests_request,
+    on_code_action_request, on_code_lens_request, on_formatting, on_goto_definition_request,
+    on_initialize, on_profile_run_request, on_shutdown, on_test_run_request, on_tests_request,
 };
 use serde_json::Value as JsonValue;
 use thiserror::Error;
@@ -90,6 +90,7 @@ impl NargoLspService {
             .request::<request::Formatting, _>(on_formatting)
             .request::<request::Shutdown, _>(on_shutdown)
             .request::<request::CodeLens, _>(on_code_lens_request)
+            .request::<request::CodeAction, _>(on_code_action_request)
             .request::<request::NargoTests, _>(on_tests_request)
             .request::<request::NargoTestRun, _>(on_test_run_request)
             .request::<request::NargoProfileRun, _>(on_profile_run_request)
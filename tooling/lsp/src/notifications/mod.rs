@@ -3,13 +3,14 @@ use std::ops::ControlFlow;
 use async_lsp::{ErrorCode, LanguageClient, ResponseError};
 use nargo::{insert_all_files_for_workspace_into_file_manager, prepare_package};
 use noirc_driver::{check_crate, file_manager_with_stdlib};
-use noirc_errors::{DiagnosticKind, FileDiagnostic};
+use noirc_errors::{Applicability, DiagnosticKind, FileDiagnostic};
 
 use crate::requests::collect_lenses_for_package;
 use crate::types::{
-    notification, Diagnostic, DiagnosticSeverity, DidChangeConfigurationParams,
+    notification, Diagnostic, DiagnosticFix, DiagnosticSeverity, DidChangeConfigurationParams,
     DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-    DidSaveTextDocumentParams, InitializedParams, NargoPackageTests, PublishDiagnosticsParams,
+    DidSaveTextDocumentParams, InitializedParams, NargoPackageTests, NumberOrString,
+    PublishDiagnosticsParams,
 };
 
 use crate::{
@@ -168,10 +169,32 @@ pub(super) fn on_did_save_text_document(
                         DiagnosticKind::Error => DiagnosticSeverity::ERROR,
                         DiagnosticKind::Warning => DiagnosticSeverity::WARNING,
                     };
+
+                    // Suggested fixes are sent along with the diagnostic so that they can be
+                    // offered as quick fixes when the client requests code actions for it.
+                    let fixes: Vec<_> = diagnostic
+                        .suggestions
+                        .into_iter()
+                        .filter_map(|suggestion| {
+                            let range = byte_span_to_range(files, file_id, suggestion.span.into())?;
+                            Some(DiagnosticFix {
+                                title: suggestion.message,
+                                range,
+                                new_text: suggestion.replacement,
+                                is_preferred: suggestion.applicability
+                                    == Applicability::MachineApplicable,
+                            })
+                        })
+                        .collect();
+                    let data =
+                        if fixes.is_empty() { None } else { serde_json::to_value(fixes).ok() };
+
                     Some(Diagnostic {
                         range,
                         severity: Some(severity),
+                        code: diagnostic.code.map(|code| NumberOrString::String(code.to_owned())),
                         message: diagnostic.message,
+                        data,
                         ..Default::default()
                     })
                 })
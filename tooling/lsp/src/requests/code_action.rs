@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::future::{self, Future};
+
+use async_lsp::ResponseError;
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, TextEdit, WorkspaceEdit,
+};
+
+use crate::{
+    types::{CodeActionResult, DiagnosticFix},
+    LspState,
+};
+
+pub(crate) fn on_code_action_request(
+    _state: &mut LspState,
+    params: CodeActionParams,
+) -> impl Future<Output = Result<CodeActionResult, ResponseError>> {
+    future::ready(Ok(on_code_action_inner(params)))
+}
+
+/// Offers the fixes attached to the published diagnostics the client sends back as quick fixes.
+fn on_code_action_inner(params: CodeActionParams) -> CodeActionResult {
+    let uri = params.text_document.uri;
+
+    let mut actions = Vec::new();
+    for diagnostic in params.context.diagnostics {
+        let fixes: Vec<DiagnosticFix> = match &diagnostic.data {
+            Some(data) => serde_json::from_value(data.clone()).unwrap_or_default(),
+            None => continue,
+        };
+
+        for fix in fixes {
+            let edit = TextEdit { range: fix.range, new_text: fix.new_text };
+            let changes = HashMap::from([(uri.clone(), vec![edit])]);
+
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(WorkspaceEdit { changes: Some(changes), ..Default::default() }),
+                is_preferred: Some(fix.is_preferred),
+                ..Default::default()
+            }));
+        }
+    }
+
+    if actions.is_empty() {
+        None
+    } else {
+        Some(actions)
+    }
+}
+
+#[cfg(test)]
+mod code_action_tests {
+    use lsp_types::{CodeActionContext, Diagnostic, Position, Range, TextDocumentIdentifier, Url};
+
+    use super::*;
+
+    #[test]
+    fn offers_fixes_attached_to_diagnostics() {
+        let range = Range::new(Position::new(1, 8), Position::new(1, 9));
+        let fix = DiagnosticFix {
+            title: "if this is intentional, prefix it with an underscore".to_string(),
+            range,
+            new_text: "_x".to_string(),
+            is_preferred: true,
+        };
+        let diagnostic = Diagnostic {
+            range,
+            message: "unused variable x".to_string(),
+            data: Some(serde_json::to_value(vec![fix]).unwrap()),
+            ..Default::default()
+        };
+        let unfixable = Diagnostic { message: "no fix".to_string(), ..Default::default() };
+
+        let uri = Url::parse("file:///project/src/main.nr").unwrap();
+        let params = CodeActionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            range,
+            context: CodeActionContext {
+                diagnostics: vec![diagnostic, unfixable],
+                ..Default::default()
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+
+        let actions = on_code_action_inner(params).expect("Expected a quick fix");
+        assert_eq!(actions.len(), 1);
+
+        let action = match &actions[0] {
+            CodeActionOrCommand::CodeAction(action) => action,
+            command => panic!("Expected a code action, got: {command:?}"),
+        };
+        assert_eq!(action.kind, Some(CodeActionKind::QUICKFIX));
+        assert_eq!(action.is_preferred, Some(true));
+
+        let changes = action.edit.as_ref().and_then(|edit| edit.changes.as_ref()).unwrap();
+        assert_eq!(changes[&uri], vec![TextEdit { range, new_text: "_x".to_string() }]);
+    }
+}
@@ -2,7 +2,9 @@ use std::future::Future;
 
 use crate::types::{CodeLensOptions, InitializeParams};
 use async_lsp::ResponseError;
-use lsp_types::{Position, TextDocumentSyncCapability, TextDocumentSyncKind};
+use lsp_types::{
+    CodeActionProviderCapability, Position, TextDocumentSyncCapability, TextDocumentSyncKind,
+};
 use nargo_fmt::Config;
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +23,7 @@ use crate::{
 // They are not attached to the `NargoLspService` struct so they can be unit tested with only `LspState`
 // and params passed in.
 
+mod code_action;
 mod code_lens_request;
 mod goto_definition;
 mod profile_run;
@@ -28,9 +31,9 @@ mod test_run;
 mod tests;
 
 pub(crate) use {
-    code_lens_request::collect_lenses_for_package, code_lens_request::on_code_lens_request,
-    goto_definition::on_goto_definition_request, profile_run::on_profile_run_request,
-    test_run::on_test_run_request, tests::on_tests_request,
+    code_action::on_code_action_request, code_lens_request::collect_lenses_for_package,
+    code_lens_request::on_code_lens_request, goto_definition::on_goto_definition_request,
+    profile_run::on_profile_run_request, test_run::on_test_run_request, tests::on_tests_request,
 };
 
 /// LSP client will send initialization request after the server has started.
@@ -85,6 +88,7 @@ pub(crate) fn on_initialize(
             capabilities: ServerCapabilities {
                 text_document_sync: Some(text_document_sync),
                 code_lens_provider: code_lens,
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
                 document_formatting_provider: true,
                 nargo: Some(nargo),
                 definition_provider: Some(lsp_types::OneOf::Left(true)),
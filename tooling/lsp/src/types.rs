@@ -1,5 +1,5 @@
 use fm::FileId;
-use lsp_types::{DefinitionOptions, OneOf};
+use lsp_types::{CodeActionProviderCapability, DefinitionOptions, OneOf};
 use noirc_driver::DebugFile;
 use noirc_errors::{debug_info::OpCodesCount, Location};
 use noirc_frontend::graph::CrateName;
@@ -12,7 +12,8 @@ pub(crate) use lsp_types::{
     CodeLens, CodeLensOptions, CodeLensParams, Command, Diagnostic, DiagnosticSeverity,
     DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
     DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializedParams,
-    Position, PublishDiagnosticsParams, Range, ServerInfo, TextDocumentSyncCapability, Url,
+    NumberOrString, Position, PublishDiagnosticsParams, Range, ServerInfo,
+    TextDocumentSyncCapability, Url,
 };
 
 pub(crate) mod request {
@@ -25,7 +26,8 @@ pub(crate) mod request {
 
     // Re-providing lsp_types that we don't need to override
     pub(crate) use lsp_types::request::{
-        CodeLensRequest as CodeLens, Formatting, GotoDefinition, Shutdown,
+        CodeActionRequest as CodeAction, CodeLensRequest as CodeLens, Formatting, GotoDefinition,
+        Shutdown,
     };
 
     #[derive(Debug)]
@@ -118,6 +120,10 @@ pub(crate) struct ServerCapabilities {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) code_lens_provider: Option<CodeLensOptions>,
 
+    /// The server provides quick fixes for diagnostics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) code_action_provider: Option<CodeActionProviderCapability>,
+
     /// The server provides document formatting.
     pub(crate) document_formatting_provider: bool,
 
@@ -220,5 +226,19 @@ pub(crate) struct NargoProfileRunResult {
     pub(crate) opcodes_counts: HashMap<Location, OpCodesCount>,
 }
 
+/// A fix suggested by the compiler for a diagnostic. These are sent to the client in the `data`
+/// of each published diagnostic, and are offered as quick fixes when the client sends the
+/// diagnostic back in a code action request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DiagnosticFix {
+    pub(crate) title: String,
+    pub(crate) range: Range,
+    pub(crate) new_text: String,
+    /// True if the fix can be applied without being reviewed, rather than containing placeholders.
+    pub(crate) is_preferred: bool,
+}
+
+pub(crate) type CodeActionResult = Option<lsp_types::CodeActionResponse>;
 pub(crate) type CodeLensResult = Option<Vec<CodeLens>>;
 pub(crate) type GotoDefinitionResult = Option<lsp_types::GotoDefinitionResponse>;
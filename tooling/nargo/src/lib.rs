@@ -10,6 +10,7 @@
 pub mod artifacts;
 pub mod constants;
 pub mod errors;
+pub mod macros;
 pub mod ops;
 pub mod package;
 pub mod workspace;
@@ -36,6 +37,11 @@ pub fn prepare_dependencies(
             Dependency::Remote { package } | Dependency::Local { package } => {
                 let crate_id = prepare_dependency(context, &package.entry_path);
                 add_dep(context, parent_crate, crate_id, dep_name.clone());
+
+                // Macros declared by a dependency run on the crates which depend on it.
+                for package_macro in &package.macros {
+                    context.add_macro_processor(parent_crate, package_macro.processor);
+                }
                 prepare_dependencies(context, crate_id, &package.dependencies);
             }
         }
@@ -0,0 +1,317 @@
+//! The macros which packages can declare in the `macros` field of their `Nargo.toml`.
+//!
+//! A macro is an implementation of [MacroProcessor] which transforms the AST of a crate.
+//! A library package declares the names of the macros it provides, and nargo runs those
+//! macros on every crate which depends directly on that package.
+//!
+//! ```toml
+//! [package]
+//! name = "serde"
+//! type = "lib"
+//! macros = ["serialize"]
+//! ```
+//!
+//! Each name is looked up in two places:
+//! - The macros registered with [register_macro] by the tool embedding nargo.
+//! - Otherwise, an executable at `macros/<name>` within the package. nargo runs it once for each
+//!   module of a dependent crate, with the source of that module on its standard input, after
+//!   a header naming the module and its file:
+//!
+//!   ```text
+//!   // module: crate::foo
+//!   // file: src/foo.nr
+//!   ```
+//!
+//!   The source of a module excludes the bodies of its inline submodules, which are given to
+//!   the executable separately. Any Noir items it prints to its standard output are added to
+//!   that module, e.g. the serialization functions derived from the structs of the module.
+//!   Errors in the generated code are reported against the file of the module.
+//!
+//! Macros run arbitrary code on the crates they apply to, so a package must list the macros
+//! of its dependencies which it allows in the `allowed_macros` field of its own `Nargo.toml`.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock, RwLock};
+
+use noirc_frontend::macros_api::{
+    CrateId, FileId, HirContext, MacroError, MacroProcessor, SortedModule, Span,
+};
+use noirc_frontend::parse_program;
+
+/// The directory of a package containing the executables of the macros it provides.
+pub const MACROS_DIR: &str = "macros";
+
+/// A macro processor which may be shared across the threads compiling each package.
+pub type RegisteredMacro = &'static (dyn MacroProcessor + Sync);
+
+/// A macro declared in the manifest of a package, along with its processor.
+#[derive(Clone)]
+pub struct PackageMacro {
+    pub name: String,
+    pub processor: RegisteredMacro,
+}
+
+fn registry() -> &'static RwLock<BTreeMap<String, RegisteredMacro>> {
+    static REGISTRY: OnceLock<RwLock<BTreeMap<String, RegisteredMacro>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Registers a macro processor under the given name so that packages may declare it.
+/// Registering a second macro under the same name replaces the first.
+pub fn register_macro(name: impl Into<String>, macro_processor: RegisteredMacro) {
+    registry()
+        .write()
+        .expect("macro registry lock was poisoned")
+        .insert(name.into(), macro_processor);
+}
+
+/// Returns the macro processor registered under the given name, if there is one.
+pub fn find_macro(name: &str) -> Option<RegisteredMacro> {
+    registry().read().expect("macro registry lock was poisoned").get(name).copied()
+}
+
+/// Returns the processor of the macro declared under the given name by the package at
+/// `package_root`, or `None` if it is neither registered nor provided by the package.
+pub fn load_macro(package_root: &Path, name: &str) -> Option<RegisteredMacro> {
+    if let Some(macro_processor) = find_macro(name) {
+        return Some(macro_processor);
+    }
+
+    let command = package_root.join(MACROS_DIR).join(name);
+    command.is_file().then(|| command_macro(name, command))
+}
+
+/// Returns the processor running the given executable, creating it on first use.
+/// Processors live until the end of the program, so there is only one for each executable.
+fn command_macro(name: &str, command: PathBuf) -> RegisteredMacro {
+    static COMMAND_MACROS: OnceLock<Mutex<BTreeMap<PathBuf, RegisteredMacro>>> = OnceLock::new();
+    let mut command_macros = COMMAND_MACROS
+        .get_or_init(Default::default)
+        .lock()
+        .expect("macro registry lock was poisoned");
+
+    *command_macros
+        .entry(command.clone())
+        .or_insert_with(|| Box::leak(Box::new(CommandMacro { name: name.to_string(), command })))
+}
+
+/// A macro implemented by an executable shipped with a package.
+struct CommandMacro {
+    name: String,
+    command: PathBuf,
+}
+
+impl CommandMacro {
+    /// Runs the executable on the given source, returning what it printed.
+    fn run(&self, source: &str) -> Result<String, String> {
+        let mut child = Command::new(&self.command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|error| format!("could not run {}: {error}", self.command.display()))?;
+
+        // The input is written from another thread so that a macro printing its output
+        // before reading all of its input cannot block on a full pipe.
+        let mut stdin = child.stdin.take().expect("stdin should be piped");
+        let source = source.to_string();
+        let writer = std::thread::spawn(move || stdin.write_all(source.as_bytes()));
+
+        let output = child
+            .wait_with_output()
+            .map_err(|error| format!("could not run {}: {error}", self.command.display()))?;
+        // A macro may exit without reading its input, so errors writing to it are ignored.
+        let _ = writer.join();
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!(
+                "{} exited with {}: {}",
+                self.command.display(),
+                output.status,
+                stderr.trim()
+            ));
+        }
+        String::from_utf8(output.stdout)
+            .map_err(|_| format!("{} printed invalid UTF-8", self.command.display()))
+    }
+
+    fn error(&self, primary_message: &str, secondary_message: String) -> MacroError {
+        MacroError {
+            primary_message: format!("macro `{}` {primary_message}", self.name),
+            secondary_message: Some(secondary_message),
+            span: None,
+        }
+    }
+
+    /// Runs the executable on the module `module_path`, whose source is the `body` of the file
+    /// `file_id`, then on each of its inline submodules, adding the items it prints to each.
+    fn expand_module(
+        &self,
+        mut ast: SortedModule,
+        file_id: FileId,
+        module_path: &str,
+        body: Span,
+        context: &HirContext,
+    ) -> Result<SortedModule, (MacroError, FileId)> {
+        let source = context.file_manager.fetch_file(file_id);
+        let input = format!(
+            "// module: {module_path}\n// file: {}\n{}",
+            context.file_manager.path(file_id).display(),
+            module_source(source, body, &ast)
+        );
+
+        let generated =
+            self.run(&input).map_err(|message| (self.error("failed", message), file_id))?;
+        let (generated, parser_errors) = parse_program(&generated);
+        if let Some(parser_error) = parser_errors.first() {
+            let error = self.error("generated invalid code", parser_error.to_string());
+            return Err((error, file_id));
+        }
+
+        // Submodules generated by other macros have no source within the file
+        let mut submodules = Vec::with_capacity(ast.submodules.len());
+        for mut submodule in std::mem::take(&mut ast.submodules) {
+            if contains(body, submodule.span) {
+                let submodule_path = format!("{module_path}::{}", submodule.name);
+                let body = submodule_body(source, submodule.span);
+                submodule.contents = self.expand_module(
+                    submodule.contents,
+                    file_id,
+                    &submodule_path,
+                    body,
+                    context,
+                )?;
+            }
+            submodules.push(submodule);
+        }
+        ast.submodules = submodules;
+
+        ast.extend(generated.into_sorted());
+        Ok(ast)
+    }
+}
+
+/// Returns the source of a module within `body`, leaving out its inline submodules.
+fn module_source(source: &str, body: Span, ast: &SortedModule) -> String {
+    let mut module_source = String::new();
+    let mut start = body.start() as usize;
+    let mut submodule_spans: Vec<_> = ast
+        .submodules
+        .iter()
+        .map(|module| module.span)
+        .filter(|span| contains(body, *span))
+        .collect();
+    submodule_spans.sort_by_key(|span| span.start());
+    for span in submodule_spans {
+        module_source.push_str(&source[start..span.start() as usize]);
+        start = span.end() as usize;
+    }
+    module_source.push_str(&source[start..body.end() as usize]);
+    module_source
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start() <= inner.start() && inner.end() <= outer.end()
+}
+
+/// Returns the span between the braces of an inline submodule such as `mod foo { ... }`.
+fn submodule_body(source: &str, span: Span) -> Span {
+    let text = &source[span.start() as usize..span.end() as usize];
+    let open = text.find('{').map_or(0, |index| index + 1);
+    let close = text.rfind('}').unwrap_or(text.len()).max(open);
+    Span::from(span.start() + open as u32..span.start() + close as u32)
+}
+
+impl MacroProcessor for CommandMacro {
+    fn process_untyped_ast(
+        &self,
+        ast: SortedModule,
+        crate_id: &CrateId,
+        context: &HirContext,
+    ) -> Result<SortedModule, (MacroError, FileId)> {
+        let root_file_id = context.crate_graph[*crate_id].root_file_id;
+        let source = context.file_manager.fetch_file(root_file_id);
+        let body = Span::from(0..source.len() as u32);
+        self.expand_module(ast, root_file_id, "crate", body, context)
+    }
+
+    fn process_untyped_module_file(
+        &self,
+        ast: SortedModule,
+        _crate_id: &CrateId,
+        file_id: FileId,
+        module_path: &str,
+        context: &HirContext,
+    ) -> Result<SortedModule, (MacroError, FileId)> {
+        let source = context.file_manager.fetch_file(file_id);
+        let body = Span::from(0..source.len() as u32);
+        self.expand_module(ast, file_id, &format!("crate::{module_path}"), body, context)
+    }
+
+    fn process_typed_ast(&self, _crate_id: &CrateId, _context: &mut HirContext) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use noirc_frontend::macros_api::{CrateId, FileId, HirContext, MacroError, SortedModule};
+
+    use noirc_frontend::macros_api::Span;
+    use noirc_frontend::parse_program;
+
+    use super::{
+        find_macro, load_macro, module_source, register_macro, submodule_body, MacroProcessor,
+        MACROS_DIR,
+    };
+
+    struct IdentityMacro;
+
+    impl MacroProcessor for IdentityMacro {
+        fn process_untyped_ast(
+            &self,
+            ast: SortedModule,
+            _crate_id: &CrateId,
+            _context: &HirContext,
+        ) -> Result<SortedModule, (MacroError, FileId)> {
+            Ok(ast)
+        }
+
+        fn process_typed_ast(&self, _crate_id: &CrateId, _context: &mut HirContext) {}
+    }
+
+    #[test]
+    fn finds_registered_macros() {
+        assert!(find_macro("identity").is_none());
+        register_macro("identity", &IdentityMacro);
+        assert!(find_macro("identity").is_some());
+    }
+
+    #[test]
+    fn loads_macros_provided_by_packages() {
+        let package_root = tempfile::tempdir().unwrap();
+        assert!(load_macro(package_root.path(), "generate").is_none());
+
+        let macros_dir = package_root.path().join(MACROS_DIR);
+        std::fs::create_dir(&macros_dir).unwrap();
+        std::fs::write(macros_dir.join("generate"), "").unwrap();
+        assert!(load_macro(package_root.path(), "generate").is_some());
+    }
+
+    #[test]
+    fn splits_inline_submodules_from_their_parent() {
+        let source = "struct Foo {}\nmod bar {\n    struct Bar {}\n}\nstruct Baz {}\n";
+        let (module, errors) = parse_program(source);
+        assert!(errors.is_empty());
+        let module = module.into_sorted();
+
+        let body = Span::from(0..source.len() as u32);
+        assert_eq!(module_source(source, body, &module), "struct Foo {}\n\nstruct Baz {}\n");
+
+        let submodule_span = module.submodules[0].span;
+        let body = submodule_body(source, submodule_span);
+        assert_eq!(&source[body.start() as usize..body.end() as usize], "\n    struct Bar {}\n");
+    }
+}
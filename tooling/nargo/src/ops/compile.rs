@@ -1,6 +1,7 @@
 use acvm::ExpressionWidth;
 use fm::FileManager;
 use noirc_driver::{CompilationResult, CompileOptions, CompiledContract, CompiledProgram};
+use noirc_errors::reporter::MessageFormat;
 
 use crate::errors::CompileError;
 use crate::prepare_package;
@@ -42,6 +43,7 @@ pub fn compile_workspace(
                 file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -53,6 +55,7 @@ pub fn compile_workspace(
                 file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -112,6 +115,7 @@ pub(crate) fn report_errors<T>(
     file_manager: &FileManager,
     deny_warnings: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> Result<T, CompileError> {
     let (t, warnings) = result.map_err(|errors| {
         noirc_errors::reporter::report_all(
@@ -119,6 +123,7 @@ pub(crate) fn report_errors<T>(
             &errors,
             deny_warnings,
             silence_warnings,
+            message_format,
         )
     })?;
 
@@ -127,6 +132,7 @@ pub(crate) fn report_errors<T>(
         &warnings,
         deny_warnings,
         silence_warnings,
+        message_format,
     );
 
     Ok(t)
@@ -3,6 +3,7 @@ use std::{collections::BTreeMap, fmt::Display, path::PathBuf};
 use noirc_frontend::graph::CrateName;
 
 use crate::constants::{PROVER_INPUT_FILE, VERIFIER_INPUT_FILE};
+use crate::macros::PackageMacro;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum PackageType {
@@ -39,6 +40,13 @@ impl Dependency {
             Self::Local { package } | Self::Remote { package } => &package.name,
         }
     }
+
+    /// The macros the package provides, which run on the package depending on it
+    pub fn macros(&self) -> &[PackageMacro] {
+        match self {
+            Self::Local { package } | Self::Remote { package } => &package.macros,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -51,6 +59,8 @@ pub struct Package {
     pub entry_path: PathBuf,
     pub name: CrateName,
     pub dependencies: BTreeMap<CrateName, Dependency>,
+    /// The macros this package provides to the packages which depend on it
+    pub macros: Vec<PackageMacro>,
 }
 
 impl Package {
@@ -3,7 +3,7 @@ use crate::errors::CliError;
 
 use clap::Args;
 use fm::FileManager;
-use iter_extended::btree_map;
+use iter_extended::{btree_map, vecmap};
 use nargo::{
     errors::CompileError, insert_all_files_for_workspace_into_file_manager, package::Package,
     prepare_package,
@@ -11,12 +11,14 @@ use nargo::{
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::{AbiParameter, AbiType, MAIN_RETURN_NAME};
 use noirc_driver::{
-    check_crate, compute_function_abi, file_manager_with_stdlib, CompileOptions,
-    NOIR_ARTIFACT_VERSION_STRING,
+    check_crate, check_ssa_no_compile, compute_function_abi, file_manager_with_stdlib,
+    CompileOptions, NOIR_ARTIFACT_VERSION_STRING,
 };
+use noirc_errors::{reporter::MessageFormat, FileDiagnostic};
 use noirc_frontend::{
     graph::{CrateId, CrateName},
     hir::Context,
+    node_interner::FuncId,
 };
 
 use super::fs::write_to_file;
@@ -74,14 +76,32 @@ fn check_package(
         compile_options.deny_warnings,
         compile_options.disable_macros,
         compile_options.silence_warnings,
+        compile_options.message_format,
     )?;
 
-    if package.is_library() || package.is_contract() {
-        // Libraries do not have ABIs while contracts have many, so we cannot generate a `Prover.toml` file.
+    if package.is_library() {
+        // Libraries do not have ABIs, so we cannot generate a `Prover.toml` file.
+        // Nor do they have entry points to run the SSA passes from, as their functions
+        // may be generic, so only the packages using them report their SSA warnings.
         Ok(())
+    } else if package.is_contract() {
+        // Contracts have many ABIs, so we cannot generate a `Prover.toml` file.
+        let entry_points = context
+            .get_all_contracts(&crate_id)
+            .into_iter()
+            .flat_map(|contract| contract.functions)
+            .filter(|function| function.is_entry_point)
+            .map(|function| function.function_id)
+            .collect();
+        check_ssa_warnings(&context, entry_points, compile_options)
     } else {
         // XXX: We can have a --overwrite flag to determine if you want to overwrite the Prover/Verifier.toml files
         if let Some((parameters, return_type)) = compute_function_abi(&context, &crate_id) {
+            let main = context
+                .get_main_function(&crate_id)
+                .expect("Expected package to have a main function");
+            check_ssa_warnings(&context, vec![main], compile_options)?;
+
             let path_to_prover_input = package.prover_input_path();
             let path_to_verifier_input = package.verifier_input_path();
 
@@ -105,6 +125,41 @@ fn check_package(
     }
 }
 
+/// Runs the SSA passes over the given entry points to report the warnings they find, such as
+/// integer operations on constants which are known to overflow. ACIR is not generated, so
+/// errors which are only found while compiling are left to `nargo compile`.
+fn check_ssa_warnings(
+    context: &Context,
+    entry_points: Vec<FuncId>,
+    compile_options: &CompileOptions,
+) -> Result<(), CompileError> {
+    let result = entry_points
+        .into_iter()
+        .try_fold(Vec::new(), |mut warnings, entry_point| {
+            check_ssa_no_compile(context, compile_options, entry_point).map(|new_warnings| {
+                warnings.extend(new_warnings);
+                warnings
+            })
+        })
+        .map_err(|error| vec![FileDiagnostic::from(error)])
+        .and_then(|warnings| {
+            let warnings = vecmap(warnings, FileDiagnostic::from);
+            let has_errors = warnings.iter().any(|warning| warning.diagnostic.is_error());
+            if has_errors || (compile_options.deny_warnings && !warnings.is_empty()) {
+                Err(warnings)
+            } else {
+                Ok(((), warnings))
+            }
+        });
+    super::compile_cmd::report_errors(
+        result,
+        &context.file_manager,
+        compile_options.deny_warnings,
+        compile_options.silence_warnings,
+        compile_options.message_format,
+    )
+}
+
 /// Generates the contents of a toml file with fields for each of the passed parameters.
 fn create_input_toml_template(
     parameters: Vec<AbiParameter>,
@@ -196,6 +251,7 @@ pub(crate) fn check_crate_and_report_errors(
     deny_warnings: bool,
     disable_macros: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> Result<(), CompileError> {
     let result = check_crate(context, crate_id, deny_warnings, disable_macros);
     super::compile_cmd::report_errors(
@@ -203,5 +259,6 @@ pub(crate) fn check_crate_and_report_errors(
         &context.file_manager,
         deny_warnings,
         silence_warnings,
+        message_format,
     )
 }
@@ -16,6 +16,7 @@ use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelec
 use noirc_driver::file_manager_with_stdlib;
 use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
 use noirc_driver::{CompilationResult, CompileOptions, CompiledContract, CompiledProgram};
+use noirc_errors::reporter::MessageFormat;
 use noirc_frontend::graph::CrateName;
 
 use clap::Args;
@@ -119,6 +120,7 @@ pub(super) fn compile_workspace(
                 file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -130,6 +132,7 @@ pub(super) fn compile_workspace(
                 file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -156,6 +159,7 @@ pub(crate) fn compile_bin_package(
         file_manager,
         compile_options.deny_warnings,
         compile_options.silence_warnings,
+        compile_options.message_format,
     )?;
 
     Ok(program)
@@ -306,6 +310,7 @@ pub(crate) fn report_errors<T>(
     file_manager: &FileManager,
     deny_warnings: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> Result<T, CompileError> {
     let (t, warnings) = result.map_err(|errors| {
         noirc_errors::reporter::report_all(
@@ -313,6 +318,7 @@ pub(crate) fn report_errors<T>(
             &errors,
             deny_warnings,
             silence_warnings,
+            message_format,
         )
     })?;
 
@@ -321,6 +327,7 @@ pub(crate) fn report_errors<T>(
         &warnings,
         deny_warnings,
         silence_warnings,
+        message_format,
     );
 
     Ok(t)
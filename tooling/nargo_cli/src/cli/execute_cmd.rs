@@ -14,6 +14,7 @@ use noirc_abi::InputMap;
 use noirc_driver::{
     file_manager_with_stdlib, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
 };
+use noirc_errors::reporter::MessageFormat;
 use noirc_frontend::graph::CrateName;
 
 use super::compile_cmd::compile_bin_package;
@@ -82,6 +83,7 @@ pub(crate) fn run(
             package,
             &args.prover_name,
             args.oracle_resolver.as_deref(),
+            args.compile_options.message_format,
         )?;
 
         println!("[{}] Circuit witness successfully solved", package.name);
@@ -102,11 +104,13 @@ fn execute_program_and_decode(
     package: &Package,
     prover_name: &str,
     foreign_call_resolver_url: Option<&str>,
+    message_format: MessageFormat,
 ) -> Result<(Option<InputValue>, WitnessMap), CliError> {
     // Parse the initial witness values from Prover.toml
     let (inputs_map, _) =
         read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &program.abi)?;
-    let solved_witness = execute_program(&program, &inputs_map, foreign_call_resolver_url)?;
+    let solved_witness =
+        execute_program(&program, &inputs_map, foreign_call_resolver_url, message_format)?;
     let public_abi = program.abi.public_abi();
     let (_, return_value) = public_abi.decode(&solved_witness)?;
 
@@ -117,6 +121,7 @@ pub(crate) fn execute_program(
     compiled_program: &CompiledProgram,
     inputs_map: &InputMap,
     foreign_call_resolver_url: Option<&str>,
+    message_format: MessageFormat,
 ) -> Result<WitnessMap, CliError> {
     let blackbox_solver = Bn254BlackBoxSolver::new();
 
@@ -138,7 +143,7 @@ pub(crate) fn execute_program(
             };
 
             if let Some(diagnostic) = try_to_diagnose_runtime_error(&err, &compiled_program.debug) {
-                diagnostic.report(&debug_artifact, false);
+                diagnostic.report(&debug_artifact, false, message_format);
             }
 
             Err(crate::errors::CliError::NargoError(err))
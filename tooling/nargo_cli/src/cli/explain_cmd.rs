@@ -0,0 +1,26 @@
+use clap::Args;
+use noirc_errors::error_codes;
+
+use crate::errors::CliError;
+
+/// Print a detailed explanation of an error code
+///
+/// Every error and warning reported by the compiler has a code, such as `E0201`, which is shown
+/// next to it. This prints a longer description of what causes it, usually with an example.
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ExplainCommand {
+    /// The error code to explain, such as `E0201`
+    code: String,
+}
+
+pub(crate) fn run(args: ExplainCommand) -> Result<(), CliError> {
+    let code = args.code.trim().to_uppercase();
+
+    match error_codes::explain(&code) {
+        Some(explanation) => {
+            println!("{explanation}");
+            Ok(())
+        }
+        None => Err(CliError::Generic(format!("`{}` is not a known error code", args.code))),
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+
+use clap::Args;
+use fm::FileId;
+use nargo::{insert_all_files_for_workspace_into_file_manager, prepare_package};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_driver::{check_crate, file_manager_with_stdlib, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_errors::{Applicability, CustomSuggestion, FileDiagnostic};
+use noirc_frontend::graph::CrateName;
+
+use crate::errors::CliError;
+
+use super::NargoConfig;
+
+/// Apply the fixes suggested by the compiler's errors and warnings
+///
+/// Only suggestions which are known not to change the meaning of the program are applied, such as
+/// removing an unnecessary `mut` or prefixing an unused variable with an underscore.
+#[derive(Debug, Clone, Args)]
+pub(crate) struct FixCommand {
+    /// The name of the package to fix
+    #[clap(long, conflicts_with = "workspace")]
+    package: Option<CrateName>,
+
+    /// Fix all packages in the workspace
+    #[clap(long, conflicts_with = "package")]
+    workspace: bool,
+
+    /// Disable any macros
+    #[arg(long, hide = true)]
+    disable_macros: bool,
+}
+
+pub(crate) fn run(args: FixCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let default_selection =
+        if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
+    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        selection,
+        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+    )?;
+
+    let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+
+    // Suggestions are collected for all packages before any are applied, since a file
+    // shared by several packages would otherwise be reported and fixed more than once.
+    let mut suggestions: BTreeMap<FileId, Vec<CustomSuggestion>> = BTreeMap::new();
+    for package in &workspace {
+        let (mut context, crate_id) = prepare_package(&workspace_file_manager, package);
+        let diagnostics = match check_crate(&mut context, crate_id, false, args.disable_macros) {
+            Ok(((), warnings)) => warnings,
+            Err(errors) => errors,
+        };
+
+        for FileDiagnostic { file_id, diagnostic, .. } in diagnostics {
+            let path = workspace_file_manager.path(file_id);
+            if !path.starts_with(&workspace.root_dir) {
+                // Don't modify the standard library or dependencies outside of the workspace
+                continue;
+            }
+
+            let fixes = diagnostic
+                .suggestions
+                .into_iter()
+                .filter(|suggestion| suggestion.applicability == Applicability::MachineApplicable);
+            suggestions.entry(file_id).or_default().extend(fixes);
+        }
+    }
+
+    let mut fix_count = 0;
+    for (file_id, suggestions) in suggestions {
+        let source = workspace_file_manager.fetch_file(file_id);
+        let (fixed_source, applied) = apply_suggestions(source, suggestions);
+        if applied == 0 {
+            continue;
+        }
+
+        let path = workspace_file_manager.path(file_id);
+        std::fs::write(path, fixed_source).map_err(|error| CliError::Generic(error.to_string()))?;
+        println!(
+            "Fixed {} ({applied} fix{})",
+            path.display(),
+            if applied == 1 { "" } else { "es" }
+        );
+        fix_count += applied;
+    }
+
+    if fix_count == 0 {
+        println!("No fixes were found");
+    }
+    Ok(())
+}
+
+/// Applies the given suggestions to `source`, returning the fixed source along with the number of
+/// suggestions which were applied. Duplicate suggestions are applied once, and a suggestion which
+/// overlaps one that was already applied is skipped.
+fn apply_suggestions(source: &str, mut suggestions: Vec<CustomSuggestion>) -> (String, usize) {
+    // Apply the suggestions from the end of the file so that the spans of the remaining
+    // suggestions still point at the same source.
+    suggestions.sort_by_key(|suggestion| (suggestion.span.start(), suggestion.span.end()));
+    suggestions.dedup();
+
+    let mut fixed_source = source.to_string();
+    let mut applied = 0;
+    let mut applied_start = u32::MAX;
+    for suggestion in suggestions.into_iter().rev() {
+        let span = suggestion.span;
+        // An insertion at the start of an applied suggestion is ambiguous, so it is skipped as well.
+        let is_insertion = span.start() == span.end();
+        if span.end() > applied_start || (is_insertion && span.end() == applied_start) {
+            continue;
+        }
+
+        let range = span.start() as usize..span.end() as usize;
+        fixed_source.replace_range(range, &suggestion.replacement);
+        applied_start = span.start();
+        applied += 1;
+    }
+
+    (fixed_source, applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use noirc_errors::{Applicability, CustomSuggestion, Span};
+
+    use super::apply_suggestions;
+
+    fn suggestion(start: u32, end: u32, replacement: &str) -> CustomSuggestion {
+        CustomSuggestion {
+            message: String::new(),
+            span: Span::from(start..end),
+            replacement: replacement.to_string(),
+            applicability: Applicability::MachineApplicable,
+        }
+    }
+
+    #[test]
+    fn applies_suggestions_in_any_order() {
+        let source = "let mut mut x = y;";
+        let suggestions =
+            vec![suggestion(12, 13, "_x"), suggestion(16, 16, "&"), suggestion(8, 12, "")];
+        assert_eq!(apply_suggestions(source, suggestions), ("let mut _x = &y;".to_string(), 3));
+    }
+
+    #[test]
+    fn skips_duplicate_and_overlapping_suggestions() {
+        let source = "let mut mut x = y;";
+        let suggestions = vec![
+            suggestion(12, 13, "_x"),
+            suggestion(12, 13, "_x"),
+            suggestion(4, 13, "z"),
+            suggestion(16, 16, "&"),
+            suggestion(16, 16, "*"),
+        ];
+        let (fixed_source, applied) = apply_suggestions(source, suggestions);
+        assert_eq!(applied, 2);
+        assert_eq!(fixed_source, "let mut mut _x = *y;");
+    }
+}
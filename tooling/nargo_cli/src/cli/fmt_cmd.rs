@@ -4,6 +4,7 @@ use clap::Args;
 use nargo::insert_all_files_for_workspace_into_file_manager;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::{file_manager_with_stdlib, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_errors::reporter::MessageFormat;
 use noirc_errors::CustomDiagnostic;
 use noirc_frontend::{hir::def_map::parse_file, parser::ParserError};
 
@@ -57,6 +58,7 @@ pub(crate) fn run(args: FormatCommand, config: NargoConfig) -> Result<(), CliErr
                     &workspace_file_manager,
                     false,
                     false,
+                    MessageFormat::Human,
                 );
                 return Ok(());
             }
@@ -17,6 +17,8 @@ mod compile_cmd;
 mod dap_cmd;
 mod debug_cmd;
 mod execute_cmd;
+mod explain_cmd;
+mod fix_cmd;
 mod fmt_cmd;
 mod info_cmd;
 mod init_cmd;
@@ -62,6 +64,7 @@ enum NargoCommand {
     Backend(backend_cmd::BackendCommand),
     Check(check_cmd::CheckCommand),
     Fmt(fmt_cmd::FormatCommand),
+    Fix(fix_cmd::FixCommand),
     CodegenVerifier(codegen_verifier_cmd::CodegenVerifierCommand),
     #[command(alias = "build")]
     Compile(compile_cmd::CompileCommand),
@@ -74,6 +77,7 @@ enum NargoCommand {
     Verify(verify_cmd::VerifyCommand),
     Test(test_cmd::TestCommand),
     Info(info_cmd::InfoCommand),
+    Explain(explain_cmd::ExplainCommand),
     Lsp(lsp_cmd::LspCommand),
     #[command(hide = true)]
     Dap(dap_cmd::DapCommand),
@@ -95,6 +99,7 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
             | NargoCommand::Lsp(_)
             | NargoCommand::Backend(_)
             | NargoCommand::Dap(_)
+            | NargoCommand::Explain(_)
     ) {
         config.program_dir = find_package_root(&config.program_dir)?;
     }
@@ -118,6 +123,8 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
         NargoCommand::Lsp(args) => lsp_cmd::run(&backend, args, config),
         NargoCommand::Dap(args) => dap_cmd::run(&backend, args, config),
         NargoCommand::Fmt(args) => fmt_cmd::run(args, config),
+        NargoCommand::Fix(args) => fix_cmd::run(args, config),
+        NargoCommand::Explain(args) => explain_cmd::run(args),
     }?;
 
     Ok(())
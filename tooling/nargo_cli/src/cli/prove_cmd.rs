@@ -8,6 +8,7 @@ use noirc_abi::input_parser::Format;
 use noirc_driver::{
     file_manager_with_stdlib, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
 };
+use noirc_errors::reporter::MessageFormat;
 use noirc_frontend::graph::CrateName;
 
 use super::compile_cmd::compile_bin_package;
@@ -86,6 +87,7 @@ pub(crate) fn run(
             &args.verifier_name,
             args.verify,
             args.oracle_resolver.as_deref(),
+            args.compile_options.message_format,
         )?;
     }
 
@@ -102,13 +104,14 @@ pub(crate) fn prove_package(
     verifier_name: &str,
     check_proof: bool,
     foreign_call_resolver_url: Option<&str>,
+    message_format: MessageFormat,
 ) -> Result<(), CliError> {
     // Parse the initial witness values from Prover.toml
     let (inputs_map, _) =
         read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &compiled_program.abi)?;
 
     let solved_witness =
-        execute_program(&compiled_program, &inputs_map, foreign_call_resolver_url)?;
+        execute_program(&compiled_program, &inputs_map, foreign_call_resolver_url, message_format)?;
 
     // Write public inputs into Verifier.toml
     let public_abi = compiled_program.abi.public_abi();
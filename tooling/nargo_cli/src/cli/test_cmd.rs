@@ -112,6 +112,7 @@ fn run_tests<S: BlackBoxFunctionSolver>(
         compile_options.deny_warnings,
         compile_options.disable_macros,
         compile_options.silence_warnings,
+        compile_options.message_format,
     )?;
 
     let test_functions = context.get_all_test_functions_in_crate_matching(&crate_id, fn_name);
@@ -172,6 +173,7 @@ fn run_tests<S: BlackBoxFunctionSolver>(
                         &[diag],
                         compile_options.deny_warnings,
                         compile_options.silence_warnings,
+                        compile_options.message_format,
                     );
                 }
                 count_failed += 1;
@@ -182,6 +184,7 @@ fn run_tests<S: BlackBoxFunctionSolver>(
                     &[err],
                     compile_options.deny_warnings,
                     compile_options.silence_warnings,
+                    compile_options.message_format,
                 );
                 count_failed += 1;
             }
@@ -0,0 +1,58 @@
+//! This integration test checks that `nargo check` reports the warnings found by the SSA passes,
+//! such as integer operations on constants which overflow, for binary and contract packages.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+use assert_fs::prelude::{FileWriteStr, PathChild};
+
+const OVERFLOW_MESSAGE: &str = "the result 300 does not fit within type u8";
+
+fn write_package(test_dir: &assert_fs::TempDir, package_type: &str, source: &str) {
+    test_dir
+        .child("Nargo.toml")
+        .write_str(&format!(
+            "[package]\nname = \"overflow\"\ntype = \"{package_type}\"\nauthors = [\"\"]\n\n[dependencies]\n"
+        ))
+        .unwrap();
+    test_dir.child("src").child("main.nr").write_str(source).unwrap();
+}
+
+#[test]
+fn check_reports_integer_overflow_in_binaries() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+    write_package(
+        &test_dir,
+        "bin",
+        "fn main(x: u8) {\n    let y: u8 = 200 + 100;\n    assert(x != y);\n}\n",
+    );
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&test_dir).arg("check");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("E0512").and(predicate::str::contains(OVERFLOW_MESSAGE)));
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&test_dir).arg("check").arg("--deny-warnings");
+    cmd.assert().failure().stderr(predicate::str::contains(OVERFLOW_MESSAGE));
+}
+
+#[test]
+fn check_reports_integer_overflow_in_contracts() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+    write_package(
+        &test_dir,
+        "contract",
+        "contract Foo {\n    fn overflow(x: u8) -> pub u8 {\n        let y: u8 = 200 + 100;\n        x + y\n    }\n}\n",
+    );
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&test_dir).arg("check");
+    cmd.assert().success().stderr(predicate::str::contains(OVERFLOW_MESSAGE));
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&test_dir).arg("check").arg("--deny-warnings");
+    cmd.assert().failure().stderr(predicate::str::contains(OVERFLOW_MESSAGE));
+}
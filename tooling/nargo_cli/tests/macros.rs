@@ -0,0 +1,142 @@
+//! This integration test checks that a macro provided by a library package transforms the
+//! code of the packages which depend on it, as documented for the `macros` field of `Nargo.toml`.
+#![cfg(unix)]
+
+use assert_cmd::prelude::*;
+use std::os::unix::fs::PermissionsExt;
+use std::process::Command;
+
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use assert_fs::TempDir;
+
+/// Writes the `answers` library, whose `answer` macro gives every struct of the module
+/// it runs on an `answer` method, and returns the directory of the package depending on it.
+fn write_packages(test_dir: &TempDir, allowed_macros: &str) -> assert_fs::fixture::ChildPath {
+    let library_dir = test_dir.child("answers");
+    library_dir
+        .child("Nargo.toml")
+        .write_str(
+            r#"
+            [package]
+            name = "answers"
+            type = "lib"
+            authors = [""]
+            macros = ["answer"]
+
+            [dependencies]
+            "#,
+        )
+        .unwrap();
+    library_dir.child("src").child("lib.nr").write_str("").unwrap();
+
+    let macro_file = library_dir.child("macros").child("answer");
+    macro_file
+        .write_str(
+            "#!/bin/sh\nsed -n 's/^\\(pub \\)\\{0,1\\}struct \\([A-Za-z_]*\\).*/impl \\2 { pub fn answer() -> Field { 42 } }/p'\n",
+        )
+        .unwrap();
+    std::fs::set_permissions(&macro_file, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let binary_dir = test_dir.child("question");
+    binary_dir
+        .child("Nargo.toml")
+        .write_str(&format!(
+            r#"
+            [package]
+            name = "question"
+            type = "bin"
+            authors = [""]
+            {allowed_macros}
+
+            [dependencies]
+            answers = {{ path = "../answers" }}
+            "#
+        ))
+        .unwrap();
+    binary_dir.child("Prover.toml").write_str("x = 42").unwrap();
+    binary_dir
+}
+
+#[test]
+fn macros_transform_dependent_packages() {
+    let test_dir = TempDir::new().unwrap();
+    let binary_dir = write_packages(&test_dir, r#"allowed_macros = ["answer"]"#);
+    binary_dir
+        .child("src")
+        .child("main.nr")
+        .write_str(
+            "struct Question {}\n\nfn main(x: Field) {\n    assert(x == Question::answer());\n}\n",
+        )
+        .unwrap();
+
+    // `Question::answer` only exists once the macro of `answers` has run on `question`.
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&binary_dir).arg("execute");
+    cmd.assert().success();
+
+    // Nor does it exist when macros are disabled
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&binary_dir).arg("check").arg("--disable-macros");
+    cmd.assert().failure();
+}
+
+#[test]
+fn macros_transform_submodules() {
+    let test_dir = TempDir::new().unwrap();
+    let binary_dir = write_packages(&test_dir, r#"allowed_macros = ["answer"]"#);
+    binary_dir
+        .child("src")
+        .child("main.nr")
+        .write_str(
+            "mod shapes;\n\nfn main(x: Field) {\n    assert(x == shapes::Circle::answer());\n    assert(x == shapes::inner::Square::answer());\n}\n",
+        )
+        .unwrap();
+    // The generated methods must be added to the module of each struct to be found.
+    binary_dir
+        .child("src")
+        .child("shapes.nr")
+        .write_str("pub struct Circle {}\n\nmod inner {\npub struct Square {}\n}\n")
+        .unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&binary_dir).arg("execute");
+    cmd.assert().success();
+}
+
+#[test]
+fn macros_must_be_allowed_by_dependent_packages() {
+    let test_dir = TempDir::new().unwrap();
+    let binary_dir = write_packages(&test_dir, "");
+    binary_dir.child("src").child("main.nr").write_str("fn main(x: Field) {}\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&binary_dir).arg("check");
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("Dependency `answers` provides the macro `answer`"));
+}
+
+#[test]
+fn undeclared_macro_executables_are_reported() {
+    let test_dir = TempDir::new().unwrap();
+
+    test_dir
+        .child("Nargo.toml")
+        .write_str(
+            r#"
+            [package]
+            name = "answers"
+            type = "lib"
+            authors = [""]
+            macros = ["answer"]
+
+            [dependencies]
+            "#,
+        )
+        .unwrap();
+    test_dir.child("src").child("lib.nr").write_str("").unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&test_dir).arg("check");
+    cmd.assert().failure().stderr(predicates::str::contains("Macro `answer` declared in"));
+}
@@ -177,6 +177,10 @@ impl super::FmtVisitor<'_> {
 
                     let keyword = if module.is_contract { "contract" } else { "mod" };
 
+                    for attribute in module.attributes {
+                        self.push_str(&attribute.to_string());
+                        self.push_str(&self.indent.to_string_with_newline());
+                    }
                     self.push_str(&format!("{keyword} {name} "));
 
                     if module.contents.items.is_empty() {
@@ -191,13 +195,14 @@ impl super::FmtVisitor<'_> {
                     self.close_block((self.last_position..span.end() - 1).into());
                     self.last_position = span.end();
                 }
-                ItemKind::Import(_)
+                ItemKind::Import(..)
                 | ItemKind::Struct(_)
+                | ItemKind::Enum(_)
                 | ItemKind::Trait(_)
                 | ItemKind::TraitImpl(_)
                 | ItemKind::Impl(_)
                 | ItemKind::TypeAlias(_)
-                | ItemKind::Global(_)
+                | ItemKind::Global(..)
                 | ItemKind::ModuleDecl(_) => {
                     self.push_rewrite(self.slice(span).to_string(), span);
                     self.last_position = span.end();
@@ -95,7 +95,16 @@ impl super::FmtVisitor<'_> {
                     let result = format!("for {identifier} in {range} {block}");
                     self.push_rewrite(result, span);
                 }
-                StatementKind::Assign(_) => {
+                StatementKind::While(while_stmt) => {
+                    let condition = rewrite::sub_expr(self, self.shape(), while_stmt.condition);
+                    let block = rewrite::sub_expr(self, self.shape(), while_stmt.block);
+
+                    let result = format!("while {condition} {block}");
+                    self.push_rewrite(result, span);
+                }
+                StatementKind::Break => self.push_rewrite("break;".to_string(), span),
+                StatementKind::Continue => self.push_rewrite("continue;".to_string(), span),
+                StatementKind::Assign(_) | StatementKind::Attributed(..) => {
                     self.push_rewrite(self.slice(span).to_string(), span);
                 }
                 StatementKind::Error => unreachable!(),
@@ -61,6 +61,12 @@ pub enum ManifestError {
     #[error("Package `{0}` has type `bin` but you cannot depend on binary packages")]
     BinaryDependency(CrateName),
 
+    #[error("Macro `{name}` declared in {toml} is neither registered in nargo nor provided by an executable at {command}")]
+    UnknownMacro { toml: PathBuf, name: String, command: PathBuf },
+
+    #[error("Dependency `{dependency}` provides the macro `{name}`, which runs code on this package, but it is not listed in the `allowed_macros` field of {toml}")]
+    MacroNotAllowed { toml: PathBuf, dependency: CrateName, name: String },
+
     #[error("Missing `name` field in {toml}")]
     MissingNameField { toml: PathBuf },
 
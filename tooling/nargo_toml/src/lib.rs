@@ -11,6 +11,7 @@ use std::{
 use errors::SemverError;
 use fm::{NormalizePath, FILE_EXTENSION};
 use nargo::{
+    macros::{load_macro, PackageMacro, MACROS_DIR},
     package::{Dependency, Package, PackageType},
     workspace::Workspace,
 };
@@ -141,6 +142,21 @@ impl PackageConfig {
             dependencies.insert(name, resolved_dep);
         }
 
+        // The macros of a dependency run arbitrary code on this package, so each of them
+        // must be allowed by name in this package's own manifest.
+        let allowed_macros = self.package.allowed_macros.as_deref().unwrap_or_default();
+        for (dependency_name, dependency) in &dependencies {
+            for package_macro in dependency.macros() {
+                if !allowed_macros.contains(&package_macro.name) {
+                    return Err(ManifestError::MacroNotAllowed {
+                        toml: root_dir.join("Nargo.toml"),
+                        dependency: dependency_name.clone(),
+                        name: package_macro.name.clone(),
+                    });
+                }
+            }
+        }
+
         let package_type = match self.package.package_type.as_deref() {
             Some("lib") => PackageType::Library,
             Some("bin") => PackageType::Binary,
@@ -195,6 +211,17 @@ impl PackageConfig {
             })?;
         }
 
+        let mut macros = Vec::new();
+        for name in self.package.macros.iter().flatten() {
+            let processor =
+                load_macro(root_dir, name).ok_or_else(|| ManifestError::UnknownMacro {
+                    toml: root_dir.join("Nargo.toml"),
+                    name: name.clone(),
+                    command: root_dir.join(MACROS_DIR).join(name),
+                })?;
+            macros.push(PackageMacro { name: name.clone(), processor });
+        }
+
         Ok(Package {
             version: self.package.version.clone(),
             compiler_required_version: self.package.compiler_version.clone(),
@@ -203,6 +230,7 @@ impl PackageConfig {
             package_type,
             name,
             dependencies,
+            macros,
         })
     }
 }
@@ -271,6 +299,10 @@ struct PackageMetadata {
     // so you will not need to supply an ACIR and compiler version
     compiler_version: Option<String>,
     license: Option<String>,
+    // The names of the macros this package provides to its dependents
+    macros: Option<Vec<String>>,
+    // The names of the macros provided by dependencies which may run on this package
+    allowed_macros: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -468,6 +500,24 @@ fn parse_package_toml_no_deps() {
     assert!(Config::try_from(src).is_ok());
 }
 
+#[test]
+fn parse_package_toml_with_macros() {
+    let src = r#"
+        [package]
+        name = "test"
+        type = "lib"
+        macros = ["serialize", "selector"]
+        allowed_macros = ["answer"]
+    "#;
+
+    let package_config = match Config::try_from(src).unwrap() {
+        Config::Package { package_config } => package_config,
+        Config::Workspace { .. } => panic!("expected a package config"),
+    };
+    assert_eq!(package_config.package.macros, Some(vec!["serialize".into(), "selector".into()]));
+    assert_eq!(package_config.package.allowed_macros, Some(vec!["answer".into()]));
+}
+
 #[test]
 fn parse_workspace_toml() {
     let src = r#"
@@ -88,6 +88,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            macros: Vec::new(),
             version: Some("1.0".to_string()),
         };
         if let Err(err) = semver_check_package(&package, &compiler_version) {
@@ -119,6 +120,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            macros: Vec::new(),
             version: Some("1.0".to_string()),
         };
 
@@ -129,6 +131,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("good_dependency").unwrap(),
             dependencies: BTreeMap::new(),
+            macros: Vec::new(),
             version: Some("1.0".to_string()),
         };
         let invalid_dependency = Package {
@@ -138,6 +141,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("bad_dependency").unwrap(),
             dependencies: BTreeMap::new(),
+            macros: Vec::new(),
             version: Some("1.0".to_string()),
         };
 
@@ -178,6 +182,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            macros: Vec::new(),
             version: Some("1.0".to_string()),
         };
 
@@ -197,6 +202,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            macros: Vec::new(),
             version: Some("1.0".to_string()),
         };
 